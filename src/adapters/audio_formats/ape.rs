@@ -0,0 +1,519 @@
+//! Monkey's Audio (APE) format implementation of the AudioFile trait.
+//!
+//! APE files carry APEv2 tags, which lofty maps to the same item keys as the
+//! other formats.
+
+use lofty::{
+    config::WriteOptions,
+    file::{AudioFile as LoftyAudioFile, TaggedFile, TaggedFileExt},
+    prelude::ItemKey,
+    read_from_path,
+    tag::{ItemValue, TagItem},
+};
+
+use crate::adapters::audio_formats::tag_fields::{
+    MusicBrainzId, is_track_artists_key, item_value_text, musicbrainz_id_kind, parse_bpm,
+    parse_flag, parse_original_year, parse_replay_gain, push_track_artists, read_duration_only,
+    stream_format, track_duration, write_comment,
+};
+use std::path::Path;
+
+use crate::core::domain::models::{
+    FOLDER_INFERRED_CONFIDENCE, MetadataValue, Track, TrackMetadata,
+};
+use crate::core::domain::traits::{AudioFile, AudioFileError};
+use crate::core::services::inference::{infer_album_from_path, infer_artist_from_path};
+
+/// Monkey's Audio format handler
+pub struct ApeHandler;
+
+impl ApeHandler {
+    /// Create a new Monkey's Audio handler
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for ApeHandler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AudioFile for ApeHandler {
+    fn can_handle(&self, path: &Path) -> bool {
+        path.extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("ape"))
+    }
+
+    fn supported_extensions(&self) -> Vec<&'static str> {
+        vec!["ape"]
+    }
+
+    fn mime_type(&self) -> &'static str {
+        "audio/x-ape"
+    }
+
+    fn read_metadata(&self, path: &Path) -> Result<Track, AudioFileError> {
+        if !self.can_handle(path) {
+            return Err(AudioFileError::UnsupportedFormat);
+        }
+
+        // Use lofty to read the file
+        let tagged_file = read_from_path(path)
+            .map_err(|e| AudioFileError::InvalidFile(format!("Failed to read APE file: {}", e)))?;
+
+        // Extract metadata from tags and file properties
+        let metadata = self.extract_metadata_from_tags(&tagged_file, path);
+
+        Ok(Track::new(path.to_path_buf(), metadata))
+    }
+
+    fn write_metadata(&self, path: &Path, metadata: &TrackMetadata) -> Result<(), AudioFileError> {
+        if !self.can_handle(path) {
+            return Err(AudioFileError::UnsupportedFormat);
+        }
+
+        // Use lofty to write the APEv2 tag
+        let mut tagged_file = read_from_path(path)
+            .map_err(|e| AudioFileError::InvalidFile(format!("Failed to read APE file: {}", e)))?;
+
+        // Get or create the primary tag
+        let tag = tagged_file
+            .primary_tag_mut()
+            .ok_or_else(|| AudioFileError::WriteError("APE file has no primary tag".to_string()))?;
+
+        // Helper function to set a tag item
+        let mut set_tag = |key: ItemKey, value: &str| {
+            tag.insert(TagItem::new(key, ItemValue::Text(value.to_string())));
+        };
+
+        // Write metadata fields that have values
+        if let Some(ref title) = metadata.title {
+            set_tag(ItemKey::TrackTitle, &title.value);
+        }
+
+        if let Some(ref artist) = metadata.artist {
+            set_tag(ItemKey::TrackArtist, &artist.value);
+        }
+
+        if let Some(ref album) = metadata.album {
+            set_tag(ItemKey::AlbumTitle, &album.value);
+        }
+
+        if let Some(ref album_artist) = metadata.album_artist {
+            set_tag(ItemKey::AlbumArtist, &album_artist.value);
+        }
+
+        if let Some(ref track_number) = metadata.track_number {
+            set_tag(ItemKey::TrackNumber, &track_number.value.to_string());
+        }
+
+        if let Some(ref disc_number) = metadata.disc_number {
+            set_tag(ItemKey::DiscNumber, &disc_number.value.to_string());
+        }
+
+        if let Some(ref year) = metadata.year {
+            set_tag(ItemKey::Year, &year.value.to_string());
+        }
+
+        if let Some(ref genre) = metadata.genre {
+            set_tag(ItemKey::Genre, &genre.value);
+        }
+
+        if let Some(ref media) = metadata.media {
+            set_tag(ItemKey::OriginalMediaType, &media.value);
+        }
+
+        if let Some(ref barcode) = metadata.barcode {
+            set_tag(ItemKey::Barcode, &barcode.value);
+        }
+
+        write_comment(tag, metadata.comment.as_ref());
+
+        // Save the changes to disk with default write options
+        let write_options = WriteOptions::default();
+        tagged_file
+            .save_to_path(path, write_options)
+            .map_err(|e| AudioFileError::WriteError(format!("Failed to save APE file: {}", e)))?;
+
+        Ok(())
+    }
+
+    fn read_basic_info(&self, path: &Path) -> Result<TrackMetadata, AudioFileError> {
+        if !self.can_handle(path) {
+            return Err(AudioFileError::UnsupportedFormat);
+        }
+
+        let tagged_file = read_from_path(path)
+            .map_err(|e| AudioFileError::InvalidFile(format!("Failed to read APE file: {}", e)))?;
+
+        Ok(self.extract_basic_metadata(&tagged_file, path))
+    }
+
+    fn read_duration(&self, path: &Path) -> Result<Option<f64>, AudioFileError> {
+        if !self.can_handle(path) {
+            return Err(AudioFileError::UnsupportedFormat);
+        }
+
+        read_duration_only(path, "APE")
+    }
+}
+
+impl ApeHandler {
+    /// Extract metadata from lofty TaggedFile and convert to our TrackMetadata
+    fn extract_metadata_from_tags(&self, tagged_file: &TaggedFile, path: &Path) -> TrackMetadata {
+        let mut title = None;
+        let mut artist = None;
+        let mut album = None;
+        let mut album_artist = None;
+        let mut track_number = None;
+        let mut disc_number = None;
+        let mut year = None;
+        let mut genre = None;
+        let mut media = None;
+        let mut barcode = None;
+        let mut bpm = None;
+        let mut initial_key = None;
+        let mut comment = None;
+        let mut compilation = None;
+        let mut original_year = None;
+        let mut musicbrainz_recording_id = None;
+        let mut musicbrainz_release_id = None;
+        let mut artists = None;
+        let mut track_gain = None;
+        let mut track_peak = None;
+        let mut album_gain = None;
+        let mut album_peak = None;
+
+        // Get the primary tag
+        if let Some(tag) = tagged_file.primary_tag() {
+            for tag_item in tag.items() {
+                // Helper function to convert ItemValue to string
+                let item_value_str = item_value_text(tag_item);
+
+                match tag_item.key() {
+                    ItemKey::TrackTitle => {
+                        title = Some(MetadataValue::embedded(item_value_str));
+                    }
+                    ItemKey::TrackArtist => {
+                        artist = Some(MetadataValue::embedded(item_value_str));
+                    }
+                    ItemKey::AlbumTitle => {
+                        album = Some(MetadataValue::embedded(item_value_str));
+                    }
+                    ItemKey::AlbumArtist => {
+                        album_artist = Some(MetadataValue::embedded(item_value_str));
+                    }
+                    ItemKey::TrackNumber => {
+                        if let Ok(num) = item_value_str.parse::<u32>() {
+                            track_number = Some(MetadataValue::embedded(num));
+                        }
+                    }
+                    ItemKey::DiscNumber => {
+                        if let Ok(num) = item_value_str.parse::<u32>() {
+                            disc_number = Some(MetadataValue::embedded(num));
+                        }
+                    }
+                    ItemKey::Year => {
+                        if let Ok(year_val) = item_value_str.parse::<u32>() {
+                            year = Some(MetadataValue::embedded(year_val));
+                        }
+                    }
+                    ItemKey::Genre => {
+                        genre = Some(MetadataValue::embedded(item_value_str));
+                    }
+                    ItemKey::OriginalMediaType => {
+                        media = Some(MetadataValue::embedded(item_value_str));
+                    }
+                    ItemKey::Barcode => {
+                        barcode = Some(MetadataValue::embedded(item_value_str));
+                    }
+                    ItemKey::Bpm | ItemKey::IntegerBpm => {
+                        if let Some(value) = parse_bpm(&item_value_str) {
+                            bpm = Some(MetadataValue::embedded(value));
+                        }
+                    }
+                    ItemKey::InitialKey => {
+                        initial_key = Some(MetadataValue::embedded(item_value_str));
+                    }
+                    ItemKey::FlagCompilation => {
+                        compilation = parse_flag(&item_value_str).map(MetadataValue::embedded);
+                    }
+                    ItemKey::Comment => {
+                        comment = Some(MetadataValue::embedded(item_value_str));
+                    }
+                    ItemKey::RecordingDate => {
+                        let clean_value = item_value_str.trim();
+                        if let Ok(year_val) = clean_value.parse::<u32>() {
+                            year = Some(MetadataValue::embedded(year_val));
+                        }
+                    }
+                    ItemKey::ReplayGainTrackGain => {
+                        track_gain =
+                            parse_replay_gain(&item_value_str).map(MetadataValue::embedded);
+                    }
+                    ItemKey::ReplayGainTrackPeak => {
+                        track_peak =
+                            parse_replay_gain(&item_value_str).map(MetadataValue::embedded);
+                    }
+                    ItemKey::ReplayGainAlbumGain => {
+                        album_gain =
+                            parse_replay_gain(&item_value_str).map(MetadataValue::embedded);
+                    }
+                    ItemKey::ReplayGainAlbumPeak => {
+                        album_peak =
+                            parse_replay_gain(&item_value_str).map(MetadataValue::embedded);
+                    }
+                    key => {
+                        if let Some(kind) = musicbrainz_id_kind(key) {
+                            let id =
+                                Some(MetadataValue::embedded(item_value_str.trim().to_string()));
+                            match kind {
+                                MusicBrainzId::Recording => musicbrainz_recording_id = id,
+                                MusicBrainzId::Release => musicbrainz_release_id = id,
+                            }
+                        } else if is_track_artists_key(key) {
+                            push_track_artists(&mut artists, &item_value_str);
+                        } else if original_year.is_none() {
+                            original_year = parse_original_year(key, &item_value_str)
+                                .map(MetadataValue::embedded);
+                        }
+                    }
+                }
+            }
+        }
+
+        // Get duration from file properties
+        let duration = track_duration(tagged_file);
+
+        // Apply folder inference as fallback when embedded metadata is missing
+        let inferred_artist = if artist.is_none() {
+            infer_artist_from_path(path)
+                .map(|artist| MetadataValue::inferred(artist, FOLDER_INFERRED_CONFIDENCE))
+        } else {
+            artist
+        };
+
+        let inferred_album = if album.is_none() {
+            infer_album_from_path(path)
+                .map(|album| MetadataValue::inferred(album, FOLDER_INFERRED_CONFIDENCE))
+        } else {
+            album
+        };
+
+        let (sample_rate, bit_depth) = stream_format(tagged_file);
+
+        TrackMetadata {
+            title,
+            artist: inferred_artist,
+            album: inferred_album,
+            album_artist,
+            track_number,
+            disc_number,
+            year,
+            genre,
+            duration,
+            track_gain,
+            track_peak,
+            album_gain,
+            album_peak,
+            sample_rate,
+            bit_depth,
+            media,
+            barcode,
+            bpm,
+            initial_key,
+            comment,
+            compilation,
+            original_year,
+            musicbrainz_recording_id,
+            musicbrainz_release_id,
+            artists,
+            lossy: None,
+            format: "ape".to_string(),
+            path: path.to_path_buf(),
+        }
+    }
+
+    /// Extract basic metadata (minimal parsing for performance)
+    fn extract_basic_metadata(&self, tagged_file: &TaggedFile, path: &Path) -> TrackMetadata {
+        // For basic info, just get format, duration, and use folder inference
+        let duration = track_duration(tagged_file);
+
+        let inferred_artist = infer_artist_from_path(path)
+            .map(|artist| MetadataValue::inferred(artist, FOLDER_INFERRED_CONFIDENCE));
+        let inferred_album = infer_album_from_path(path)
+            .map(|album| MetadataValue::inferred(album, FOLDER_INFERRED_CONFIDENCE));
+
+        let (sample_rate, bit_depth) = stream_format(tagged_file);
+
+        TrackMetadata {
+            artist: inferred_artist,
+            album: inferred_album,
+            duration,
+            sample_rate,
+            bit_depth,
+            format: "ape".to_string(),
+            path: path.to_path_buf(),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_ape_handler_supported_extensions() {
+        let handler = ApeHandler::new();
+        let extensions = handler.supported_extensions();
+        assert_eq!(extensions, vec!["ape"]);
+    }
+
+    #[test]
+    fn test_ape_handler_can_handle() {
+        let handler = ApeHandler::new();
+
+        assert!(handler.can_handle(&PathBuf::from("test.ape")));
+        assert!(handler.can_handle(&PathBuf::from("test.APE")));
+        assert!(!handler.can_handle(&PathBuf::from("test.flac")));
+        assert!(!handler.can_handle(&PathBuf::from("test.mp3")));
+    }
+
+    #[test]
+    fn test_ape_handler_new_creates_instance() {
+        let handler = ApeHandler::new();
+        assert!(handler.can_handle(&PathBuf::from("test.ape")));
+    }
+
+    #[test]
+    fn test_ape_handler_default_creates_instance() {
+        let handler = ApeHandler::default();
+        assert!(handler.can_handle(&PathBuf::from("test.ape")));
+    }
+
+    #[test]
+    fn test_ape_handler_read_metadata_unsupported_format() {
+        let handler = ApeHandler::new();
+        let result = handler.read_metadata(&PathBuf::from("test.mp3"));
+        assert!(matches!(result, Err(AudioFileError::UnsupportedFormat)));
+    }
+
+    #[test]
+    fn test_ape_handler_write_metadata_unsupported_format() {
+        let handler = ApeHandler::new();
+        let metadata = TrackMetadata {
+            format: "ape".to_string(),
+            path: PathBuf::from("test.ape"),
+            ..Default::default()
+        };
+        let result = handler.write_metadata(&PathBuf::from("test.mp3"), &metadata);
+        assert!(matches!(result, Err(AudioFileError::UnsupportedFormat)));
+    }
+
+    #[test]
+    fn test_ape_handler_read_basic_info_unsupported_format() {
+        let handler = ApeHandler::new();
+        let result = handler.read_basic_info(&PathBuf::from("test.mp3"));
+        assert!(matches!(result, Err(AudioFileError::UnsupportedFormat)));
+    }
+
+    #[test]
+    fn test_ape_handler_read_basic_info_nonexistent_file() {
+        let handler = ApeHandler::new();
+        let result = handler.read_basic_info(&PathBuf::from("nonexistent.ape"));
+        assert!(matches!(result, Err(AudioFileError::InvalidFile(_))));
+    }
+
+    #[test]
+    fn test_ape_handler_read_metadata_nonexistent_file() {
+        let handler = ApeHandler::new();
+        let result = handler.read_metadata(&PathBuf::from("nonexistent.ape"));
+        assert!(matches!(result, Err(AudioFileError::InvalidFile(_))));
+    }
+
+    #[test]
+    fn test_ape_handler_write_metadata_nonexistent_file() {
+        let handler = ApeHandler::new();
+        let metadata = TrackMetadata {
+            format: "ape".to_string(),
+            path: PathBuf::from("nonexistent.ape"),
+            ..Default::default()
+        };
+        let result = handler.write_metadata(&PathBuf::from("nonexistent.ape"), &metadata);
+        assert!(matches!(result, Err(AudioFileError::InvalidFile(_))));
+    }
+
+    #[test]
+    fn test_ape_handler_with_real_file_should_fail_on_dummy() {
+        // Test that a dummy file (not a real APE file) produces an error
+        let handler = ApeHandler::new();
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("test.ape");
+
+        // Create a dummy file that is not a real APE file
+        fs::write(&test_file, b"not a real ape file").unwrap();
+
+        let result = handler.read_metadata(&test_file);
+        assert!(matches!(result, Err(AudioFileError::InvalidFile(_))));
+    }
+
+    #[test]
+    fn test_ape_handler_write_metadata_with_all_fields() {
+        let handler = ApeHandler::new();
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("test.ape");
+
+        // Create a dummy file to simulate a APE file for this test
+        // In a real scenario, we'd need an actual APE file
+        fs::write(&test_file, b"dummy content").unwrap();
+
+        let metadata = TrackMetadata {
+            title: Some(MetadataValue::embedded("Test Title".to_string())),
+            artist: Some(MetadataValue::embedded("Test Artist".to_string())),
+            album: Some(MetadataValue::embedded("Test Album".to_string())),
+            album_artist: Some(MetadataValue::embedded("Test Album Artist".to_string())),
+            track_number: Some(MetadataValue::embedded(5)),
+            disc_number: Some(MetadataValue::embedded(1)),
+            year: Some(MetadataValue::embedded(2023)),
+            genre: Some(MetadataValue::embedded("Test Genre".to_string())),
+            duration: Some(MetadataValue::embedded(180.0)),
+            format: "ape".to_string(),
+            path: test_file.clone(),
+            ..Default::default()
+        };
+
+        let result = handler.write_metadata(&test_file, &metadata);
+        // This should fail because the dummy file is not a real APE file
+        assert!(matches!(result, Err(AudioFileError::InvalidFile(_))));
+    }
+
+    #[test]
+    fn test_ape_handler_write_metadata_with_partial_fields() {
+        let handler = ApeHandler::new();
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("partial.ape");
+
+        // Create a dummy file to simulate a APE file for this test
+        fs::write(&test_file, b"dummy content").unwrap();
+
+        let metadata = TrackMetadata {
+            title: Some(MetadataValue::embedded("Partial Title".to_string())),
+            artist: None, // No artist
+            album: Some(MetadataValue::embedded("Partial Album".to_string())),
+            duration: Some(MetadataValue::embedded(120.0)),
+            format: "ape".to_string(),
+            path: test_file.clone(),
+            ..Default::default()
+        };
+
+        let result = handler.write_metadata(&test_file, &metadata);
+        // This should fail because the dummy file is not a real APE file
+        assert!(matches!(result, Err(AudioFileError::InvalidFile(_))));
+    }
+}
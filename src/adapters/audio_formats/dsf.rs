@@ -2,6 +2,9 @@ use dsf::DsfFile;
 use id3::TagLike;
 use std::path::Path;
 
+use crate::adapters::audio_formats::tag_fields::{
+    parse_bpm, parse_flag, parse_year, push_track_artists,
+};
 use crate::core::domain::models::{
     FOLDER_INFERRED_CONFIDENCE, MetadataValue, Track, TrackMetadata,
 };
@@ -34,6 +37,10 @@ impl AudioFile for DsfHandler {
         vec!["dsf"]
     }
 
+    fn mime_type(&self) -> &'static str {
+        "audio/x-dsf"
+    }
+
     fn read_metadata(&self, path: &Path) -> Result<Track, AudioFileError> {
         if !self.can_handle(path) {
             return Err(AudioFileError::UnsupportedFormat);
@@ -54,9 +61,11 @@ impl AudioFile for DsfHandler {
     ) -> Result<(), AudioFileError> {
         // The `dsf` crate does not support writing ID3 tags directly.
         // Therefore, writing DSF metadata is currently not supported.
-        Err(AudioFileError::WriteError(
-            "Writing DSF metadata is not supported.".to_string(),
-        ))
+        Err(AudioFileError::WriteUnsupported("DSF".to_string()))
+    }
+
+    fn can_write(&self) -> bool {
+        false
     }
 
     fn read_basic_info(&self, path: &Path) -> Result<TrackMetadata, AudioFileError> {
@@ -82,6 +91,16 @@ impl DsfHandler {
         let mut disc_number = None;
         let mut year = None;
         let mut genre = None;
+        let mut media = None;
+        let mut barcode = None;
+        let mut bpm = None;
+        let mut initial_key = None;
+        let mut comment = None;
+        let mut compilation = None;
+        let mut original_year = None;
+        let mut musicbrainz_recording_id = None;
+        let mut musicbrainz_release_id = None;
+        let mut artists = None;
 
         if let Some(tag) = dsf_file.id3_tag() {
             title = TagLike::title(tag).map(|s| MetadataValue::embedded(s.to_string()));
@@ -92,6 +111,45 @@ impl DsfHandler {
             track_number = TagLike::track(tag).map(MetadataValue::embedded);
             disc_number = TagLike::disc(tag).map(MetadataValue::embedded);
             genre = TagLike::genre(tag).map(|s| MetadataValue::embedded(s.to_string()));
+            media = TagLike::get(tag, "TMED")
+                .and_then(|frame| frame.content().text())
+                .map(|s| MetadataValue::embedded(s.to_string()));
+            barcode = tag
+                .extended_texts()
+                .find(|t| t.description.eq_ignore_ascii_case("BARCODE"))
+                .map(|t| MetadataValue::embedded(t.value.clone()));
+            let extended_text = |description: &str| {
+                tag.extended_texts()
+                    .find(|t| t.description.eq_ignore_ascii_case(description))
+                    .map(|t| MetadataValue::embedded(t.value.trim().to_string()))
+            };
+            musicbrainz_recording_id = extended_text("MusicBrainz Track Id");
+            musicbrainz_release_id = extended_text("MusicBrainz Album Id");
+            for text in tag
+                .extended_texts()
+                .filter(|t| t.description.eq_ignore_ascii_case("ARTISTS"))
+            {
+                push_track_artists(&mut artists, &text.value);
+            }
+            bpm = TagLike::get(tag, "TBPM")
+                .and_then(|frame| frame.content().text())
+                .and_then(parse_bpm)
+                .map(MetadataValue::embedded);
+            initial_key = TagLike::get(tag, "TKEY")
+                .and_then(|frame| frame.content().text())
+                .map(|s| MetadataValue::embedded(s.to_string()));
+            comment = TagLike::comments(tag)
+                .next()
+                .map(|c| MetadataValue::embedded(c.text.clone()));
+            compilation = TagLike::get(tag, "TCMP")
+                .and_then(|frame| frame.content().text())
+                .and_then(parse_flag)
+                .map(MetadataValue::embedded);
+            original_year = TagLike::get(tag, "TDOR")
+                .or_else(|| TagLike::get(tag, "TORY"))
+                .and_then(|frame| frame.content().text())
+                .and_then(parse_year)
+                .map(MetadataValue::embedded);
 
             // Get year from tag.date_recorded()
             year = TagLike::date_recorded(tag)
@@ -122,6 +180,8 @@ impl DsfHandler {
             album
         };
 
+        let (sample_rate, bit_depth) = dsf_stream_format(dsf_file);
+
         TrackMetadata {
             title,
             artist: inferred_artist,
@@ -132,8 +192,21 @@ impl DsfHandler {
             year,
             genre,
             duration,
+            sample_rate,
+            bit_depth,
+            media,
+            barcode,
+            bpm,
+            initial_key,
+            comment,
+            compilation,
+            original_year,
+            musicbrainz_recording_id,
+            musicbrainz_release_id,
+            artists,
             format: "dsf".to_string(),
             path: path.to_path_buf(),
+            ..Default::default()
         }
     }
 
@@ -157,22 +230,37 @@ impl DsfHandler {
         let inferred_album = infer_album_from_path(path)
             .map(|album| MetadataValue::inferred(album, FOLDER_INFERRED_CONFIDENCE));
 
+        let (sample_rate, bit_depth) = dsf_stream_format(dsf_file);
+
         TrackMetadata {
-            title: None,
             artist: inferred_artist,
             album: inferred_album,
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
-            year: None,
-            genre: None,
             duration,
+            sample_rate,
+            bit_depth,
             format: "dsf".to_string(),
             path: path.to_path_buf(),
+            ..Default::default()
         }
     }
 }
 
+/// Sample rate (Hz) and bits per sample from the DSF fmt chunk.
+fn dsf_stream_format(
+    dsf_file: &DsfFile,
+) -> (Option<MetadataValue<u32>>, Option<MetadataValue<u32>>) {
+    let fmt_chunk = dsf_file.fmt_chunk();
+    let sample_rate = u32::try_from(fmt_chunk.sampling_frequency())
+        .ok()
+        .filter(|&hz| hz > 0)
+        .map(MetadataValue::embedded);
+    let bit_depth = u32::try_from(fmt_chunk.bits_per_sample())
+        .ok()
+        .filter(|&bits| bits > 0)
+        .map(MetadataValue::embedded);
+    (sample_rate, bit_depth)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -223,20 +311,12 @@ mod tests {
     fn test_dsf_handler_write_metadata_unsupported_format() {
         let handler = DsfHandler::new();
         let metadata = TrackMetadata {
-            title: None,
-            artist: None,
-            album: None,
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
-            year: None,
-            genre: None,
-            duration: None,
             format: "dsf".to_string(),
             path: PathBuf::from("test.dsf"),
+            ..Default::default()
         };
         let result = handler.write_metadata(&PathBuf::from("test.mp3"), &metadata);
-        assert!(matches!(result, Err(AudioFileError::WriteError(_))));
+        assert!(matches!(result, Err(AudioFileError::WriteUnsupported(_))));
     }
 
     #[test]
@@ -278,9 +358,11 @@ mod tests {
         // assert_eq!(track.metadata.title.unwrap().value, "Test Title");
         assert!(track.metadata.duration.is_some());
         assert!(track.metadata.duration.unwrap().value > 0.0);
+        assert!(track.metadata.sample_rate.is_some());
+        assert!(track.metadata.bit_depth.is_some());
     }
 
-    // Test case for write_metadata, now expecting it to return WriteError
+    // Test case for write_metadata, now expecting it to return WriteUnsupported
     #[test]
     #[ignore = "Use it manually"]
     fn test_dsf_handler_write_metadata_unsupported() {
@@ -291,25 +373,28 @@ mod tests {
 
         let metadata = TrackMetadata {
             title: Some(MetadataValue::embedded("New Title".to_string())),
-            artist: None,
-            album: None,
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
-            year: None,
-            genre: None,
-            duration: None,
             format: "dsf".to_string(),
             path: temp_dsf_path.clone(),
+            ..Default::default()
         };
 
         let result = handler.write_metadata(&temp_dsf_path, &metadata);
-        assert!(matches!(result, Err(AudioFileError::WriteError(_))));
-        if let Err(AudioFileError::WriteError(msg)) = result {
-            assert!(msg.contains("Writing DSF metadata is not supported."));
+        assert!(matches!(result, Err(AudioFileError::WriteUnsupported(_))));
+        if let Err(err) = result {
+            assert!(
+                err.to_string()
+                    .contains("Writing DSF metadata is not supported")
+            );
         }
     }
 
+    #[test]
+    fn test_dsf_handler_reports_read_only() {
+        let handler = DsfHandler::new();
+        assert!(handler.can_read());
+        assert!(!handler.can_write());
+    }
+
     // New test case for read_basic_info
     #[test]
     #[ignore = "Use it manually"]
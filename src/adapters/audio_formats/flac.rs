@@ -8,7 +8,11 @@ use lofty::{
     tag::{ItemValue, TagItem},
 };
 
-use crate::adapters::audio_formats::wav::item_value_text;
+use crate::adapters::audio_formats::tag_fields::{
+    MusicBrainzId, is_track_artists_key, item_value_text, musicbrainz_id_kind, parse_bpm,
+    parse_flag, parse_original_year, parse_replay_gain, push_track_artists, read_duration_only,
+    stream_format, track_duration, write_comment,
+};
 use crate::core::domain::models::{
     FOLDER_INFERRED_CONFIDENCE, MetadataValue, Track, TrackMetadata,
 };
@@ -42,6 +46,10 @@ impl AudioFile for FlacHandler {
         vec!["flac"]
     }
 
+    fn mime_type(&self) -> &'static str {
+        "audio/flac"
+    }
+
     fn read_metadata(&self, path: &Path) -> Result<Track, AudioFileError> {
         if !self.can_handle(path) {
             return Err(AudioFileError::UnsupportedFormat);
@@ -109,6 +117,16 @@ impl AudioFile for FlacHandler {
             set_tag(ItemKey::Genre, &genre.value);
         }
 
+        if let Some(ref media) = metadata.media {
+            set_tag(ItemKey::OriginalMediaType, &media.value);
+        }
+
+        if let Some(ref barcode) = metadata.barcode {
+            set_tag(ItemKey::Barcode, &barcode.value);
+        }
+
+        write_comment(tag, metadata.comment.as_ref());
+
         // Save the changes to disk with default write options
         let write_options = WriteOptions::default();
         tagged_file
@@ -128,6 +146,14 @@ impl AudioFile for FlacHandler {
 
         Ok(self.extract_basic_metadata(&tagged_file, path))
     }
+
+    fn read_duration(&self, path: &Path) -> Result<Option<f64>, AudioFileError> {
+        if !self.can_handle(path) {
+            return Err(AudioFileError::UnsupportedFormat);
+        }
+
+        read_duration_only(path, "FLAC")
+    }
 }
 
 impl FlacHandler {
@@ -141,6 +167,20 @@ impl FlacHandler {
         let mut disc_number = None;
         let mut year = None;
         let mut genre = None;
+        let mut media = None;
+        let mut barcode = None;
+        let mut bpm = None;
+        let mut initial_key = None;
+        let mut comment = None;
+        let mut compilation = None;
+        let mut original_year = None;
+        let mut musicbrainz_recording_id = None;
+        let mut musicbrainz_release_id = None;
+        let mut artists = None;
+        let mut track_gain = None;
+        let mut track_peak = None;
+        let mut album_gain = None;
+        let mut album_peak = None;
 
         // Get the primary tag (usually Vorbis Comments for FLAC)
         if let Some(tag) = tagged_file.primary_tag() {
@@ -179,20 +219,69 @@ impl FlacHandler {
                     ItemKey::Genre => {
                         genre = Some(MetadataValue::embedded(item_value_str));
                     }
+                    ItemKey::OriginalMediaType => {
+                        media = Some(MetadataValue::embedded(item_value_str));
+                    }
+                    ItemKey::Barcode => {
+                        barcode = Some(MetadataValue::embedded(item_value_str));
+                    }
+                    ItemKey::Bpm | ItemKey::IntegerBpm => {
+                        if let Some(value) = parse_bpm(&item_value_str) {
+                            bpm = Some(MetadataValue::embedded(value));
+                        }
+                    }
+                    ItemKey::InitialKey => {
+                        initial_key = Some(MetadataValue::embedded(item_value_str));
+                    }
+                    ItemKey::FlagCompilation => {
+                        compilation = parse_flag(&item_value_str).map(MetadataValue::embedded);
+                    }
+                    ItemKey::Comment => {
+                        comment = Some(MetadataValue::embedded(item_value_str));
+                    }
                     ItemKey::RecordingDate => {
                         let clean_value = item_value_str.trim();
                         if let Ok(year_val) = clean_value.parse::<u32>() {
                             year = Some(MetadataValue::embedded(year_val));
                         }
                     }
-                    _ => {} // Ignore other tags for now
+                    ItemKey::ReplayGainTrackGain => {
+                        track_gain =
+                            parse_replay_gain(&item_value_str).map(MetadataValue::embedded);
+                    }
+                    ItemKey::ReplayGainTrackPeak => {
+                        track_peak =
+                            parse_replay_gain(&item_value_str).map(MetadataValue::embedded);
+                    }
+                    ItemKey::ReplayGainAlbumGain => {
+                        album_gain =
+                            parse_replay_gain(&item_value_str).map(MetadataValue::embedded);
+                    }
+                    ItemKey::ReplayGainAlbumPeak => {
+                        album_peak =
+                            parse_replay_gain(&item_value_str).map(MetadataValue::embedded);
+                    }
+                    key => {
+                        if let Some(kind) = musicbrainz_id_kind(key) {
+                            let id =
+                                Some(MetadataValue::embedded(item_value_str.trim().to_string()));
+                            match kind {
+                                MusicBrainzId::Recording => musicbrainz_recording_id = id,
+                                MusicBrainzId::Release => musicbrainz_release_id = id,
+                            }
+                        } else if is_track_artists_key(key) {
+                            push_track_artists(&mut artists, &item_value_str);
+                        } else if original_year.is_none() {
+                            original_year = parse_original_year(key, &item_value_str)
+                                .map(MetadataValue::embedded);
+                        }
+                    }
                 }
             }
         }
 
         // Get duration from file properties
-        let properties = tagged_file.properties();
-        let duration = Some(MetadataValue::embedded(properties.duration().as_secs_f64()));
+        let duration = track_duration(tagged_file);
 
         // Apply folder inference as fallback when embedded metadata is missing
         let inferred_artist = if artist.is_none() {
@@ -209,6 +298,8 @@ impl FlacHandler {
             album
         };
 
+        let (sample_rate, bit_depth) = stream_format(tagged_file);
+
         TrackMetadata {
             title,
             artist: inferred_artist,
@@ -219,6 +310,23 @@ impl FlacHandler {
             year,
             genre,
             duration,
+            track_gain,
+            track_peak,
+            album_gain,
+            album_peak,
+            sample_rate,
+            bit_depth,
+            media,
+            barcode,
+            bpm,
+            initial_key,
+            comment,
+            compilation,
+            original_year,
+            musicbrainz_recording_id,
+            musicbrainz_release_id,
+            artists,
+            lossy: None,
             format: "flac".to_string(),
             path: path.to_path_buf(),
         }
@@ -227,26 +335,24 @@ impl FlacHandler {
     /// Extract basic metadata (minimal parsing for performance)
     fn extract_basic_metadata(&self, tagged_file: &TaggedFile, path: &Path) -> TrackMetadata {
         // For basic info, just get format, duration, and use folder inference
-        let properties = tagged_file.properties();
-        let duration = Some(MetadataValue::embedded(properties.duration().as_secs_f64()));
+        let duration = track_duration(tagged_file);
 
         let inferred_artist = infer_artist_from_path(path)
             .map(|artist| MetadataValue::inferred(artist, FOLDER_INFERRED_CONFIDENCE));
         let inferred_album = infer_album_from_path(path)
             .map(|album| MetadataValue::inferred(album, FOLDER_INFERRED_CONFIDENCE));
 
+        let (sample_rate, bit_depth) = stream_format(tagged_file);
+
         TrackMetadata {
-            title: None,
             artist: inferred_artist,
             album: inferred_album,
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
-            year: None,
-            genre: None,
             duration,
+            sample_rate,
+            bit_depth,
             format: "flac".to_string(),
             path: path.to_path_buf(),
+            ..Default::default()
         }
     }
 }
@@ -254,6 +360,7 @@ impl FlacHandler {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::domain::models::MetadataSource;
     use std::fs;
     use std::path::PathBuf;
     use tempfile::TempDir;
@@ -298,17 +405,9 @@ mod tests {
     fn test_flac_handler_write_metadata_unsupported_format() {
         let handler = FlacHandler::new();
         let metadata = TrackMetadata {
-            title: None,
-            artist: None,
-            album: None,
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
-            year: None,
-            genre: None,
-            duration: None,
             format: "flac".to_string(),
             path: PathBuf::from("test.flac"),
+            ..Default::default()
         };
         let result = handler.write_metadata(&PathBuf::from("test.mp3"), &metadata);
         assert!(matches!(result, Err(AudioFileError::UnsupportedFormat)));
@@ -339,17 +438,9 @@ mod tests {
     fn test_flac_handler_write_metadata_nonexistent_file() {
         let handler = FlacHandler::new();
         let metadata = TrackMetadata {
-            title: None,
-            artist: None,
-            album: None,
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
-            year: None,
-            genre: None,
-            duration: None,
             format: "flac".to_string(),
             path: PathBuf::from("nonexistent.flac"),
+            ..Default::default()
         };
         let result = handler.write_metadata(&PathBuf::from("nonexistent.flac"), &metadata);
         assert!(matches!(result, Err(AudioFileError::InvalidFile(_))));
@@ -391,6 +482,7 @@ mod tests {
             duration: Some(MetadataValue::embedded(180.0)),
             format: "flac".to_string(),
             path: test_file.clone(),
+            ..Default::default()
         };
 
         let result = handler.write_metadata(&test_file, &metadata);
@@ -411,14 +503,10 @@ mod tests {
             title: Some(MetadataValue::embedded("Partial Title".to_string())),
             artist: None, // No artist
             album: Some(MetadataValue::embedded("Partial Album".to_string())),
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
-            year: None,
-            genre: None,
             duration: Some(MetadataValue::embedded(120.0)),
             format: "flac".to_string(),
             path: test_file.clone(),
+            ..Default::default()
         };
 
         let result = handler.write_metadata(&test_file, &metadata);
@@ -477,14 +565,10 @@ mod tests {
             title: Some(MetadataValue::embedded("Test Title".to_string())),
             artist: Some(MetadataValue::embedded("Test Artist".to_string())),
             album: Some(MetadataValue::embedded("Test Album".to_string())),
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
-            year: None,
-            genre: None,
             duration: Some(MetadataValue::embedded(180.0)),
             format: "flac".to_string(),
             path: test_file.clone(),
+            ..Default::default()
         };
 
         let result = handler.write_metadata(&test_file, &metadata);
@@ -626,4 +710,143 @@ mod tests {
         // This should fail because the dummy file is not a real FLAC file
         assert!(matches!(result, Err(AudioFileError::InvalidFile(_))));
     }
+
+    #[test]
+    fn test_flac_handler_reads_bpm_and_initial_key() {
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("dj.flac");
+        fs::copy("tests/fixtures/flac/simple/track1.flac", &test_file).unwrap();
+
+        let mut tagged_file = read_from_path(&test_file).unwrap();
+        let tag = tagged_file.primary_tag_mut().unwrap();
+        tag.insert(TagItem::new(
+            ItemKey::Bpm,
+            ItemValue::Text("127.6".to_string()),
+        ));
+        tag.insert(TagItem::new(
+            ItemKey::InitialKey,
+            ItemValue::Text("F#m".to_string()),
+        ));
+        tagged_file
+            .save_to_path(&test_file, WriteOptions::default())
+            .unwrap();
+
+        let track = FlacHandler::new().read_metadata(&test_file).unwrap();
+        assert_eq!(track.metadata.bpm.unwrap().value, 128);
+        assert_eq!(track.metadata.initial_key.unwrap().value, "F#m");
+    }
+
+    #[test]
+    fn test_flac_handler_reads_compilation_flag() {
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("mix.flac");
+        fs::copy("tests/fixtures/flac/simple/track1.flac", &test_file).unwrap();
+        assert!(
+            FlacHandler::new()
+                .read_metadata(&test_file)
+                .unwrap()
+                .metadata
+                .compilation
+                .is_none()
+        );
+
+        let mut tagged_file = read_from_path(&test_file).unwrap();
+        let tag = tagged_file.primary_tag_mut().unwrap();
+        tag.insert(TagItem::new(
+            ItemKey::FlagCompilation,
+            ItemValue::Text("1".to_string()),
+        ));
+        tagged_file
+            .save_to_path(&test_file, WriteOptions::default())
+            .unwrap();
+
+        let track = FlacHandler::new().read_metadata(&test_file).unwrap();
+        assert!(track.metadata.compilation.unwrap().value);
+    }
+
+    #[test]
+    fn test_flac_handler_reads_original_release_year() {
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("reissue.flac");
+        fs::copy("tests/fixtures/flac/simple/track1.flac", &test_file).unwrap();
+
+        let mut tagged_file = read_from_path(&test_file).unwrap();
+        let tag = tagged_file.primary_tag_mut().unwrap();
+        tag.insert(TagItem::new(
+            ItemKey::OriginalReleaseDate,
+            ItemValue::Text("1973-03-01".to_string()),
+        ));
+        tagged_file
+            .save_to_path(&test_file, WriteOptions::default())
+            .unwrap();
+
+        let track = FlacHandler::new().read_metadata(&test_file).unwrap();
+        assert_eq!(track.metadata.original_year.unwrap().value, 1973);
+    }
+
+    #[test]
+    fn test_flac_handler_reads_musicbrainz_ids() {
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("picard.flac");
+        fs::copy("tests/fixtures/flac/simple/track1.flac", &test_file).unwrap();
+
+        let mut tagged_file = read_from_path(&test_file).unwrap();
+        let tag = tagged_file.primary_tag_mut().unwrap();
+        tag.insert(TagItem::new(
+            ItemKey::MusicBrainzRecordingId,
+            ItemValue::Text("b1a9c0e9-d987-4042-ae91-78d6a3267d69".to_string()),
+        ));
+        tag.insert(TagItem::new(
+            ItemKey::MusicBrainzReleaseId,
+            ItemValue::Text("1dc4c347-a1db-32aa-b14f-bc9cc507b843".to_string()),
+        ));
+        tagged_file
+            .save_to_path(&test_file, WriteOptions::default())
+            .unwrap();
+
+        let metadata = FlacHandler::new()
+            .read_metadata(&test_file)
+            .unwrap()
+            .metadata;
+        assert_eq!(
+            metadata.musicbrainz_recording_id.unwrap().value,
+            "b1a9c0e9-d987-4042-ae91-78d6a3267d69"
+        );
+        assert_eq!(
+            metadata.musicbrainz_release_id.unwrap().value,
+            "1dc4c347-a1db-32aa-b14f-bc9cc507b843"
+        );
+    }
+
+    #[test]
+    fn test_flac_handler_reads_plural_artists_tag() {
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("picard.flac");
+        fs::copy("tests/fixtures/flac/simple/track1.flac", &test_file).unwrap();
+
+        let mut tagged_file = read_from_path(&test_file).unwrap();
+        let tag = tagged_file.primary_tag_mut().unwrap();
+        tag.insert(TagItem::new(
+            ItemKey::TrackArtist,
+            ItemValue::Text("Main Artist feat. Guest, Jr.".to_string()),
+        ));
+        for name in ["Main Artist", "Guest, Jr."] {
+            tag.push(TagItem::new(
+                ItemKey::TrackArtists,
+                ItemValue::Text(name.to_string()),
+            ));
+        }
+        tagged_file
+            .save_to_path(&test_file, WriteOptions::default())
+            .unwrap();
+
+        let metadata = FlacHandler::new()
+            .read_metadata(&test_file)
+            .unwrap()
+            .metadata;
+        let artists = metadata.artists.as_ref().unwrap();
+        assert_eq!(artists.value, vec!["Main Artist", "Guest, Jr."]);
+        assert_eq!(artists.source, MetadataSource::Embedded);
+        assert_eq!(metadata.artist_names(), artists.value);
+    }
 }
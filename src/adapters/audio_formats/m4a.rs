@@ -9,7 +9,11 @@ use lofty::{
 };
 use std::path::Path;
 
-use crate::adapters::audio_formats::wav::item_value_text;
+use crate::adapters::audio_formats::tag_fields::{
+    MusicBrainzId, is_track_artists_key, item_value_text, musicbrainz_id_kind, parse_bpm,
+    parse_flag, parse_original_year, parse_replay_gain, push_track_artists, read_duration_only,
+    stream_format, track_duration, write_comment,
+};
 use crate::core::domain::models::{
     FOLDER_INFERRED_CONFIDENCE, MetadataValue, Track, TrackMetadata,
 };
@@ -42,6 +46,10 @@ impl AudioFile for M4aHandler {
         vec!["m4a"]
     }
 
+    fn mime_type(&self) -> &'static str {
+        "audio/mp4"
+    }
+
     fn read_metadata(&self, path: &Path) -> Result<Track, AudioFileError> {
         if !self.can_handle(path) {
             return Err(AudioFileError::UnsupportedFormat);
@@ -95,6 +103,15 @@ impl AudioFile for M4aHandler {
         if let Some(ref genre) = metadata.genre {
             set_tag(ItemKey::Genre, &genre.value);
         }
+        if let Some(ref media) = metadata.media {
+            set_tag(ItemKey::OriginalMediaType, &media.value);
+        }
+
+        if let Some(ref barcode) = metadata.barcode {
+            set_tag(ItemKey::Barcode, &barcode.value);
+        }
+
+        write_comment(tag, metadata.comment.as_ref());
 
         tagged_file
             .save_to_path(path, WriteOptions::default())
@@ -113,6 +130,14 @@ impl AudioFile for M4aHandler {
 
         Ok(self.extract_basic_metadata(&tagged_file, path))
     }
+
+    fn read_duration(&self, path: &Path) -> Result<Option<f64>, AudioFileError> {
+        if !self.can_handle(path) {
+            return Err(AudioFileError::UnsupportedFormat);
+        }
+
+        read_duration_only(path, "M4A")
+    }
 }
 
 impl M4aHandler {
@@ -126,6 +151,20 @@ impl M4aHandler {
         let mut disc_number = None;
         let mut year = None;
         let mut genre = None;
+        let mut media = None;
+        let mut barcode = None;
+        let mut bpm = None;
+        let mut initial_key = None;
+        let mut comment = None;
+        let mut compilation = None;
+        let mut original_year = None;
+        let mut musicbrainz_recording_id = None;
+        let mut musicbrainz_release_id = None;
+        let mut artists = None;
+        let mut track_gain = None;
+        let mut track_peak = None;
+        let mut album_gain = None;
+        let mut album_peak = None;
 
         if let Some(tag) = tagged_file.primary_tag() {
             for tag_item in tag.items() {
@@ -154,14 +193,58 @@ impl M4aHandler {
                         }
                     }
                     ItemKey::Genre => genre = Some(MetadataValue::embedded(item_value_str)),
-                    _ => {}
+                    ItemKey::OriginalMediaType => {
+                        media = Some(MetadataValue::embedded(item_value_str))
+                    }
+                    ItemKey::Barcode => barcode = Some(MetadataValue::embedded(item_value_str)),
+                    ItemKey::Bpm | ItemKey::IntegerBpm => {
+                        if let Some(value) = parse_bpm(&item_value_str) {
+                            bpm = Some(MetadataValue::embedded(value));
+                        }
+                    }
+                    ItemKey::InitialKey => {
+                        initial_key = Some(MetadataValue::embedded(item_value_str))
+                    }
+                    ItemKey::FlagCompilation => {
+                        compilation = parse_flag(&item_value_str).map(MetadataValue::embedded);
+                    }
+                    ItemKey::Comment => comment = Some(MetadataValue::embedded(item_value_str)),
+                    ItemKey::ReplayGainTrackGain => {
+                        track_gain =
+                            parse_replay_gain(&item_value_str).map(MetadataValue::embedded);
+                    }
+                    ItemKey::ReplayGainTrackPeak => {
+                        track_peak =
+                            parse_replay_gain(&item_value_str).map(MetadataValue::embedded);
+                    }
+                    ItemKey::ReplayGainAlbumGain => {
+                        album_gain =
+                            parse_replay_gain(&item_value_str).map(MetadataValue::embedded);
+                    }
+                    ItemKey::ReplayGainAlbumPeak => {
+                        album_peak =
+                            parse_replay_gain(&item_value_str).map(MetadataValue::embedded);
+                    }
+                    key => {
+                        if let Some(kind) = musicbrainz_id_kind(key) {
+                            let id =
+                                Some(MetadataValue::embedded(item_value_str.trim().to_string()));
+                            match kind {
+                                MusicBrainzId::Recording => musicbrainz_recording_id = id,
+                                MusicBrainzId::Release => musicbrainz_release_id = id,
+                            }
+                        } else if is_track_artists_key(key) {
+                            push_track_artists(&mut artists, &item_value_str);
+                        } else if original_year.is_none() {
+                            original_year = parse_original_year(key, &item_value_str)
+                                .map(MetadataValue::embedded);
+                        }
+                    }
                 }
             }
         }
 
-        let duration = Some(MetadataValue::embedded(
-            tagged_file.properties().duration().as_secs_f64(),
-        ));
+        let duration = track_duration(tagged_file);
 
         let inferred_artist = if artist.is_none() {
             infer_artist_from_path(path)
@@ -177,6 +260,8 @@ impl M4aHandler {
             album
         };
 
+        let (sample_rate, bit_depth) = stream_format(tagged_file);
+
         TrackMetadata {
             title,
             artist: inferred_artist,
@@ -187,6 +272,23 @@ impl M4aHandler {
             year,
             genre,
             duration,
+            track_gain,
+            track_peak,
+            album_gain,
+            album_peak,
+            sample_rate,
+            bit_depth,
+            media,
+            barcode,
+            bpm,
+            initial_key,
+            comment,
+            compilation,
+            original_year,
+            musicbrainz_recording_id,
+            musicbrainz_release_id,
+            artists,
+            lossy: None,
             format: "m4a".to_string(),
             path: path.to_path_buf(),
         }
@@ -194,27 +296,24 @@ impl M4aHandler {
 
     /// Extract basic metadata (minimal parsing for performance)
     fn extract_basic_metadata(&self, tagged_file: &TaggedFile, path: &Path) -> TrackMetadata {
-        let duration = Some(MetadataValue::embedded(
-            tagged_file.properties().duration().as_secs_f64(),
-        ));
+        let duration = track_duration(tagged_file);
 
         let inferred_artist = infer_artist_from_path(path)
             .map(|artist| MetadataValue::inferred(artist, FOLDER_INFERRED_CONFIDENCE));
         let inferred_album = infer_album_from_path(path)
             .map(|album| MetadataValue::inferred(album, FOLDER_INFERRED_CONFIDENCE));
 
+        let (sample_rate, bit_depth) = stream_format(tagged_file);
+
         TrackMetadata {
-            title: None,
             artist: inferred_artist,
             album: inferred_album,
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
-            year: None,
-            genre: None,
             duration,
+            sample_rate,
+            bit_depth,
             format: "m4a".to_string(),
             path: path.to_path_buf(),
+            ..Default::default()
         }
     }
 }
@@ -312,17 +411,9 @@ mod tests {
     fn test_m4a_handler_write_metadata_unsupported_format() {
         let handler = M4aHandler::new();
         let metadata = TrackMetadata {
-            title: None,
-            artist: None,
-            album: None,
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
-            year: None,
-            genre: None,
-            duration: None,
             format: "m4a".to_string(),
             path: PathBuf::from("test.m4a"),
+            ..Default::default()
         };
         let result = handler.write_metadata(&PathBuf::from("test.flac"), &metadata);
         assert!(matches!(result, Err(AudioFileError::UnsupportedFormat)));
@@ -351,16 +442,9 @@ mod tests {
 
         let metadata = TrackMetadata {
             title: Some(MetadataValue::embedded("Title".to_string())),
-            artist: None,
-            album: None,
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
-            year: None,
-            genre: None,
-            duration: None,
             format: "m4a".to_string(),
             path: m4a_path.clone(),
+            ..Default::default()
         };
 
         let result = handler.write_metadata(&m4a_path, &metadata);
@@ -1,22 +1,50 @@
 //! Audio format registry and factory.
+//!
+//! Each handler module and its registration sit behind a Cargo feature of the
+//! same name (all on by default), so the registry only reports formats
+//! compiled into the binary.
+#[cfg(feature = "ape")]
+use crate::adapters::audio_formats::ape::ApeHandler;
+#[cfg(feature = "dsf")]
 use crate::adapters::audio_formats::dsf::DsfHandler;
+#[cfg(feature = "flac")]
 use crate::adapters::audio_formats::flac::FlacHandler;
+#[cfg(feature = "m4a")]
 use crate::adapters::audio_formats::m4a::M4aHandler;
+#[cfg(feature = "mp3")]
 use crate::adapters::audio_formats::mp3::Mp3Handler;
+#[cfg(feature = "ogg")]
 use crate::adapters::audio_formats::ogg::OggHandler;
+#[cfg(feature = "wav")]
 use crate::adapters::audio_formats::wav::WavHandler;
+#[cfg(feature = "wavpack")]
 use crate::adapters::audio_formats::wavpack::WavPackHandler;
 use crate::core::domain::models::{MetadataValue, TrackMetadata};
 #[allow(unused_imports)]
-use crate::core::domain::traits::{AudioFileError, AudioFileRegistry};
+use crate::core::domain::traits::{AudioFileError, AudioFileRegistry, FormatCapabilities};
 use std::path::Path;
 
+#[cfg(feature = "ape")]
+pub mod ape;
+#[cfg(feature = "dsf")]
 pub mod dsf;
+#[cfg(feature = "flac")]
 pub mod flac;
+#[cfg(feature = "m4a")]
 pub mod m4a;
+#[cfg(feature = "mp3")]
 pub mod mp3;
+#[cfg(feature = "ogg")]
 pub mod ogg;
+pub mod pcm;
+#[cfg(feature = "ogg")]
+pub mod r128;
+#[cfg(feature = "silence")]
+pub mod silence;
+pub mod tag_fields;
+#[cfg(feature = "wav")]
 pub mod wav;
+#[cfg(feature = "wavpack")]
 pub mod wavpack;
 
 /// Basic audio information extracted for CUE file processing.
@@ -26,39 +54,53 @@ pub struct BasicAudioInfo {
     pub format: String,
 }
 
-/// Create a new audio file registry with all supported format handlers
+/// Create a new audio file registry with all compiled-in format handlers
 pub fn create_audio_registry() -> AudioFileRegistry {
+    #[allow(unused_mut)]
     let mut registry = AudioFileRegistry::new();
 
     // Register FLAC handler
+    #[cfg(feature = "flac")]
     registry.register(Box::new(FlacHandler::new()));
 
     // Register MP3 handler
+    #[cfg(feature = "mp3")]
     registry.register(Box::new(Mp3Handler::new()));
 
     // Register WAV handler
+    #[cfg(feature = "wav")]
     registry.register(Box::new(WavHandler::new()));
 
     // Register OGG handler
+    #[cfg(feature = "ogg")]
     registry.register(Box::new(OggHandler::new()));
 
     // Register M4A handler
+    #[cfg(feature = "m4a")]
     registry.register(Box::new(M4aHandler::new()));
 
     // Register DSF handler
+    #[cfg(feature = "dsf")]
     registry.register(Box::new(DsfHandler::new()));
 
     // Register WavPack handler
+    #[cfg(feature = "wavpack")]
     registry.register(Box::new(WavPackHandler::new()));
 
+    // Register Monkey's Audio handler
+    #[cfg(feature = "ape")]
+    registry.register(Box::new(ApeHandler::new()));
+
     registry
 }
 
 /// Read metadata from a file using the appropriate format handler
+///
+/// Errors carry the file path (see [`AudioFileError::path`]).
 pub fn read_metadata(path: &Path) -> Result<crate::core::domain::models::Track, AudioFileError> {
     let registry = create_audio_registry();
-    let handler = registry.find_handler(path)?;
-    let track = handler.read_metadata(path)?;
+    let handler = registry.find_handler(path).map_err(|e| e.with_path(path))?;
+    let track = handler.read_metadata(path).map_err(|e| e.with_path(path))?;
 
     // NOTE: We don't validate metadata schema during normal read operations
     // to avoid side effects. Validation should be done explicitly by calling
@@ -68,23 +110,62 @@ pub fn read_metadata(path: &Path) -> Result<crate::core::domain::models::Track,
 }
 
 /// Read basic metadata (duration, format) from a file.
-/// This is used primarily for CUE sheet processing where full metadata is not needed.
+/// This is used primarily for CUE sheet processing where full metadata is not
+/// needed, so the duration comes from the handler's [`read_duration`] fast path.
 pub fn read_basic_info(path: &Path) -> Result<BasicAudioInfo, AudioFileError> {
     let registry = create_audio_registry();
-    let handler = registry.find_handler(path)?;
-    let track = handler.read_metadata(path)?; // Temporarily read full metadata
+    let handler = registry.find_handler(path).map_err(|e| e.with_path(path))?;
+    let duration = handler.read_duration(path).map_err(|e| e.with_path(path))?;
 
     Ok(BasicAudioInfo {
-        duration: track.metadata.duration,
-        format: track.metadata.format,
+        duration: duration.map(MetadataValue::embedded),
+        format: handler
+            .supported_extensions()
+            .first()
+            .map_or_else(|| format_name(path).to_lowercase(), |ext| ext.to_string()),
     })
 }
 
+/// Read only the duration (seconds) of a file, skipping tag parsing where
+/// the format allows it. Much cheaper than [`read_metadata`] when the length
+/// is all a caller needs, e.g. for playlist `#EXTINF` lines.
+///
+/// `Ok(None)` when the stream reports no length.
+pub fn read_duration(path: &Path) -> Result<Option<f64>, AudioFileError> {
+    let registry = create_audio_registry();
+    let handler = registry.find_handler(path).map_err(|e| e.with_path(path))?;
+    handler.read_duration(path).map_err(|e| e.with_path(path))
+}
+
 /// Write metadata to a file using the appropriate format handler
+///
+/// Errors carry the file path (see [`AudioFileError::path`]).
 pub fn write_metadata(path: &Path, metadata: &TrackMetadata) -> Result<(), AudioFileError> {
     let registry = create_audio_registry();
-    let handler = registry.find_handler(path)?;
-    handler.write_metadata(path, metadata)
+    let handler = registry.find_handler(path).map_err(|e| e.with_path(path))?;
+    if !handler.can_write() {
+        return Err(AudioFileError::WriteUnsupported(format_name(path)).with_path(path));
+    }
+    handler
+        .write_metadata(path, metadata)
+        .map_err(|e| e.with_path(path))
+}
+
+/// Check if metadata can be written to a file of this format
+pub fn can_write_metadata(path: &Path) -> bool {
+    let registry = create_audio_registry();
+    registry
+        .find_handler(path)
+        .map(|handler| handler.can_write())
+        .unwrap_or(false)
+}
+
+/// Upper-case extension used to name a format in messages, e.g. "DSF"
+fn format_name(path: &Path) -> String {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_uppercase())
+        .unwrap_or_else(|| "this format's".to_string())
 }
 
 /// Check if a file format is supported
@@ -98,3 +179,27 @@ pub fn get_supported_extensions() -> Vec<String> {
     let registry = create_audio_registry();
     registry.supported_extensions()
 }
+
+/// Get all supported MIME types
+pub fn get_supported_mime_types() -> Vec<&'static str> {
+    let registry = create_audio_registry();
+    registry.supported_mime_types()
+}
+
+/// Get each supported extension paired with its MIME type, sorted by extension
+pub fn get_supported_formats() -> Vec<(String, &'static str)> {
+    let registry = create_audio_registry();
+    registry.supported_formats()
+}
+
+/// Get each supported extension with its MIME type and read/write support
+pub fn get_format_capabilities() -> Vec<FormatCapabilities> {
+    let registry = create_audio_registry();
+    registry.format_capabilities()
+}
+
+/// Get the MIME type for a file based on its format handler
+pub fn mime_for_path(path: &Path) -> Option<&'static str> {
+    let registry = create_audio_registry();
+    registry.mime_for_path(path)
+}
@@ -8,7 +8,11 @@ use lofty::{
     tag::{ItemValue, TagItem},
 };
 
-use crate::adapters::audio_formats::wav::item_value_text;
+use crate::adapters::audio_formats::tag_fields::{
+    MusicBrainzId, is_track_artists_key, item_value_text, musicbrainz_id_kind, parse_bpm,
+    parse_flag, parse_original_year, parse_replay_gain, push_track_artists, read_duration_only,
+    stream_format, track_duration, write_comment,
+};
 use crate::core::domain::models::{
     FOLDER_INFERRED_CONFIDENCE, MetadataValue, Track, TrackMetadata,
 };
@@ -42,6 +46,10 @@ impl AudioFile for Mp3Handler {
         vec!["mp3"]
     }
 
+    fn mime_type(&self) -> &'static str {
+        "audio/mpeg"
+    }
+
     fn read_metadata(&self, path: &Path) -> Result<Track, AudioFileError> {
         if !self.can_handle(path) {
             return Err(AudioFileError::UnsupportedFormat);
@@ -119,6 +127,16 @@ impl AudioFile for Mp3Handler {
             set_tag(ItemKey::Genre, &genre.value);
         }
 
+        if let Some(ref media) = metadata.media {
+            set_tag(ItemKey::OriginalMediaType, &media.value);
+        }
+
+        if let Some(ref barcode) = metadata.barcode {
+            set_tag(ItemKey::Barcode, &barcode.value);
+        }
+
+        write_comment(tag, metadata.comment.as_ref());
+
         // Save the changes to disk with default write options
         let write_options = WriteOptions::default();
         tagged_file
@@ -148,6 +166,14 @@ impl AudioFile for Mp3Handler {
 
         Ok(self.extract_basic_metadata(&tagged_file, path))
     }
+
+    fn read_duration(&self, path: &Path) -> Result<Option<f64>, AudioFileError> {
+        if !self.can_handle(path) {
+            return Err(AudioFileError::UnsupportedFormat);
+        }
+
+        read_duration_only(path, "MP3")
+    }
 }
 
 impl Mp3Handler {
@@ -161,6 +187,20 @@ impl Mp3Handler {
         let mut disc_number = None;
         let mut year = None;
         let mut genre = None;
+        let mut media = None;
+        let mut barcode = None;
+        let mut bpm = None;
+        let mut initial_key = None;
+        let mut comment = None;
+        let mut compilation = None;
+        let mut original_year = None;
+        let mut musicbrainz_recording_id = None;
+        let mut musicbrainz_release_id = None;
+        let mut artists = None;
+        let mut track_gain = None;
+        let mut track_peak = None;
+        let mut album_gain = None;
+        let mut album_peak = None;
 
         // Get the primary tag (usually ID3v2 for MP3)
         if let Some(tag) = tagged_file.primary_tag() {
@@ -205,20 +245,69 @@ impl Mp3Handler {
                     ItemKey::Genre => {
                         genre = Some(MetadataValue::embedded(item_value_str));
                     }
+                    ItemKey::OriginalMediaType => {
+                        media = Some(MetadataValue::embedded(item_value_str));
+                    }
+                    ItemKey::Barcode => {
+                        barcode = Some(MetadataValue::embedded(item_value_str));
+                    }
+                    ItemKey::Bpm | ItemKey::IntegerBpm => {
+                        if let Some(value) = parse_bpm(&item_value_str) {
+                            bpm = Some(MetadataValue::embedded(value));
+                        }
+                    }
+                    ItemKey::InitialKey => {
+                        initial_key = Some(MetadataValue::embedded(item_value_str));
+                    }
+                    ItemKey::FlagCompilation => {
+                        compilation = parse_flag(&item_value_str).map(MetadataValue::embedded);
+                    }
+                    ItemKey::Comment => {
+                        comment = Some(MetadataValue::embedded(item_value_str));
+                    }
                     ItemKey::RecordingDate => {
                         let clean_value = item_value_str.trim();
                         if let Ok(year_val) = clean_value.parse::<u32>() {
                             year = Some(MetadataValue::embedded(year_val));
                         }
                     }
-                    _ => {} // Ignore other tags for now
+                    ItemKey::ReplayGainTrackGain => {
+                        track_gain =
+                            parse_replay_gain(&item_value_str).map(MetadataValue::embedded);
+                    }
+                    ItemKey::ReplayGainTrackPeak => {
+                        track_peak =
+                            parse_replay_gain(&item_value_str).map(MetadataValue::embedded);
+                    }
+                    ItemKey::ReplayGainAlbumGain => {
+                        album_gain =
+                            parse_replay_gain(&item_value_str).map(MetadataValue::embedded);
+                    }
+                    ItemKey::ReplayGainAlbumPeak => {
+                        album_peak =
+                            parse_replay_gain(&item_value_str).map(MetadataValue::embedded);
+                    }
+                    key => {
+                        if let Some(kind) = musicbrainz_id_kind(key) {
+                            let id =
+                                Some(MetadataValue::embedded(item_value_str.trim().to_string()));
+                            match kind {
+                                MusicBrainzId::Recording => musicbrainz_recording_id = id,
+                                MusicBrainzId::Release => musicbrainz_release_id = id,
+                            }
+                        } else if is_track_artists_key(key) {
+                            push_track_artists(&mut artists, &item_value_str);
+                        } else if original_year.is_none() {
+                            original_year = parse_original_year(key, &item_value_str)
+                                .map(MetadataValue::embedded);
+                        }
+                    }
                 }
             }
         }
 
         // Get duration from file properties
-        let properties = tagged_file.properties();
-        let duration = Some(MetadataValue::embedded(properties.duration().as_secs_f64()));
+        let duration = track_duration(tagged_file);
 
         // Apply folder inference as fallback when embedded metadata is missing
         let inferred_artist = if artist.is_none() {
@@ -235,6 +324,8 @@ impl Mp3Handler {
             album
         };
 
+        let (sample_rate, bit_depth) = stream_format(tagged_file);
+
         TrackMetadata {
             title,
             artist: inferred_artist,
@@ -245,6 +336,23 @@ impl Mp3Handler {
             year,
             genre,
             duration,
+            track_gain,
+            track_peak,
+            album_gain,
+            album_peak,
+            sample_rate,
+            bit_depth,
+            media,
+            barcode,
+            bpm,
+            initial_key,
+            comment,
+            compilation,
+            original_year,
+            musicbrainz_recording_id,
+            musicbrainz_release_id,
+            artists,
+            lossy: None,
             format: "mp3".to_string(),
             path: path.to_path_buf(),
         }
@@ -253,26 +361,24 @@ impl Mp3Handler {
     /// Extract basic metadata (minimal parsing for performance)
     fn extract_basic_metadata(&self, tagged_file: &TaggedFile, path: &Path) -> TrackMetadata {
         // For basic info, just get format, duration, and use folder inference
-        let properties = tagged_file.properties();
-        let duration = Some(MetadataValue::embedded(properties.duration().as_secs_f64()));
+        let duration = track_duration(tagged_file);
 
         let inferred_artist = infer_artist_from_path(path)
             .map(|artist| MetadataValue::inferred(artist, FOLDER_INFERRED_CONFIDENCE));
         let inferred_album = infer_album_from_path(path)
             .map(|album| MetadataValue::inferred(album, FOLDER_INFERRED_CONFIDENCE));
 
+        let (sample_rate, bit_depth) = stream_format(tagged_file);
+
         TrackMetadata {
-            title: None,
             artist: inferred_artist,
             album: inferred_album,
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
-            year: None,
-            genre: None,
             duration,
+            sample_rate,
+            bit_depth,
             format: "mp3".to_string(),
             path: path.to_path_buf(),
+            ..Default::default()
         }
     }
 }
@@ -325,17 +431,9 @@ mod tests {
     fn test_mp3_handler_write_metadata_unsupported_format() {
         let handler = Mp3Handler::new();
         let metadata = TrackMetadata {
-            title: None,
-            artist: None,
-            album: None,
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
-            year: None,
-            genre: None,
-            duration: None,
             format: "mp3".to_string(),
             path: PathBuf::from("test.mp3"),
+            ..Default::default()
         };
         let result = handler.write_metadata(&PathBuf::from("test.flac"), &metadata);
         assert!(matches!(result, Err(AudioFileError::UnsupportedFormat)));
@@ -366,17 +464,9 @@ mod tests {
     fn test_mp3_handler_write_metadata_nonexistent_file() {
         let handler = Mp3Handler::new();
         let metadata = TrackMetadata {
-            title: None,
-            artist: None,
-            album: None,
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
-            year: None,
-            genre: None,
-            duration: None,
             format: "mp3".to_string(),
             path: PathBuf::from("nonexistent.mp3"),
+            ..Default::default()
         };
         let result = handler.write_metadata(&PathBuf::from("nonexistent.mp3"), &metadata);
         assert!(matches!(result, Err(AudioFileError::InvalidFile(_))));
@@ -0,0 +1,362 @@
+//! Audio-content (PCM) checksums for matching the same recording across formats.
+//!
+//! The checksum is the MD5 of the decoded samples laid out the way FLAC defines
+//! its STREAMINFO signature: interleaved, signed, little-endian, using
+//! `ceil(bits_per_sample / 8)` bytes per sample. That lets the two formats meet
+//! without a full decoder:
+//!
+//! * FLAC encoders already store this MD5 in STREAMINFO, so it is read as-is.
+//!   Files whose encoder left the signature unset (all zeros) are reported as
+//!   errors rather than hashed.
+//! * PCM WAV data is already decoded; its samples are converted to the FLAC
+//!   layout (unsigned 8-bit samples recentred, padded containers narrowed) and
+//!   hashed. This reads the whole data chunk, like the file-bytes checksum.
+//!
+//! Other formats have no PCM checksum.
+
+use std::fs::File;
+use std::io::{BufReader, Read, Seek, SeekFrom};
+use std::path::Path;
+
+use md5::{Digest, Md5};
+
+use crate::core::domain::traits::AudioFileError;
+
+const WAVE_FORMAT_PCM: u16 = 0x0001;
+const WAVE_FORMAT_EXTENSIBLE: u16 = 0xFFFE;
+/// Largest `fmt ` chunk accepted; the extensible layout needs 40 bytes.
+const MAX_FMT_CHUNK_SIZE: u32 = 256;
+
+/// Returns the PCM-content checksum of `path` as lowercase hex, or `None` for
+/// formats that have no PCM checksum.
+pub fn pcm_checksum(path: &Path) -> Result<Option<String>, AudioFileError> {
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_lowercase);
+
+    let digest = match extension.as_deref() {
+        Some("flac") => flac_audio_md5(path)?,
+        Some("wav") => wav_audio_md5(path)?,
+        _ => return Ok(None),
+    };
+
+    Ok(Some(digest.iter().map(|b| format!("{:02x}", b)).collect()))
+}
+
+/// Reads the MD5 signature of the unencoded audio from a FLAC STREAMINFO block.
+fn flac_audio_md5(path: &Path) -> Result<[u8; 16], AudioFileError> {
+    let mut reader = BufReader::new(File::open(path).map_err(io_error)?);
+
+    let mut marker = [0u8; 4];
+    reader.read_exact(&mut marker).map_err(io_error)?;
+    if &marker[..3] == b"ID3" {
+        // Skip a leading ID3v2 tag: 10-byte header with a syncsafe size.
+        let mut header = [0u8; 6];
+        reader.read_exact(&mut header).map_err(io_error)?;
+        let size = header[2..6]
+            .iter()
+            .fold(0u64, |acc, b| (acc << 7) | u64::from(b & 0x7F));
+        reader
+            .seek(SeekFrom::Current(size as i64))
+            .map_err(io_error)?;
+        reader.read_exact(&mut marker).map_err(io_error)?;
+    }
+    if &marker != b"fLaC" {
+        return Err(AudioFileError::InvalidFile(
+            "missing fLaC stream marker".to_string(),
+        ));
+    }
+
+    // STREAMINFO is always the first metadata block; the MD5 is its last 16 bytes.
+    let mut block_header = [0u8; 4];
+    reader.read_exact(&mut block_header).map_err(io_error)?;
+    let block_length = u32::from_be_bytes([0, block_header[1], block_header[2], block_header[3]]);
+    if block_header[0] & 0x7F != 0 || block_length < 34 {
+        return Err(AudioFileError::InvalidFile(
+            "first metadata block is not STREAMINFO".to_string(),
+        ));
+    }
+
+    let mut stream_info = [0u8; 34];
+    reader.read_exact(&mut stream_info).map_err(io_error)?;
+    let mut md5 = [0u8; 16];
+    md5.copy_from_slice(&stream_info[18..34]);
+    if md5 == [0u8; 16] {
+        return Err(AudioFileError::InvalidFile(
+            "FLAC stream has no audio MD5 signature".to_string(),
+        ));
+    }
+
+    Ok(md5)
+}
+
+/// Sample layout of a PCM WAV `fmt ` chunk.
+struct WavSampleFormat {
+    channels: u16,
+    block_align: u16,
+    valid_bits: u16,
+}
+
+/// Hashes the samples of a PCM WAV data chunk in FLAC's MD5 layout.
+fn wav_audio_md5(path: &Path) -> Result<[u8; 16], AudioFileError> {
+    let mut reader = BufReader::new(File::open(path).map_err(io_error)?);
+
+    let mut riff = [0u8; 12];
+    reader.read_exact(&mut riff).map_err(io_error)?;
+    if &riff[..4] != b"RIFF" || &riff[8..12] != b"WAVE" {
+        return Err(AudioFileError::InvalidFile(
+            "not a RIFF/WAVE file".to_string(),
+        ));
+    }
+
+    let mut format: Option<WavSampleFormat> = None;
+    loop {
+        let mut chunk_header = [0u8; 8];
+        if reader.read_exact(&mut chunk_header).is_err() {
+            return Err(AudioFileError::InvalidFile(
+                "no data chunk found".to_string(),
+            ));
+        }
+        let chunk_size = u32::from_le_bytes([
+            chunk_header[4],
+            chunk_header[5],
+            chunk_header[6],
+            chunk_header[7],
+        ]);
+
+        match &chunk_header[..4] {
+            b"fmt " => {
+                if chunk_size > MAX_FMT_CHUNK_SIZE {
+                    return Err(AudioFileError::InvalidFile(format!(
+                        "fmt chunk too large ({chunk_size} bytes)"
+                    )));
+                }
+                let mut chunk = vec![0u8; chunk_size as usize];
+                reader.read_exact(&mut chunk).map_err(io_error)?;
+                format = Some(parse_wav_format(&chunk)?);
+            }
+            b"data" => {
+                let format = format.ok_or_else(|| {
+                    AudioFileError::InvalidFile("data chunk before fmt chunk".to_string())
+                })?;
+                return hash_wav_samples(reader.take(u64::from(chunk_size)), &format);
+            }
+            _ => {
+                reader
+                    .seek(SeekFrom::Current(i64::from(chunk_size)))
+                    .map_err(io_error)?;
+            }
+        }
+
+        // Chunks are padded to an even size.
+        if chunk_size % 2 == 1 {
+            reader.seek(SeekFrom::Current(1)).map_err(io_error)?;
+        }
+    }
+}
+
+fn parse_wav_format(chunk: &[u8]) -> Result<WavSampleFormat, AudioFileError> {
+    let u16_at = |offset: usize| u16::from_le_bytes([chunk[offset], chunk[offset + 1]]);
+
+    if chunk.len() < 16 {
+        return Err(AudioFileError::InvalidFile(
+            "fmt chunk too short".to_string(),
+        ));
+    }
+    let format_tag = u16_at(0);
+    let channels = u16_at(2);
+    let block_align = u16_at(12);
+    let bits_per_sample = u16_at(14);
+
+    let (format_code, valid_bits) = if format_tag == WAVE_FORMAT_EXTENSIBLE && chunk.len() >= 40 {
+        let valid_bits = match u16_at(18) {
+            0 => bits_per_sample,
+            bits => bits,
+        };
+        (u16_at(24), valid_bits)
+    } else {
+        (format_tag, bits_per_sample)
+    };
+
+    if format_code != WAVE_FORMAT_PCM {
+        return Err(AudioFileError::InvalidFile(format!(
+            "WAV sample format {:#06x} is not integer PCM",
+            format_code
+        )));
+    }
+    if channels == 0 || block_align % channels != 0 {
+        return Err(AudioFileError::InvalidFile(
+            "inconsistent WAV block alignment".to_string(),
+        ));
+    }
+    let container_bytes = block_align / channels;
+    if valid_bits == 0 || container_bytes == 0 || container_bytes > 4 {
+        return Err(AudioFileError::InvalidFile(format!(
+            "unsupported WAV sample size: {} bits",
+            valid_bits
+        )));
+    }
+    if valid_bits > container_bytes * 8 {
+        return Err(AudioFileError::InvalidFile(
+            "WAV valid bits exceed sample container".to_string(),
+        ));
+    }
+
+    Ok(WavSampleFormat {
+        channels,
+        block_align,
+        valid_bits,
+    })
+}
+
+fn hash_wav_samples<R: Read>(
+    mut data: R,
+    format: &WavSampleFormat,
+) -> Result<[u8; 16], AudioFileError> {
+    let container_bytes = usize::from(format.block_align / format.channels);
+    let container_bits = container_bytes as u32 * 8;
+    let valid_bits = u32::from(format.valid_bits);
+    let out_bytes = valid_bits.div_ceil(8) as usize;
+    // 16/24/32-bit samples that fill their container are already in FLAC's layout.
+    let passthrough = container_bytes > 1 && valid_bits == container_bits;
+
+    let mut hasher = Md5::new();
+    let block_align = usize::from(format.block_align);
+    let mut buffer = vec![0u8; block_align * 4096];
+    let mut pending = 0;
+    let mut converted = Vec::with_capacity(buffer.len());
+
+    loop {
+        let read = data.read(&mut buffer[pending..]).map_err(io_error)?;
+        if read == 0 {
+            break;
+        }
+        let available = pending + read;
+        let whole = available - available % block_align;
+
+        if passthrough {
+            hasher.update(&buffer[..whole]);
+        } else {
+            converted.clear();
+            for sample in buffer[..whole].chunks_exact(container_bytes) {
+                let value = if container_bytes == 1 {
+                    // 8-bit WAV is unsigned; FLAC hashes it as signed.
+                    i32::from(sample[0]) - 128
+                } else {
+                    let mut raw = [0u8; 4];
+                    raw[4 - container_bytes..].copy_from_slice(sample);
+                    // WAV left-justifies the valid bits within the container.
+                    i32::from_le_bytes(raw) >> (32 - valid_bits)
+                };
+                converted.extend_from_slice(&value.to_le_bytes()[..out_bytes]);
+            }
+            hasher.update(&converted);
+        }
+
+        buffer.copy_within(whole..available, 0);
+        pending = available - whole;
+    }
+
+    Ok(hasher.finalize().into())
+}
+
+fn io_error(e: std::io::Error) -> AudioFileError {
+    AudioFileError::IoError(e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn wav_bytes(channels: u16, bits: u16, data: &[u8]) -> Vec<u8> {
+        let block_align = channels * bits.div_ceil(8);
+        let mut out = Vec::new();
+        out.extend_from_slice(b"RIFF");
+        out.extend_from_slice(&(36 + data.len() as u32).to_le_bytes());
+        out.extend_from_slice(b"WAVE");
+        out.extend_from_slice(b"fmt ");
+        out.extend_from_slice(&16u32.to_le_bytes());
+        out.extend_from_slice(&WAVE_FORMAT_PCM.to_le_bytes());
+        out.extend_from_slice(&channels.to_le_bytes());
+        out.extend_from_slice(&44100u32.to_le_bytes());
+        out.extend_from_slice(&(44100 * u32::from(block_align)).to_le_bytes());
+        out.extend_from_slice(&block_align.to_le_bytes());
+        out.extend_from_slice(&bits.to_le_bytes());
+        out.extend_from_slice(b"data");
+        out.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        out.extend_from_slice(data);
+        out
+    }
+
+    fn md5_hex(data: &[u8]) -> String {
+        Md5::digest(data)
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect()
+    }
+
+    #[test]
+    fn test_wav_16bit_hashes_raw_samples() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("a.wav");
+        let samples = [0x01, 0x00, 0xFF, 0xFF, 0x34, 0x12, 0x00, 0x80];
+        std::fs::write(&path, wav_bytes(2, 16, &samples)).unwrap();
+
+        assert_eq!(pcm_checksum(&path).unwrap(), Some(md5_hex(&samples)));
+    }
+
+    #[test]
+    fn test_wav_8bit_samples_are_recentred() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("a.wav");
+        std::fs::write(&path, wav_bytes(1, 8, &[0x80, 0xFF, 0x00])).unwrap();
+
+        assert_eq!(
+            pcm_checksum(&path).unwrap(),
+            Some(md5_hex(&[0x00, 0x7F, 0x80]))
+        );
+    }
+
+    #[test]
+    fn test_wav_oversized_fmt_chunk_is_error() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("a.wav");
+        let mut bytes = wav_bytes(1, 8, &[0x80]);
+        bytes[16..20].copy_from_slice(&u32::MAX.to_le_bytes());
+        std::fs::write(&path, bytes).unwrap();
+
+        assert!(pcm_checksum(&path).is_err());
+    }
+
+    #[test]
+    fn test_flac_streaminfo_md5() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("a.flac");
+        let mut bytes = b"fLaC".to_vec();
+        bytes.extend_from_slice(&[0x80, 0x00, 0x00, 34]);
+        bytes.extend_from_slice(&[0u8; 18]);
+        bytes.extend_from_slice(&[0xAB; 16]);
+        std::fs::write(&path, bytes).unwrap();
+
+        assert_eq!(pcm_checksum(&path).unwrap(), Some("ab".repeat(16)));
+    }
+
+    #[test]
+    fn test_flac_without_md5_is_error() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("a.flac");
+        let mut bytes = b"fLaC".to_vec();
+        bytes.extend_from_slice(&[0x80, 0x00, 0x00, 34]);
+        bytes.extend_from_slice(&[0u8; 34]);
+        std::fs::write(&path, bytes).unwrap();
+
+        assert!(pcm_checksum(&path).is_err());
+    }
+
+    #[test]
+    fn test_other_formats_have_no_pcm_checksum() {
+        assert_eq!(pcm_checksum(Path::new("song.mp3")).unwrap(), None);
+    }
+}
@@ -0,0 +1,76 @@
+//! EBU R128 gain values used by Opus streams.
+//!
+//! Opus stores loudness information differently from ReplayGain:
+//!
+//! * the `OpusHead` identification header carries an *output gain* that every
+//!   decoder applies unconditionally, and
+//! * the `R128_TRACK_GAIN` / `R128_ALBUM_GAIN` comments carry the additional
+//!   gain needed to reach the EBU R128 reference of -23 LUFS.
+//!
+//! Both are signed Q7.8 fixed-point integers: the value divided by 256 gives
+//! decibels. ReplayGain 2.0 targets -18 LUFS, so an R128 comment gain converts
+//! to its ReplayGain equivalent by adding 5 dB. The header output gain is
+//! already part of the decoded signal, so it is neither read nor added again.
+
+use std::path::Path;
+
+use lofty::{config::ParseOptions, file::AudioFile, ogg::OpusFile};
+
+/// Difference between the ReplayGain 2.0 (-18 LUFS) and EBU R128 (-23 LUFS)
+/// reference levels, in dB.
+pub const R128_TO_REPLAYGAIN_OFFSET_DB: f64 = 5.0;
+
+/// Converts a Q7.8 fixed-point gain to decibels.
+pub fn q78_to_db(value: i16) -> f64 {
+    f64::from(value) / 256.0
+}
+
+/// Parses an `R128_TRACK_GAIN` / `R128_ALBUM_GAIN` comment and returns the
+/// ReplayGain-equivalent gain in dB, e.g. `-2048` → -8 dB + 5 dB = -3 dB.
+///
+/// Values outside the `i16` range are rejected, as the Opus spec requires.
+pub fn parse_r128_gain(value: &str) -> Option<f64> {
+    let q78 = value.trim().trim_start_matches('+').parse::<i16>().ok()?;
+    Some(q78_to_db(q78) + R128_TO_REPLAYGAIN_OFFSET_DB)
+}
+
+/// Reads the `R128_TRACK_GAIN` and `R128_ALBUM_GAIN` comments of an Opus file,
+/// converted to ReplayGain-equivalent dB. Unreadable files yield `(None, None)`.
+pub fn read_opus_r128_gains(path: &Path) -> (Option<f64>, Option<f64>) {
+    let Ok(mut file) = std::fs::File::open(path) else {
+        return (None, None);
+    };
+    let Ok(opus) = OpusFile::read_from(&mut file, ParseOptions::new()) else {
+        return (None, None);
+    };
+
+    let comments = opus.vorbis_comments();
+    (
+        comments.get("R128_TRACK_GAIN").and_then(parse_r128_gain),
+        comments.get("R128_ALBUM_GAIN").and_then(parse_r128_gain),
+    )
+}
+
+// ── Tests ───────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_q78_to_db() {
+        assert_eq!(q78_to_db(0), 0.0);
+        assert_eq!(q78_to_db(256), 1.0);
+        assert_eq!(q78_to_db(-1536), -6.0);
+        assert_eq!(q78_to_db(i16::MIN), -128.0);
+    }
+
+    #[test]
+    fn test_parse_r128_gain_converts_to_replaygain_reference() {
+        assert_eq!(parse_r128_gain("-2048"), Some(-3.0));
+        assert_eq!(parse_r128_gain(" 0 "), Some(5.0));
+        assert_eq!(parse_r128_gain("+384"), Some(6.5));
+        assert_eq!(parse_r128_gain("-1.5"), None);
+        assert_eq!(parse_r128_gain("40000"), None);
+    }
+}
@@ -0,0 +1,110 @@
+//! Leading and trailing silence measured from decoded samples.
+//!
+//! Unlike the rest of the adapters this decodes the whole stream, so it is
+//! only built with the `silence` feature and only run on request
+//! (`verify --analyze-silence`).
+
+use std::fs::File;
+use std::path::Path;
+
+use symphonia::core::audio::SampleBuffer;
+use symphonia::core::codecs::DecoderOptions;
+use symphonia::core::errors::Error;
+use symphonia::core::formats::FormatOptions;
+use symphonia::core::io::MediaSourceStream;
+use symphonia::core::meta::MetadataOptions;
+use symphonia::core::probe::Hint;
+
+use crate::core::domain::traits::AudioFileError;
+
+/// Samples at or below this magnitude (-60 dBFS) count as silence.
+pub const SILENCE_THRESHOLD: f32 = 0.001;
+
+/// Returns the `(leading, trailing)` silence of `path` in seconds.
+///
+/// A frame is silent when every channel's sample is within
+/// [`SILENCE_THRESHOLD`]; an all-silent track reports its full length as
+/// leading silence and none as trailing. `Ok(None)` when the container or
+/// codec can't be decoded (e.g. DSD or WavPack).
+pub fn measure_silence(path: &Path) -> Result<Option<(f64, f64)>, AudioFileError> {
+    let file = File::open(path).map_err(|e| AudioFileError::IoError(e.to_string()))?;
+    let stream = MediaSourceStream::new(Box::new(file), Default::default());
+    let mut hint = Hint::new();
+    if let Some(extension) = path.extension().and_then(|e| e.to_str()) {
+        hint.with_extension(extension);
+    }
+
+    let probed = match symphonia::default::get_probe().format(
+        &hint,
+        stream,
+        &FormatOptions::default(),
+        &MetadataOptions::default(),
+    ) {
+        Ok(probed) => probed,
+        Err(Error::Unsupported(_)) => return Ok(None),
+        Err(e) => return Err(decode_error(e)),
+    };
+    let mut format = probed.format;
+    let Some(track) = format.default_track() else {
+        return Ok(None);
+    };
+    let track_id = track.id;
+    let Some(sample_rate) = track.codec_params.sample_rate else {
+        return Ok(None);
+    };
+    let mut decoder = match symphonia::default::get_codecs()
+        .make(&track.codec_params, &DecoderOptions::default())
+    {
+        Ok(decoder) => decoder,
+        Err(Error::Unsupported(_)) => return Ok(None),
+        Err(e) => return Err(decode_error(e)),
+    };
+
+    let mut frames: u64 = 0;
+    let mut first_sound: Option<u64> = None;
+    let mut last_sound: Option<u64> = None;
+    let mut buffer: Option<SampleBuffer<f32>> = None;
+    loop {
+        let packet = match format.next_packet() {
+            Ok(packet) => packet,
+            Err(Error::IoError(e)) if e.kind() == std::io::ErrorKind::UnexpectedEof => break,
+            Err(e) => return Err(decode_error(e)),
+        };
+        if packet.track_id() != track_id {
+            continue;
+        }
+        let decoded = match decoder.decode(&packet) {
+            Ok(decoded) => decoded,
+            // A corrupt packet is skipped, like a player would
+            Err(Error::DecodeError(_)) => continue,
+            Err(e) => return Err(decode_error(e)),
+        };
+
+        let spec = *decoded.spec();
+        let channels = spec.channels.count().max(1);
+        let samples =
+            buffer.get_or_insert_with(|| SampleBuffer::<f32>::new(decoded.capacity() as u64, spec));
+        if samples.capacity() < decoded.capacity() * channels {
+            *samples = SampleBuffer::<f32>::new(decoded.capacity() as u64, spec);
+        }
+        samples.copy_interleaved_ref(decoded);
+
+        for frame in samples.samples().chunks(channels) {
+            if frame.iter().any(|s| s.abs() > SILENCE_THRESHOLD) {
+                first_sound.get_or_insert(frames);
+                last_sound = Some(frames);
+            }
+            frames += 1;
+        }
+    }
+
+    let seconds = |frames: u64| frames as f64 / f64::from(sample_rate);
+    Ok(Some(match (first_sound, last_sound) {
+        (Some(first), Some(last)) => (seconds(first), seconds(frames - last - 1)),
+        _ => (seconds(frames), 0.0),
+    }))
+}
+
+fn decode_error(e: Error) -> AudioFileError {
+    AudioFileError::InvalidFile(format!("Failed to decode audio: {}", e))
+}
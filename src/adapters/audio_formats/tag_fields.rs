@@ -0,0 +1,329 @@
+//! Tag field parsing shared by the format handlers: stream properties,
+//! durations and the text values of numeric, flag and identifier tags.
+
+use lofty::{
+    config::ParseOptions,
+    file::{AudioFile as LoftyAudioFile, TaggedFile, TaggedFileExt},
+    prelude::ItemKey,
+    probe::Probe,
+    read_from_path,
+    tag::{ItemValue, Tag, TagItem, TagType},
+};
+
+use std::path::Path;
+
+use crate::core::domain::models::{MetadataValue, TAG_DURATION_CONFIDENCE};
+use crate::core::domain::traits::AudioFileError;
+
+pub fn item_value_text(tag_item: &TagItem) -> String {
+    match tag_item.value() {
+        ItemValue::Text(s) => s.to_string(),
+        ItemValue::Locator(s) => s.to_string(),
+        ItemValue::Binary(_) => "<binary data>".to_string(),
+    }
+}
+
+/// Sample rate (Hz) and bit depth read from the stream properties.
+///
+/// Lossy formats report no bit depth.
+pub fn stream_format(
+    tagged_file: &TaggedFile,
+) -> (Option<MetadataValue<u32>>, Option<MetadataValue<u32>>) {
+    let properties = tagged_file.properties();
+    (
+        properties.sample_rate().map(MetadataValue::embedded),
+        properties
+            .bit_depth()
+            .map(|bits| MetadataValue::embedded(u32::from(bits))),
+    )
+}
+
+/// Track duration: the computed stream length, or a LENGTH tag (inferred,
+/// [`TAG_DURATION_CONFIDENCE`]) when the stream reports none, e.g. behind a
+/// corrupt header.
+pub fn track_duration(tagged_file: &TaggedFile) -> Option<MetadataValue<f64>> {
+    let computed = tagged_file.properties().duration();
+    if computed.is_zero()
+        && let Some(seconds) = tag_duration(tagged_file)
+    {
+        return Some(MetadataValue::inferred(seconds, TAG_DURATION_CONFIDENCE));
+    }
+    Some(MetadataValue::embedded(computed.as_secs_f64()))
+}
+
+/// Duration in seconds from the first LENGTH (or ID3 TLEN) tag item.
+fn tag_duration(tagged_file: &TaggedFile) -> Option<f64> {
+    tagged_file.tags().iter().find_map(|tag| {
+        tag.items().find_map(|item| {
+            let millis = match item.key() {
+                // ID3 TLEN holds milliseconds
+                ItemKey::Length => tag.tag_type() == TagType::Id3v2,
+                ItemKey::Unknown(name) if name.eq_ignore_ascii_case("LENGTH") => false,
+                ItemKey::Unknown(name) if name.eq_ignore_ascii_case("TLEN") => true,
+                _ => return None,
+            };
+            parse_length(&item_value_text(item), millis)
+        })
+    })
+}
+
+/// Parses a length tag such as `245`, `245.5`, `4:05` or `1:02:05` into
+/// seconds. A plain number is read as milliseconds when `millis` is set.
+/// Returns `None` for unparseable or non-positive values.
+pub fn parse_length(value: &str, millis: bool) -> Option<f64> {
+    let value = value.trim();
+    let seconds = if value.contains(':') {
+        let mut total = 0.0;
+        for part in value.split(':') {
+            let part: f64 = part.trim().parse().ok()?;
+            if part < 0.0 {
+                return None;
+            }
+            total = total * 60.0 + part;
+        }
+        total
+    } else {
+        let number: f64 = value.replace(',', ".").parse().ok()?;
+        if millis { number / 1000.0 } else { number }
+    };
+    (seconds.is_finite() && seconds > 0.0).then_some(seconds)
+}
+
+/// Stream duration in seconds, read without parsing any tags.
+///
+/// `Ok(None)` when the stream reports no length and the file has no LENGTH
+/// tag; only then are the tags read.
+pub fn read_duration_only(path: &Path, format: &str) -> Result<Option<f64>, AudioFileError> {
+    let read_error =
+        |e| AudioFileError::InvalidFile(format!("Failed to read {} file: {}", format, e));
+    let tagged_file = Probe::open(path)
+        .and_then(|probe| probe.options(ParseOptions::new().read_tags(false)).read())
+        .map_err(read_error)?;
+    let duration = tagged_file.properties().duration();
+    if !duration.is_zero() {
+        return Ok(Some(duration.as_secs_f64()));
+    }
+    let tagged_file = read_from_path(path).map_err(read_error)?;
+    Ok(tag_duration(&tagged_file))
+}
+
+/// Parses a ReplayGain gain or peak value such as `-6.54 dB`, `+1.2dB` or `0.988547`.
+///
+/// Tolerates surrounding whitespace, a trailing `dB` unit (any case), a leading `+`
+/// and a decimal comma. Returns `None` for non-finite or unparseable values.
+pub fn parse_replay_gain(value: &str) -> Option<f64> {
+    let trimmed = value.trim();
+    let number = match trimmed.len().checked_sub(2).and_then(|i| trimmed.get(i..)) {
+        Some(unit) if unit.eq_ignore_ascii_case("db") => &trimmed[..trimmed.len() - 2],
+        _ => trimmed,
+    };
+    let number = number.trim().trim_start_matches('+').replace(',', ".");
+
+    number.parse::<f64>().ok().filter(|v| v.is_finite())
+}
+
+/// Parses a BPM tag such as `128`, `127.96` or `98,5`, rounding to a whole
+/// number. Returns `None` for unparseable, non-positive or non-finite values.
+pub fn parse_bpm(value: &str) -> Option<u32> {
+    let bpm = value.trim().replace(',', ".").parse::<f64>().ok()?;
+    (bpm.is_finite() && bpm >= 0.5).then(|| bpm.round() as u32)
+}
+
+/// Parses a boolean flag tag such as COMPILATION (`1`/`0`, `true`/`false`,
+/// `yes`/`no`). Returns `None` for anything else.
+pub fn parse_flag(value: &str) -> Option<bool> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "1" | "true" | "yes" => Some(true),
+        "0" | "false" | "no" => Some(false),
+        _ => None,
+    }
+}
+
+/// Parses the year from a date tag such as `1973` or `1973-03-01`.
+pub fn parse_year(value: &str) -> Option<u32> {
+    let digits = value.trim().get(..4)?;
+    digits
+        .chars()
+        .all(|c| c.is_ascii_digit())
+        .then(|| digits.parse().ok())
+        .flatten()
+}
+
+/// Returns the original release year for reissue tags: lofty's
+/// `OriginalReleaseDate` or a raw ORIGINALYEAR/ORIGINALDATE (Vorbis, APE)
+/// or TORY/TDOR (ID3) key.
+pub fn parse_original_year(key: &ItemKey, value: &str) -> Option<u32> {
+    let is_original = match key {
+        ItemKey::OriginalReleaseDate => true,
+        ItemKey::Unknown(name) => ["ORIGINALYEAR", "ORIGINALDATE", "TORY", "TDOR"]
+            .iter()
+            .any(|k| name.eq_ignore_ascii_case(k)),
+        _ => false,
+    };
+    if is_original { parse_year(value) } else { None }
+}
+
+/// A MusicBrainz identifier kept in [`TrackMetadata`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MusicBrainzId {
+    /// Vorbis MUSICBRAINZ_TRACKID, ID3 UFID or TXXX:MusicBrainz Track Id
+    Recording,
+    /// Vorbis MUSICBRAINZ_ALBUMID, ID3 TXXX:MusicBrainz Album Id
+    Release,
+}
+
+/// Returns which MusicBrainz identifier a tag item holds: lofty's
+/// `MusicBrainzRecordingId`/`MusicBrainzReleaseId`, or a raw Vorbis/APE key
+/// or ID3 TXXX description lofty left unmapped.
+pub fn musicbrainz_id_kind(key: &ItemKey) -> Option<MusicBrainzId> {
+    const RECORDING: [&str; 2] = ["MUSICBRAINZ_TRACKID", "MusicBrainz Track Id"];
+    const RELEASE: [&str; 2] = ["MUSICBRAINZ_ALBUMID", "MusicBrainz Album Id"];
+
+    match key {
+        ItemKey::MusicBrainzRecordingId => Some(MusicBrainzId::Recording),
+        ItemKey::MusicBrainzReleaseId => Some(MusicBrainzId::Release),
+        ItemKey::Unknown(name) => {
+            let name = name.strip_prefix("TXXX:").unwrap_or(name);
+            let matches = |keys: [&str; 2]| keys.iter().any(|k| name.eq_ignore_ascii_case(k));
+            if matches(RECORDING) {
+                Some(MusicBrainzId::Recording)
+            } else if matches(RELEASE) {
+                Some(MusicBrainzId::Release)
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Whether a tag item is Picard's plural ARTISTS tag: lofty's `TrackArtists`,
+/// or a raw Vorbis/APE key or ID3 TXXX description lofty left unmapped.
+pub fn is_track_artists_key(key: &ItemKey) -> bool {
+    match key {
+        ItemKey::TrackArtists => true,
+        ItemKey::Unknown(name) => name
+            .strip_prefix("TXXX:")
+            .unwrap_or(name)
+            .eq_ignore_ascii_case("ARTISTS"),
+        _ => false,
+    }
+}
+
+/// Appends the names of one ARTISTS item to `artists`. Vorbis comments repeat
+/// the key once per artist; ID3v2.4 keeps them in one frame separated by NUL.
+pub fn push_track_artists(artists: &mut Option<MetadataValue<Vec<String>>>, value: &str) {
+    let names: Vec<String> = value
+        .split('\0')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect();
+    if !names.is_empty() {
+        artists
+            .get_or_insert_with(|| MetadataValue::embedded(Vec::new()))
+            .value
+            .extend(names);
+    }
+}
+
+/// Writes a comment into `tag`. An empty comment removes the tag instead, so
+/// `normalize --strip-comments --apply` can clear it; `None` leaves it as is.
+pub fn write_comment(tag: &mut Tag, comment: Option<&MetadataValue<String>>) {
+    match comment {
+        Some(comment) if comment.value.is_empty() => {
+            tag.retain(|item| !matches!(item.key(), ItemKey::Comment));
+        }
+        Some(comment) => {
+            tag.insert(TagItem::new(
+                ItemKey::Comment,
+                ItemValue::Text(comment.value.clone()),
+            ));
+        }
+        None => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_replay_gain() {
+        assert_eq!(parse_replay_gain("-6.54 dB"), Some(-6.54));
+        assert_eq!(parse_replay_gain("+1.20dB"), Some(1.2));
+        assert_eq!(parse_replay_gain("  0.988547 "), Some(0.988547));
+        assert_eq!(parse_replay_gain("1,05"), Some(1.05));
+        assert_eq!(parse_replay_gain("-3 DB"), Some(-3.0));
+        assert_eq!(parse_replay_gain(""), None);
+        assert_eq!(parse_replay_gain("dB"), None);
+        assert_eq!(parse_replay_gain("loud"), None);
+        assert_eq!(parse_replay_gain("NaN"), None);
+        assert_eq!(parse_replay_gain("é"), None);
+    }
+
+    #[test]
+    fn test_parse_bpm() {
+        assert_eq!(parse_bpm("128"), Some(128));
+        assert_eq!(parse_bpm(" 127.6 "), Some(128));
+        assert_eq!(parse_bpm("98,4"), Some(98));
+        assert_eq!(parse_bpm("0"), None);
+        assert_eq!(parse_bpm("-120"), None);
+        assert_eq!(parse_bpm("fast"), None);
+    }
+
+    #[test]
+    fn test_parse_flag() {
+        assert_eq!(parse_flag("1"), Some(true));
+        assert_eq!(parse_flag(" True "), Some(true));
+        assert_eq!(parse_flag("0"), Some(false));
+        assert_eq!(parse_flag("no"), Some(false));
+        assert_eq!(parse_flag("maybe"), None);
+    }
+
+    #[test]
+    fn test_parse_original_year() {
+        assert_eq!(parse_year("1973-03-01"), Some(1973));
+        assert_eq!(parse_year("73"), None);
+        assert_eq!(
+            parse_original_year(&ItemKey::Unknown("originalyear".to_string()), "1973"),
+            Some(1973)
+        );
+        assert_eq!(
+            parse_original_year(&ItemKey::OriginalReleaseDate, "1973-03"),
+            Some(1973)
+        );
+        assert_eq!(parse_original_year(&ItemKey::Year, "2011"), None);
+    }
+
+    #[test]
+    fn test_musicbrainz_id_kind_maps_raw_keys() {
+        let unknown = |name: &str| ItemKey::Unknown(name.to_string());
+        assert_eq!(
+            musicbrainz_id_kind(&unknown("MUSICBRAINZ_TRACKID")),
+            Some(MusicBrainzId::Recording)
+        );
+        assert_eq!(
+            musicbrainz_id_kind(&unknown("TXXX:MusicBrainz Album Id")),
+            Some(MusicBrainzId::Release)
+        );
+        assert_eq!(
+            musicbrainz_id_kind(&ItemKey::MusicBrainzRecordingId),
+            Some(MusicBrainzId::Recording)
+        );
+        assert_eq!(musicbrainz_id_kind(&unknown("MUSICBRAINZ_ARTISTID")), None);
+        assert_eq!(musicbrainz_id_kind(&ItemKey::TrackTitle), None);
+    }
+
+    #[test]
+    fn test_parse_length() {
+        assert_eq!(parse_length("245", false), Some(245.0));
+        assert_eq!(parse_length(" 245,5 ", false), Some(245.5));
+        assert_eq!(parse_length("4:05", false), Some(245.0));
+        assert_eq!(parse_length("1:02:05", false), Some(3725.0));
+        assert_eq!(parse_length("245500", true), Some(245.5));
+        assert_eq!(parse_length("0", false), None);
+        assert_eq!(parse_length("4:-5", false), None);
+        assert_eq!(parse_length("unknown", false), None);
+    }
+}
@@ -8,8 +8,15 @@ use lofty::{
     tag::{ItemValue, TagItem},
 };
 
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
 use std::path::Path;
 
+use crate::adapters::audio_formats::tag_fields::{
+    MusicBrainzId, is_track_artists_key, item_value_text, musicbrainz_id_kind, parse_bpm,
+    parse_flag, parse_original_year, parse_replay_gain, push_track_artists, read_duration_only,
+    stream_format, track_duration, write_comment,
+};
 use crate::core::domain::models::{MetadataValue, Track, TrackMetadata};
 use crate::core::domain::traits::{AudioFile, AudioFileError};
 use crate::core::services::inference::{infer_album_from_path, infer_artist_from_path};
@@ -40,6 +47,10 @@ impl AudioFile for WavHandler {
         vec!["wav"]
     }
 
+    fn mime_type(&self) -> &'static str {
+        "audio/wav"
+    }
+
     fn read_metadata(&self, path: &Path) -> Result<Track, AudioFileError> {
         if !self.can_handle(path) {
             return Err(AudioFileError::UnsupportedFormat);
@@ -60,60 +71,68 @@ impl AudioFile for WavHandler {
             return Err(AudioFileError::UnsupportedFormat);
         }
 
-        // Use lofty to write metadata to WAV file
+        // Reading through lofty first rejects files that are not WAV at all
         let mut tagged_file = read_from_path(path)
             .map_err(|e| AudioFileError::InvalidFile(format!("Failed to read WAV file: {}", e)))?;
 
-        // Get or create the primary tag
-        let tag = tagged_file
-            .primary_tag_mut()
-            .ok_or_else(|| AudioFileError::WriteError("WAV file has no primary tag".to_string()))?;
+        // Update the ID3v2 tag when the file already has one
+        if let Some(tag) = tagged_file.primary_tag_mut() {
+            let mut set_tag = |key: ItemKey, value: &str| {
+                tag.insert(TagItem::new(key, ItemValue::Text(value.to_string())));
+            };
 
-        // Helper function to set a tag item
-        let mut set_tag = |key: ItemKey, value: &str| {
-            tag.insert(TagItem::new(key, ItemValue::Text(value.to_string())));
-        };
+            if let Some(ref title) = metadata.title {
+                set_tag(ItemKey::TrackTitle, &title.value);
+            }
 
-        // Write metadata fields that have values
-        if let Some(ref title) = metadata.title {
-            set_tag(ItemKey::TrackTitle, &title.value);
-        }
+            if let Some(ref artist) = metadata.artist {
+                set_tag(ItemKey::TrackArtist, &artist.value);
+            }
 
-        if let Some(ref artist) = metadata.artist {
-            set_tag(ItemKey::TrackArtist, &artist.value);
-        }
+            if let Some(ref album) = metadata.album {
+                set_tag(ItemKey::AlbumTitle, &album.value);
+            }
 
-        if let Some(ref album) = metadata.album {
-            set_tag(ItemKey::AlbumTitle, &album.value);
-        }
+            if let Some(ref album_artist) = metadata.album_artist {
+                set_tag(ItemKey::AlbumArtist, &album_artist.value);
+            }
 
-        if let Some(ref album_artist) = metadata.album_artist {
-            set_tag(ItemKey::AlbumArtist, &album_artist.value);
-        }
+            if let Some(ref track_number) = metadata.track_number {
+                set_tag(ItemKey::TrackNumber, &track_number.value.to_string());
+            }
 
-        if let Some(ref track_number) = metadata.track_number {
-            set_tag(ItemKey::TrackNumber, &track_number.value.to_string());
-        }
+            if let Some(ref disc_number) = metadata.disc_number {
+                set_tag(ItemKey::DiscNumber, &disc_number.value.to_string());
+            }
 
-        if let Some(ref disc_number) = metadata.disc_number {
-            set_tag(ItemKey::DiscNumber, &disc_number.value.to_string());
-        }
+            if let Some(ref year) = metadata.year {
+                set_tag(ItemKey::Year, &year.value.to_string());
+            }
 
-        if let Some(ref year) = metadata.year {
-            set_tag(ItemKey::Year, &year.value.to_string());
-        }
+            if let Some(ref genre) = metadata.genre {
+                set_tag(ItemKey::Genre, &genre.value);
+            }
 
-        if let Some(ref genre) = metadata.genre {
-            set_tag(ItemKey::Genre, &genre.value);
-        }
+            if let Some(ref media) = metadata.media {
+                set_tag(ItemKey::OriginalMediaType, &media.value);
+            }
+
+            if let Some(ref barcode) = metadata.barcode {
+                set_tag(ItemKey::Barcode, &barcode.value);
+            }
+
+            write_comment(tag, metadata.comment.as_ref());
 
-        // Save changes to disk with default write options
-        let write_options = WriteOptions::default();
-        tagged_file
-            .save_to_path(path, write_options)
-            .map_err(|e| AudioFileError::WriteError(format!("Failed to save WAV file: {}", e)))?;
+            // Save changes to disk with default write options
+            let write_options = WriteOptions::default();
+            tagged_file.save_to_path(path, write_options).map_err(|e| {
+                AudioFileError::WriteError(format!("Failed to save WAV file: {}", e))
+            })?;
+        }
 
-        Ok(())
+        // Always keep the RIFF INFO list in step, since many players read
+        // nothing else from WAV
+        write_list_info(path, &info_entries(metadata))
     }
 
     fn read_basic_info(&self, path: &Path) -> Result<TrackMetadata, AudioFileError> {
@@ -126,13 +145,13 @@ impl AudioFile for WavHandler {
 
         Ok(self.extract_basic_metadata(&tagged_file, path))
     }
-}
 
-pub fn item_value_text(tag_item: &TagItem) -> String {
-    match tag_item.value() {
-        ItemValue::Text(s) => s.to_string(),
-        ItemValue::Locator(s) => s.to_string(),
-        ItemValue::Binary(_) => "<binary data>".to_string(),
+    fn read_duration(&self, path: &Path) -> Result<Option<f64>, AudioFileError> {
+        if !self.can_handle(path) {
+            return Err(AudioFileError::UnsupportedFormat);
+        }
+
+        read_duration_only(path, "WAV")
     }
 }
 
@@ -147,6 +166,20 @@ impl WavHandler {
         let mut disc_number = None;
         let mut year = None;
         let mut genre = None;
+        let mut media = None;
+        let mut barcode = None;
+        let mut bpm = None;
+        let mut initial_key = None;
+        let mut comment = None;
+        let mut compilation = None;
+        let mut original_year = None;
+        let mut musicbrainz_recording_id = None;
+        let mut musicbrainz_release_id = None;
+        let mut artists = None;
+        let mut track_gain = None;
+        let mut track_peak = None;
+        let mut album_gain = None;
+        let mut album_peak = None;
 
         // Get the primary tag (usually INFO chunks for WAV)
         if let Some(tag) = tagged_file.primary_tag() {
@@ -185,8 +218,78 @@ impl WavHandler {
                     ItemKey::Genre => {
                         genre = Some(MetadataValue::embedded(item_value_str));
                     }
-                    _ => {}
+                    ItemKey::OriginalMediaType => {
+                        media = Some(MetadataValue::embedded(item_value_str));
+                    }
+                    ItemKey::Barcode => {
+                        barcode = Some(MetadataValue::embedded(item_value_str));
+                    }
+                    ItemKey::Bpm | ItemKey::IntegerBpm => {
+                        if let Some(value) = parse_bpm(&item_value_str) {
+                            bpm = Some(MetadataValue::embedded(value));
+                        }
+                    }
+                    ItemKey::InitialKey => {
+                        initial_key = Some(MetadataValue::embedded(item_value_str));
+                    }
+                    ItemKey::FlagCompilation => {
+                        compilation = parse_flag(&item_value_str).map(MetadataValue::embedded);
+                    }
+                    ItemKey::Comment => {
+                        comment = Some(MetadataValue::embedded(item_value_str));
+                    }
+                    ItemKey::ReplayGainTrackGain => {
+                        track_gain =
+                            parse_replay_gain(&item_value_str).map(MetadataValue::embedded);
+                    }
+                    ItemKey::ReplayGainTrackPeak => {
+                        track_peak =
+                            parse_replay_gain(&item_value_str).map(MetadataValue::embedded);
+                    }
+                    ItemKey::ReplayGainAlbumGain => {
+                        album_gain =
+                            parse_replay_gain(&item_value_str).map(MetadataValue::embedded);
+                    }
+                    ItemKey::ReplayGainAlbumPeak => {
+                        album_peak =
+                            parse_replay_gain(&item_value_str).map(MetadataValue::embedded);
+                    }
+                    key => {
+                        if let Some(kind) = musicbrainz_id_kind(key) {
+                            let id =
+                                Some(MetadataValue::embedded(item_value_str.trim().to_string()));
+                            match kind {
+                                MusicBrainzId::Recording => musicbrainz_recording_id = id,
+                                MusicBrainzId::Release => musicbrainz_release_id = id,
+                            }
+                        } else if is_track_artists_key(key) {
+                            push_track_artists(&mut artists, &item_value_str);
+                        } else if original_year.is_none() {
+                            original_year = parse_original_year(key, &item_value_str)
+                                .map(MetadataValue::embedded);
+                        }
+                    }
+                }
+            }
+        }
+
+        // Legacy RIFF LIST-INFO fallback. lofty only surfaces the primary
+        // tag (ID3v2 when present) and maps ICRD to a recording date rather
+        // than a year, so read the INFO chunk directly for anything missing.
+        for (id, text) in read_list_info(path) {
+            match &id {
+                b"INAM" if title.is_none() => title = Some(MetadataValue::embedded(text)),
+                b"IART" if artist.is_none() => artist = Some(MetadataValue::embedded(text)),
+                b"IPRD" if album.is_none() => album = Some(MetadataValue::embedded(text)),
+                b"IGNR" if genre.is_none() => genre = Some(MetadataValue::embedded(text)),
+                b"ICMT" if comment.is_none() => comment = Some(MetadataValue::embedded(text)),
+                b"ICRD" if year.is_none() => {
+                    year = leading_year(&text).map(MetadataValue::embedded);
                 }
+                b"ITRK" if track_number.is_none() => {
+                    track_number = text.parse::<u32>().ok().map(MetadataValue::embedded);
+                }
+                _ => {}
             }
         }
 
@@ -212,7 +315,9 @@ impl WavHandler {
         }
 
         // Extract duration from file properties
-        let duration = tagged_file.properties().duration().as_secs_f64();
+        let duration = track_duration(tagged_file);
+
+        let (sample_rate, bit_depth) = stream_format(tagged_file);
 
         TrackMetadata {
             title,
@@ -223,7 +328,24 @@ impl WavHandler {
             disc_number,
             year,
             genre,
-            duration: Some(MetadataValue::embedded(duration)),
+            duration,
+            track_gain,
+            track_peak,
+            album_gain,
+            album_peak,
+            sample_rate,
+            bit_depth,
+            media,
+            barcode,
+            bpm,
+            initial_key,
+            comment,
+            compilation,
+            original_year,
+            musicbrainz_recording_id,
+            musicbrainz_release_id,
+            artists,
+            lossy: None,
             format: "wav".to_string(),
             path: path.to_path_buf(),
         }
@@ -231,24 +353,326 @@ impl WavHandler {
 
     /// Extract basic metadata (only duration and format info)
     fn extract_basic_metadata(&self, tagged_file: &TaggedFile, path: &Path) -> TrackMetadata {
-        let duration = tagged_file.properties().duration().as_secs_f64();
+        let duration = track_duration(tagged_file);
+
+        let (sample_rate, bit_depth) = stream_format(tagged_file);
 
         TrackMetadata {
-            title: None,
-            artist: None,
-            album: None,
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
-            year: None,
-            genre: None,
-            duration: Some(MetadataValue::embedded(duration)),
+            duration,
+            sample_rate,
+            bit_depth,
             format: "wav".to_string(),
             path: path.to_path_buf(),
+            ..Default::default()
         }
     }
 }
 
+/// Reads the entries of a RIFF `LIST`/`INFO` chunk (INAM, IART, IPRD, ...).
+///
+/// Returns an empty list when the file is not RIFF/WAVE or has no INFO chunk.
+/// Only chunk headers are read while walking, so large files are cheap.
+fn read_list_info(path: &Path) -> Vec<([u8; 4], String)> {
+    parse_info_entries(&read_info_body(path))
+}
+
+/// Body of the first `LIST`/`INFO` chunk after the list type, or an empty
+/// body when there is none.
+fn read_info_body(path: &Path) -> Vec<u8> {
+    let Ok(mut file) = File::open(path) else {
+        return Vec::new();
+    };
+
+    let mut header = [0u8; 12];
+    if file.read_exact(&mut header).is_err()
+        || &header[0..4] != b"RIFF"
+        || &header[8..12] != b"WAVE"
+    {
+        return Vec::new();
+    }
+
+    let file_len = file.metadata().map(|m| m.len()).unwrap_or(0);
+    let mut chunk_header = [0u8; 8];
+    while file.read_exact(&mut chunk_header).is_ok() {
+        let size = u32::from_le_bytes([
+            chunk_header[4],
+            chunk_header[5],
+            chunk_header[6],
+            chunk_header[7],
+        ]) as u64;
+
+        // A size past the end of a truncated or corrupt file would otherwise
+        // allocate up to 4 GiB; nothing readable follows such a chunk.
+        let remaining = file_len.saturating_sub(file.stream_position().unwrap_or(file_len));
+        if size > remaining {
+            return Vec::new();
+        }
+
+        if &chunk_header[0..4] == b"LIST" && size >= 4 {
+            let mut body = vec![0u8; size as usize];
+            if file.read_exact(&mut body).is_err() {
+                return Vec::new();
+            }
+            if &body[0..4] == b"INFO" {
+                body.drain(..4);
+                return body;
+            }
+        } else if file.seek(SeekFrom::Current(size as i64)).is_err() {
+            return Vec::new();
+        }
+
+        // Chunks are word-aligned
+        if size % 2 == 1 && file.seek(SeekFrom::Current(1)).is_err() {
+            return Vec::new();
+        }
+    }
+
+    Vec::new()
+}
+
+/// INFO entries written for `metadata`; fields without a value are left out.
+fn info_entries(metadata: &TrackMetadata) -> Vec<([u8; 4], String)> {
+    let text = |id: &[u8; 4], value: &Option<MetadataValue<String>>| {
+        value.as_ref().map(|v| (*id, v.value.clone()))
+    };
+    let number = |id: &[u8; 4], value: &Option<MetadataValue<u32>>| {
+        value.as_ref().map(|v| (*id, v.value.to_string()))
+    };
+    [
+        text(b"INAM", &metadata.title),
+        text(b"IART", &metadata.artist),
+        text(b"IPRD", &metadata.album),
+        text(b"IGNR", &metadata.genre),
+        text(b"ICMT", &metadata.comment),
+        number(b"ICRD", &metadata.year),
+        number(b"ITRK", &metadata.track_number),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// A top-level chunk of a RIFF/WAVE file.
+struct RiffChunk {
+    id: [u8; 4],
+    /// Offset of the chunk header from the start of the file.
+    offset: u64,
+    /// Body size from the chunk header, excluding the pad byte.
+    size: u64,
+    /// Whether this is a `LIST` chunk of type `INFO`.
+    is_info: bool,
+}
+
+impl RiffChunk {
+    /// Offset just past the chunk, including its pad byte.
+    fn end(&self) -> u64 {
+        self.offset + 8 + self.size + self.size % 2
+    }
+}
+
+/// Lists the top-level chunks of a RIFF/WAVE file, or `None` when the file
+/// has no RIFF/WAVE header.
+fn riff_chunks(file: &mut File) -> Option<Vec<RiffChunk>> {
+    file.seek(SeekFrom::Start(0)).ok()?;
+    let mut header = [0u8; 12];
+    file.read_exact(&mut header).ok()?;
+    if &header[0..4] != b"RIFF" || &header[8..12] != b"WAVE" {
+        return None;
+    }
+
+    let len = file.metadata().ok()?.len();
+    let mut chunks = Vec::new();
+    let mut offset = 12;
+    let mut chunk_header = [0u8; 8];
+    while offset + 8 <= len {
+        file.seek(SeekFrom::Start(offset)).ok()?;
+        file.read_exact(&mut chunk_header).ok()?;
+        let size = u32::from_le_bytes([
+            chunk_header[4],
+            chunk_header[5],
+            chunk_header[6],
+            chunk_header[7],
+        ]) as u64;
+
+        let mut is_info = false;
+        if &chunk_header[0..4] == b"LIST" && size >= 4 {
+            let mut list_type = [0u8; 4];
+            file.read_exact(&mut list_type).ok()?;
+            is_info = &list_type == b"INFO";
+        }
+
+        let chunk = RiffChunk {
+            id: [
+                chunk_header[0],
+                chunk_header[1],
+                chunk_header[2],
+                chunk_header[3],
+            ],
+            offset,
+            size,
+            is_info,
+        };
+        offset = chunk.end();
+        chunks.push(chunk);
+    }
+
+    Some(chunks)
+}
+
+/// Encodes raw entry payloads as a complete `LIST`/`INFO` chunk with
+/// word-aligned values.
+fn encode_list_info(entries: &[([u8; 4], Vec<u8>)]) -> Vec<u8> {
+    let mut body = b"INFO".to_vec();
+    for (id, payload) in entries {
+        body.extend_from_slice(id);
+        body.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+        body.extend_from_slice(&payload);
+        if payload.len() % 2 == 1 {
+            body.push(0);
+        }
+    }
+
+    let mut chunk = b"LIST".to_vec();
+    chunk.extend_from_slice(&(body.len() as u32).to_le_bytes());
+    chunk.extend_from_slice(&body);
+    chunk
+}
+
+/// Sets the RIFF header size for a file that is `total_len` bytes long.
+fn write_riff_size(file: &mut File, total_len: u64) -> std::io::Result<()> {
+    file.seek(SeekFrom::Start(4))?;
+    file.write_all(&((total_len - 8) as u32).to_le_bytes())
+}
+
+/// Stores `updates` in the RIFF `LIST`/`INFO` chunk, keeping its other
+/// entries byte for byte, and adds the chunk when the file has none. An empty
+/// value removes its entry; new values are written as NUL-terminated UTF-8.
+///
+/// Only the INFO list changes. When it is already the last chunk, or absent,
+/// the file is updated in place; otherwise every other chunk, including
+/// `data`, is copied byte for byte into a replacement file with the new list
+/// at the end.
+fn write_list_info(path: &Path, updates: &[([u8; 4], String)]) -> Result<(), AudioFileError> {
+    let io_err = |e: std::io::Error| AudioFileError::IoError(e.to_string());
+
+    let mut entries = parse_raw_info_entries(&read_info_body(path));
+    for (id, text) in updates {
+        if text.is_empty() {
+            entries.retain(|(k, _)| k != id);
+            continue;
+        }
+        let mut payload = text.as_bytes().to_vec();
+        payload.push(0);
+        match entries.iter_mut().find(|(k, _)| k == id) {
+            Some(entry) => entry.1 = payload,
+            None => entries.push((*id, payload)),
+        }
+    }
+    let list = encode_list_info(&entries);
+
+    let mut file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(path)
+        .map_err(io_err)?;
+    let chunks = riff_chunks(&mut file).ok_or_else(|| {
+        AudioFileError::WriteUnsupported("WAV without a RIFF/WAVE header".to_string())
+    })?;
+
+    let file_len = file.metadata().map_err(io_err)?.len();
+    let first_info = chunks.iter().position(|c| c.is_info);
+    let info_at_end = first_info.is_none_or(|i| chunks[i..].iter().all(|c| c.is_info));
+
+    if info_at_end {
+        let end = match first_info {
+            Some(i) => chunks[i].offset,
+            // Never extend a truncated file to the size its last chunk claims
+            None => chunks
+                .last()
+                .map_or(12, RiffChunk::end)
+                .min(file_len + file_len % 2),
+        };
+        file.set_len(end).map_err(io_err)?;
+        file.seek(SeekFrom::Start(end)).map_err(io_err)?;
+        file.write_all(&list).map_err(io_err)?;
+        return write_riff_size(&mut file, end + list.len() as u64).map_err(io_err);
+    }
+
+    let permissions = file.metadata().map_err(io_err)?.permissions();
+    let tmp_path = path.with_extension("wav.tmp");
+    let mut out = File::create(&tmp_path).map_err(io_err)?;
+    let copy = |file: &mut File, out: &mut File| -> std::io::Result<()> {
+        let mut header = [0u8; 12];
+        file.seek(SeekFrom::Start(0))?;
+        file.read_exact(&mut header)?;
+        out.write_all(&header)?;
+        for chunk in chunks.iter().filter(|c| !c.is_info) {
+            file.seek(SeekFrom::Start(chunk.offset))?;
+            let copied = std::io::copy(&mut (&mut *file).take(chunk.end() - chunk.offset), out)?;
+            // A pad byte missing at end of file still has to be written
+            if copied % 2 == 1 {
+                out.write_all(&[0])?;
+            }
+        }
+        out.write_all(&list)?;
+        let total_len = out.stream_position()?;
+        write_riff_size(out, total_len)?;
+        out.set_permissions(permissions)
+    };
+    if let Err(e) = copy(&mut file, &mut out) {
+        let _ = std::fs::remove_file(&tmp_path);
+        return Err(io_err(e));
+    }
+
+    drop(out);
+    drop(file);
+    std::fs::rename(&tmp_path, path).map_err(io_err)
+}
+
+/// Parses the sub-chunks of an INFO list body into `(id, text)` pairs.
+fn parse_info_entries(data: &[u8]) -> Vec<([u8; 4], String)> {
+    parse_raw_info_entries(data)
+        .into_iter()
+        .filter_map(|(id, raw)| {
+            let text = String::from_utf8_lossy(&raw)
+                .trim_end_matches('\0')
+                .trim()
+                .to_string();
+            (!text.is_empty()).then_some((id, text))
+        })
+        .collect()
+}
+
+/// Splits an INFO list body into `(id, payload)` pairs with each payload as
+/// stored, so rewriting the list leaves untouched entries byte for byte intact.
+fn parse_raw_info_entries(mut data: &[u8]) -> Vec<([u8; 4], Vec<u8>)> {
+    let mut entries = Vec::new();
+
+    while data.len() >= 8 {
+        let id = [data[0], data[1], data[2], data[3]];
+        let size = u32::from_le_bytes([data[4], data[5], data[6], data[7]]) as usize;
+        let Some(raw) = data.get(8..8 + size) else {
+            break;
+        };
+        entries.push((id, raw.to_vec()));
+
+        let advance = 8 + size + (size % 2);
+        data = data.get(advance..).unwrap_or(&[]);
+    }
+
+    entries
+}
+
+/// Extracts a year from an ICRD value such as `1998`, `1998-05-12` or `1998/05`.
+fn leading_year(text: &str) -> Option<u32> {
+    let digits: String = text.chars().take_while(|c| c.is_ascii_digit()).collect();
+    if digits.len() == 4 {
+        digits.parse().ok()
+    } else {
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -296,17 +720,9 @@ mod tests {
     fn test_wav_handler_write_metadata_unsupported_format() {
         let handler = WavHandler::new();
         let metadata = TrackMetadata {
-            title: None,
-            artist: None,
-            album: None,
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
-            year: None,
-            genre: None,
-            duration: None,
             format: "wav".to_string(),
             path: PathBuf::from("test.wav"),
+            ..Default::default()
         };
         let result = handler.write_metadata(&PathBuf::from("test.flac"), &metadata);
         assert!(matches!(result, Err(AudioFileError::UnsupportedFormat)));
@@ -337,17 +753,9 @@ mod tests {
     fn test_wav_handler_write_metadata_nonexistent_file() {
         let handler = WavHandler::new();
         let metadata = TrackMetadata {
-            title: None,
-            artist: None,
-            album: None,
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
-            year: None,
-            genre: None,
-            duration: None,
             format: "wav".to_string(),
             path: PathBuf::from("nonexistent.wav"),
+            ..Default::default()
         };
         let result = handler.write_metadata(&PathBuf::from("nonexistent.wav"), &metadata);
         assert!(matches!(result, Err(AudioFileError::InvalidFile(_))));
@@ -389,6 +797,7 @@ mod tests {
             duration: Some(MetadataValue::embedded(180.0)),
             format: "wav".to_string(),
             path: test_file.clone(),
+            ..Default::default()
         };
 
         let result = handler.write_metadata(&test_file, &metadata);
@@ -409,14 +818,10 @@ mod tests {
             title: Some(MetadataValue::embedded("Partial Title".to_string())),
             artist: None, // No artist
             album: Some(MetadataValue::embedded("Partial Album".to_string())),
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
-            year: None,
-            genre: None,
             duration: Some(MetadataValue::embedded(120.0)),
             format: "wav".to_string(),
             path: test_file.clone(),
+            ..Default::default()
         };
 
         let result = handler.write_metadata(&test_file, &metadata);
@@ -475,14 +880,10 @@ mod tests {
             title: Some(MetadataValue::embedded("Test Title".to_string())),
             artist: Some(MetadataValue::embedded("Test Artist".to_string())),
             album: Some(MetadataValue::embedded("Test Album".to_string())),
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
-            year: None,
-            genre: None,
             duration: Some(MetadataValue::embedded(180.0)),
             format: "wav".to_string(),
             path: test_file.clone(),
+            ..Default::default()
         };
 
         let result = handler.write_metadata(&test_file, &metadata);
@@ -545,6 +946,336 @@ mod tests {
         assert!(matches!(result, Err(AudioFileError::InvalidFile(_))));
     }
 
+    fn info_sub_chunk(id: &[u8; 4], text: &str) -> Vec<u8> {
+        let mut payload = text.as_bytes().to_vec();
+        payload.push(0);
+        let mut chunk = id.to_vec();
+        chunk.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+        chunk.extend_from_slice(&payload);
+        if payload.len() % 2 == 1 {
+            chunk.push(0);
+        }
+        chunk
+    }
+
+    #[test]
+    fn test_parse_info_entries() {
+        let mut body = info_sub_chunk(b"INAM", "Song");
+        body.extend(info_sub_chunk(b"IART", "Band"));
+        body.extend(info_sub_chunk(b"ICRD", "1998-05-12"));
+
+        let entries = parse_info_entries(&body);
+        assert_eq!(
+            entries,
+            vec![
+                (*b"INAM", "Song".to_string()),
+                (*b"IART", "Band".to_string()),
+                (*b"ICRD", "1998-05-12".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_info_entries_truncated() {
+        let mut body = info_sub_chunk(b"INAM", "Song");
+        body.extend_from_slice(b"IART\xff\x00\x00\x00abc");
+
+        let entries = parse_info_entries(&body);
+        assert_eq!(entries, vec![(*b"INAM", "Song".to_string())]);
+    }
+
+    #[test]
+    fn test_leading_year() {
+        assert_eq!(leading_year("1998"), Some(1998));
+        assert_eq!(leading_year("1998-05-12"), Some(1998));
+        assert_eq!(leading_year("2001/03"), Some(2001));
+        assert_eq!(leading_year("98"), None);
+        assert_eq!(leading_year("May 1998"), None);
+    }
+
+    #[test]
+    fn test_read_list_info_non_riff_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("fake.wav");
+        fs::write(&test_file, b"not a real wav file").unwrap();
+
+        assert!(read_list_info(&test_file).is_empty());
+        assert!(read_list_info(&temp_dir.path().join("missing.wav")).is_empty());
+    }
+
+    #[test]
+    fn test_read_list_info_oversized_chunk_is_skipped() {
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("truncated.wav");
+        let mut list = b"LIST".to_vec();
+        list.extend_from_slice(&u32::MAX.to_le_bytes());
+        list.extend_from_slice(b"INFO");
+        fs::write(&test_file, synthetic_wav(&[list])).unwrap();
+
+        assert!(read_list_info(&test_file).is_empty());
+    }
+
+    /// Builds a PCM WAV with the given chunks after `fmt `.
+    fn synthetic_wav(chunks: &[Vec<u8>]) -> Vec<u8> {
+        let mut fmt = b"fmt ".to_vec();
+        fmt.extend_from_slice(&16u32.to_le_bytes());
+        fmt.extend_from_slice(&1u16.to_le_bytes()); // PCM
+        fmt.extend_from_slice(&1u16.to_le_bytes()); // mono
+        fmt.extend_from_slice(&8000u32.to_le_bytes());
+        fmt.extend_from_slice(&8000u32.to_le_bytes());
+        fmt.extend_from_slice(&1u16.to_le_bytes());
+        fmt.extend_from_slice(&8u16.to_le_bytes());
+
+        let mut body = b"WAVE".to_vec();
+        body.extend_from_slice(&fmt);
+        for chunk in chunks {
+            body.extend_from_slice(chunk);
+        }
+        let mut wav = b"RIFF".to_vec();
+        wav.extend_from_slice(&(body.len() as u32).to_le_bytes());
+        wav.extend_from_slice(&body);
+        wav
+    }
+
+    /// An odd-sized `data` chunk, so padding is exercised.
+    fn data_chunk() -> Vec<u8> {
+        let mut chunk = b"data".to_vec();
+        chunk.extend_from_slice(&5u32.to_le_bytes());
+        chunk.extend_from_slice(&[1, 2, 3, 4, 5, 0]);
+        chunk
+    }
+
+    fn list_chunk(entries: &[(&[u8; 4], &str)]) -> Vec<u8> {
+        let mut body = b"INFO".to_vec();
+        for (id, text) in entries {
+            body.extend_from_slice(&info_sub_chunk(id, text));
+        }
+        let mut chunk = b"LIST".to_vec();
+        chunk.extend_from_slice(&(body.len() as u32).to_le_bytes());
+        chunk.extend_from_slice(&body);
+        chunk
+    }
+
+    fn chunk_layout(path: &Path) -> (Vec<[u8; 4]>, Vec<u8>) {
+        let mut file = File::open(path).unwrap();
+        let chunks = riff_chunks(&mut file).unwrap();
+        let data = chunks.iter().find(|c| &c.id == b"data").unwrap();
+        let mut bytes = vec![0u8; data.size as usize];
+        file.seek(SeekFrom::Start(data.offset + 8)).unwrap();
+        file.read_exact(&mut bytes).unwrap();
+        (chunks.iter().map(|c| c.id).collect(), bytes)
+    }
+
+    fn riff_size_matches(path: &Path) -> bool {
+        let bytes = fs::read(path).unwrap();
+        u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]) as usize == bytes.len() - 8
+    }
+
+    #[test]
+    fn test_write_list_info_moves_info_after_data_and_keeps_other_entries() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("middle.wav");
+        let info = list_chunk(&[(b"INAM", "Old Title"), (b"ICMT", "Ripped with care")]);
+        fs::write(&path, synthetic_wav(&[info, data_chunk()])).unwrap();
+
+        let updates = [
+            (*b"INAM", "New Title".to_string()),
+            (*b"IART", "New Artist".to_string()),
+        ];
+        write_list_info(&path, &updates).unwrap();
+
+        let entries = read_list_info(&path);
+        assert_eq!(
+            entries,
+            vec![
+                (*b"INAM", "New Title".to_string()),
+                (*b"ICMT", "Ripped with care".to_string()),
+                (*b"IART", "New Artist".to_string()),
+            ]
+        );
+        let (ids, data) = chunk_layout(&path);
+        assert_eq!(ids, vec![*b"fmt ", *b"data", *b"LIST"]);
+        assert_eq!(data, vec![1, 2, 3, 4, 5]);
+        assert!(riff_size_matches(&path));
+        assert!(!path.with_extension("wav.tmp").exists());
+    }
+
+    #[test]
+    fn test_write_list_info_keeps_untouched_entries_byte_for_byte() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("legacy.wav");
+        // A Latin-1 comment with a trailing space, and an empty IENG entry
+        let mut body = b"INFO".to_vec();
+        body.extend_from_slice(&info_sub_chunk(b"INAM", "Old"));
+        body.extend_from_slice(b"ICMT\x06\x00\x00\x00Caf\xe9 \x00");
+        body.extend_from_slice(b"IENG\x01\x00\x00\x00\x00\x00");
+        let mut info = b"LIST".to_vec();
+        info.extend_from_slice(&(body.len() as u32).to_le_bytes());
+        info.extend_from_slice(&body);
+        fs::write(&path, synthetic_wav(&[info, data_chunk()])).unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&path, fs::Permissions::from_mode(0o640)).unwrap();
+        }
+
+        write_list_info(&path, &[(*b"INAM", "New".to_string())]).unwrap();
+
+        let raw = parse_raw_info_entries(&read_info_body(&path));
+        assert_eq!(
+            raw,
+            vec![
+                (*b"INAM", b"New\0".to_vec()),
+                (*b"ICMT", b"Caf\xe9 \0".to_vec()),
+                (*b"IENG", b"\0".to_vec()),
+            ]
+        );
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o640);
+        }
+    }
+
+    #[test]
+    fn test_write_list_info_empty_value_removes_entry() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("ripped.wav");
+        let info = list_chunk(&[(b"INAM", "Title"), (b"ICMT", "Ripped by EAC")]);
+        fs::write(&path, synthetic_wav(&[data_chunk(), info])).unwrap();
+
+        write_list_info(&path, &[(*b"ICMT", String::new())]).unwrap();
+
+        assert_eq!(read_list_info(&path), vec![(*b"INAM", "Title".to_string())]);
+        assert!(riff_size_matches(&path));
+    }
+
+    #[test]
+    fn test_write_list_info_adds_missing_chunk_in_place() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("bare.wav");
+        fs::write(&path, synthetic_wav(&[data_chunk()])).unwrap();
+
+        write_list_info(&path, &[(*b"IGNR", "Jazz".to_string())]).unwrap();
+        write_list_info(&path, &[(*b"ICRD", "1999".to_string())]).unwrap();
+
+        assert_eq!(
+            read_list_info(&path),
+            vec![
+                (*b"IGNR", "Jazz".to_string()),
+                (*b"ICRD", "1999".to_string())
+            ]
+        );
+        let (ids, data) = chunk_layout(&path);
+        assert_eq!(ids, vec![*b"fmt ", *b"data", *b"LIST"]);
+        assert_eq!(data, vec![1, 2, 3, 4, 5]);
+        assert!(riff_size_matches(&path));
+    }
+
+    #[test]
+    fn test_write_list_info_requires_riff_header() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("fake.wav");
+        fs::write(&path, b"not a real wav file").unwrap();
+
+        let result = write_list_info(&path, &[(*b"INAM", "Title".to_string())]);
+        assert!(matches!(result, Err(AudioFileError::WriteUnsupported(_))));
+    }
+
+    #[test]
+    fn test_wav_handler_write_metadata_round_trips_info() {
+        let handler = WavHandler::new();
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("legacy.wav");
+        fs::copy("tests/fixtures/wav/list_info/legacy.wav", &path).unwrap();
+        let (_, data_before) = chunk_layout(&path);
+
+        let metadata = TrackMetadata {
+            title: Some(MetadataValue::embedded("New Title".to_string())),
+            year: Some(MetadataValue::embedded(2001)),
+            track_number: Some(MetadataValue::embedded(3)),
+            ..handler.read_metadata(&path).unwrap().metadata
+        };
+        handler.write_metadata(&path, &metadata).unwrap();
+
+        let read = handler.read_metadata(&path).unwrap().metadata;
+        assert_eq!(read.title.unwrap().value, "New Title");
+        assert_eq!(read.artist.unwrap().value, "Legacy Artist");
+        assert_eq!(read.album.unwrap().value, "Legacy Album");
+        assert_eq!(read.year.unwrap().value, 2001);
+        assert_eq!(read.track_number.unwrap().value, 3);
+
+        let (_, data_after) = chunk_layout(&path);
+        assert_eq!(data_before, data_after);
+    }
+
+    #[test]
+    fn test_wav_handler_write_metadata_updates_id3v2_and_info() {
+        use lofty::tag::{Tag, TagExt, TagType};
+
+        let handler = WavHandler::new();
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("both.wav");
+        fs::copy("tests/fixtures/wav/list_info/legacy.wav", &path).unwrap();
+        let mut id3 = Tag::new(TagType::Id3v2);
+        id3.insert_text(ItemKey::TrackTitle, "Legacy Title".to_string());
+        id3.insert_text(ItemKey::TrackArtist, "Legacy Artist".to_string());
+        id3.save_to_path(&path, WriteOptions::default()).unwrap();
+        let (_, data_before) = chunk_layout(&path);
+
+        let metadata = TrackMetadata {
+            title: Some(MetadataValue::embedded("New Title".to_string())),
+            ..handler.read_metadata(&path).unwrap().metadata
+        };
+        handler.write_metadata(&path, &metadata).unwrap();
+
+        // Both tags carry the new title, the untouched artist survives in each
+        let tagged_file = read_from_path(&path).unwrap();
+        let id3 = tagged_file.tag(TagType::Id3v2).expect("ID3v2 tag kept");
+        let id3_title = id3
+            .items()
+            .find(|item| matches!(item.key(), ItemKey::TrackTitle))
+            .map(item_value_text);
+        let id3_artist = id3
+            .items()
+            .find(|item| matches!(item.key(), ItemKey::TrackArtist))
+            .map(item_value_text);
+        assert_eq!(id3_title.as_deref(), Some("New Title"));
+        assert_eq!(id3_artist.as_deref(), Some("Legacy Artist"));
+
+        let info = read_list_info(&path);
+        let info_text = |id: &[u8; 4]| info.iter().find(|(k, _)| k == id).map(|(_, v)| v.as_str());
+        assert_eq!(info_text(b"INAM"), Some("New Title"));
+        assert_eq!(info_text(b"IART"), Some("Legacy Artist"));
+
+        // Written twice, but still one INFO list and the same audio
+        let (chunks, data_after) = chunk_layout(&path);
+        assert_eq!(chunks.iter().filter(|id| *id == b"LIST").count(), 1);
+        assert_eq!(data_before, data_after);
+        assert!(riff_size_matches(&path));
+
+        let read = handler.read_metadata(&path).unwrap().metadata;
+        assert_eq!(read.title.unwrap().value, "New Title");
+    }
+
+    #[test]
+    fn test_read_list_info_legacy_fixture() {
+        let entries = read_list_info(Path::new("tests/fixtures/wav/list_info/legacy.wav"));
+        let get = |id: &[u8; 4]| {
+            entries
+                .iter()
+                .find(|(k, _)| k == id)
+                .map(|(_, v)| v.as_str())
+        };
+
+        assert_eq!(get(b"INAM"), Some("Legacy Title"));
+        assert_eq!(get(b"IART"), Some("Legacy Artist"));
+        assert_eq!(get(b"IPRD"), Some("Legacy Album"));
+        assert_eq!(get(b"ICRD"), Some("1998-05-12"));
+        assert_eq!(get(b"IGNR"), Some("Jazz"));
+    }
+
     #[test]
     fn test_wav_handler_metadata_confidence_levels() {
         // Test that embedded metadata has confidence 1.0 and inferred has lower confidence
@@ -8,7 +8,13 @@ use lofty::{
     tag::{ItemValue, TagItem},
 };
 
-use crate::adapters::audio_formats::wav::item_value_text;
+use crate::adapters::audio_formats::tag_fields::{
+    MusicBrainzId, is_track_artists_key, item_value_text, musicbrainz_id_kind, parse_bpm,
+    parse_flag, parse_original_year, parse_replay_gain, push_track_artists, read_duration_only,
+    stream_format, track_duration, write_comment,
+};
+use std::fs::File;
+use std::io::Read;
 use std::path::Path;
 
 use crate::core::domain::models::{
@@ -17,6 +23,25 @@ use crate::core::domain::models::{
 use crate::core::domain::traits::{AudioFile, AudioFileError};
 use crate::core::services::inference::{infer_album_from_path, infer_artist_from_path};
 
+/// WavPack block header flag set when the file was encoded in hybrid mode.
+const HYBRID_FLAG: u32 = 0x8;
+
+/// True when `path` is a hybrid WavPack file without its `.wvc` correction
+/// file, so only the lossy part of the audio is present.
+///
+/// Files that cannot be read or lack a WavPack block header are not treated
+/// as hybrid.
+fn is_hybrid_lossy(path: &Path) -> bool {
+    let mut header = [0u8; 32];
+    let read = File::open(path).and_then(|mut file| file.read_exact(&mut header));
+    if read.is_err() || &header[..4] != b"wvpk" {
+        return false;
+    }
+
+    let flags = u32::from_le_bytes([header[24], header[25], header[26], header[27]]);
+    flags & HYBRID_FLAG != 0 && !path.with_extension("wvc").exists()
+}
+
 /// WavPack format handler
 pub struct WavPackHandler;
 
@@ -43,6 +68,10 @@ impl AudioFile for WavPackHandler {
         vec!["wv"]
     }
 
+    fn mime_type(&self) -> &'static str {
+        "audio/x-wavpack"
+    }
+
     fn read_metadata(&self, path: &Path) -> Result<Track, AudioFileError> {
         if !self.can_handle(path) {
             return Err(AudioFileError::UnsupportedFormat);
@@ -112,6 +141,16 @@ impl AudioFile for WavPackHandler {
             set_tag(ItemKey::Genre, &genre.value);
         }
 
+        if let Some(ref media) = metadata.media {
+            set_tag(ItemKey::OriginalMediaType, &media.value);
+        }
+
+        if let Some(ref barcode) = metadata.barcode {
+            set_tag(ItemKey::Barcode, &barcode.value);
+        }
+
+        write_comment(tag, metadata.comment.as_ref());
+
         // Save the changes to disk with default write options
         let write_options = WriteOptions::default();
         tagged_file.save_to_path(path, write_options).map_err(|e| {
@@ -132,6 +171,14 @@ impl AudioFile for WavPackHandler {
 
         Ok(self.extract_basic_metadata(&tagged_file, path))
     }
+
+    fn read_duration(&self, path: &Path) -> Result<Option<f64>, AudioFileError> {
+        if !self.can_handle(path) {
+            return Err(AudioFileError::UnsupportedFormat);
+        }
+
+        read_duration_only(path, "WavPack")
+    }
 }
 
 impl WavPackHandler {
@@ -145,6 +192,20 @@ impl WavPackHandler {
         let mut disc_number = None;
         let mut year = None;
         let mut genre = None;
+        let mut media = None;
+        let mut barcode = None;
+        let mut bpm = None;
+        let mut initial_key = None;
+        let mut comment = None;
+        let mut compilation = None;
+        let mut original_year = None;
+        let mut musicbrainz_recording_id = None;
+        let mut musicbrainz_release_id = None;
+        let mut artists = None;
+        let mut track_gain = None;
+        let mut track_peak = None;
+        let mut album_gain = None;
+        let mut album_peak = None;
 
         // Get the primary tag
         if let Some(tag) = tagged_file.primary_tag() {
@@ -183,20 +244,69 @@ impl WavPackHandler {
                     ItemKey::Genre => {
                         genre = Some(MetadataValue::embedded(item_value_str));
                     }
+                    ItemKey::OriginalMediaType => {
+                        media = Some(MetadataValue::embedded(item_value_str));
+                    }
+                    ItemKey::Barcode => {
+                        barcode = Some(MetadataValue::embedded(item_value_str));
+                    }
+                    ItemKey::Bpm | ItemKey::IntegerBpm => {
+                        if let Some(value) = parse_bpm(&item_value_str) {
+                            bpm = Some(MetadataValue::embedded(value));
+                        }
+                    }
+                    ItemKey::InitialKey => {
+                        initial_key = Some(MetadataValue::embedded(item_value_str));
+                    }
+                    ItemKey::FlagCompilation => {
+                        compilation = parse_flag(&item_value_str).map(MetadataValue::embedded);
+                    }
+                    ItemKey::Comment => {
+                        comment = Some(MetadataValue::embedded(item_value_str));
+                    }
                     ItemKey::RecordingDate => {
                         let clean_value = item_value_str.trim();
                         if let Ok(year_val) = clean_value.parse::<u32>() {
                             year = Some(MetadataValue::embedded(year_val));
                         }
                     }
-                    _ => {} // Ignore other tags for now
+                    ItemKey::ReplayGainTrackGain => {
+                        track_gain =
+                            parse_replay_gain(&item_value_str).map(MetadataValue::embedded);
+                    }
+                    ItemKey::ReplayGainTrackPeak => {
+                        track_peak =
+                            parse_replay_gain(&item_value_str).map(MetadataValue::embedded);
+                    }
+                    ItemKey::ReplayGainAlbumGain => {
+                        album_gain =
+                            parse_replay_gain(&item_value_str).map(MetadataValue::embedded);
+                    }
+                    ItemKey::ReplayGainAlbumPeak => {
+                        album_peak =
+                            parse_replay_gain(&item_value_str).map(MetadataValue::embedded);
+                    }
+                    key => {
+                        if let Some(kind) = musicbrainz_id_kind(key) {
+                            let id =
+                                Some(MetadataValue::embedded(item_value_str.trim().to_string()));
+                            match kind {
+                                MusicBrainzId::Recording => musicbrainz_recording_id = id,
+                                MusicBrainzId::Release => musicbrainz_release_id = id,
+                            }
+                        } else if is_track_artists_key(key) {
+                            push_track_artists(&mut artists, &item_value_str);
+                        } else if original_year.is_none() {
+                            original_year = parse_original_year(key, &item_value_str)
+                                .map(MetadataValue::embedded);
+                        }
+                    }
                 }
             }
         }
 
         // Get duration from file properties
-        let properties = tagged_file.properties();
-        let duration = Some(MetadataValue::embedded(properties.duration().as_secs_f64()));
+        let duration = track_duration(tagged_file);
 
         // Apply folder inference as fallback when embedded metadata is missing
         let inferred_artist = if artist.is_none() {
@@ -213,6 +323,8 @@ impl WavPackHandler {
             album
         };
 
+        let (sample_rate, bit_depth) = stream_format(tagged_file);
+
         TrackMetadata {
             title,
             artist: inferred_artist,
@@ -223,6 +335,23 @@ impl WavPackHandler {
             year,
             genre,
             duration,
+            track_gain,
+            track_peak,
+            album_gain,
+            album_peak,
+            sample_rate,
+            bit_depth,
+            media,
+            barcode,
+            bpm,
+            initial_key,
+            comment,
+            compilation,
+            original_year,
+            musicbrainz_recording_id,
+            musicbrainz_release_id,
+            artists,
+            lossy: Some(MetadataValue::embedded(is_hybrid_lossy(path))),
             format: "wv".to_string(),
             path: path.to_path_buf(),
         }
@@ -231,26 +360,25 @@ impl WavPackHandler {
     /// Extract basic metadata (minimal parsing for performance)
     fn extract_basic_metadata(&self, tagged_file: &TaggedFile, path: &Path) -> TrackMetadata {
         // For basic info, just get format, duration, and use folder inference
-        let properties = tagged_file.properties();
-        let duration = Some(MetadataValue::embedded(properties.duration().as_secs_f64()));
+        let duration = track_duration(tagged_file);
 
         let inferred_artist = infer_artist_from_path(path)
             .map(|artist| MetadataValue::inferred(artist, FOLDER_INFERRED_CONFIDENCE));
         let inferred_album = infer_album_from_path(path)
             .map(|album| MetadataValue::inferred(album, FOLDER_INFERRED_CONFIDENCE));
 
+        let (sample_rate, bit_depth) = stream_format(tagged_file);
+
         TrackMetadata {
-            title: None,
             artist: inferred_artist,
             album: inferred_album,
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
-            year: None,
-            genre: None,
             duration,
+            sample_rate,
+            bit_depth,
+            lossy: Some(MetadataValue::embedded(is_hybrid_lossy(path))),
             format: "wv".to_string(),
             path: path.to_path_buf(),
+            ..Default::default()
         }
     }
 }
@@ -262,6 +390,26 @@ mod tests {
     use std::path::PathBuf;
     use tempfile::TempDir;
 
+    #[test]
+    fn test_is_hybrid_lossy_needs_missing_correction_file() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("hybrid.wv");
+        let mut header = [0u8; 32];
+        header[..4].copy_from_slice(b"wvpk");
+        header[24] = HYBRID_FLAG as u8;
+        fs::write(&path, header).unwrap();
+
+        assert!(is_hybrid_lossy(&path));
+
+        fs::write(dir.path().join("hybrid.wvc"), b"").unwrap();
+        assert!(!is_hybrid_lossy(&path));
+
+        header[24] = 0;
+        fs::write(&path, header).unwrap();
+        fs::remove_file(dir.path().join("hybrid.wvc")).unwrap();
+        assert!(!is_hybrid_lossy(&path));
+    }
+
     #[test]
     fn test_wavpack_handler_supported_extensions() {
         let handler = WavPackHandler::new();
@@ -302,17 +450,9 @@ mod tests {
     fn test_wavpack_handler_write_metadata_unsupported_format() {
         let handler = WavPackHandler::new();
         let metadata = TrackMetadata {
-            title: None,
-            artist: None,
-            album: None,
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
-            year: None,
-            genre: None,
-            duration: None,
             format: "wv".to_string(),
             path: PathBuf::from("test.wv"),
+            ..Default::default()
         };
         let result = handler.write_metadata(&PathBuf::from("test.mp3"), &metadata);
         assert!(matches!(result, Err(AudioFileError::UnsupportedFormat)));
@@ -343,17 +483,9 @@ mod tests {
     fn test_wavpack_handler_write_metadata_nonexistent_file() {
         let handler = WavPackHandler::new();
         let metadata = TrackMetadata {
-            title: None,
-            artist: None,
-            album: None,
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
-            year: None,
-            genre: None,
-            duration: None,
             format: "wv".to_string(),
             path: PathBuf::from("nonexistent.wv"),
+            ..Default::default()
         };
         let result = handler.write_metadata(&PathBuf::from("nonexistent.wv"), &metadata);
         assert!(matches!(result, Err(AudioFileError::InvalidFile(_))));
@@ -395,6 +527,7 @@ mod tests {
             duration: Some(MetadataValue::embedded(180.0)),
             format: "wv".to_string(),
             path: test_file.clone(),
+            ..Default::default()
         };
 
         let result = handler.write_metadata(&test_file, &metadata);
@@ -415,14 +548,10 @@ mod tests {
             title: Some(MetadataValue::embedded("Partial Title".to_string())),
             artist: None, // No artist
             album: Some(MetadataValue::embedded("Partial Album".to_string())),
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
-            year: None,
-            genre: None,
             duration: Some(MetadataValue::embedded(120.0)),
             format: "wv".to_string(),
             path: test_file.clone(),
+            ..Default::default()
         };
 
         let result = handler.write_metadata(&test_file, &metadata);
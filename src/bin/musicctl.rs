@@ -2,7 +2,10 @@
 
 use clap::Parser;
 use env_logger::Env;
-use music_chore::presentation::cli::{Cli, handle_command};
+use music_chore::adapters::audio_formats::get_format_capabilities;
+use music_chore::presentation::cli::{
+    Cli, OUTPUT_FORMAT_ENV, OutputFormat, handle_command, resolve_output_format,
+};
 
 fn main() {
     env_logger::Builder::from_env(Env::default().default_filter_or("warn,lofty::flac::read=error"))
@@ -16,8 +19,41 @@ fn main() {
         return;
     }
 
+    if cli.list_formats {
+        for format in get_format_capabilities() {
+            let access = match (format.can_read, format.can_write) {
+                (true, true) => "read/write",
+                (true, false) => "read-only",
+                (false, true) => "write-only",
+                (false, false) => "none",
+            };
+            println!(
+                ".{:<6} {:<18} {}",
+                format.extension, format.mime_type, access
+            );
+        }
+        return;
+    }
+
     // Handle subcommand if provided
-    if let Some(command) = cli.command {
+    if let Some(mut command) = cli.command {
+        // Apply the default output format: --json/--text > MUSIC_CHORE_FORMAT > text.
+        // A text-only option such as `tree --depth album` wins over the env default.
+        let env_format = std::env::var(OUTPUT_FORMAT_ENV).ok().and_then(|value| {
+            let format = OutputFormat::parse(&value);
+            if format.is_none() && !value.trim().is_empty() {
+                eprintln!(
+                    "Warning: ignoring {}={:?} (expected json or text)",
+                    OUTPUT_FORMAT_ENV, value
+                );
+            }
+            format
+        });
+        let env_format = env_format.filter(|_| !command.requires_text_output());
+        if let Some(json) = command.json_flag_mut() {
+            *json = resolve_output_format(*json, cli.text, env_format) == OutputFormat::Json;
+        }
+
         match handle_command(command) {
             Ok(()) => {
                 // Command succeeded
@@ -43,7 +79,8 @@ fn main() {
         println!("  help       Print this message or the help of the given subcommand(s)");
         println!();
         println!("Options:");
-        println!("  -v, --version  Show version information");
-        println!("  -h, --help     Print help");
+        println!("  -v, --version       Show version information");
+        println!("      --list-formats  List supported audio formats and MIME types");
+        println!("  -h, --help          Print help");
     }
 }
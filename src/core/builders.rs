@@ -1,39 +1,23 @@
 //! Builder patterns for complex objects in the music chore application.
 
-use crate::core::domain::models::{MetadataSource, MetadataValue, TrackMetadata};
+use crate::core::domain::models::{MetadataSource, MetadataValue, Track, TrackMetadata};
 use std::path::PathBuf;
 
 /// Builder for TrackMetadata to facilitate easy construction of metadata objects
 #[derive(Debug, Clone)]
 pub struct TrackMetadataBuilder {
-    title: Option<MetadataValue<String>>,
-    artist: Option<MetadataValue<String>>,
-    album: Option<MetadataValue<String>>,
-    album_artist: Option<MetadataValue<String>>,
-    track_number: Option<MetadataValue<u32>>,
-    disc_number: Option<MetadataValue<u32>>,
-    year: Option<MetadataValue<u32>>,
-    genre: Option<MetadataValue<String>>,
-    duration: Option<MetadataValue<f64>>,
-    format: String,
-    path: PathBuf,
+    metadata: TrackMetadata,
 }
 
 impl TrackMetadataBuilder {
     /// Create a new builder with default values
     pub fn new<P: Into<PathBuf>>(path: P) -> Self {
         Self {
-            title: None,
-            artist: None,
-            album: None,
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
-            year: None,
-            genre: None,
-            duration: None,
-            format: "unknown".to_string(),
-            path: path.into(),
+            metadata: TrackMetadata {
+                format: "unknown".to_string(),
+                path: path.into(),
+                ..Default::default()
+            },
         }
     }
 
@@ -44,7 +28,7 @@ impl TrackMetadataBuilder {
         source: MetadataSource,
         confidence: f32,
     ) -> Self {
-        self.title = Some(MetadataValue {
+        self.metadata.title = Some(MetadataValue {
             value: value.into(),
             source,
             confidence,
@@ -59,7 +43,7 @@ impl TrackMetadataBuilder {
         source: MetadataSource,
         confidence: f32,
     ) -> Self {
-        self.artist = Some(MetadataValue {
+        self.metadata.artist = Some(MetadataValue {
             value: value.into(),
             source,
             confidence,
@@ -74,7 +58,7 @@ impl TrackMetadataBuilder {
         source: MetadataSource,
         confidence: f32,
     ) -> Self {
-        self.album = Some(MetadataValue {
+        self.metadata.album = Some(MetadataValue {
             value: value.into(),
             source,
             confidence,
@@ -82,6 +66,20 @@ impl TrackMetadataBuilder {
         self
     }
 
+    /// Set the individual track artists (the plural ARTISTS tag)
+    pub fn artists<I, V>(mut self, values: I, source: MetadataSource, confidence: f32) -> Self
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<String>,
+    {
+        self.metadata.artists = Some(MetadataValue {
+            value: values.into_iter().map(Into::into).collect(),
+            source,
+            confidence,
+        });
+        self
+    }
+
     /// Set the album artist metadata
     pub fn album_artist<V: Into<String>>(
         mut self,
@@ -89,7 +87,7 @@ impl TrackMetadataBuilder {
         source: MetadataSource,
         confidence: f32,
     ) -> Self {
-        self.album_artist = Some(MetadataValue {
+        self.metadata.album_artist = Some(MetadataValue {
             value: value.into(),
             source,
             confidence,
@@ -99,7 +97,7 @@ impl TrackMetadataBuilder {
 
     /// Set the track number metadata
     pub fn track_number(mut self, value: u32, source: MetadataSource, confidence: f32) -> Self {
-        self.track_number = Some(MetadataValue {
+        self.metadata.track_number = Some(MetadataValue {
             value,
             source,
             confidence,
@@ -109,7 +107,7 @@ impl TrackMetadataBuilder {
 
     /// Set the disc number metadata
     pub fn disc_number(mut self, value: u32, source: MetadataSource, confidence: f32) -> Self {
-        self.disc_number = Some(MetadataValue {
+        self.metadata.disc_number = Some(MetadataValue {
             value,
             source,
             confidence,
@@ -119,7 +117,7 @@ impl TrackMetadataBuilder {
 
     /// Set the year metadata
     pub fn year(mut self, value: u32, source: MetadataSource, confidence: f32) -> Self {
-        self.year = Some(MetadataValue {
+        self.metadata.year = Some(MetadataValue {
             value,
             source,
             confidence,
@@ -134,7 +132,7 @@ impl TrackMetadataBuilder {
         source: MetadataSource,
         confidence: f32,
     ) -> Self {
-        self.genre = Some(MetadataValue {
+        self.metadata.genre = Some(MetadataValue {
             value: value.into(),
             source,
             confidence,
@@ -144,7 +142,167 @@ impl TrackMetadataBuilder {
 
     /// Set the duration metadata
     pub fn duration(mut self, value: f64, source: MetadataSource, confidence: f32) -> Self {
-        self.duration = Some(MetadataValue {
+        self.metadata.duration = Some(MetadataValue {
+            value,
+            source,
+            confidence,
+        });
+        self
+    }
+
+    /// Set the ReplayGain track gain (dB) metadata
+    pub fn track_gain(mut self, value: f64, source: MetadataSource, confidence: f32) -> Self {
+        self.metadata.track_gain = Some(MetadataValue {
+            value,
+            source,
+            confidence,
+        });
+        self
+    }
+
+    /// Set the ReplayGain track peak metadata
+    pub fn track_peak(mut self, value: f64, source: MetadataSource, confidence: f32) -> Self {
+        self.metadata.track_peak = Some(MetadataValue {
+            value,
+            source,
+            confidence,
+        });
+        self
+    }
+
+    /// Set the ReplayGain album gain (dB) metadata
+    pub fn album_gain(mut self, value: f64, source: MetadataSource, confidence: f32) -> Self {
+        self.metadata.album_gain = Some(MetadataValue {
+            value,
+            source,
+            confidence,
+        });
+        self
+    }
+
+    /// Set the ReplayGain album peak metadata
+    pub fn album_peak(mut self, value: f64, source: MetadataSource, confidence: f32) -> Self {
+        self.metadata.album_peak = Some(MetadataValue {
+            value,
+            source,
+            confidence,
+        });
+        self
+    }
+
+    /// Set the sample rate metadata
+    pub fn sample_rate(mut self, value: u32, source: MetadataSource, confidence: f32) -> Self {
+        self.metadata.sample_rate = Some(MetadataValue {
+            value,
+            source,
+            confidence,
+        });
+        self
+    }
+
+    /// Set the bit depth metadata
+    pub fn bit_depth(mut self, value: u32, source: MetadataSource, confidence: f32) -> Self {
+        self.metadata.bit_depth = Some(MetadataValue {
+            value,
+            source,
+            confidence,
+        });
+        self
+    }
+
+    /// Set whether the stream is lossy despite a lossless container
+    pub fn lossy(mut self, value: bool, source: MetadataSource, confidence: f32) -> Self {
+        self.metadata.lossy = Some(MetadataValue {
+            value,
+            source,
+            confidence,
+        });
+        self
+    }
+
+    /// Set the source media (e.g. CD, Vinyl) metadata
+    pub fn media<V: Into<String>>(
+        mut self,
+        value: V,
+        source: MetadataSource,
+        confidence: f32,
+    ) -> Self {
+        self.metadata.media = Some(MetadataValue {
+            value: value.into(),
+            source,
+            confidence,
+        });
+        self
+    }
+
+    /// Set the release barcode (UPC/EAN) metadata
+    pub fn barcode<V: Into<String>>(
+        mut self,
+        value: V,
+        source: MetadataSource,
+        confidence: f32,
+    ) -> Self {
+        self.metadata.barcode = Some(MetadataValue {
+            value: value.into(),
+            source,
+            confidence,
+        });
+        self
+    }
+
+    /// Set the tempo (BPM) metadata
+    pub fn bpm(mut self, value: u32, source: MetadataSource, confidence: f32) -> Self {
+        self.metadata.bpm = Some(MetadataValue {
+            value,
+            source,
+            confidence,
+        });
+        self
+    }
+
+    /// Set the musical key metadata
+    pub fn initial_key<V: Into<String>>(
+        mut self,
+        value: V,
+        source: MetadataSource,
+        confidence: f32,
+    ) -> Self {
+        self.metadata.initial_key = Some(MetadataValue {
+            value: value.into(),
+            source,
+            confidence,
+        });
+        self
+    }
+
+    /// Set the free-text comment
+    pub fn comment<V: Into<String>>(
+        mut self,
+        value: V,
+        source: MetadataSource,
+        confidence: f32,
+    ) -> Self {
+        self.metadata.comment = Some(MetadataValue {
+            value: value.into(),
+            source,
+            confidence,
+        });
+        self
+    }
+
+    /// Set the compilation flag
+    pub fn compilation(mut self, value: bool, source: MetadataSource, confidence: f32) -> Self {
+        self.metadata.compilation = Some(MetadataValue {
+            value,
+            source,
+            confidence,
+        });
+        self
+    }
+
+    /// Set the original release year
+    pub fn original_year(mut self, value: u32, source: MetadataSource, confidence: f32) -> Self {
+        self.metadata.original_year = Some(MetadataValue {
             value,
             source,
             confidence,
@@ -152,27 +310,45 @@ impl TrackMetadataBuilder {
         self
     }
 
+    /// Set the MusicBrainz recording ID
+    pub fn musicbrainz_recording_id<V: Into<String>>(
+        mut self,
+        value: V,
+        source: MetadataSource,
+        confidence: f32,
+    ) -> Self {
+        self.metadata.musicbrainz_recording_id = Some(MetadataValue {
+            value: value.into(),
+            source,
+            confidence,
+        });
+        self
+    }
+
+    /// Set the MusicBrainz release ID
+    pub fn musicbrainz_release_id<V: Into<String>>(
+        mut self,
+        value: V,
+        source: MetadataSource,
+        confidence: f32,
+    ) -> Self {
+        self.metadata.musicbrainz_release_id = Some(MetadataValue {
+            value: value.into(),
+            source,
+            confidence,
+        });
+        self
+    }
+
     /// Set the format
     pub fn format<V: Into<String>>(mut self, value: V) -> Self {
-        self.format = value.into();
+        self.metadata.format = value.into();
         self
     }
 
     /// Build the TrackMetadata instance
     pub fn build(self) -> TrackMetadata {
-        TrackMetadata {
-            title: self.title,
-            artist: self.artist,
-            album: self.album,
-            album_artist: self.album_artist,
-            track_number: self.track_number,
-            disc_number: self.disc_number,
-            year: self.year,
-            genre: self.genre,
-            duration: self.duration,
-            format: self.format,
-            path: self.path,
-        }
+        self.metadata
     }
 }
 
@@ -181,3 +357,251 @@ impl Default for TrackMetadataBuilder {
         Self::new(PathBuf::new())
     }
 }
+
+/// Builder for [`Track`] whose setters take just the value, recorded as an
+/// embedded tag (confidence 1.0). Unset fields stay `None`.
+///
+/// Use [`TrackMetadataBuilder`] when the source or confidence matters.
+///
+/// ```
+/// use music_chore::Track;
+///
+/// let track = Track::builder("Artist/Album/01 - Song.flac")
+///     .title("Song")
+///     .artist("Artist")
+///     .album("Album")
+///     .track_number(1)
+///     .format("flac")
+///     .build();
+///
+/// assert_eq!(track.metadata.title.unwrap().value, "Song");
+/// assert_eq!(track.metadata.track_number.unwrap().value, 1);
+/// assert!(track.metadata.genre.is_none());
+/// ```
+#[derive(Debug, Clone)]
+pub struct TrackBuilder {
+    path: PathBuf,
+    metadata: TrackMetadataBuilder,
+    checksum: Option<String>,
+}
+
+impl TrackBuilder {
+    /// Create a builder for the track at `path`
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        let path = path.into();
+        Self {
+            metadata: TrackMetadataBuilder::new(path.clone()),
+            path,
+            checksum: None,
+        }
+    }
+
+    /// Set the title
+    pub fn title<V: Into<String>>(mut self, value: V) -> Self {
+        self.metadata = self.metadata.title(value, MetadataSource::Embedded, 1.0);
+        self
+    }
+
+    /// Set the artist
+    pub fn artist<V: Into<String>>(mut self, value: V) -> Self {
+        self.metadata = self.metadata.artist(value, MetadataSource::Embedded, 1.0);
+        self
+    }
+
+    /// Set the album
+    pub fn album<V: Into<String>>(mut self, value: V) -> Self {
+        self.metadata = self.metadata.album(value, MetadataSource::Embedded, 1.0);
+        self
+    }
+
+    /// Set the individual track artists
+    pub fn artists<I, V>(mut self, values: I) -> Self
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<String>,
+    {
+        self.metadata = self.metadata.artists(values, MetadataSource::Embedded, 1.0);
+        self
+    }
+
+    /// Set the album artist
+    pub fn album_artist<V: Into<String>>(mut self, value: V) -> Self {
+        self.metadata = self
+            .metadata
+            .album_artist(value, MetadataSource::Embedded, 1.0);
+        self
+    }
+
+    /// Set the track number
+    pub fn track_number(mut self, value: u32) -> Self {
+        self.metadata = self
+            .metadata
+            .track_number(value, MetadataSource::Embedded, 1.0);
+        self
+    }
+
+    /// Set the disc number
+    pub fn disc_number(mut self, value: u32) -> Self {
+        self.metadata = self
+            .metadata
+            .disc_number(value, MetadataSource::Embedded, 1.0);
+        self
+    }
+
+    /// Set the year
+    pub fn year(mut self, value: u32) -> Self {
+        self.metadata = self.metadata.year(value, MetadataSource::Embedded, 1.0);
+        self
+    }
+
+    /// Set the genre
+    pub fn genre<V: Into<String>>(mut self, value: V) -> Self {
+        self.metadata = self.metadata.genre(value, MetadataSource::Embedded, 1.0);
+        self
+    }
+
+    /// Set the duration in seconds
+    pub fn duration(mut self, value: f64) -> Self {
+        self.metadata = self.metadata.duration(value, MetadataSource::Embedded, 1.0);
+        self
+    }
+
+    /// Set the ReplayGain track gain (dB)
+    pub fn track_gain(mut self, value: f64) -> Self {
+        self.metadata = self
+            .metadata
+            .track_gain(value, MetadataSource::Embedded, 1.0);
+        self
+    }
+
+    /// Set the ReplayGain track peak
+    pub fn track_peak(mut self, value: f64) -> Self {
+        self.metadata = self
+            .metadata
+            .track_peak(value, MetadataSource::Embedded, 1.0);
+        self
+    }
+
+    /// Set the ReplayGain album gain (dB)
+    pub fn album_gain(mut self, value: f64) -> Self {
+        self.metadata = self
+            .metadata
+            .album_gain(value, MetadataSource::Embedded, 1.0);
+        self
+    }
+
+    /// Set the ReplayGain album peak
+    pub fn album_peak(mut self, value: f64) -> Self {
+        self.metadata = self
+            .metadata
+            .album_peak(value, MetadataSource::Embedded, 1.0);
+        self
+    }
+
+    /// Set the sample rate
+    pub fn sample_rate(mut self, value: u32) -> Self {
+        self.metadata = self
+            .metadata
+            .sample_rate(value, MetadataSource::Embedded, 1.0);
+        self
+    }
+
+    /// Set the bit depth
+    pub fn bit_depth(mut self, value: u32) -> Self {
+        self.metadata = self
+            .metadata
+            .bit_depth(value, MetadataSource::Embedded, 1.0);
+        self
+    }
+
+    /// Mark the stream as lossy, e.g. a hybrid WavPack without its correction file
+    pub fn lossy(mut self, value: bool) -> Self {
+        self.metadata = self.metadata.lossy(value, MetadataSource::Embedded, 1.0);
+        self
+    }
+
+    /// Set the source media, e.g. "CD" or "Vinyl"
+    pub fn media<V: Into<String>>(mut self, value: V) -> Self {
+        self.metadata = self.metadata.media(value, MetadataSource::Embedded, 1.0);
+        self
+    }
+
+    /// Set the release barcode (UPC/EAN)
+    pub fn barcode<V: Into<String>>(mut self, value: V) -> Self {
+        self.metadata = self.metadata.barcode(value, MetadataSource::Embedded, 1.0);
+        self
+    }
+
+    /// Set the tempo in beats per minute
+    pub fn bpm(mut self, value: u32) -> Self {
+        self.metadata = self.metadata.bpm(value, MetadataSource::Embedded, 1.0);
+        self
+    }
+
+    /// Set the musical key, e.g. "Am" or "8A"
+    pub fn initial_key<V: Into<String>>(mut self, value: V) -> Self {
+        self.metadata = self
+            .metadata
+            .initial_key(value, MetadataSource::Embedded, 1.0);
+        self
+    }
+
+    /// Set the free-text comment
+    pub fn comment<V: Into<String>>(mut self, value: V) -> Self {
+        self.metadata = self.metadata.comment(value, MetadataSource::Embedded, 1.0);
+        self
+    }
+
+    /// Set the compilation flag
+    pub fn compilation(mut self, value: bool) -> Self {
+        self.metadata = self
+            .metadata
+            .compilation(value, MetadataSource::Embedded, 1.0);
+        self
+    }
+
+    /// Set the original release year
+    pub fn original_year(mut self, value: u32) -> Self {
+        self.metadata = self
+            .metadata
+            .original_year(value, MetadataSource::Embedded, 1.0);
+        self
+    }
+
+    /// Set the MusicBrainz recording ID
+    pub fn musicbrainz_recording_id<V: Into<String>>(mut self, value: V) -> Self {
+        self.metadata =
+            self.metadata
+                .musicbrainz_recording_id(value, MetadataSource::Embedded, 1.0);
+        self
+    }
+
+    /// Set the MusicBrainz release ID
+    pub fn musicbrainz_release_id<V: Into<String>>(mut self, value: V) -> Self {
+        self.metadata = self
+            .metadata
+            .musicbrainz_release_id(value, MetadataSource::Embedded, 1.0);
+        self
+    }
+
+    /// Set the format
+    pub fn format<V: Into<String>>(mut self, value: V) -> Self {
+        self.metadata = self.metadata.format(value);
+        self
+    }
+
+    /// Set the checksum
+    pub fn checksum<V: Into<String>>(mut self, value: V) -> Self {
+        self.checksum = Some(value.into());
+        self
+    }
+
+    /// Build the Track instance
+    pub fn build(self) -> Track {
+        Track {
+            file_path: self.path,
+            metadata: self.metadata.build(),
+            checksum: self.checksum,
+        }
+    }
+}
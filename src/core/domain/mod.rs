@@ -10,5 +10,5 @@ pub use models::{
     AlbumNode, ArtistNode, Library, MetadataSource, MetadataValue, OperationResult, Track,
     TrackMetadata, TrackNode,
 };
-pub use schema_version::{SchemaVersionWrapper, with_schema_version};
-pub use traits::{AudioFile, AudioFileError, AudioFileRegistry};
+pub use schema_version::{SCHEMA_VERSION, SchemaVersionWrapper, with_schema_version};
+pub use traits::{AudioFile, AudioFileError, AudioFileRegistry, FormatCapabilities};
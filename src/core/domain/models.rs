@@ -1,5 +1,6 @@
 //! Core domain models for music library representation.
 
+use crate::core::domain::schema_version::SCHEMA_VERSION;
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use std::path::PathBuf;
@@ -19,6 +20,10 @@ pub enum MetadataSource {
 
 pub const FOLDER_INFERRED_CONFIDENCE: f32 = 0.3;
 
+/// Confidence of a duration read from a LENGTH tag because the stream
+/// length could not be computed.
+pub const TAG_DURATION_CONFIDENCE: f32 = 0.8;
+
 /// Wrapper for metadata values with provenance
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, schemars::JsonSchema)]
 pub struct MetadataValue<T> {
@@ -68,10 +73,16 @@ impl<T: std::fmt::Display> std::fmt::Display for MetadataValue<T> {
 }
 
 /// Track metadata with provenance tracking
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, schemars::JsonSchema)]
+///
+/// `Default` leaves every field empty, so constructors only spell out the
+/// fields they set and finish with `..Default::default()`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, schemars::JsonSchema)]
 pub struct TrackMetadata {
     pub title: Option<MetadataValue<String>>,
     pub artist: Option<MetadataValue<String>>,
+    /// Each track artist on its own, from the plural ARTISTS tag Picard writes
+    /// next to the joined ARTIST (ID3 TXXX:ARTISTS). See [`TrackMetadata::artist_names`].
+    pub artists: Option<MetadataValue<Vec<String>>>,
     pub album: Option<MetadataValue<String>>,
     pub album_artist: Option<MetadataValue<String>>,
     pub track_number: Option<MetadataValue<u32>>,
@@ -79,10 +90,137 @@ pub struct TrackMetadata {
     pub year: Option<MetadataValue<u32>>,
     pub genre: Option<MetadataValue<String>>,
     pub duration: Option<MetadataValue<f64>>, // seconds
+    /// ReplayGain track gain in dB
+    pub track_gain: Option<MetadataValue<f64>>,
+    /// ReplayGain track peak, linear scale (1.0 = full scale)
+    pub track_peak: Option<MetadataValue<f64>>,
+    /// ReplayGain album gain in dB
+    pub album_gain: Option<MetadataValue<f64>>,
+    /// ReplayGain album peak, linear scale (1.0 = full scale)
+    pub album_peak: Option<MetadataValue<f64>>,
+    /// Sample rate in Hz
+    pub sample_rate: Option<MetadataValue<u32>>,
+    /// Bits per sample (lossless formats only)
+    pub bit_depth: Option<MetadataValue<u32>>,
+    /// Whether the audio is stored lossily in a format that can hold either,
+    /// e.g. hybrid WavPack without its `.wvc` correction file
+    pub lossy: Option<MetadataValue<bool>>,
+    /// Source media, e.g. "CD", "Vinyl" or "Digital Media" (Vorbis MEDIA, ID3 TMED)
+    pub media: Option<MetadataValue<String>>,
+    /// Release barcode, usually a UPC or EAN (Vorbis BARCODE, ID3 TXXX:BARCODE)
+    pub barcode: Option<MetadataValue<String>>,
+    /// Tempo in beats per minute, rounded to a whole number (Vorbis BPM, ID3 TBPM)
+    pub bpm: Option<MetadataValue<u32>>,
+    /// Musical key, e.g. "Am" or "8A" (Vorbis INITIALKEY, ID3 TKEY)
+    pub initial_key: Option<MetadataValue<String>>,
+    /// Free-text comment (Vorbis COMMENT, ID3 COMM)
+    pub comment: Option<MetadataValue<String>>,
+    /// iTunes compilation flag (MP4 cpil, Vorbis COMPILATION, ID3 TCMP)
+    pub compilation: Option<MetadataValue<bool>>,
+    /// Year of the original release for reissues (ORIGINALYEAR/ORIGINALDATE, ID3 TORY/TDOR)
+    pub original_year: Option<MetadataValue<u32>>,
+    /// MusicBrainz recording ID (Vorbis MUSICBRAINZ_TRACKID, ID3 TXXX:MusicBrainz Track Id)
+    pub musicbrainz_recording_id: Option<MetadataValue<String>>,
+    /// MusicBrainz release ID (Vorbis MUSICBRAINZ_ALBUMID, ID3 TXXX:MusicBrainz Album Id)
+    pub musicbrainz_release_id: Option<MetadataValue<String>>,
     pub format: String,
     pub path: PathBuf,
 }
 
+impl TrackMetadata {
+    /// Whether the format stores audio losslessly.
+    ///
+    /// FLAC, WAV, AIFF, DSF and APE are always lossless; MP3, AAC, Ogg and Opus
+    /// never are. WavPack is lossless unless the reader marked it [`lossy`]
+    /// (hybrid mode without the correction file).
+    ///
+    /// [`lossy`]: TrackMetadata::lossy
+    pub fn is_lossless(&self) -> bool {
+        match self.format.to_lowercase().as_str() {
+            "flac" | "wav" | "aiff" | "aif" | "dsf" | "ape" => true,
+            "wv" | "wavpack" => !self.lossy.as_ref().is_some_and(|lossy| lossy.value),
+            _ => false,
+        }
+    }
+
+    /// The individual track artists: the ARTISTS tag when present, otherwise
+    /// the ARTIST value split with [`split_artist_credit`].
+    pub fn artist_names(&self) -> Vec<String> {
+        match &self.artists {
+            Some(artists) if !artists.value.is_empty() => artists.value.clone(),
+            _ => self
+                .artist
+                .as_ref()
+                .map(|artist| split_artist_credit(&artist.value))
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Clear inferred title, artist, album and genre values whose confidence
+    /// is below `threshold`. Embedded and user-edited values are always kept.
+    pub fn drop_inferred_below(&mut self, threshold: f32) {
+        for field in [
+            &mut self.title,
+            &mut self.artist,
+            &mut self.album,
+            &mut self.genre,
+        ] {
+            if field.as_ref().is_some_and(|v| {
+                matches!(
+                    v.source,
+                    MetadataSource::FolderInferred | MetadataSource::CueInferred
+                ) && v.confidence < threshold
+            }) {
+                *field = None;
+            }
+        }
+    }
+}
+
+/// Separators that join several artists into one ARTIST value, matched
+/// case-insensitively. Commas are left out so that "Last, First" names such
+/// as "Beethoven, Ludwig van" stay whole; band names like "Earth, Wind & Fire"
+/// still split on " & ", which is what the ARTISTS tag avoids.
+const ARTIST_CREDIT_SEPARATORS: &[&str] = &[
+    "; ",
+    " / ",
+    " feat. ",
+    " feat ",
+    " ft. ",
+    " featuring ",
+    " & ",
+];
+
+/// Splits a joined artist credit such as "Artist A feat. Artist B" into its
+/// artists. A credit without separators comes back as the single name.
+pub fn split_artist_credit(credit: &str) -> Vec<String> {
+    let lower = credit.to_ascii_lowercase();
+    let mut names = Vec::new();
+    let mut start = 0;
+    let mut i = 0;
+    while i < lower.len() {
+        match ARTIST_CREDIT_SEPARATORS
+            .iter()
+            .find(|sep| lower.as_bytes()[i..].starts_with(sep.as_bytes()))
+        {
+            Some(sep) => {
+                names.push(&credit[start..i]);
+                i += sep.len();
+                start = i;
+            }
+            None => i += 1,
+        }
+    }
+    names.push(&credit[start..]);
+
+    names
+        .into_iter()
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
 /// Basic representation of a music track.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, schemars::JsonSchema)]
 pub struct Track {
@@ -101,6 +239,11 @@ impl Track {
         }
     }
 
+    /// Start building a track at `path`; see [`TrackBuilder`](crate::core::builders::TrackBuilder)
+    pub fn builder<P: Into<PathBuf>>(path: P) -> crate::core::builders::TrackBuilder {
+        crate::core::builders::TrackBuilder::new(path)
+    }
+
     /// Create a new track with checksum
     pub fn with_checksum(file_path: PathBuf, metadata: TrackMetadata, checksum: String) -> Self {
         Self {
@@ -110,6 +253,11 @@ impl Track {
         }
     }
 
+    /// Whether the track's audio is lossless; see [`TrackMetadata::is_lossless`]
+    pub fn is_lossless(&self) -> bool {
+        self.metadata.is_lossless()
+    }
+
     /// Calculate SHA256 checksum of the file
     pub fn calculate_checksum(&self) -> Result<String, Box<dyn std::error::Error>> {
         use sha2::{Digest, Sha256};
@@ -156,6 +304,30 @@ pub struct ArtistNode {
     pub albums: Vec<AlbumNode>,
 }
 
+/// Year node of the year-first tree (`tree --group-by year`)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, schemars::JsonSchema)]
+pub struct YearNode {
+    /// `None` for the tracks without a year.
+    pub year: Option<u32>,
+    pub artists: Vec<ArtistNode>,
+}
+
+impl YearNode {
+    /// The year, or "Unknown Year".
+    pub fn label(&self) -> String {
+        self.year
+            .map_or_else(|| "Unknown Year".to_string(), |y| y.to_string())
+    }
+
+    pub fn track_count(&self) -> usize {
+        self.artists
+            .iter()
+            .flat_map(|a| &a.albums)
+            .map(|a| a.tracks.len())
+            .sum()
+    }
+}
+
 /// Complete library representation
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default, schemars::JsonSchema)]
 pub struct Library {
@@ -188,6 +360,38 @@ impl Library {
         Self::default()
     }
 
+    /// Loads a library from JSON written by `emit --json` or `tree --json`.
+    ///
+    /// The `__schema_version` envelope field is optional; when present it must
+    /// match [`SCHEMA_VERSION`].
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let mut value: serde_json::Value =
+            serde_json::from_str(json).map_err(|e| format!("Invalid library JSON: {}", e))?;
+
+        if let Some(version) = value
+            .as_object_mut()
+            .and_then(|object| object.remove("__schema_version"))
+            && version.as_str() != Some(SCHEMA_VERSION)
+        {
+            return Err(format!(
+                "Unsupported schema version {} (expected \"{}\")",
+                version, SCHEMA_VERSION
+            ));
+        }
+
+        serde_json::from_value(value).map_err(|e| format!("Invalid library JSON: {}", e))
+    }
+
+    /// All tracks in the library, in hierarchy order.
+    pub fn tracks(&self) -> Vec<Track> {
+        self.artists
+            .iter()
+            .flat_map(|artist| &artist.albums)
+            .flat_map(|album| &album.tracks)
+            .map(|node| Track::new(node.file_path.clone(), node.metadata.clone()))
+            .collect()
+    }
+
     pub fn add_artist(&mut self, artist: ArtistNode) {
         self.total_artists += 1;
         self.total_albums += artist.albums.len();
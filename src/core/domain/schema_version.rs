@@ -2,6 +2,9 @@
 
 use serde::{Deserialize, Serialize};
 
+/// Schema version written to, and accepted from, JSON output.
+pub const SCHEMA_VERSION: &str = "1.0.0";
+
 #[derive(Debug, Clone, Serialize, Deserialize, schemars::JsonSchema)]
 pub struct SchemaVersionWrapper<T: serde::Serialize> {
     /// Schema version for this API
@@ -16,7 +19,7 @@ impl<T: serde::Serialize> SchemaVersionWrapper<T> {
     /// Create a new wrapper with the current schema version
     pub fn new(data: T) -> Self {
         Self {
-            schema_version: "1.0.0".to_string(),
+            schema_version: SCHEMA_VERSION.to_string(),
             data,
         }
     }
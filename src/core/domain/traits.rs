@@ -1,7 +1,7 @@
 //! Format-agnostic trait for audio file operations.
 
 use crate::core::domain::models::{Track, TrackMetadata};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 /// Errors that can occur during audio file operations
 #[derive(Debug, Clone)]
@@ -14,6 +14,43 @@ pub enum AudioFileError {
     IoError(String),
     /// Metadata could not be written
     WriteError(String),
+    /// The handler can read this format but not write its metadata
+    WriteUnsupported(String),
+    /// One of the errors above, raised for the file at `path`
+    AtPath {
+        path: PathBuf,
+        source: Box<AudioFileError>,
+    },
+}
+
+impl AudioFileError {
+    /// Attaches the file the error was raised for; an existing path is kept.
+    pub fn with_path(self, path: &Path) -> Self {
+        match self {
+            AudioFileError::AtPath { .. } => self,
+            other => AudioFileError::AtPath {
+                path: path.to_path_buf(),
+                source: Box::new(other),
+            },
+        }
+    }
+
+    /// File the error was raised for, when known.
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            AudioFileError::AtPath { path, .. } => Some(path),
+            _ => None,
+        }
+    }
+
+    /// The error without its path context, for matching on the variant or
+    /// for messages that already name the file.
+    pub fn kind(&self) -> &AudioFileError {
+        match self {
+            AudioFileError::AtPath { source, .. } => source.kind(),
+            other => other,
+        }
+    }
 }
 
 impl std::fmt::Display for AudioFileError {
@@ -23,11 +60,24 @@ impl std::fmt::Display for AudioFileError {
             AudioFileError::InvalidFile(msg) => write!(f, "Invalid file: {}", msg),
             AudioFileError::IoError(msg) => write!(f, "I/O error: {}", msg),
             AudioFileError::WriteError(msg) => write!(f, "Write error: {}", msg),
+            AudioFileError::WriteUnsupported(format) => {
+                write!(f, "Writing {} metadata is not supported", format)
+            }
+            AudioFileError::AtPath { path, source } => {
+                write!(f, "{}: {}", path.display(), source)
+            }
         }
     }
 }
 
-impl std::error::Error for AudioFileError {}
+impl std::error::Error for AudioFileError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AudioFileError::AtPath { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
 
 /// Format-agnostic trait for audio file operations
 pub trait AudioFile: Send + Sync {
@@ -37,6 +87,9 @@ pub trait AudioFile: Send + Sync {
     /// Get the file extensions this handler supports
     fn supported_extensions(&self) -> Vec<&'static str>;
 
+    /// Get the MIME type of files handled by this handler
+    fn mime_type(&self) -> &'static str;
+
     /// Read metadata from an audio file
     fn read_metadata(&self, path: &Path) -> Result<Track, AudioFileError>;
 
@@ -45,6 +98,31 @@ pub trait AudioFile: Send + Sync {
 
     /// Get basic track information without full metadata parsing
     fn read_basic_info(&self, path: &Path) -> Result<TrackMetadata, AudioFileError>;
+
+    /// Read only the duration in seconds, skipping tag parsing where the
+    /// format allows it. `Ok(None)` when the stream reports no length.
+    fn read_duration(&self, path: &Path) -> Result<Option<f64>, AudioFileError> {
+        Ok(self.read_basic_info(path)?.duration.map(|d| d.value))
+    }
+
+    /// Whether this handler can read metadata
+    fn can_read(&self) -> bool {
+        true
+    }
+
+    /// Whether this handler can write metadata
+    fn can_write(&self) -> bool {
+        true
+    }
+}
+
+/// Read/write support of a single file extension
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatCapabilities {
+    pub extension: String,
+    pub mime_type: &'static str,
+    pub can_read: bool,
+    pub can_write: bool,
 }
 
 /// Registry for audio file handlers
@@ -90,6 +168,59 @@ impl AudioFileRegistry {
         extensions.dedup();
         extensions
     }
+
+    /// Get all supported MIME types
+    pub fn supported_mime_types(&self) -> Vec<&'static str> {
+        let mut mime_types: Vec<&'static str> =
+            self.handlers.iter().map(|h| h.mime_type()).collect();
+        mime_types.sort();
+        mime_types.dedup();
+        mime_types
+    }
+
+    /// Get every supported extension (lowercase) with its handler's MIME type
+    pub fn supported_formats(&self) -> Vec<(String, &'static str)> {
+        let mut formats: Vec<(String, &'static str)> = self
+            .handlers
+            .iter()
+            .flat_map(|h| {
+                let mime = h.mime_type();
+                h.supported_extensions()
+                    .into_iter()
+                    .map(move |ext| (ext.to_lowercase(), mime))
+            })
+            .collect();
+        formats.sort();
+        formats.dedup();
+        formats
+    }
+
+    /// Get every supported extension (lowercase) with its handler's capabilities
+    pub fn format_capabilities(&self) -> Vec<FormatCapabilities> {
+        let mut formats: Vec<FormatCapabilities> = self
+            .handlers
+            .iter()
+            .flat_map(|h| {
+                let (mime_type, can_read, can_write) = (h.mime_type(), h.can_read(), h.can_write());
+                h.supported_extensions()
+                    .into_iter()
+                    .map(move |ext| FormatCapabilities {
+                        extension: ext.to_lowercase(),
+                        mime_type,
+                        can_read,
+                        can_write,
+                    })
+            })
+            .collect();
+        formats.sort_by(|a, b| a.extension.cmp(&b.extension));
+        formats.dedup_by(|a, b| a.extension == b.extension);
+        formats
+    }
+
+    /// Get the MIME type for a file, if any handler supports it
+    pub fn mime_for_path(&self, path: &Path) -> Option<&'static str> {
+        self.find_handler(path).ok().map(|h| h.mime_type())
+    }
 }
 
 impl Default for AudioFileRegistry {
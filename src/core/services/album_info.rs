@@ -0,0 +1,338 @@
+//! Human-readable `album.nfo` summaries, one per album folder.
+//!
+//! `write-album-info` scans a library, groups it into albums and writes an
+//! info file with the album tags, a track listing with durations and a
+//! format/quality line into every leaf album folder: one holding the tracks
+//! of exactly one album and no audio in subfolders.
+
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use crate::core::domain::models::{AlbumNode, TrackNode};
+use crate::core::services::cue::best_value;
+use crate::core::services::duplicates::{QualityTier, classify_metadata};
+use crate::core::services::library::build_library_hierarchy;
+use crate::core::services::mutation_summary::{FileOutcome, MutationSummary};
+use crate::core::services::scanner::scan_dir_with_metadata;
+
+/// File name written into each album folder.
+pub const ALBUM_INFO_FILE_NAME: &str = "album.nfo";
+
+/// What happened to one album folder.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AlbumInfoStatus {
+    Written,
+    /// Dry run: the file would be written.
+    WouldWrite,
+    /// The existing file already holds this content.
+    Unchanged,
+    /// A different file exists and `--force` was not given.
+    Exists,
+    /// Not a leaf album folder; the reason.
+    Skipped(String),
+    Error(String),
+}
+
+/// Outcome for one album folder.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlbumInfoResult {
+    pub directory: PathBuf,
+    pub content: String,
+    pub status: AlbumInfoStatus,
+}
+
+impl AlbumInfoResult {
+    pub fn output_path(&self) -> PathBuf {
+        self.directory.join(ALBUM_INFO_FILE_NAME)
+    }
+}
+
+/// `m:ss`, or `h:mm:ss` from one hour up.
+fn format_duration(seconds: f64) -> String {
+    let total = seconds.round() as u64;
+    let (hours, minutes, secs) = (total / 3600, total / 60 % 60, total % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, secs)
+    } else {
+        format!("{}:{:02}", minutes, secs)
+    }
+}
+
+/// One value when every track agrees, otherwise "mixed".
+fn uniform(values: BTreeSet<String>) -> Option<String> {
+    match values.len() {
+        0 => None,
+        1 => values.into_iter().next(),
+        _ => Some("mixed".to_string()),
+    }
+}
+
+/// Formats, stream format and lossless/lossy, e.g.
+/// `FLAC, 16-bit / 44.1 kHz, lossless`.
+fn quality_summary(tracks: &[TrackNode]) -> String {
+    let formats: BTreeSet<String> = tracks
+        .iter()
+        .map(|t| t.metadata.format.trim_start_matches('.').to_uppercase())
+        .collect();
+    let mut parts = vec![formats.into_iter().collect::<Vec<_>>().join("/")];
+
+    let stream = uniform(
+        tracks
+            .iter()
+            .filter_map(|t| {
+                let rate = t.metadata.sample_rate.as_ref()?.value;
+                Some(match &t.metadata.bit_depth {
+                    Some(bits) => format!("{}-bit / {} kHz", bits.value, f64::from(rate) / 1000.0),
+                    None => format!("{} kHz", f64::from(rate) / 1000.0),
+                })
+            })
+            .collect(),
+    );
+    parts.extend(stream);
+
+    let tiers: BTreeSet<QualityTier> = tracks
+        .iter()
+        .map(|t| classify_metadata(&t.metadata))
+        .collect();
+    parts.push(
+        match tiers.into_iter().collect::<Vec<_>>().as_slice() {
+            [QualityTier::Lossless] => "lossless",
+            [QualityTier::Lossy] => "lossy",
+            [QualityTier::Unknown] => "unknown quality",
+            _ => "mixed quality",
+        }
+        .to_string(),
+    );
+    parts.join(", ")
+}
+
+/// Render the `album.nfo` text for `album`: artist, album, year and genre,
+/// a track listing with durations in disc/track order, and a quality line.
+pub fn render_album_info(album: &AlbumNode) -> String {
+    let tracks = &album.tracks;
+    let mut out = String::new();
+
+    let artist = best_value(tracks, |t| t.metadata.album_artist.as_ref())
+        .or_else(|| best_value(tracks, |t| t.metadata.artist.as_ref()));
+    let title =
+        best_value(tracks, |t| t.metadata.album.as_ref()).unwrap_or_else(|| album.title.clone());
+    let year = best_value(tracks, |t| t.metadata.year.as_ref()).or(album.year);
+    let genre = best_value(tracks, |t| t.metadata.genre.as_ref());
+
+    let _ = writeln!(
+        out,
+        "Artist:  {}",
+        artist.as_deref().unwrap_or("Unknown Artist")
+    );
+    let _ = writeln!(out, "Album:   {}", title);
+    if let Some(year) = year {
+        let _ = writeln!(out, "Year:    {}", year);
+    }
+    if let Some(genre) = genre {
+        let _ = writeln!(out, "Genre:   {}", genre);
+    }
+    let _ = writeln!(out, "Quality: {}", quality_summary(tracks));
+
+    let mut ordered: Vec<&TrackNode> = tracks.iter().collect();
+    let number = |t: &TrackNode| t.metadata.track_number.as_ref().map(|n| n.value);
+    let disc = |t: &TrackNode| t.metadata.disc_number.as_ref().map_or(1, |d| d.value);
+    ordered.sort_by(|a, b| {
+        (disc(a), number(a), &a.file_path).cmp(&(disc(b), number(b), &b.file_path))
+    });
+    let multi_disc = ordered.iter().any(|t| disc(t) > 1);
+
+    let _ = writeln!(out, "\nTracks:");
+    let mut total = 0.0;
+    for (i, track) in ordered.iter().enumerate() {
+        let position = number(track).unwrap_or(i as u32 + 1);
+        let position = if multi_disc {
+            format!("{}-{:02}", disc(track), position)
+        } else {
+            format!("{:02}", position)
+        };
+        let name = track
+            .metadata
+            .title
+            .as_ref()
+            .map(|t| t.value.clone())
+            .unwrap_or_else(|| {
+                track
+                    .file_path
+                    .file_stem()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+                    .into_owned()
+            });
+        let duration = track.metadata.duration.as_ref().map(|d| d.value);
+        total += duration.unwrap_or(0.0);
+        let _ = writeln!(
+            out,
+            "  {}. {} ({})",
+            position,
+            name,
+            duration.map_or("--:--".to_string(), format_duration)
+        );
+    }
+    let _ = writeln!(
+        out,
+        "\nTotal: {} tracks, {}",
+        tracks.len(),
+        format_duration(total)
+    );
+    out
+}
+
+/// Write `album.nfo` into every leaf album folder under `path`.
+///
+/// With `dry_run` nothing is written; an existing, different file is only
+/// replaced with `force`.
+pub fn write_album_info(
+    path: &Path,
+    dry_run: bool,
+    force: bool,
+) -> Result<Vec<AlbumInfoResult>, String> {
+    let tracks =
+        scan_dir_with_metadata(path).map_err(|e| format!("Failed to scan directory: {}", e))?;
+    let track_dirs: Vec<PathBuf> = tracks
+        .iter()
+        .filter_map(|t| t.file_path.parent().map(Path::to_path_buf))
+        .collect();
+    let library = build_library_hierarchy(tracks);
+    let albums: Vec<&AlbumNode> = library
+        .artists
+        .iter()
+        .flat_map(|artist| &artist.albums)
+        .collect();
+
+    let mut results: Vec<AlbumInfoResult> = albums
+        .iter()
+        .map(|album| {
+            let directory = album.path.clone();
+            let skip = if album
+                .tracks
+                .iter()
+                .any(|t| t.file_path.parent() != Some(directory.as_path()))
+                || track_dirs
+                    .iter()
+                    .any(|d| d != &directory && d.starts_with(&directory))
+            {
+                Some("tracks in subfolders")
+            } else if albums.iter().filter(|a| a.path == directory).count() > 1 {
+                Some("folder holds more than one album")
+            } else {
+                None
+            };
+            if let Some(reason) = skip {
+                return AlbumInfoResult {
+                    directory,
+                    content: String::new(),
+                    status: AlbumInfoStatus::Skipped(reason.to_string()),
+                };
+            }
+
+            let content = render_album_info(album);
+            let output = directory.join(ALBUM_INFO_FILE_NAME);
+            let status = match std::fs::read_to_string(&output) {
+                Ok(existing) if existing == content => AlbumInfoStatus::Unchanged,
+                Ok(_) if !force => AlbumInfoStatus::Exists,
+                _ if dry_run => AlbumInfoStatus::WouldWrite,
+                _ => match std::fs::write(&output, &content) {
+                    Ok(()) => AlbumInfoStatus::Written,
+                    Err(e) => AlbumInfoStatus::Error(e.to_string()),
+                },
+            };
+            AlbumInfoResult {
+                directory,
+                content,
+                status,
+            }
+        })
+        .collect();
+    results.sort_by(|a, b| a.directory.cmp(&b.directory));
+    results.dedup_by(|a, b| a.directory == b.directory && a.status == b.status);
+    Ok(results)
+}
+
+/// Format results as text; a dry run prints each file's content. Ends with
+/// the [`MutationSummary`] line.
+pub fn format_album_info_results(results: &[AlbumInfoResult], dry_run: bool) -> String {
+    let mut out = String::new();
+    let mut summary = MutationSummary::new();
+    for result in results {
+        let output = result.output_path();
+        match &result.status {
+            AlbumInfoStatus::Written => {
+                summary.record(&output, FileOutcome::Changed);
+                let _ = writeln!(out, "WROTE {}", output.display());
+            }
+            AlbumInfoStatus::WouldWrite => {
+                summary.record(&output, FileOutcome::Changed);
+                let _ = writeln!(out, "=== {} ===\n{}", output.display(), result.content);
+            }
+            AlbumInfoStatus::Unchanged => {
+                summary.record(&output, FileOutcome::Unchanged);
+            }
+            AlbumInfoStatus::Exists => {
+                summary.record(&output, FileOutcome::Unchanged);
+                let _ = writeln!(
+                    out,
+                    "EXISTS {} (use --force to overwrite)",
+                    output.display()
+                );
+            }
+            AlbumInfoStatus::Skipped(reason) => {
+                let _ = writeln!(out, "SKIPPED {}: {}", result.directory.display(), reason);
+            }
+            AlbumInfoStatus::Error(e) => {
+                summary.record(&output, FileOutcome::Error);
+                let _ = writeln!(out, "ERROR {}: {}", output.display(), e);
+            }
+        }
+    }
+    out.push_str(&summary.line(dry_run));
+    out
+}
+
+// ── Tests ───────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::builders::TrackBuilder;
+
+    #[test]
+    fn test_render_album_info_lists_tracks_in_order() {
+        let track = |path: &str, number: u32, title: &str, seconds: f64| {
+            TrackBuilder::new(path)
+                .artist("Band")
+                .album("Record")
+                .title(title)
+                .track_number(number)
+                .year(1999)
+                .genre("Rock")
+                .duration(seconds)
+                .format("flac")
+                .build()
+        };
+        let library = build_library_hierarchy(vec![
+            track("Band/Record/02.flac", 2, "Second", 3725.0),
+            track("Band/Record/01.flac", 1, "First", 65.4),
+        ]);
+
+        let info = render_album_info(&library.artists[0].albums[0]);
+
+        assert!(info.starts_with("Artist:  Band\nAlbum:   Record\nYear:    1999\n"));
+        assert!(info.contains("Genre:   Rock\n"));
+        assert!(info.contains("Quality: FLAC, lossless\n"));
+        assert!(info.contains("  01. First (1:05)\n  02. Second (1:02:05)\n"));
+        assert!(info.ends_with("Total: 2 tracks, 1:03:10\n"));
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(0.0), "0:00");
+        assert_eq!(format_duration(59.6), "1:00");
+        assert_eq!(format_duration(3600.0), "1:00:00");
+    }
+}
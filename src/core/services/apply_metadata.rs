@@ -1,5 +1,7 @@
 use crate::adapters::audio_formats::{read_metadata, write_metadata};
 use crate::core::errors::MusicChoreError;
+use crate::core::services::mutation_summary::{FileOutcome, MutationSummary};
+use crate::core::services::toml_metadata::TomlTrack;
 use std::fmt::Write;
 use std::path::Path;
 
@@ -52,14 +54,14 @@ pub fn write_metadata_by_path(
         Ok(track) => track,
         Err(e) => {
             return Err(MusicChoreError::MetadataParseError(format!(
-                "Unsupported file format: {}, error: {}",
-                file.display(),
+                "Unsupported file format: {}",
                 e
             )));
         }
     };
 
     let mut out = String::new();
+    let before = TomlTrack::from_metadata(file, &track.metadata);
 
     // Parse and apply metadata updates
     for metadata_item in set {
@@ -86,14 +88,24 @@ pub fn write_metadata_by_path(
         }
     }
 
+    let mut summary = MutationSummary::new();
+    let outcome = if TomlTrack::from_metadata(file, &track.metadata) == before {
+        FileOutcome::Unchanged
+    } else {
+        FileOutcome::Changed
+    };
+    summary.record(file, outcome);
+
     if effective_dry_run {
         writeln!(out, "DRY RUN: No changes made to file: {}", file.display()).unwrap();
+        writeln!(out, "{}", summary.line(true)).unwrap();
         return Ok(out);
     }
 
     match write_metadata(file, &track.metadata) {
         Ok(()) => {
             writeln!(out, "Successfully updated metadata: {}", file.display()).unwrap();
+            writeln!(out, "{}", summary.line(false)).unwrap();
             Ok(out)
         }
         Err(e) => Err(MusicChoreError::Other(format!(
@@ -165,6 +177,15 @@ fn apply_metadata_update(
         "genre" => {
             metadata.genre = Some(MetadataValue::user_set(value.to_string()));
         }
+        "media" => {
+            metadata.media = Some(MetadataValue::user_set(value.to_string()));
+        }
+        "barcode" => {
+            metadata.barcode = Some(MetadataValue::user_set(value.to_string()));
+        }
+        "comment" => {
+            metadata.comment = Some(MetadataValue::user_set(value.to_string()));
+        }
         _ => {
             return Err(MusicChoreError::InvalidMetadataField {
                 field: key.to_string(),
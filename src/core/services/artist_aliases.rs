@@ -0,0 +1,307 @@
+//! Artist spellings that name the same artist.
+//!
+//! "The Beatles", "Beatles" and "the beatles" end up as three artists in the
+//! tree. [`detect_artist_aliases`] groups such spellings into clusters, and
+//! [`apply_artist_canonicalization`] rewrites a cluster's other spellings to
+//! the one the user picks. Nothing is picked automatically.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::adapters::audio_formats as formats;
+use crate::core::domain::models::Track;
+use crate::core::services::apply_metadata::write_metadata_by_path;
+use crate::core::services::mutation_summary::{FileOutcome, MutationSummary};
+use crate::core::services::scanner::scan_dir_with_metadata;
+
+/// One spelling of an artist and the files using it as artist or album artist.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ArtistSpelling {
+    pub name: String,
+    pub files: Vec<PathBuf>,
+}
+
+/// Spellings sharing one [`alias_key`], most used first.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ArtistAliasCluster {
+    pub key: String,
+    pub spellings: Vec<ArtistSpelling>,
+}
+
+/// Tag rewrites for one file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ArtistRewrite {
+    pub path: PathBuf,
+    /// `key=value` updates, e.g. `artist=The Beatles`.
+    pub changes: Vec<String>,
+    pub error: Option<String>,
+}
+
+/// Comparison form of an artist name: lowercase letters and digits only,
+/// "&" read as "and", and a leading "The " or trailing ", The" dropped.
+pub fn alias_key(name: &str) -> String {
+    let lower = name.trim().to_lowercase();
+    let lower = lower.strip_prefix("the ").unwrap_or(&lower);
+    let lower = lower.strip_suffix(", the").unwrap_or(lower);
+    lower
+        .replace('&', "and")
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .collect()
+}
+
+/// Group the artist and album artist spellings of `tracks` by [`alias_key`].
+///
+/// Only keys with more than one spelling form a cluster. Clusters are ordered
+/// by the number of files involved, most first.
+pub fn detect_artist_aliases(tracks: &[Track]) -> Vec<ArtistAliasCluster> {
+    let mut spellings: BTreeMap<String, BTreeMap<String, BTreeSet<PathBuf>>> = BTreeMap::new();
+    for track in tracks {
+        let names = [&track.metadata.artist, &track.metadata.album_artist];
+        for name in names.into_iter().flatten() {
+            let key = alias_key(&name.value);
+            if key.is_empty() {
+                continue;
+            }
+            spellings
+                .entry(key)
+                .or_default()
+                .entry(name.value.clone())
+                .or_default()
+                .insert(track.file_path.clone());
+        }
+    }
+
+    let mut clusters: Vec<ArtistAliasCluster> = spellings
+        .into_iter()
+        .filter(|(_, names)| names.len() > 1)
+        .map(|(key, names)| {
+            let mut spellings: Vec<ArtistSpelling> = names
+                .into_iter()
+                .map(|(name, files)| ArtistSpelling {
+                    name,
+                    files: files.into_iter().collect(),
+                })
+                .collect();
+            spellings.sort_by(|a, b| {
+                b.files
+                    .len()
+                    .cmp(&a.files.len())
+                    .then_with(|| a.name.cmp(&b.name))
+            });
+            ArtistAliasCluster { key, spellings }
+        })
+        .collect();
+    clusters.sort_by_key(|c| std::cmp::Reverse(c.file_count()));
+    clusters
+}
+
+impl ArtistAliasCluster {
+    /// Files using any spelling of the cluster.
+    pub fn file_count(&self) -> usize {
+        self.spellings
+            .iter()
+            .flat_map(|s| &s.files)
+            .collect::<BTreeSet<_>>()
+            .len()
+    }
+}
+
+/// Rewrite the other spellings of each chosen cluster to its canonical name.
+///
+/// Every name in `canonical_choices` picks the cluster with the same
+/// [`alias_key`]; it need not be one of the detected spellings, so a cluster
+/// can be corrected to a spelling nobody used yet. Clusters without a choice
+/// are left alone. Without `apply` nothing is written and the results only
+/// list the changes.
+pub fn apply_artist_canonicalization(
+    clusters: &[ArtistAliasCluster],
+    canonical_choices: &[String],
+    apply: bool,
+) -> Result<Vec<ArtistRewrite>, String> {
+    let mut renames: HashMap<&str, &str> = HashMap::new();
+    let mut chosen: HashMap<&str, &str> = HashMap::new();
+    let mut files: BTreeSet<&Path> = BTreeSet::new();
+
+    for choice in canonical_choices {
+        let key = alias_key(choice);
+        let Some(cluster) = clusters.iter().find(|c| c.key == key) else {
+            return Err(format!(
+                "'{}' doesn't match any detected artist alias cluster",
+                choice
+            ));
+        };
+        if let Some(previous) = chosen.insert(&cluster.key, choice) {
+            return Err(format!(
+                "'{}' and '{}' pick the same artist cluster",
+                previous, choice
+            ));
+        }
+        for spelling in cluster.spellings.iter().filter(|s| s.name != *choice) {
+            renames.insert(&spelling.name, choice);
+            files.extend(spelling.files.iter().map(PathBuf::as_path));
+        }
+    }
+
+    Ok(files
+        .into_iter()
+        .map(|path| rewrite_file(path, &renames, apply))
+        .collect())
+}
+
+/// Rename the artist and album artist of one file, re-reading its tags so
+/// values changed since detection are left alone.
+fn rewrite_file(path: &Path, renames: &HashMap<&str, &str>, apply: bool) -> ArtistRewrite {
+    let mut result = ArtistRewrite {
+        path: path.to_path_buf(),
+        changes: Vec::new(),
+        error: None,
+    };
+    let track = match formats::read_metadata(path) {
+        Ok(track) => track,
+        Err(e) => {
+            result.error = Some(format!("Failed to read: {}", e));
+            return result;
+        }
+    };
+    let fields = [
+        ("artist", &track.metadata.artist),
+        ("album_artist", &track.metadata.album_artist),
+    ];
+    for (key, value) in fields {
+        if let Some(canonical) = value.as_ref().and_then(|v| renames.get(v.value.as_str())) {
+            result.changes.push(format!("{}={}", key, canonical));
+        }
+    }
+    if apply && !result.changes.is_empty() {
+        result.error = write_metadata_by_path(path, result.changes.clone(), true, false)
+            .err()
+            .map(|e| e.to_string());
+    }
+    result
+}
+
+/// Format clusters as text, one line per cluster.
+pub fn format_artist_aliases(clusters: &[ArtistAliasCluster]) -> String {
+    let mut out = String::new();
+    out.push_str("=== ARTIST ALIASES ===\n");
+    if clusters.is_empty() {
+        out.push_str("No artist spelled more than one way.\n");
+    }
+    for cluster in clusters {
+        let spellings: Vec<String> = cluster
+            .spellings
+            .iter()
+            .map(|s| {
+                let noun = if s.files.len() == 1 { "file" } else { "files" };
+                format!("'{}' ({} {})", s.name, s.files.len(), noun)
+            })
+            .collect();
+        let _ = writeln!(out, "{}", spellings.join(", "));
+    }
+    if !clusters.is_empty() {
+        out.push_str(
+            "Pick a spelling per cluster with --canonical-artist NAME to rewrite the others.\n",
+        );
+    }
+    out.push_str("=== END ARTIST ALIASES ===\n");
+    out
+}
+
+/// Format rewrite results as text, one block per changed file, and the
+/// [`MutationSummary`] line.
+pub fn format_artist_rewrites(results: &[ArtistRewrite], apply: bool) -> String {
+    let mut out = String::new();
+    let mut summary = MutationSummary::new();
+    for result in results {
+        if let Some(error) = &result.error {
+            summary.record(&result.path, FileOutcome::Error);
+            let _ = writeln!(out, "ERROR {}: {}", result.path.display(), error);
+            continue;
+        }
+        if result.changes.is_empty() {
+            summary.record(&result.path, FileOutcome::Unchanged);
+            continue;
+        }
+        summary.record(&result.path, FileOutcome::Changed);
+        let verb = if apply { "UPDATED" } else { "WOULD UPDATE" };
+        let _ = writeln!(out, "{} {}", verb, result.path.display());
+        for change in &result.changes {
+            let _ = writeln!(out, "  {}", change);
+        }
+    }
+    out.push_str(&summary.line(!apply));
+    out
+}
+
+/// Scan `path` and [`detect_artist_aliases`] in its tracks.
+pub fn detect_artist_aliases_by_path(path: &Path) -> Result<Vec<ArtistAliasCluster>, String> {
+    let tracks =
+        scan_dir_with_metadata(path).map_err(|e| format!("Failed to scan directory: {}", e))?;
+    Ok(detect_artist_aliases(&tracks))
+}
+
+// ── Tests ───────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::builders::TrackMetadataBuilder;
+    use crate::core::domain::models::MetadataSource;
+
+    fn track(path: &str, artist: &str) -> Track {
+        let metadata = TrackMetadataBuilder::new(path)
+            .artist(artist, MetadataSource::Embedded, 1.0)
+            .build();
+        Track::new(PathBuf::from(path), metadata)
+    }
+
+    #[test]
+    fn test_alias_key_ignores_article_case_and_punctuation() {
+        assert_eq!(alias_key("The Beatles"), "beatles");
+        assert_eq!(alias_key("beatles"), "beatles");
+        assert_eq!(alias_key("Beatles, The"), "beatles");
+        assert_eq!(
+            alias_key("Simon & Garfunkel"),
+            alias_key("Simon and Garfunkel")
+        );
+        assert_eq!(alias_key("AC/DC"), alias_key("ACDC"));
+        assert_ne!(alias_key("The The"), "");
+    }
+
+    #[test]
+    fn test_detect_artist_aliases_orders_spellings_by_use() {
+        let tracks = vec![
+            track("/a/1.flac", "The Beatles"),
+            track("/a/2.flac", "The Beatles"),
+            track("/b/1.flac", "Beatles"),
+            track("/c/1.flac", "Pink Floyd"),
+        ];
+        let clusters = detect_artist_aliases(&tracks);
+
+        assert_eq!(clusters.len(), 1);
+        assert_eq!(clusters[0].key, "beatles");
+        assert_eq!(clusters[0].spellings[0].name, "The Beatles");
+        assert_eq!(clusters[0].spellings[0].files.len(), 2);
+        assert_eq!(clusters[0].spellings[1].name, "Beatles");
+        assert_eq!(clusters[0].file_count(), 3);
+    }
+
+    #[test]
+    fn test_canonicalization_rejects_unknown_and_repeated_choices() {
+        let clusters = detect_artist_aliases(&[
+            track("/a/1.flac", "The Beatles"),
+            track("/b/1.flac", "Beatles"),
+        ]);
+
+        let err = apply_artist_canonicalization(&clusters, &["Pink Floyd".to_string()], false)
+            .unwrap_err();
+        assert!(err.contains("'Pink Floyd'"));
+
+        let choices = ["The Beatles".to_string(), "Beatles".to_string()];
+        assert!(apply_artist_canonicalization(&clusters, &choices, false).is_err());
+    }
+}
@@ -1,13 +1,14 @@
 //! Cue file generation and parsing services.
 
-use std::collections::HashSet;
+use encoding_rs::{EncoderResult, WINDOWS_1252};
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::fmt::Write;
 use std::path::{Path, PathBuf};
 
-use crate::adapters::audio_formats::read_metadata;
+use crate::adapters::audio_formats::{is_format_supported, read_basic_info, read_metadata};
 use crate::core::domain::models::{AlbumNode, MetadataSource, MetadataValue, TrackNode};
 use crate::core::services::normalization::to_title_case;
-use crate::core::services::scanner::scan_dir_immediate;
+use crate::core::services::scanner::{scan_dir_immediate, scan_dir_paths};
 
 // ── Metadata helpers ────────────────────────────────────────────────────────
 
@@ -21,7 +22,7 @@ fn is_embedded<T>(mv: &MetadataValue<T>) -> bool {
 /// *Embedded* sources always beat inferred ones; among values with the same
 /// source kind the highest confidence wins.  Ties are broken in favour of the
 /// first occurrence (track order).
-fn best_value<T: Clone>(
+pub(crate) fn best_value<T: Clone>(
     tracks: &[TrackNode],
     extractor: impl Fn(&TrackNode) -> Option<&MetadataValue<T>>,
 ) -> Option<T> {
@@ -53,17 +54,90 @@ fn track_performer(track: &TrackNode) -> Option<&String> {
         .map(|mv| &mv.value)
 }
 
+/// Returns the zero-padding width for track numbers in an album.
+///
+/// The width is the digit count of the highest track number (falling back to
+/// the track's position when it has no number), with a minimum of two so
+/// ordinary albums keep the familiar `01`..`99` form.
+pub fn track_number_width(tracks: &[TrackNode]) -> usize {
+    let highest = tracks
+        .iter()
+        .enumerate()
+        .map(|(i, t)| {
+            t.metadata
+                .track_number
+                .as_ref()
+                .map_or((i + 1) as u32, |mv| mv.value)
+        })
+        .max()
+        .unwrap_or(0);
+    highest.to_string().len().max(2)
+}
+
+/// Formats a track number zero-padded to `width` digits.
+pub fn format_track_number(number: u32, width: usize) -> String {
+    format!("{:0width$}", number, width = width)
+}
+
 // ── CUE generation ─────────────────────────────────────────────────────────
 
+/// Returns `true` for a valid `CATALOG` value: exactly 13 ASCII digits.
+pub fn is_valid_catalog(value: &str) -> bool {
+    value.len() == 13 && value.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Converts a barcode tag to a `CATALOG` value.
+///
+/// EAN-13 barcodes are used as is and 12-digit UPC-A barcodes get the
+/// leading zero that makes them EAN-13; anything else yields `None`.
+pub fn catalog_from_barcode(barcode: &str) -> Option<String> {
+    let barcode = barcode.trim();
+    if !barcode.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    match barcode.len() {
+        13 => Some(barcode.to_string()),
+        12 => Some(format!("0{}", barcode)),
+        _ => None,
+    }
+}
+
 /// Generates `.cue` file content for an album from its track metadata.
 ///
 /// When tracks carry conflicting metadata (different artists, years, or
 /// genres), embedded metadata takes precedence over folder-inferred values.
 /// Among values with the same source kind the highest confidence wins.
 /// Text fields (artist, album, genre) are normalised to title case.
+///
+/// An album without tracks gives an empty string rather than a sheet of
+/// headers with nothing to index.
 pub fn generate_cue_content(album: &AlbumNode) -> String {
+    render_cue_content(album, None)
+}
+
+/// Generates `.cue` file content like [`generate_cue_content`], carrying over
+/// the `REM` lines of a previously parsed sheet for the same album.
+///
+/// Disc ID, AccurateRip CRC, comment and unrecognised `REM` lines are
+/// re-emitted so regenerating a ripper's sheet keeps its provenance and
+/// ReplayGain values. Track-level lines are matched by track number.
+pub fn regenerate_cue_content(album: &AlbumNode, previous: &CueFile) -> String {
+    render_cue_content(album, Some(previous))
+}
+
+fn render_cue_content(album: &AlbumNode, previous: Option<&CueFile>) -> String {
     let tracks = &album.tracks;
     let mut out = String::new();
+    if tracks.is_empty() {
+        return out;
+    }
+
+    // CATALOG – only written for a barcode that is a valid UPC/EAN
+    if let Some(catalog) =
+        best_value(tracks, |t| t.metadata.barcode.as_ref()).and_then(|b| catalog_from_barcode(&b))
+    {
+        let _ = writeln!(out, "CATALOG {}", catalog);
+    }
 
     // Album-level PERFORMER
     let artist = best_value(tracks, |t| t.metadata.album_artist.as_ref())
@@ -92,7 +166,24 @@ pub fn generate_cue_content(album: &AlbumNode) -> String {
         let _ = writeln!(out, "REM DATE {}", y);
     }
 
+    // Preserved REM lines from the previous sheet
+    if let Some(prev) = previous {
+        if let Some(disc_id) = &prev.disc_id {
+            let _ = writeln!(out, "REM DISCID {}", disc_id);
+        }
+        if let Some(comment) = &prev.comment {
+            let _ = writeln!(out, "REM COMMENT \"{}\"", comment);
+        }
+        if let Some(crc) = &prev.accuraterip_crc {
+            let _ = writeln!(out, "REM ACCURATERIPCRC {}", crc);
+        }
+        for rem in &prev.other_rems {
+            let _ = writeln!(out, "REM {}", rem);
+        }
+    }
+
     // Tracks, grouped by source file
+    let width = track_number_width(tracks);
     let mut current_file: Option<String> = None;
     let mut file_track_idx: u32 = 0;
 
@@ -116,7 +207,11 @@ pub fn generate_cue_content(album: &AlbumNode) -> String {
             .map(|mv| mv.value)
             .unwrap_or((i + 1) as u32);
 
-        let _ = writeln!(out, "  TRACK {:02} AUDIO", track_num);
+        let _ = writeln!(
+            out,
+            "  TRACK {} AUDIO",
+            format_track_number(track_num, width)
+        );
 
         if let Some(t) = track.metadata.title.as_ref() {
             let _ = writeln!(out, "    TITLE \"{}\"", t.value);
@@ -126,6 +221,12 @@ pub fn generate_cue_content(album: &AlbumNode) -> String {
             let _ = writeln!(out, "    PERFORMER \"{}\"", to_title_case(performer));
         }
 
+        let previous_track =
+            previous.and_then(|prev| prev.tracks.iter().find(|t| t.number == track_num));
+        for rem in previous_track.into_iter().flat_map(|t| &t.other_rems) {
+            let _ = writeln!(out, "    REM {}", rem);
+        }
+
         let _ = writeln!(out, "    INDEX 01 00:{:02}:00", file_track_idx * 2);
         file_track_idx += 1;
     }
@@ -139,7 +240,15 @@ pub fn generate_cue_file_name(album: &AlbumNode) -> String {
 }
 
 /// Writes a `.cue` file for an album to the given path.
+///
+/// Fails without writing anything when the album has no tracks.
 pub fn write_cue_file(album: &AlbumNode, output_path: &Path) -> Result<(), std::io::Error> {
+    if album.tracks.is_empty() {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!("Album '{}' has no tracks", album.title),
+        ));
+    }
     std::fs::write(output_path, generate_cue_content(album))
 }
 
@@ -161,33 +270,41 @@ pub fn generate_cue_for_path(
     path: &Path,
     output: Option<PathBuf>,
 ) -> Result<CueGenerationResult, CueGenerationError> {
+    let album = album_for_path(path)?;
+    let output_path = output.unwrap_or_else(|| path.join(generate_cue_file_name(&album)));
+    Ok(generate_cue_for_album(album, output_path))
+}
+
+/// Reads the audio files directly in `path` into an album titled after the
+/// directory.
+fn album_for_path(path: &Path) -> Result<AlbumNode, CueGenerationError> {
     let file_paths = scan_dir_immediate(path);
     if file_paths.is_empty() {
         return Err(CueGenerationError::NoMusicFiles);
     }
 
-    let tracks: Vec<_> = file_paths
+    let (tracks, errors): (Vec<_>, Vec<_>) = file_paths
         .iter()
-        .map(|fp| {
-            read_metadata(fp).map_err(|e| {
-                CueGenerationError::FileReadError(
-                    format!("Failed to read {}: {}", fp.display(), e,),
-                )
-            })
-        })
-        .collect::<Result<_, _>>()?;
-
+        .map(|fp| read_metadata(fp))
+        .partition(Result::is_ok);
+    // Nothing readable leaves no track to index; a partly readable album
+    // would silently lose tracks, so that is an error of its own
     if tracks.is_empty() {
         return Err(CueGenerationError::NoReadableFiles);
     }
+    if let Some(Err(e)) = errors.into_iter().next() {
+        return Err(CueGenerationError::FileReadError(format!(
+            "Failed to read {}",
+            e
+        )));
+    }
+    let tracks: Vec<_> = tracks.into_iter().flatten().collect();
 
     let album_name = path
         .file_name()
         .map(|n| n.to_string_lossy().into_owned())
         .unwrap_or_else(|| "Unknown Album".to_string());
 
-    let tracks_count = tracks.len();
-
     let track_nodes: Vec<TrackNode> = tracks
         .into_iter()
         .map(|t| TrackNode {
@@ -198,21 +315,239 @@ pub fn generate_cue_for_path(
 
     let album_files: HashSet<PathBuf> = track_nodes.iter().map(|t| t.file_path.clone()).collect();
 
-    let album = AlbumNode {
+    Ok(AlbumNode {
         title: album_name,
         year: None,
         tracks: track_nodes,
         files: album_files,
         path: path.to_path_buf(),
-    };
+    })
+}
 
-    let output_path = output.unwrap_or_else(|| path.join(generate_cue_file_name(&album)));
-    let cue_content = generate_cue_content(&album);
+fn generate_cue_for_album(album: AlbumNode, output_path: PathBuf) -> CueGenerationResult {
+    // Regenerating over an existing sheet keeps its REM lines
+    let cue_content = match parse_cue_file(&output_path) {
+        Ok(previous) => regenerate_cue_content(&album, &previous),
+        Err(_) => generate_cue_content(&album),
+    };
 
-    Ok(CueGenerationResult {
+    CueGenerationResult {
         cue_content,
         output_path,
-        tracks_count,
+        tracks_count: album.tracks.len(),
+    }
+}
+
+// ── Recursive CUE generation ────────────────────────────────────────────────
+
+/// Placeholders understood by a CUE `--output-template`.
+pub const CUE_OUTPUT_PLACEHOLDERS: &[&str] = &["album_dir", "album", "artist"];
+
+/// Default CUE `--output-template`: next to the album's audio files.
+pub const DEFAULT_CUE_OUTPUT_TEMPLATE: &str = "{album_dir}/{album}.cue";
+
+/// CUE sheets generated for every album directory under a base directory.
+pub struct RecursiveCueGeneration {
+    pub results: Vec<CueGenerationResult>,
+    /// Album directories skipped because their files could not be read.
+    pub errors: Vec<String>,
+}
+
+/// Where the CUE sheet of `album` goes under `template`, relative to `base`.
+///
+/// `{album_dir}` is the album directory relative to `base`, `{album}` the
+/// album title (the directory name) and `{artist}` the album artist or
+/// artist of its first track that has one. Fails when the template uses an
+/// unknown placeholder or the path leaves `base`.
+pub fn cue_output_path(base: &Path, album: &AlbumNode, template: &str) -> Result<PathBuf, String> {
+    let album_dir = album.path.strip_prefix(base).unwrap_or(&album.path);
+    let artist = album
+        .tracks
+        .iter()
+        .find_map(|t| {
+            t.metadata
+                .album_artist
+                .as_ref()
+                .or(t.metadata.artist.as_ref())
+        })
+        .map_or("Unknown Artist", |a| a.value.as_str());
+
+    let mut expanded = String::new();
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        expanded.push_str(&rest[..open]);
+        let Some(close) = rest[open..].find('}') else {
+            return Err(format!("Unclosed placeholder in template: {}", template));
+        };
+        match &rest[open + 1..open + close] {
+            "album_dir" => {
+                let components: Vec<_> = album_dir.iter().map(|c| c.to_string_lossy()).collect();
+                expanded.push_str(&components.join("/"));
+            }
+            "album" => expanded.push_str(&path_component(&album.title)),
+            "artist" => expanded.push_str(&path_component(artist)),
+            name => {
+                return Err(format!(
+                    "Unknown placeholder {{{}}} (supported: {})",
+                    name,
+                    CUE_OUTPUT_PLACEHOLDERS.join(", ")
+                ));
+            }
+        }
+        rest = &rest[open + close + 1..];
+    }
+    expanded.push_str(rest);
+
+    let relative: PathBuf = expanded.split('/').filter(|c| !c.is_empty()).collect();
+    if relative.as_os_str().is_empty()
+        || relative
+            .components()
+            .any(|c| !matches!(c, std::path::Component::Normal(_)))
+    {
+        return Err(format!(
+            "Output template gives '{}', which is outside {}",
+            expanded,
+            base.display()
+        ));
+    }
+    Ok(base.join(relative))
+}
+
+/// Replace characters that cannot appear in a path component.
+fn path_component(value: &str) -> String {
+    value
+        .trim()
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            c => c,
+        })
+        .collect()
+}
+
+/// Generate a CUE sheet for every directory under `base` that directly holds
+/// audio files, each written where `template` puts it (see
+/// [`cue_output_path`]).
+///
+/// The template is checked for every album before anything is generated: it
+/// must keep each sheet inside `base` and give every album its own path.
+pub fn generate_cues_recursive(
+    base: &Path,
+    template: &str,
+) -> Result<RecursiveCueGeneration, String> {
+    let dirs: BTreeSet<PathBuf> = scan_dir_paths(base)
+        .iter()
+        .filter_map(|p| p.parent().map(Path::to_path_buf))
+        .collect();
+
+    let mut albums = Vec::new();
+    let mut errors = Vec::new();
+    let mut claimed: HashMap<PathBuf, PathBuf> = HashMap::new();
+    for dir in dirs {
+        let album = match album_for_path(&dir) {
+            Ok(album) => album,
+            Err(CueGenerationError::FileReadError(msg)) => {
+                errors.push(format!("{}: {}", dir.display(), msg));
+                continue;
+            }
+            Err(CueGenerationError::NoReadableFiles) => {
+                errors.push(format!("{}: No readable music files", dir.display()));
+                continue;
+            }
+            Err(CueGenerationError::NoMusicFiles) => continue,
+        };
+        let output_path = cue_output_path(base, &album, template)?;
+        if let Some(other) = claimed.insert(output_path.clone(), dir.clone()) {
+            return Err(format!(
+                "Output template puts the CUE sheets of {} and {} both at {}",
+                other.display(),
+                dir.display(),
+                output_path.display()
+            ));
+        }
+        albums.push((album, output_path));
+    }
+
+    Ok(RecursiveCueGeneration {
+        results: albums
+            .into_iter()
+            .map(|(album, output_path)| generate_cue_for_album(album, output_path))
+            .collect(),
+        errors,
+    })
+}
+
+// ── CUE text encoding ───────────────────────────────────────────────────────
+
+/// Character encoding of a written `.cue` file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CueCharset {
+    #[default]
+    Utf8,
+    /// Latin-1 superset read by many hardware players.
+    Windows1252,
+}
+
+/// Parses a `--charset` value: `utf-8` or `windows-1252` (also `cp1252`,
+/// `latin-1`, `iso-8859-1`).
+///
+/// The error message is shown to the user as-is.
+pub fn parse_cue_charset(input: &str) -> Result<CueCharset, String> {
+    match input.trim().to_ascii_lowercase().as_str() {
+        "utf-8" | "utf8" => Ok(CueCharset::Utf8),
+        "windows-1252" | "cp1252" | "latin-1" | "latin1" | "iso-8859-1" => {
+            Ok(CueCharset::Windows1252)
+        }
+        _ => Err(format!(
+            "invalid charset '{input}' (expected 'utf-8' or 'windows-1252')"
+        )),
+    }
+}
+
+/// Encodes CUE text in `charset`.
+///
+/// Characters the charset cannot represent are written as `?` and returned,
+/// once each in order of appearance, so callers can warn about them.
+pub fn encode_cue_content(content: &str, charset: CueCharset) -> (Vec<u8>, Vec<char>) {
+    match charset {
+        CueCharset::Utf8 => (content.as_bytes().to_vec(), Vec::new()),
+        CueCharset::Windows1252 => {
+            let mut encoder = WINDOWS_1252.new_encoder();
+            let mut bytes = Vec::with_capacity(content.len());
+            let mut unmappable = Vec::new();
+            let mut rest = content;
+            loop {
+                let needed = encoder
+                    .max_buffer_length_from_utf8_without_replacement(rest.len())
+                    .unwrap_or(rest.len());
+                bytes.reserve(needed);
+                let (result, read) =
+                    encoder.encode_from_utf8_to_vec_without_replacement(rest, &mut bytes, true);
+                rest = &rest[read..];
+                match result {
+                    EncoderResult::InputEmpty => break,
+                    EncoderResult::OutputFull => {}
+                    EncoderResult::Unmappable(c) => {
+                        bytes.push(b'?');
+                        if !unmappable.contains(&c) {
+                            unmappable.push(c);
+                        }
+                    }
+                }
+            }
+            (bytes, unmappable)
+        }
+    }
+}
+
+/// Decodes CUE bytes as UTF-8, falling back to Windows-1252 for sheets
+/// written by older rippers or with `--charset windows-1252`.
+fn decode_cue_content(bytes: Vec<u8>) -> String {
+    String::from_utf8(bytes).unwrap_or_else(|e| {
+        WINDOWS_1252
+            .decode_without_bom_handling(e.as_bytes())
+            .0
+            .into_owned()
     })
 }
 
@@ -221,10 +556,28 @@ pub fn generate_cue_for_path(
 /// Represents a parsed `.cue` file.
 #[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
 pub struct CueFile {
+    /// Disc `CATALOG` number (UPC/EAN), always 13 digits.
+    #[serde(default)]
+    pub catalog: Option<String>,
+    /// `CDTEXTFILE` the disc's CD-TEXT was read from.
+    #[serde(default)]
+    pub cdtextfile: Option<String>,
     pub performer: Option<String>,
     pub title: Option<String>,
     pub genre: Option<String>,
     pub date: Option<String>,
+    /// `REM DISCID`, the freedb disc ID written by EAC and similar rippers.
+    #[serde(default)]
+    pub disc_id: Option<String>,
+    /// `REM ACCURATERIPCRC` checksum of the rip.
+    #[serde(default)]
+    pub accuraterip_crc: Option<String>,
+    /// `REM COMMENT`, usually the ripping software (e.g. "ExactAudioCopy v1.6").
+    #[serde(default)]
+    pub comment: Option<String>,
+    /// Any other disc-level `REM` lines, without the `REM` prefix, in file order.
+    #[serde(default)]
+    pub other_rems: Vec<String>,
     pub files: Vec<String>,
     pub tracks: Vec<CueTrack>,
 }
@@ -236,7 +589,43 @@ pub struct CueTrack {
     pub title: Option<String>,
     pub performer: Option<String>,
     pub index: Option<String>,
+    /// Track start in CD frames: the `INDEX 01` position, or the first
+    /// index seen when the track has no `INDEX 01`.
+    #[serde(default)]
+    pub start_frame: Option<u32>,
     pub file: Option<String>,
+    /// Track-level `REM` lines (e.g. ReplayGain), without the `REM` prefix.
+    #[serde(default)]
+    pub other_rems: Vec<String>,
+}
+
+/// Number of CD frames per second in `INDEX` timestamps.
+pub const CUE_FRAMES_PER_SECOND: u32 = 75;
+
+/// Parses an `mm:ss:ff` `INDEX` timestamp into a frame count.
+///
+/// Minutes may exceed 99 (long single-file rips); seconds must be below 60
+/// and frames below [`CUE_FRAMES_PER_SECOND`].
+pub fn parse_cue_timestamp(value: &str) -> Option<u32> {
+    let mut parts = value.split(':');
+    let minutes = parts.next()?.parse::<u32>().ok()?;
+    let seconds = parts.next()?.parse::<u32>().ok()?;
+    let frames = parts.next()?.parse::<u32>().ok()?;
+    if parts.next().is_some() || seconds >= 60 || frames >= CUE_FRAMES_PER_SECOND {
+        return None;
+    }
+    Some((minutes * 60 + seconds) * CUE_FRAMES_PER_SECOND + frames)
+}
+
+/// Formats a frame count as an `mm:ss:ff` timestamp.
+pub fn format_cue_timestamp(frames: u32) -> String {
+    let seconds = frames / CUE_FRAMES_PER_SECOND;
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 60,
+        seconds % 60,
+        frames % CUE_FRAMES_PER_SECOND
+    )
 }
 
 /// Extracts the text between the first and last `"` on a line.
@@ -259,7 +648,8 @@ fn parse_track_line(line: &str) -> Option<CueTrack> {
 
 /// Parses a `.cue` file and returns a [`CueFile`] with the extracted data.
 pub fn parse_cue_file(cue_path: &Path) -> Result<CueFile, String> {
-    let content = std::fs::read_to_string(cue_path)
+    let content = std::fs::read(cue_path)
+        .map(decode_cue_content)
         .map_err(|e| format!("Failed to read CUE file '{}': {}", cue_path.display(), e))?;
 
     let mut cue = CueFile::default();
@@ -288,6 +678,22 @@ pub fn parse_cue_file(cue_path: &Path) -> Result<CueFile, String> {
                         .ok_or_else(|| format!("Malformed TITLE at {}", line_ctx()))?,
                 );
             }
+            ("CATALOG", false) => {
+                let value = trimmed.trim_start_matches("CATALOG").trim();
+                if !is_valid_catalog(value) {
+                    return Err(format!(
+                        "Malformed CATALOG (expected 13 digits) at {}",
+                        line_ctx()
+                    ));
+                }
+                cue.catalog = Some(value.to_string());
+            }
+            ("CDTEXTFILE", false) => {
+                cue.cdtextfile = extract_quoted_value(trimmed).or_else(|| {
+                    let v = trimmed.trim_start_matches("CDTEXTFILE").trim();
+                    (!v.is_empty()).then(|| v.to_string())
+                });
+            }
             ("FILE", _) => {
                 let name = extract_quoted_value(trimmed)
                     .ok_or_else(|| format!("Malformed FILE at {}", line_ctx()))?;
@@ -295,17 +701,25 @@ pub fn parse_cue_file(cue_path: &Path) -> Result<CueFile, String> {
                 cue.files.push(name);
             }
 
-            // REM fields
-            ("REM", _) if trimmed.starts_with("REM GENRE") => {
-                cue.genre = extract_quoted_value(trimmed).or_else(|| {
-                    let v = trimmed.trim_start_matches("REM GENRE").trim();
-                    (!v.is_empty()).then(|| v.to_string())
-                });
-            }
-            ("REM", _) if trimmed.starts_with("REM DATE") => {
-                let v = trimmed.trim_start_matches("REM DATE").trim();
-                if !v.is_empty() {
-                    cue.date = Some(v.to_string());
+            // REM fields; unrecognised ones are kept verbatim in `other_rems`
+            ("REM", _) => {
+                let rest = trimmed["REM".len()..].trim();
+                let (key, value) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+                let value = value.trim();
+                let text = extract_quoted_value(value)
+                    .or_else(|| (!value.is_empty()).then(|| value.to_string()));
+                match key.to_ascii_uppercase().as_str() {
+                    "GENRE" => cue.genre = text,
+                    "DATE" if text.is_some() => cue.date = text,
+                    "DATE" => {}
+                    "DISCID" => cue.disc_id = text,
+                    "ACCURATERIPCRC" => cue.accuraterip_crc = text,
+                    "COMMENT" => cue.comment = text,
+                    _ if rest.is_empty() => {}
+                    _ => match current_track.as_mut() {
+                        Some(track) if is_track_level => track.other_rems.push(rest.to_string()),
+                        _ => cue.other_rems.push(rest.to_string()),
+                    },
                 }
             }
 
@@ -334,10 +748,20 @@ pub fn parse_cue_file(cue_path: &Path) -> Result<CueFile, String> {
             ("INDEX", true) if current_track.is_some() => {
                 let remainder = trimmed.trim_start_matches("INDEX").trim();
                 let parts: Vec<&str> = remainder.split_whitespace().collect();
-                if parts.len() >= 2 && parts[0].parse::<u32>().is_ok() {
-                    current_track.as_mut().unwrap().index = Some(remainder.to_string());
-                } else {
+                let position = match parts.as_slice() {
+                    [number, timestamp, ..] => number
+                        .parse::<u32>()
+                        .ok()
+                        .zip(parse_cue_timestamp(timestamp)),
+                    _ => None,
+                };
+                let Some((number, frame)) = position else {
                     return Err(format!("Malformed INDEX at {}", line_ctx()));
+                };
+                let track = current_track.as_mut().unwrap();
+                track.index = Some(remainder.to_string());
+                if number == 1 || track.start_frame.is_none() {
+                    track.start_frame = Some(frame);
                 }
             }
 
@@ -361,9 +785,26 @@ pub struct CueValidationResult {
     pub parsing_error: bool,
     pub file_missing: bool,
     pub track_count_mismatch: bool,
+    /// `FILE` entries with no matching audio file on disk.
+    #[serde(default)]
+    pub missing_files: Vec<String>,
+    /// Audio files next to the sheet that no `FILE` entry references.
+    #[serde(default)]
+    pub extra_files: Vec<String>,
+}
+
+/// Returns the bare file name of a `FILE` reference, which may carry a
+/// relative directory written with either separator.
+fn referenced_file_name(reference: &str) -> &str {
+    reference.rsplit(['/', '\\']).next().unwrap_or(reference)
 }
 
 /// Validates the consistency of a `.cue` file against a set of audio files.
+///
+/// Every `FILE` entry is matched by name (case-insensitively) against
+/// `audio_files`; unmatched entries are reported in `missing_files`, and
+/// supported audio files that no entry references end up in `extra_files`.
+/// Non-audio files such as cover art or rip logs are ignored.
 pub fn validate_cue_consistency(cue_path: &Path, audio_files: &[&Path]) -> CueValidationResult {
     let mut result = CueValidationResult::default();
 
@@ -376,24 +817,32 @@ pub fn validate_cue_consistency(cue_path: &Path, audio_files: &[&Path]) -> CueVa
         }
     };
 
-    result.is_valid = true;
-
-    for ref_name in &cue.files {
-        let found = audio_files.iter().any(|p| {
-            p.file_name()
-                .and_then(|n| n.to_str())
-                .is_some_and(|n| n == ref_name)
-        });
-        if !found {
-            result.file_missing = true;
-            result.is_valid = false;
+    let available: Vec<(&str, &Path)> = audio_files
+        .iter()
+        .filter_map(|p| p.file_name().and_then(|n| n.to_str()).map(|n| (n, *p)))
+        .collect();
+
+    let mut referenced = HashSet::new();
+    for reference in &cue.files {
+        let name = referenced_file_name(reference).to_lowercase();
+        if !referenced.insert(name.clone()) {
+            continue;
+        }
+        if !available.iter().any(|(n, _)| n.to_lowercase() == name) {
+            result.missing_files.push(reference.clone());
         }
     }
 
-    if cue.files.len() != audio_files.len() {
-        result.track_count_mismatch = true;
-        result.is_valid = false;
+    for (name, path) in &available {
+        if !referenced.contains(&name.to_lowercase()) && is_format_supported(path) {
+            result.extra_files.push(name.to_string());
+        }
     }
+    result.extra_files.sort();
+
+    result.file_missing = !result.missing_files.is_empty();
+    result.track_count_mismatch = !result.extra_files.is_empty();
+    result.is_valid = !result.file_missing && !result.track_count_mismatch;
 
     result
 }
@@ -406,13 +855,27 @@ pub fn format_cue_validation_result(result: &CueValidationResult) -> String {
 
     let mut errors = Vec::new();
     if result.parsing_error {
-        errors.push("Error parsing CUE file");
+        errors.push("Error parsing CUE file".to_string());
     }
     if result.file_missing {
-        errors.push("Referenced audio file(s) missing");
+        if result.missing_files.is_empty() {
+            errors.push("Referenced audio file(s) missing".to_string());
+        } else {
+            errors.push(format!(
+                "Referenced audio file(s) missing: {}",
+                result.missing_files.join(", ")
+            ));
+        }
     }
     if result.track_count_mismatch {
-        errors.push("Track count mismatch between CUE and audio files");
+        if result.extra_files.is_empty() {
+            errors.push("Track count mismatch between CUE and audio files".to_string());
+        } else {
+            errors.push(format!(
+                "Audio file(s) not referenced by CUE: {}",
+                result.extra_files.join(", ")
+            ));
+        }
     }
 
     if errors.is_empty() {
@@ -422,6 +885,167 @@ pub fn format_cue_validation_result(result: &CueValidationResult) -> String {
     }
 }
 
+// ── CUE duration check ─────────────────────────────────────────────────────
+
+/// The implied last track may run this many times longer than the longest
+/// earlier track before the sheet is considered to end far too early.
+const TRAILING_TRACK_FACTOR: f64 = 3.0;
+
+/// Implied last tracks shorter than this are never flagged as too long.
+const MIN_TRAILING_TRACK_SECS: f64 = 600.0;
+
+/// Outcome of comparing a sheet's `INDEX` timestamps with an audio file's length.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum CueDurationStatus {
+    /// The last track starts inside the file and the remainder is plausible.
+    Consistent,
+    /// The last track starts at or past the end of the audio: the file is truncated.
+    Truncated,
+    /// The last track would run far longer than the rest: the sheet ends too early.
+    TooShort,
+    /// The duration could not be read or the sheet has no usable `INDEX`.
+    Unknown,
+}
+
+/// Duration check of one audio file referenced by a `.cue` file.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+pub struct CueDurationReport {
+    pub audio_file: String,
+    /// Tracks of the sheet that live in this file.
+    pub track_count: usize,
+    /// Length of the audio file in seconds.
+    pub audio_duration: Option<f64>,
+    /// Start of the last track as an `mm:ss:ff` timestamp.
+    pub last_index: Option<String>,
+    /// Time left for the last track (negative when it starts past the end).
+    pub last_track_duration: Option<f64>,
+    pub status: CueDurationStatus,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+/// Compares the `INDEX` timestamps of `cue` against the length of `audio_file`.
+///
+/// Only tracks whose `FILE` entry names `audio_file` are considered, unless the
+/// sheet references a single file. The last track is flagged
+/// [`CueDurationStatus::Truncated`] when it starts at or beyond the end of the
+/// audio, and [`CueDurationStatus::TooShort`] when the time left for it is both
+/// over ten minutes and more than three times the longest earlier track.
+pub fn validate_cue_duration(cue: &CueFile, audio_file: &Path) -> CueDurationReport {
+    let file_name = audio_file
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or_default();
+    let mut starts: Vec<u32> = cue
+        .tracks
+        .iter()
+        .filter(|t| {
+            cue.files.len() <= 1
+                || t.file
+                    .as_deref()
+                    .is_some_and(|f| referenced_file_name(f).eq_ignore_ascii_case(file_name))
+        })
+        .filter_map(|t| t.start_frame)
+        .collect();
+    starts.sort_unstable();
+
+    let mut report = CueDurationReport {
+        audio_file: audio_file.display().to_string(),
+        track_count: starts.len(),
+        audio_duration: None,
+        last_index: starts.last().map(|f| format_cue_timestamp(*f)),
+        last_track_duration: None,
+        status: CueDurationStatus::Unknown,
+        error: None,
+    };
+
+    match read_basic_info(audio_file) {
+        Ok(info) => report.audio_duration = info.duration.map(|d| d.value),
+        Err(e) => report.error = Some(e.to_string()),
+    }
+    let (Some(duration), Some(&last)) = (report.audio_duration, starts.last()) else {
+        if report.error.is_none() {
+            report.error = Some(if starts.is_empty() {
+                "No INDEX entries for this file".to_string()
+            } else {
+                "Audio duration unavailable".to_string()
+            });
+        }
+        return report;
+    };
+
+    let to_secs = |frames: u32| f64::from(frames) / f64::from(CUE_FRAMES_PER_SECOND);
+    let remaining = duration - to_secs(last);
+    let longest_earlier = starts
+        .windows(2)
+        .map(|w| to_secs(w[1] - w[0]))
+        .fold(0.0, f64::max);
+
+    report.last_track_duration = Some(remaining);
+    report.status = if remaining <= 0.0 {
+        CueDurationStatus::Truncated
+    } else if longest_earlier > 0.0
+        && remaining > MIN_TRAILING_TRACK_SECS
+        && remaining > longest_earlier * TRAILING_TRACK_FACTOR
+    {
+        CueDurationStatus::TooShort
+    } else {
+        CueDurationStatus::Consistent
+    };
+    report
+}
+
+/// Checks every audio file referenced by the `.cue` file at `cue_path`,
+/// resolving `FILE` names against `audio_dir`.
+pub fn validate_cue_durations(
+    cue_path: &Path,
+    audio_dir: &Path,
+) -> Result<Vec<CueDurationReport>, String> {
+    let cue = parse_cue_file(cue_path)?;
+    let mut seen = HashSet::new();
+    Ok(cue
+        .files
+        .iter()
+        .filter(|f| seen.insert(referenced_file_name(f).to_lowercase()))
+        .map(|f| validate_cue_duration(&cue, &audio_dir.join(referenced_file_name(f))))
+        .collect())
+}
+
+pub fn format_cue_duration_report(report: &CueDurationReport) -> String {
+    let mut out = format!("{}: ", report.audio_file);
+    match report.status {
+        CueDurationStatus::Consistent => out.push_str("OK"),
+        CueDurationStatus::Truncated => {
+            out.push_str("TRUNCATED (last INDEX is past the end of the audio)")
+        }
+        CueDurationStatus::TooShort => {
+            out.push_str("TOO SHORT (CUE timestamps end far before the audio)")
+        }
+        CueDurationStatus::Unknown => out.push_str("UNKNOWN"),
+    }
+    if let Some(duration) = report.audio_duration {
+        let frames = (duration * f64::from(CUE_FRAMES_PER_SECOND)).round() as u32;
+        let _ = write!(out, "\n  Audio length: {}", format_cue_timestamp(frames));
+    }
+    if let Some(last) = &report.last_index {
+        let _ = write!(
+            out,
+            "\n  Last INDEX: {} ({} track(s))",
+            last, report.track_count
+        );
+    }
+    if let Some(remaining) = report.last_track_duration {
+        let _ = write!(out, "\n  Last track length: {:.2}s", remaining);
+    }
+    if let Some(error) = &report.error {
+        let _ = write!(out, "\n  Error: {}", error);
+    }
+    out
+}
+
 // ── Tests ───────────────────────────────────────────────────────────────────
 
 #[cfg(test)]
@@ -442,15 +1066,11 @@ mod tests {
             metadata: TrackMetadata {
                 title: Some(MetadataValue::embedded(title.to_string())),
                 artist: Some(MetadataValue::embedded(artist.to_string())),
-                album: None,
-                album_artist: None,
-                track_number: None,
-                disc_number: None,
                 year: year.map(MetadataValue::embedded),
                 genre: genre.map(|g| MetadataValue::embedded(g.to_string())),
-                duration: None,
                 format: "FLAC".to_string(),
                 path: PathBuf::from(file_name),
+                ..Default::default()
             },
         }
     }
@@ -497,6 +1117,69 @@ mod tests {
         assert!(content.contains("TRACK 02 AUDIO"));
     }
 
+    #[test]
+    fn test_generate_cue_content_pads_to_three_digits_for_large_albums() {
+        let tracks: Vec<TrackNode> = (1..=120)
+            .map(|n| {
+                let mut t = make_track(&format!("Song {n}"), "Artist", "album.flac", None, None);
+                t.metadata.track_number = Some(MetadataValue::embedded(n));
+                t
+            })
+            .collect();
+        let album = make_album("Box Set", None, tracks);
+        let content = generate_cue_content(&album);
+
+        assert!(content.contains("TRACK 001 AUDIO"));
+        assert!(content.contains("TRACK 010 AUDIO"));
+        assert!(content.contains("TRACK 099 AUDIO"));
+        assert!(content.contains("TRACK 120 AUDIO"));
+        assert!(!content.contains("TRACK 01 AUDIO"));
+    }
+
+    #[test]
+    fn test_track_number_width() {
+        let small = vec![
+            make_track("A", "Artist", "a.flac", None, None),
+            make_track("B", "Artist", "b.flac", None, None),
+        ];
+        assert_eq!(track_number_width(&small), 2);
+        assert_eq!(track_number_width(&[]), 2);
+
+        let mut numbered = make_track("C", "Artist", "c.flac", None, None);
+        numbered.metadata.track_number = Some(MetadataValue::embedded(1000));
+        assert_eq!(track_number_width(&[numbered]), 4);
+    }
+
+    #[test]
+    fn test_format_track_number() {
+        assert_eq!(format_track_number(1, 2), "01");
+        assert_eq!(format_track_number(7, 3), "007");
+        assert_eq!(format_track_number(120, 3), "120");
+        assert_eq!(format_track_number(120, 2), "120");
+    }
+
+    #[test]
+    fn test_generate_cue_content_empty_album_is_empty() {
+        let album = make_album("Nothing", Some(2024), vec![]);
+        assert_eq!(generate_cue_content(&album), "");
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let cue_path = temp_dir.path().join("Nothing.cue");
+        assert!(write_cue_file(&album, &cue_path).is_err());
+        assert!(!cue_path.exists());
+    }
+
+    #[test]
+    fn test_generate_cue_for_path_only_unreadable_files() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("01.flac"), b"not audio").unwrap();
+        std::fs::write(temp_dir.path().join("02.flac"), b"not audio either").unwrap();
+
+        let result = generate_cue_for_path(temp_dir.path(), None);
+
+        assert!(matches!(result, Err(CueGenerationError::NoReadableFiles)));
+    }
+
     #[test]
     fn test_generate_cue_file_name() {
         let album = make_album("My Album", None, vec![]);
@@ -633,15 +1316,10 @@ mod tests {
                 metadata: TrackMetadata {
                     title: Some(MetadataValue::embedded("Song One".to_string())),
                     artist: Some(MetadataValue::embedded("Track Artist".to_string())),
-                    album: None,
                     album_artist: Some(MetadataValue::embedded("Album Artist".to_string())),
-                    track_number: None,
-                    disc_number: None,
-                    year: None,
-                    genre: None,
-                    duration: None,
                     format: "FLAC".to_string(),
                     path: PathBuf::from("track1.flac"),
+                    ..Default::default()
                 },
             },
             TrackNode {
@@ -649,15 +1327,10 @@ mod tests {
                 metadata: TrackMetadata {
                     title: Some(MetadataValue::embedded("Song Two".to_string())),
                     artist: Some(MetadataValue::embedded("Track Artist".to_string())),
-                    album: None,
                     album_artist: Some(MetadataValue::embedded("Album Artist".to_string())),
-                    track_number: None,
-                    disc_number: None,
-                    year: None,
-                    genre: None,
-                    duration: None,
                     format: "FLAC".to_string(),
                     path: PathBuf::from("track2.flac"),
+                    ..Default::default()
                 },
             },
         ];
@@ -675,14 +1348,9 @@ mod tests {
                     title: Some(MetadataValue::embedded("Song One".to_string())),
                     artist: Some(MetadataValue::embedded("Artist".to_string())),
                     album: Some(MetadataValue::embedded("Real Album Name".to_string())),
-                    album_artist: None,
-                    track_number: None,
-                    disc_number: None,
-                    year: None,
-                    genre: None,
-                    duration: None,
                     format: "FLAC".to_string(),
                     path: PathBuf::from("track1.flac"),
+                    ..Default::default()
                 },
             },
             TrackNode {
@@ -691,14 +1359,9 @@ mod tests {
                     title: Some(MetadataValue::embedded("Song Two".to_string())),
                     artist: Some(MetadataValue::embedded("Artist".to_string())),
                     album: Some(MetadataValue::embedded("Real Album Name".to_string())),
-                    album_artist: None,
-                    track_number: None,
-                    disc_number: None,
-                    year: None,
-                    genre: None,
-                    duration: None,
                     format: "FLAC".to_string(),
                     path: PathBuf::from("track2.flac"),
+                    ..Default::default()
                 },
             },
         ];
@@ -716,14 +1379,11 @@ mod tests {
                     title: Some(MetadataValue::embedded("Song One".to_string())),
                     artist: Some(MetadataValue::embedded("Artist".to_string())),
                     album: Some(MetadataValue::embedded("Album From Tags".to_string())),
-                    album_artist: None,
-                    track_number: None,
-                    disc_number: None,
                     year: Some(MetadataValue::embedded(2021)),
                     genre: Some(MetadataValue::embedded("Metal".to_string())),
-                    duration: None,
                     format: "FLAC".to_string(),
                     path: PathBuf::from("track1.flac"),
+                    ..Default::default()
                 },
             },
             TrackNode {
@@ -732,14 +1392,11 @@ mod tests {
                     title: Some(MetadataValue::embedded("Song Two".to_string())),
                     artist: Some(MetadataValue::embedded("Artist".to_string())),
                     album: Some(MetadataValue::inferred("Folder Album".to_string(), 0.3)),
-                    album_artist: None,
-                    track_number: None,
-                    disc_number: None,
                     year: Some(MetadataValue::inferred(2020, 0.3)),
                     genre: Some(MetadataValue::inferred("Rock".to_string(), 0.3)),
-                    duration: None,
                     format: "FLAC".to_string(),
                     path: PathBuf::from("track2.flac"),
+                    ..Default::default()
                 },
             },
         ];
@@ -823,6 +1480,57 @@ FILE "test.flac" WAVE
         assert_eq!(result.tracks[1].file, Some("test.flac".to_string()));
     }
 
+    #[test]
+    fn test_parse_cue_timestamp() {
+        assert_eq!(parse_cue_timestamp("00:00:00"), Some(0));
+        assert_eq!(parse_cue_timestamp("00:01:74"), Some(149));
+        assert_eq!(parse_cue_timestamp("03:25:10"), Some(205 * 75 + 10));
+        assert_eq!(parse_cue_timestamp("120:00:00"), Some(7200 * 75));
+        assert_eq!(parse_cue_timestamp("00:60:00"), None);
+        assert_eq!(parse_cue_timestamp("00:00:75"), None);
+        assert_eq!(parse_cue_timestamp("00:00"), None);
+        assert_eq!(parse_cue_timestamp("00:00:00:00"), None);
+        assert_eq!(format_cue_timestamp(205 * 75 + 10), "03:25:10");
+    }
+
+    #[test]
+    fn test_parse_cue_file_start_frames() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let cue_path = temp_dir.path().join("frames.cue");
+        std::fs::write(
+            &cue_path,
+            r#"FILE "album.flac" WAVE
+  TRACK 01 AUDIO
+    INDEX 01 00:00:00
+  TRACK 02 AUDIO
+    INDEX 00 04:10:20
+    INDEX 01 04:12:05
+  TRACK 03 AUDIO
+    INDEX 00 08:00:00
+"#,
+        )
+        .unwrap();
+
+        let cue = parse_cue_file(&cue_path).unwrap();
+        assert_eq!(cue.tracks[0].start_frame, Some(0));
+        assert_eq!(cue.tracks[1].start_frame, Some(252 * 75 + 5));
+        assert_eq!(cue.tracks[2].start_frame, Some(480 * 75));
+    }
+
+    #[test]
+    fn test_parse_cue_file_rejects_bad_index_timestamp() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let cue_path = temp_dir.path().join("bad.cue");
+        std::fs::write(
+            &cue_path,
+            "FILE \"album.flac\" WAVE\n  TRACK 01 AUDIO\n    INDEX 01 00:00:80\n",
+        )
+        .unwrap();
+
+        let err = parse_cue_file(&cue_path).unwrap_err();
+        assert!(err.contains("Malformed INDEX"));
+    }
+
     #[test]
     fn test_parse_cue_file_minimal() {
         let temp_dir = tempfile::TempDir::new().unwrap();
@@ -1057,6 +1765,273 @@ FILE "track2.flac" WAVE
         assert!(result.parsing_error);
     }
 
+    fn write_split_album(dir: &Path, count: u32) -> (PathBuf, Vec<PathBuf>) {
+        let mut content = String::from("PERFORMER \"Artist\"\nTITLE \"Album\"\n");
+        let mut audio = Vec::new();
+        for n in 1..=count {
+            let name = format!("{:02} - Track.flac", n);
+            content.push_str(&format!(
+                "FILE \"{}\" WAVE\n  TRACK {:02} AUDIO\n    TITLE \"Track {}\"\n    INDEX 01 00:00:00\n",
+                name, n, n
+            ));
+            let path = dir.join(&name);
+            std::fs::write(&path, b"dummy audio").unwrap();
+            audio.push(path);
+        }
+        let cue_path = dir.join("album.cue");
+        std::fs::write(&cue_path, content).unwrap();
+        (cue_path, audio)
+    }
+
+    #[test]
+    fn test_validate_cue_consistency_split_album() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let (cue_path, audio) = write_split_album(temp_dir.path(), 10);
+        let cover = temp_dir.path().join("cover.jpg");
+        std::fs::write(&cover, b"jpeg").unwrap();
+
+        let mut refs: Vec<&Path> = audio.iter().map(|p| p.as_path()).collect();
+        refs.push(cover.as_path());
+        let result = validate_cue_consistency(&cue_path, &refs);
+
+        assert!(result.is_valid);
+        assert!(result.missing_files.is_empty());
+        assert!(result.extra_files.is_empty());
+    }
+
+    #[test]
+    fn test_validate_cue_consistency_split_album_with_stray_file() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let (cue_path, mut audio) = write_split_album(temp_dir.path(), 10);
+        let stray = temp_dir.path().join("bonus.flac");
+        std::fs::write(&stray, b"dummy audio").unwrap();
+        audio.push(stray);
+        std::fs::remove_file(&audio[3]).unwrap();
+        audio.remove(3);
+
+        let refs: Vec<&Path> = audio.iter().map(|p| p.as_path()).collect();
+        let result = validate_cue_consistency(&cue_path, &refs);
+
+        assert!(!result.is_valid);
+        assert!(result.file_missing);
+        assert!(result.track_count_mismatch);
+        assert_eq!(result.missing_files, vec!["04 - Track.flac".to_string()]);
+        assert_eq!(result.extra_files, vec!["bonus.flac".to_string()]);
+
+        let text = format_cue_validation_result(&result);
+        assert!(text.contains("missing: 04 - Track.flac"));
+        assert!(text.contains("not referenced by CUE: bonus.flac"));
+    }
+
+    #[test]
+    fn test_validate_cue_consistency_matches_case_insensitively() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let cue_path = temp_dir.path().join("test.cue");
+        let audio = temp_dir.path().join("Track1.FLAC");
+        std::fs::write(
+            &cue_path,
+            "FILE \"CD1\\track1.flac\" WAVE\n  TRACK 01 AUDIO\n    INDEX 01 00:00:00\n",
+        )
+        .unwrap();
+        std::fs::write(&audio, b"dummy audio").unwrap();
+
+        let result = validate_cue_consistency(&cue_path, &[audio.as_path()]);
+
+        assert!(result.is_valid, "{:?}", result);
+    }
+
+    #[test]
+    fn test_generate_cue_content_catalog_from_barcode() {
+        let mut track = make_track("Song One", "Test Artist", "track1.flac", None, None);
+        track.metadata.barcode = Some(MetadataValue::embedded("724384260927".to_string()));
+        let album = make_album("Test Album", None, vec![track]);
+        let content = generate_cue_content(&album);
+
+        assert!(content.starts_with("CATALOG 0724384260927\n"));
+    }
+
+    #[test]
+    fn test_generate_cue_content_skips_invalid_barcode() {
+        let mut track = make_track("Song One", "Test Artist", "track1.flac", None, None);
+        track.metadata.barcode = Some(MetadataValue::embedded("n/a".to_string()));
+        let album = make_album("Test Album", None, vec![track]);
+
+        assert!(!generate_cue_content(&album).contains("CATALOG"));
+    }
+
+    #[test]
+    fn test_parse_cue_file_catalog_and_cdtextfile() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let cue_path = temp_dir.path().join("test.cue");
+
+        std::fs::write(
+            &cue_path,
+            r#"CATALOG 0724384260927
+CDTEXTFILE "album.cdt"
+PERFORMER "Test Artist"
+TITLE "Test Album"
+FILE "test.flac" WAVE
+  TRACK 01 AUDIO
+    INDEX 01 00:00:00
+"#,
+        )
+        .unwrap();
+
+        let result = parse_cue_file(&cue_path).unwrap();
+
+        assert_eq!(result.catalog, Some("0724384260927".to_string()));
+        assert_eq!(result.cdtextfile, Some("album.cdt".to_string()));
+    }
+
+    #[test]
+    fn test_parse_cue_file_eac_rem_header() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let cue_path = temp_dir.path().join("test.cue");
+
+        std::fs::write(
+            &cue_path,
+            r#"REM GENRE "Progressive Rock"
+REM DATE 1973
+REM DISCID 2F0A9B05
+REM COMMENT "ExactAudioCopy v1.6"
+REM ACCURATERIPCRC 5A3C1F2E
+REM REPLAYGAIN_ALBUM_GAIN -7.21 dB
+CATALOG 0724384260927
+PERFORMER "Test Artist"
+TITLE "Test Album"
+FILE "Test Artist - Test Album.flac" WAVE
+  TRACK 01 AUDIO
+    TITLE "Opening"
+    REM REPLAYGAIN_TRACK_GAIN -6.50 dB
+    INDEX 01 00:00:00
+"#,
+        )
+        .unwrap();
+
+        let result = parse_cue_file(&cue_path).unwrap();
+
+        assert_eq!(result.genre.as_deref(), Some("Progressive Rock"));
+        assert_eq!(result.date.as_deref(), Some("1973"));
+        assert_eq!(result.disc_id.as_deref(), Some("2F0A9B05"));
+        assert_eq!(result.comment.as_deref(), Some("ExactAudioCopy v1.6"));
+        assert_eq!(result.accuraterip_crc.as_deref(), Some("5A3C1F2E"));
+        assert_eq!(
+            result.other_rems,
+            vec!["REPLAYGAIN_ALBUM_GAIN -7.21 dB".to_string()]
+        );
+        assert_eq!(result.tracks.len(), 1);
+        assert_eq!(
+            result.tracks[0].other_rems,
+            vec!["REPLAYGAIN_TRACK_GAIN -6.50 dB".to_string()]
+        );
+
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["disc_id"], "2F0A9B05");
+        let restored: CueFile = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.other_rems, result.other_rems);
+        assert_eq!(restored.comment, result.comment);
+    }
+
+    #[test]
+    fn test_regenerate_cue_content_preserves_rem_lines() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let cue_path = temp_dir.path().join("test.cue");
+
+        std::fs::write(
+            &cue_path,
+            r#"REM DISCID 2F0A9B05
+REM COMMENT "ExactAudioCopy v1.6"
+REM ACCURATERIPCRC 5A3C1F2E
+REM REPLAYGAIN_ALBUM_GAIN -7.21 dB
+PERFORMER "Test Artist"
+TITLE "Test Album"
+FILE "track1.flac" WAVE
+  TRACK 01 AUDIO
+    TITLE "Song One"
+    REM REPLAYGAIN_TRACK_GAIN -6.50 dB
+    INDEX 01 00:00:00
+"#,
+        )
+        .unwrap();
+        let previous = parse_cue_file(&cue_path).unwrap();
+
+        let album = make_album(
+            "Test Album",
+            None,
+            vec![make_track(
+                "Song One",
+                "Test Artist",
+                "track1.flac",
+                None,
+                None,
+            )],
+        );
+        std::fs::write(&cue_path, regenerate_cue_content(&album, &previous)).unwrap();
+        let reparsed = parse_cue_file(&cue_path).unwrap();
+
+        assert_eq!(reparsed.disc_id, previous.disc_id);
+        assert_eq!(reparsed.comment, previous.comment);
+        assert_eq!(reparsed.accuraterip_crc, previous.accuraterip_crc);
+        assert_eq!(reparsed.other_rems, previous.other_rems);
+        assert_eq!(reparsed.tracks[0].other_rems, previous.tracks[0].other_rems);
+        assert!(!generate_cue_content(&album).contains("REM DISCID"));
+    }
+
+    #[test]
+    fn test_parse_cue_charset() {
+        assert_eq!(parse_cue_charset("UTF-8"), Ok(CueCharset::Utf8));
+        assert_eq!(
+            parse_cue_charset("windows-1252"),
+            Ok(CueCharset::Windows1252)
+        );
+        assert_eq!(parse_cue_charset("latin1"), Ok(CueCharset::Windows1252));
+        assert!(parse_cue_charset("shift-jis").is_err());
+    }
+
+    #[test]
+    fn test_encode_cue_content_windows_1252() {
+        let (bytes, unmappable) =
+            encode_cue_content("TITLE \"Café – 10€\"", CueCharset::Windows1252);
+        assert_eq!(bytes, b"TITLE \"Caf\xE9 \x96 10\x80\"".to_vec());
+        assert!(unmappable.is_empty());
+
+        let (bytes, unmappable) =
+            encode_cue_content("PERFORMER \"東京事変 東\"", CueCharset::Windows1252);
+        assert_eq!(bytes, b"PERFORMER \"???? ?\"".to_vec());
+        assert_eq!(unmappable, vec!['東', '京', '事', '変']);
+
+        let (bytes, unmappable) = encode_cue_content("Café", CueCharset::Utf8);
+        assert_eq!(bytes, "Café".as_bytes());
+        assert!(unmappable.is_empty());
+    }
+
+    #[test]
+    fn test_parse_cue_file_windows_1252() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let cue_path = temp_dir.path().join("latin1.cue");
+        let (bytes, _) = encode_cue_content(
+            "PERFORMER \"Beyoncé\"\nTITLE \"Déjà Vu\"\nFILE \"a.flac\" WAVE\n  TRACK 01 AUDIO\n    INDEX 01 00:00:00\n",
+            CueCharset::Windows1252,
+        );
+        std::fs::write(&cue_path, bytes).unwrap();
+
+        let result = parse_cue_file(&cue_path).unwrap();
+
+        assert_eq!(result.performer.as_deref(), Some("Beyoncé"));
+        assert_eq!(result.title.as_deref(), Some("Déjà Vu"));
+    }
+
+    #[test]
+    fn test_parse_cue_file_rejects_short_catalog() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let cue_path = temp_dir.path().join("test.cue");
+        std::fs::write(&cue_path, "CATALOG 12345\nTITLE \"Album\"\n").unwrap();
+
+        let err = parse_cue_file(&cue_path).unwrap_err();
+
+        assert!(err.contains("Malformed CATALOG"), "{}", err);
+    }
+
     #[test]
     fn test_parse_cue_file_with_rem_genre() {
         let temp_dir = tempfile::TempDir::new().unwrap();
@@ -1159,4 +2134,26 @@ FILE "test.flac" WAVE
         assert_eq!(result.tracks[1].number, 2);
         assert_eq!(result.tracks[1].title, Some("Track Two".to_string()));
     }
+
+    #[test]
+    fn test_cue_output_path_expands_placeholders_inside_base() {
+        let mut album = make_album(
+            "A/B",
+            None,
+            vec![make_track("T", "Band", "1.flac", None, None)],
+        );
+        album.path = PathBuf::from("/lib/Band/Album");
+        let base = Path::new("/lib");
+
+        assert_eq!(
+            cue_output_path(base, &album, DEFAULT_CUE_OUTPUT_TEMPLATE).unwrap(),
+            PathBuf::from("/lib/Band/Album/A_B.cue")
+        );
+        assert_eq!(
+            cue_output_path(base, &album, "cues/{artist}/{album_dir}.cue").unwrap(),
+            PathBuf::from("/lib/cues/Band/Band/Album.cue")
+        );
+        assert!(cue_output_path(base, &album, "../{album}.cue").is_err());
+        assert!(cue_output_path(base, &album, "{title}.cue").is_err());
+    }
 }
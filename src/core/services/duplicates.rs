@@ -1,7 +1,262 @@
-use crate::core::services::scanner::scan_with_duplicates;
+use crate::core::domain::models::{Track, TrackMetadata};
+use crate::core::services::scanner::{
+    ChecksumMode, scan_dir, scan_dir_with_metadata, scan_with_duplicates_with_mode,
+};
 use serde_json::to_string_pretty;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Write;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+/// Default width, in seconds, of the duration buckets used by
+/// [`find_near_duplicates`].
+pub const DEFAULT_DURATION_TOLERANCE: f64 = 2.0;
+
+/// Coarse audio quality tier, ordered from worst to best.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum QualityTier {
+    Unknown,
+    Lossy,
+    Lossless,
+}
+
+/// Classify a track's quality from its container format.
+///
+/// M4A is treated as lossy because the container cannot tell AAC from ALAC.
+pub fn classify_quality(track: &Track) -> QualityTier {
+    classify_metadata(&track.metadata)
+}
+
+/// Classify quality from track metadata, so hybrid WavPack files without
+/// their correction file count as lossy.
+pub fn classify_metadata(metadata: &TrackMetadata) -> QualityTier {
+    if metadata.is_lossless() {
+        QualityTier::Lossless
+    } else if classify_format(&metadata.format) == QualityTier::Lossless {
+        QualityTier::Lossy
+    } else {
+        classify_format(&metadata.format)
+    }
+}
+
+/// Classify a format string such as `flac` or `mp3`.
+pub fn classify_format(format: &str) -> QualityTier {
+    match format.to_lowercase().as_str() {
+        "flac" | "wav" | "wv" | "wavpack" | "ape" | "dsf" | "aiff" | "aif" => QualityTier::Lossless,
+        "mp3" | "ogg" | "m4a" | "aac" | "opus" => QualityTier::Lossy,
+        _ => QualityTier::Unknown,
+    }
+}
+
+/// Number of descriptive tag fields present (title, artist, album, album
+/// artist, track, disc, year, genre).
+pub fn metadata_completeness(metadata: &TrackMetadata) -> usize {
+    [
+        metadata.title.is_some(),
+        metadata.artist.is_some(),
+        metadata.album.is_some(),
+        metadata.album_artist.is_some(),
+        metadata.track_number.is_some(),
+        metadata.disc_number.is_some(),
+        metadata.year.is_some(),
+        metadata.genre.is_some(),
+    ]
+    .into_iter()
+    .filter(|present| *present)
+    .count()
+}
+
+/// A set of identical files, best candidate to keep first.
+#[derive(Debug, Clone, serde::Serialize, schemars::JsonSchema)]
+pub struct DuplicateGroup {
+    /// Index into `tracks` of the copy recommended to keep.
+    pub recommended_keep: usize,
+    pub tracks: Vec<Track>,
+}
+
+/// Order a duplicate group so the recommended keep comes first: highest
+/// quality tier, then most complete metadata, then shortest path (ties
+/// broken alphabetically for a deterministic result).
+pub fn rank_duplicate_group(mut tracks: Vec<Track>) -> DuplicateGroup {
+    tracks.sort_by_cached_key(|track| {
+        let path = track.file_path.to_string_lossy().into_owned();
+        (
+            Reverse(classify_quality(track)),
+            Reverse(metadata_completeness(&track.metadata)),
+            path.chars().count(),
+            path,
+        )
+    });
+
+    DuplicateGroup {
+        recommended_keep: 0,
+        tracks,
+    }
+}
+
+/// Bucket a duration so that rips of the same recording, which rarely match
+/// to the millisecond, share a bucket.
+///
+/// Buckets are `tolerance` seconds wide, so tracks within the same window
+/// land together; two durations just either side of a bucket boundary don't.
+/// A `tolerance` of zero or less buckets by whole seconds.
+pub fn duration_bucket(seconds: f64, tolerance: f64) -> u32 {
+    let seconds = seconds.max(0.0);
+    if tolerance <= 0.0 {
+        return seconds.round() as u32;
+    }
+    (seconds / tolerance).floor() as u32
+}
+
+/// Group tracks that look like the same song from different rips: same
+/// artist and title (ignoring case and surrounding whitespace) and the same
+/// [`duration_bucket`].
+///
+/// Tracks without a title or duration are skipped. Groups are ranked with
+/// [`rank_duplicate_group`] and ordered by the path of their first track.
+pub fn find_near_duplicates(tracks: &[Track], tolerance: f64) -> Vec<DuplicateGroup> {
+    let mut groups: BTreeMap<(String, String, u32), Vec<Track>> = BTreeMap::new();
+    for track in tracks {
+        let metadata = &track.metadata;
+        let (Some(title), Some(duration)) = (&metadata.title, &metadata.duration) else {
+            continue;
+        };
+        let artist = metadata
+            .artist
+            .as_ref()
+            .map(|a| a.value.trim().to_lowercase())
+            .unwrap_or_default();
+        let key = (
+            artist,
+            title.value.trim().to_lowercase(),
+            duration_bucket(duration.value, tolerance),
+        );
+        groups.entry(key).or_default().push(track.clone());
+    }
+
+    let mut duplicates: Vec<DuplicateGroup> = groups
+        .into_values()
+        .filter(|tracks| tracks.len() > 1)
+        .map(rank_duplicate_group)
+        .collect();
+    duplicates.sort_by(|a, b| a.tracks[0].file_path.cmp(&b.tracks[0].file_path));
+    duplicates
+}
+
+/// Scan `path` and report [`find_near_duplicates`] with the given tolerance.
+pub fn find_near_duplicates_by_path(
+    path: &Path,
+    json: bool,
+    verbose: bool,
+    tolerance: f64,
+) -> Result<String, String> {
+    let tracks = scan_dir_with_metadata(path)?;
+    if tracks.is_empty() {
+        return Err(format!(
+            "No music files found in directory: {}",
+            path.display()
+        ));
+    }
+
+    let duplicates = find_near_duplicates(&tracks, tolerance);
+    format_duplicate_groups(&duplicates, json, verbose, None)
+}
+
+/// Files with the same name in different folders.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, schemars::JsonSchema)]
+pub struct FilenameCollision {
+    /// File name as spelled by the first path.
+    pub file_name: String,
+    pub paths: Vec<PathBuf>,
+}
+
+/// Group tracks by base file name (ignoring case) and return the names that
+/// occur in more than one folder.
+///
+/// Unlike checksum duplicates this is a naming-hygiene check: the files may
+/// hold different audio. Tracks sharing a path (CUE sheet tracks of one
+/// image) count once. Collisions are ordered by number of paths, most first,
+/// then by name.
+pub fn find_filename_collisions(tracks: &[Track]) -> Vec<FilenameCollision> {
+    let mut groups: BTreeMap<String, BTreeSet<&Path>> = BTreeMap::new();
+    for track in tracks {
+        let Some(name) = track.file_path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        groups
+            .entry(name.to_lowercase())
+            .or_default()
+            .insert(&track.file_path);
+    }
+
+    let mut collisions: Vec<FilenameCollision> = groups
+        .into_values()
+        .filter(|paths| paths.len() > 1)
+        .map(|paths| {
+            let paths: Vec<PathBuf> = paths.into_iter().map(Path::to_path_buf).collect();
+            FilenameCollision {
+                file_name: paths[0]
+                    .file_name()
+                    .map(|n| n.to_string_lossy().into_owned())
+                    .unwrap_or_default(),
+                paths,
+            }
+        })
+        .collect();
+    collisions.sort_by(|a, b| {
+        b.paths
+            .len()
+            .cmp(&a.paths.len())
+            .then_with(|| a.file_name.to_lowercase().cmp(&b.file_name.to_lowercase()))
+    });
+    collisions
+}
+
+/// Scan `path` (file names only, no metadata) and report
+/// [`find_filename_collisions`].
+pub fn find_filename_collisions_by_path(path: &Path, json: bool) -> Result<String, String> {
+    let tracks = scan_dir(path, true);
+    if tracks.is_empty() {
+        return Err(format!(
+            "No music files found in directory: {}",
+            path.display()
+        ));
+    }
+
+    let collisions = find_filename_collisions(&tracks);
+    if json {
+        return to_string_pretty(&collisions)
+            .map_err(|e| format!("Error serializing to JSON: {}", e));
+    }
+    if collisions.is_empty() {
+        return Ok("No file names shared across folders.".to_string());
+    }
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        "Found {} file names shared across folders:\n",
+        collisions.len()
+    )
+    .unwrap();
+    for collision in &collisions {
+        writeln!(
+            out,
+            "{} ({} files):",
+            collision.file_name,
+            collision.paths.len()
+        )
+        .unwrap();
+        for path in &collision.paths {
+            writeln!(out, "  {}", path.display()).unwrap();
+        }
+        writeln!(out).unwrap();
+    }
+    Ok(out)
+}
 
 pub fn find_duplicates(
     path: &Path,
@@ -9,7 +264,20 @@ pub fn find_duplicates(
     verbose: bool,
     parallel: Option<usize>,
 ) -> Result<String, String> {
-    let (tracks, duplicates) = scan_with_duplicates(path, verbose, parallel);
+    find_duplicates_with_mode(path, json, verbose, parallel, ChecksumMode::FileBytes)
+}
+
+/// Like [`find_duplicates`], comparing files with the given checksum mode.
+pub fn find_duplicates_with_mode(
+    path: &Path,
+    json: bool,
+    verbose: bool,
+    parallel: Option<usize>,
+    mode: ChecksumMode,
+) -> Result<String, String> {
+    let (tracks, duplicates) = scan_with_duplicates_with_mode(path, verbose, parallel, mode);
+    let duplicates: Vec<DuplicateGroup> =
+        duplicates.into_iter().map(rank_duplicate_group).collect();
 
     if tracks.is_empty() {
         return Err(format!(
@@ -18,6 +286,21 @@ pub fn find_duplicates(
         ));
     }
 
+    let checksum_label = match mode {
+        ChecksumMode::FileBytes => "sha256",
+        ChecksumMode::PcmContent => "pcm-md5",
+    };
+    format_duplicate_groups(&duplicates, json, verbose, Some(checksum_label))
+}
+
+/// Render duplicate groups as JSON or text. `checksum_label` names the
+/// checksum shown in verbose text output; `None` leaves the checksum out.
+fn format_duplicate_groups(
+    duplicates: &[DuplicateGroup],
+    json: bool,
+    verbose: bool,
+    checksum_label: Option<&str>,
+) -> Result<String, String> {
     if json {
         if duplicates.is_empty() {
             return Ok("[]".to_string());
@@ -41,11 +324,16 @@ pub fn find_duplicates(
                 out,
                 "Duplicate Group {} ({} files):",
                 i + 1,
-                duplicate_group.len()
+                duplicate_group.tracks.len()
             )
             .unwrap();
 
-            for track in duplicate_group {
+            for (j, track) in duplicate_group.tracks.iter().enumerate() {
+                let keep = if j == duplicate_group.recommended_keep {
+                    " [keep]"
+                } else {
+                    ""
+                };
                 if verbose {
                     let artist = track
                         .metadata
@@ -71,21 +359,27 @@ pub fn find_duplicates(
                         .as_ref()
                         .map(|v| format!("{:.0}s", v.value))
                         .unwrap_or_else(|| "unknown".to_string());
-                    let checksum = track.checksum.as_deref().unwrap_or("unknown");
+                    let checksum = checksum_label
+                        .map(|label| {
+                            let checksum = track.checksum.as_deref().unwrap_or("unknown");
+                            format!(" [{}: {}]", label, checksum)
+                        })
+                        .unwrap_or_default();
 
                     writeln!(
                         out,
-                        "  {} - {} - {} [{}] ({}) [sha256: {}]",
+                        "  {} - {} - {} [{}] ({}){}{}",
                         artist,
                         album,
                         title,
                         duration,
                         track.file_path.display(),
-                        checksum
+                        checksum,
+                        keep
                     )
                     .unwrap();
                 } else {
-                    writeln!(out, "  {}", track.file_path.display()).unwrap();
+                    writeln!(out, "  {}{}", track.file_path.display(), keep).unwrap();
                 }
             }
 
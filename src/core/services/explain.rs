@@ -0,0 +1,119 @@
+//! Per-field comparison of embedded tags and path inference.
+//!
+//! `read` shows one value per field, and its source alone does not tell why
+//! it won. [`explain_track`] reads the embedded tags and runs the path
+//! inference independently, so both candidates can be seen side by side.
+
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::adapters::audio_formats as formats;
+use crate::core::domain::models::{MetadataSource, MetadataValue};
+use crate::core::services::scanner::inferred_metadata;
+
+/// The embedded, inferred and effective value of one field.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FieldExplanation {
+    pub field: &'static str,
+    /// Value read from the file's own tags.
+    pub embedded: Option<MetadataValue<serde_json::Value>>,
+    /// Value path and filename inference produces for the file.
+    pub inferred: Option<MetadataValue<serde_json::Value>>,
+    /// Value `read` reports.
+    pub effective: Option<MetadataValue<serde_json::Value>>,
+}
+
+/// Explanations of every field of one file, in `read` order.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FieldExplanations {
+    pub path: PathBuf,
+    pub fields: Vec<FieldExplanation>,
+}
+
+fn to_json<T: Serialize>(
+    value: &Option<MetadataValue<T>>,
+) -> Option<MetadataValue<serde_json::Value>> {
+    value.as_ref().map(|v| MetadataValue {
+        value: serde_json::to_value(&v.value).unwrap_or_default(),
+        source: v.source.clone(),
+        confidence: v.confidence,
+    })
+}
+
+fn explain<T: Serialize>(
+    field: &'static str,
+    effective: &Option<MetadataValue<T>>,
+    inferred: &Option<MetadataValue<T>>,
+) -> FieldExplanation {
+    let effective = to_json(effective);
+    FieldExplanation {
+        field,
+        embedded: effective
+            .clone()
+            .filter(|v| v.source == MetadataSource::Embedded),
+        inferred: to_json(inferred),
+        effective,
+    }
+}
+
+/// Read `track_path` and explain where each of its identifying fields
+/// (title, artist, album, album artist, track and disc number, year, genre)
+/// could come from.
+pub fn explain_track(track_path: &Path) -> Result<FieldExplanations, String> {
+    let md = formats::read_metadata(track_path)
+        .map_err(|e| format!("Error reading metadata: {}", e))?
+        .metadata;
+    let inferred = inferred_metadata(track_path, true);
+
+    Ok(FieldExplanations {
+        path: track_path.to_path_buf(),
+        fields: vec![
+            explain("title", &md.title, &inferred.title),
+            explain("artist", &md.artist, &inferred.artist),
+            explain("album", &md.album, &inferred.album),
+            explain("album_artist", &md.album_artist, &inferred.album_artist),
+            explain("track_number", &md.track_number, &inferred.track_number),
+            explain("disc_number", &md.disc_number, &inferred.disc_number),
+            explain("year", &md.year, &inferred.year),
+            explain("genre", &md.genre, &inferred.genre),
+        ],
+    })
+}
+
+// ── Tests ───────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_explain_track_shows_embedded_and_inferred_artist() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let album = dir.path().join("Folder Artist/Folder Album");
+        std::fs::create_dir_all(&album).unwrap();
+        let file = album.join("05.flac");
+        std::fs::copy("tests/fixtures/flac/simple/track1.flac", &file).unwrap();
+
+        let explanations = explain_track(&file).unwrap();
+        let artist = &explanations.fields[1];
+
+        assert_eq!(artist.field, "artist");
+        assert_eq!(artist.embedded.as_ref().unwrap().value, "Test Artist");
+        assert_eq!(artist.effective, artist.embedded);
+        let inferred = artist.inferred.as_ref().unwrap();
+        assert_eq!(inferred.value, "Folder Artist");
+        assert_eq!(inferred.source, MetadataSource::FolderInferred);
+
+        // A numeric-only file name gives a track number but no title
+        assert!(explanations.fields[0].inferred.is_none());
+        let track_number = &explanations.fields[4];
+        assert_eq!(track_number.inferred.as_ref().unwrap().value, 5);
+        assert_eq!(track_number.embedded.as_ref().unwrap().value, 1);
+    }
+
+    #[test]
+    fn test_explain_track_missing_file_is_an_error() {
+        assert!(explain_track(Path::new("/nonexistent/track.flac")).is_err());
+    }
+}
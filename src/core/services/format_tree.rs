@@ -1,6 +1,14 @@
+use crate::core::domain::models::YearNode;
 use crate::core::domain::with_schema_version;
-use crate::core::services::scanner::{scan_dir, scan_dir_with_metadata};
-use crate::{Library, MetadataSource, Track, TrackNode, build_library_hierarchy};
+use crate::core::services::genre_map::GenreMap;
+use crate::core::services::library::{
+    ArtistSummary, HierarchyOptions, LibraryStats, RankedEntry, YearSource, artist_summaries,
+    build_library_hierarchy, build_library_hierarchy_with_options, compute_library_stats,
+    count_tracks_without_year, decade_histogram, library_content_hash, tracks_by_genre,
+};
+use crate::core::services::normalization::cluster_genre_with_map;
+use crate::core::services::scanner::{rebase_tracks, scan_dir, scan_dir_with_metadata};
+use crate::{ArtistNode, Library, MetadataSource, Track, TrackNode};
 use serde_json::to_string_pretty;
 use std::collections::{BTreeMap, HashSet};
 use std::path::{Path, PathBuf};
@@ -227,16 +235,141 @@ fn count_dirs_in_tree(node: &DirNode) -> usize {
     subdir_count + nested_count
 }
 
+/// Deepest level printed by [`format_library_output_with_depth`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TreeDepth {
+    /// Artists only, each with its album and track counts.
+    Artist,
+    /// Artists and albums, each album with its track count.
+    Album,
+    /// Artists, albums and tracks.
+    #[default]
+    Track,
+}
+
+/// Parses a `tree --depth` level: `artist`, `album` or `track`.
+pub fn parse_tree_depth(input: &str) -> Result<TreeDepth, String> {
+    match input.trim().to_ascii_lowercase().as_str() {
+        "artist" => Ok(TreeDepth::Artist),
+        "album" => Ok(TreeDepth::Album),
+        "track" => Ok(TreeDepth::Track),
+        _ => Err(format!(
+            "invalid depth '{input}' (expected 'artist', 'album' or 'track')"
+        )),
+    }
+}
+
+/// Top level of the metadata tree (`tree --group-by`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TreeGroupBy {
+    /// Artist -> album -> track.
+    #[default]
+    Artist,
+    /// Year -> artist -> album -> track.
+    Year,
+}
+
+/// Parses a `tree --group-by` value: `artist` or `year`.
+pub fn parse_tree_group_by(input: &str) -> Result<TreeGroupBy, String> {
+    match input.trim().to_ascii_lowercase().as_str() {
+        "artist" => Ok(TreeGroupBy::Artist),
+        "year" => Ok(TreeGroupBy::Year),
+        _ => Err(format!(
+            "invalid grouping '{input}' (expected 'artist' or 'year')"
+        )),
+    }
+}
+
+fn plural(count: usize, noun: &str) -> String {
+    if count == 1 {
+        format!("{} {}", count, noun)
+    } else {
+        format!("{} {}s", count, noun)
+    }
+}
+
 /// Print library tree in human-readable format (metadata-based, deprecated)
 /// Use format_tree_output(base_path) instead for directory-based view
 pub fn format_library_output(library: &Library) -> String {
+    format_library_output_with_depth(library, TreeDepth::Track)
+}
+
+/// [`format_library_output`] cut off at `depth`; the last printed level
+/// shows how many tracks (and albums, for artists) it hides.
+pub fn format_library_output_with_depth(library: &Library, depth: TreeDepth) -> String {
     let mut output = String::new();
 
-    for artist in &library.artists {
+    push_artists(&mut output, &library.artists, depth);
+
+    // Print summary
+    output.push_str("📊 Library Summary:\n");
+    output.push_str(&format!("   Artists: {}\n", library.total_artists));
+    output.push_str(&format!("   Albums: {}\n", library.total_albums));
+    output.push_str(&format!("   Files: {}\n", library.total_files));
+    output.push_str(&format!("   Tracks: {}\n", library.total_tracks));
+
+    output
+}
+
+/// Year-first tree (`tree --group-by year`): each year with its artists,
+/// albums and tracks down to `depth`.
+pub fn format_year_hierarchy(years: &[YearNode], depth: TreeDepth) -> String {
+    let mut output = String::new();
+
+    for year in years {
+        output.push_str(&format!(
+            "📅 {} ({})\n",
+            year.label(),
+            plural(year.track_count(), "track")
+        ));
+        let mut artists = String::new();
+        push_artists(&mut artists, &year.artists, depth);
+        for line in artists.lines() {
+            if !line.is_empty() {
+                output.push_str(&format!("    {}", line));
+            }
+            output.push('\n');
+        }
+        if !output.ends_with("\n\n") {
+            output.push('\n');
+        }
+    }
+
+    let known = years.iter().filter(|y| y.year.is_some()).count();
+    let tracks: usize = years.iter().map(YearNode::track_count).sum();
+    output.push_str("📊 Library Summary:\n");
+    output.push_str(&format!("   Years: {}\n", known));
+    output.push_str(&format!("   Tracks: {}\n", tracks));
+
+    output
+}
+
+/// Artist, album and track lines of the metadata tree, cut off at `depth`.
+fn push_artists(output: &mut String, artists: &[ArtistNode], depth: TreeDepth) {
+    for artist in artists {
+        if depth == TreeDepth::Artist {
+            let tracks = artist.albums.iter().map(|a| a.tracks.len()).sum();
+            output.push_str(&format!(
+                "📁 {} ({}, {})\n",
+                artist.name,
+                plural(artist.albums.len(), "album"),
+                plural(tracks, "track")
+            ));
+            continue;
+        }
         output.push_str(&format!("📁 {}\n", artist.name));
 
         for album in &artist.albums {
             let year_str = album.year.map(|y| format!(" ({})", y)).unwrap_or_default();
+            if depth == TreeDepth::Album {
+                output.push_str(&format!(
+                    "├── 📂 {}{} ({})\n",
+                    album.title,
+                    year_str,
+                    plural(album.tracks.len(), "track")
+                ));
+                continue;
+            }
             output.push_str(&format!("├── 📂 {}{}\n", album.title, year_str));
 
             for (i, track) in album.tracks.iter().enumerate() {
@@ -262,15 +395,9 @@ pub fn format_library_output(library: &Library) -> String {
         }
         output.push('\n');
     }
-
-    // Print summary
-    output.push_str("📊 Library Summary:\n");
-    output.push_str(&format!("   Artists: {}\n", library.total_artists));
-    output.push_str(&format!("   Albums: {}\n", library.total_albums));
-    output.push_str(&format!("   Files: {}\n", library.total_files));
-    output.push_str(&format!("   Tracks: {}\n", library.total_tracks));
-
-    output
+    if depth == TreeDepth::Artist && !artists.is_empty() {
+        output.push('\n');
+    }
 }
 
 /// Format track information for tree display
@@ -364,6 +491,18 @@ pub fn emit_structured_output(library: &Library) -> String {
 }
 
 pub fn emit_by_path(path: &Path, json: bool) -> Result<String, String> {
+    emit_by_path_with_options(path, json, false, &HierarchyOptions::default())
+}
+
+/// Like [`emit_by_path`], grouping albums with the given hierarchy options.
+///
+/// With `compact`, JSON output is written on a single line without indentation.
+pub fn emit_by_path_with_options(
+    path: &Path,
+    json: bool,
+    compact: bool,
+    options: &HierarchyOptions,
+) -> Result<String, String> {
     log::info!("emit_by_path called with path: {}", path.display());
 
     let tracks = match scan_dir_with_metadata(path) {
@@ -372,11 +511,16 @@ pub fn emit_by_path(path: &Path, json: bool) -> Result<String, String> {
     };
     log::info!("Found {} tracks", tracks.len());
 
-    let library = build_library_hierarchy(tracks);
+    let library = build_library_hierarchy_with_options(tracks, options);
 
     if json {
         let wrapper = with_schema_version(&library);
-        match to_string_pretty(&wrapper) {
+        let serialized = if compact {
+            serde_json::to_string(&wrapper)
+        } else {
+            to_string_pretty(&wrapper)
+        };
+        match serialized {
             Ok(s) => Ok(s),
             Err(e) => Err(format!("Error serializing to JSON: {}", e)),
         }
@@ -385,3 +529,333 @@ pub fn emit_by_path(path: &Path, json: bool) -> Result<String, String> {
         Ok(emit_structured_output(&library))
     }
 }
+
+/// Like [`emit_by_path_with_options`] with JSON output, adding a `genre_root`
+/// field to each track whose genre belongs to a known cluster.
+///
+/// Roots from `genre_map` take precedence over the built-in hierarchy.
+pub fn emit_by_path_with_genre_roots(
+    path: &Path,
+    compact: bool,
+    options: &HierarchyOptions,
+    genre_map: Option<&GenreMap>,
+) -> Result<String, String> {
+    let tracks = match scan_dir_with_metadata(path) {
+        Ok(tracks) => tracks,
+        Err(e) => return Err(format!("Failed to scan directory: {}", e)),
+    };
+
+    let library = build_library_hierarchy_with_options(tracks, options);
+    let mut value =
+        serde_json::to_value(&library).map_err(|e| format!("Error serializing to JSON: {}", e))?;
+
+    for (artist, artist_value) in library.artists.iter().zip(array_mut(&mut value, "artists")) {
+        for (album, album_value) in artist.albums.iter().zip(array_mut(artist_value, "albums")) {
+            for (track, track_value) in album.tracks.iter().zip(array_mut(album_value, "tracks")) {
+                let root = track
+                    .metadata
+                    .genre
+                    .as_ref()
+                    .and_then(|g| cluster_genre_with_map(&g.value, genre_map));
+                if let (Some(root), Some(object)) = (root, track_value.as_object_mut()) {
+                    object.insert("genre_root".to_string(), root.into());
+                }
+            }
+        }
+    }
+
+    let wrapper = with_schema_version(value);
+    let serialized = if compact {
+        serde_json::to_string(&wrapper)
+    } else {
+        to_string_pretty(&wrapper)
+    };
+    serialized.map_err(|e| format!("Error serializing to JSON: {}", e))
+}
+
+fn array_mut<'a>(
+    value: &'a mut serde_json::Value,
+    key: &str,
+) -> impl Iterator<Item = &'a mut serde_json::Value> {
+    value
+        .get_mut(key)
+        .and_then(serde_json::Value::as_array_mut)
+        .into_iter()
+        .flatten()
+}
+
+/// Format artist summaries as one line per artist.
+pub fn format_artist_summaries(summaries: &[ArtistSummary]) -> String {
+    let mut out = String::new();
+
+    out.push_str("=== ARTIST SUMMARY ===\n");
+    for summary in summaries {
+        let total_seconds = summary.total_duration as u64;
+        let years = match (summary.first_year, summary.last_year) {
+            (Some(first), Some(last)) if first != last => format!("{}-{}", first, last),
+            (Some(year), _) => year.to_string(),
+            _ => "unknown".to_string(),
+        };
+        let genres = if summary.genres.is_empty() {
+            "unknown".to_string()
+        } else {
+            summary.genres.join(", ")
+        };
+
+        out.push_str(&format!(
+            "ARTIST: {} | Albums: {} | Tracks: {} | Duration: {}:{:02}:{:02} | Years: {} | Genres: {}\n",
+            summary.name,
+            summary.album_count,
+            summary.track_count,
+            total_seconds / 3600,
+            (total_seconds % 3600) / 60,
+            total_seconds % 60,
+            years,
+            genres
+        ));
+    }
+    out.push_str("=== END ARTIST SUMMARY ===\n");
+
+    out
+}
+
+/// JSON envelope for `emit --by-artist`.
+#[derive(serde::Serialize)]
+struct ArtistSummaryOutput<'a> {
+    artists: &'a [ArtistSummary],
+}
+
+/// Emit [`artist_summaries`] for the library at `path` instead of the full tree.
+pub fn emit_artist_summaries_by_path(
+    path: &Path,
+    json: bool,
+    compact: bool,
+    options: &HierarchyOptions,
+) -> Result<String, String> {
+    let tracks = match scan_dir_with_metadata(path) {
+        Ok(tracks) => tracks,
+        Err(e) => return Err(format!("Failed to scan directory: {}", e)),
+    };
+
+    let library = build_library_hierarchy_with_options(tracks, options);
+    emit_artist_summaries(&library, json, compact)
+}
+
+/// Emit [`artist_summaries`] for an already built library.
+pub fn emit_artist_summaries(
+    library: &Library,
+    json: bool,
+    compact: bool,
+) -> Result<String, String> {
+    let summaries = artist_summaries(library);
+
+    if json {
+        let wrapper = with_schema_version(ArtistSummaryOutput {
+            artists: &summaries,
+        });
+        let serialized = if compact {
+            serde_json::to_string(&wrapper)
+        } else {
+            to_string_pretty(&wrapper)
+        };
+        serialized.map_err(|e| format!("Error serializing to JSON: {}", e))
+    } else {
+        Ok(format_artist_summaries(&summaries))
+    }
+}
+
+/// Format a decade histogram as one line per decade, plus the unknown bucket.
+pub fn format_decade_histogram(decades: &BTreeMap<u32, usize>, unknown: usize) -> String {
+    let mut out = String::new();
+
+    out.push_str("=== DECADES ===\n");
+    for (decade, count) in decades {
+        out.push_str(&format!("{}s: {}\n", decade, count));
+    }
+    out.push_str(&format!("unknown: {}\n", unknown));
+    out.push_str("=== END DECADES ===\n");
+
+    out
+}
+
+/// JSON envelope for `emit --decades`.
+#[derive(serde::Serialize)]
+struct DecadeHistogramOutput {
+    decades: BTreeMap<u32, usize>,
+    unknown: usize,
+}
+
+/// Emit [`decade_histogram`] for the library at `path` instead of the full tree.
+pub fn emit_decade_histogram_by_path(
+    path: &Path,
+    json: bool,
+    compact: bool,
+    options: &HierarchyOptions,
+    source: YearSource,
+) -> Result<String, String> {
+    let tracks = match scan_dir_with_metadata(path) {
+        Ok(tracks) => tracks,
+        Err(e) => return Err(format!("Failed to scan directory: {}", e)),
+    };
+
+    let library = build_library_hierarchy_with_options(tracks, options);
+    emit_decade_histogram(&library, json, compact, source)
+}
+
+/// Emit [`decade_histogram`] for an already built library.
+pub fn emit_decade_histogram(
+    library: &Library,
+    json: bool,
+    compact: bool,
+    source: YearSource,
+) -> Result<String, String> {
+    let decades = decade_histogram(library, source);
+    let unknown = count_tracks_without_year(library, source);
+
+    if json {
+        let wrapper = with_schema_version(DecadeHistogramOutput { decades, unknown });
+        let serialized = if compact {
+            serde_json::to_string(&wrapper)
+        } else {
+            to_string_pretty(&wrapper)
+        };
+        serialized.map_err(|e| format!("Error serializing to JSON: {}", e))
+    } else {
+        Ok(format_decade_histogram(&decades, unknown))
+    }
+}
+
+/// Format tracks per genre as one section per genre listing its track paths.
+pub fn format_tracks_by_genre(by_genre: &BTreeMap<String, Vec<PathBuf>>) -> String {
+    let mut out = String::new();
+
+    out.push_str("=== GENRES ===\n");
+    for (genre, tracks) in by_genre {
+        out.push_str(&format!("{} ({} tracks)\n", genre, tracks.len()));
+        for track in tracks {
+            out.push_str(&format!("  {}\n", track.display()));
+        }
+    }
+    out.push_str("=== END GENRES ===\n");
+
+    out
+}
+
+/// JSON envelope for `emit --by-genre`.
+#[derive(serde::Serialize)]
+struct TracksByGenreOutput {
+    genres: BTreeMap<String, Vec<PathBuf>>,
+}
+
+/// Emit [`tracks_by_genre`] for the library at `path` instead of the full tree.
+pub fn emit_tracks_by_genre_by_path(
+    path: &Path,
+    json: bool,
+    compact: bool,
+    options: &HierarchyOptions,
+) -> Result<String, String> {
+    let tracks = match scan_dir_with_metadata(path) {
+        Ok(tracks) => tracks,
+        Err(e) => return Err(format!("Failed to scan directory: {}", e)),
+    };
+
+    let library = build_library_hierarchy_with_options(tracks, options);
+    let by_genre = tracks_by_genre(&library);
+
+    if json {
+        let wrapper = with_schema_version(TracksByGenreOutput { genres: by_genre });
+        let serialized = if compact {
+            serde_json::to_string(&wrapper)
+        } else {
+            to_string_pretty(&wrapper)
+        };
+        serialized.map_err(|e| format!("Error serializing to JSON: {}", e))
+    } else {
+        Ok(format_tracks_by_genre(&by_genre))
+    }
+}
+
+/// Format library stats as totals followed by one ranked section each for
+/// artists, albums and genres.
+pub fn format_library_stats(stats: &LibraryStats) -> String {
+    let mut out = String::new();
+    let total_seconds = stats.total_duration as u64;
+
+    out.push_str("=== LIBRARY STATS ===\n");
+    out.push_str(&format!(
+        "Tracks: {} | Artists: {} | Albums: {} | Duration: {}:{:02}:{:02}\n",
+        stats.total_tracks,
+        stats.total_artists,
+        stats.total_albums,
+        total_seconds / 3600,
+        (total_seconds % 3600) / 60,
+        total_seconds % 60
+    ));
+
+    let sections: [(&str, &[RankedEntry]); 3] = [
+        ("TOP ARTISTS", &stats.top_artists),
+        ("TOP ALBUMS", &stats.top_albums),
+        ("TOP GENRES", &stats.top_genres),
+    ];
+    for (heading, entries) in sections {
+        out.push_str(&format!("\n{}:\n", heading));
+        for (rank, entry) in entries.iter().enumerate() {
+            out.push_str(&format!(
+                "{:>3}. {} ({} tracks)\n",
+                rank + 1,
+                entry.name,
+                entry.track_count
+            ));
+        }
+    }
+    out.push_str("=== END LIBRARY STATS ===\n");
+
+    out
+}
+
+/// Emit [`compute_library_stats`] for the library at `path`.
+pub fn emit_library_stats_by_path(
+    path: &Path,
+    json: bool,
+    top: usize,
+    options: &HierarchyOptions,
+) -> Result<String, String> {
+    let tracks = match scan_dir_with_metadata(path) {
+        Ok(tracks) => tracks,
+        Err(e) => return Err(format!("Failed to scan directory: {}", e)),
+    };
+
+    let library = build_library_hierarchy_with_options(tracks, options);
+    emit_library_stats(&library, json, top)
+}
+
+/// Scan `path` and return its [`library_content_hash`].
+///
+/// Track paths are made relative to `path` first (to its folder for a single
+/// file), so the same library hashes the same wherever it is mounted.
+pub fn library_hash_by_path(path: &Path) -> Result<String, String> {
+    let tracks = match scan_dir_with_metadata(path) {
+        Ok(tracks) => tracks,
+        Err(e) => return Err(format!("Failed to scan directory: {}", e)),
+    };
+    let root = if path.is_file() {
+        path.parent().unwrap_or(path)
+    } else {
+        path
+    };
+
+    let library = build_library_hierarchy(rebase_tracks(tracks, root)?);
+    Ok(library_content_hash(&library))
+}
+
+/// Emit [`compute_library_stats`] for an already built library.
+pub fn emit_library_stats(library: &Library, json: bool, top: usize) -> Result<String, String> {
+    let stats = compute_library_stats(library, top);
+
+    if json {
+        to_string_pretty(&with_schema_version(&stats))
+            .map_err(|e| format!("Error serializing to JSON: {}", e))
+    } else {
+        Ok(format_library_stats(&stats))
+    }
+}
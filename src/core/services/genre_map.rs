@@ -0,0 +1,298 @@
+//! User-supplied genre canonicalization maps.
+//!
+//! A genre map lists `variant -> canonical` pairs that take precedence over the
+//! built-in aliases in [`normalize_genre`](super::normalization::normalize_genre).
+//! Two file formats are accepted:
+//!
+//! * **TSV** – one `variant<TAB>canonical` pair per line, optionally followed by
+//!   `<TAB>root` to set the genre's cluster; blank lines and lines starting with
+//!   `#` are ignored.
+//! * **JSON** (`.json` extension) – a single object, e.g.
+//!   `{"hip hop": "Hip-Hop", "synthwave": {"genre": "Synthwave", "root": "Electronic"}}`.
+//!
+//! Roots override the built-in hierarchy used by
+//! [`cluster_genre_with_map`](super::normalization::cluster_genre_with_map).
+//!
+//! Variants are matched case-insensitively. Problems that do not prevent the map
+//! from being used (duplicate variants, canonical values outside
+//! [`STANDARD_GENRES`]) are collected as issues rather than failing the load.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::path::Path;
+
+use serde::de::{Deserialize, Deserializer, MapAccess, Visitor};
+
+use crate::core::services::normalization::STANDARD_GENRES;
+
+/// A validated set of user genre mappings.
+#[derive(Debug, Clone, Default)]
+pub struct GenreMap {
+    mappings: HashMap<String, String>,
+    roots: HashMap<String, String>,
+    issues: Vec<String>,
+}
+
+impl GenreMap {
+    /// Loads a genre map from a TSV or JSON file, chosen by extension.
+    pub fn from_file(path: &Path) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read genre map '{}': {}", path.display(), e))?;
+
+        let is_json = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+
+        if is_json {
+            Self::from_json(&content)
+        } else {
+            Self::from_tsv(&content)
+        }
+        .map_err(|e| format!("Invalid genre map '{}': {}", path.display(), e))
+    }
+
+    /// Parses `variant<TAB>canonical[<TAB>root]` lines.
+    pub fn from_tsv(content: &str) -> Result<Self, String> {
+        let mut entries = Vec::new();
+        for (line_num, line) in content.lines().enumerate() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+            let mut columns = trimmed.split('\t');
+            let (Some(variant), Some(canonical), root, None) = (
+                columns.next(),
+                columns.next(),
+                columns.next(),
+                columns.next(),
+            ) else {
+                return Err(format!(
+                    "line {}: expected 'variant<TAB>canonical[<TAB>root]', got '{}'",
+                    line_num + 1,
+                    trimmed
+                ));
+            };
+            entries.push((
+                variant.to_string(),
+                canonical.to_string(),
+                root.map(str::to_string),
+            ));
+        }
+        Self::from_rooted_entries(entries)
+    }
+
+    /// Parses a JSON object of `variant: canonical` or
+    /// `variant: {"genre": canonical, "root": root}` pairs.
+    pub fn from_json(content: &str) -> Result<Self, String> {
+        let entries: MapEntries = serde_json::from_str(content).map_err(|e| e.to_string())?;
+        Self::from_rooted_entries(entries.0)
+    }
+
+    /// Like [`from_entries`](Self::from_entries), with an optional root genre
+    /// for each mapping.
+    fn from_rooted_entries(entries: Vec<(String, String, Option<String>)>) -> Result<Self, String> {
+        let mut roots = Vec::new();
+        let pairs = entries
+            .into_iter()
+            .map(|(variant, canonical, root)| {
+                if let Some(root) = root {
+                    roots.push((variant.clone(), canonical.clone(), root));
+                }
+                (variant, canonical)
+            })
+            .collect();
+
+        let mut map = Self::from_entries(pairs)?;
+        let mut reported_unknown = HashSet::new();
+        for (variant, canonical, root) in roots {
+            let root = root.trim();
+            if root.is_empty() {
+                continue;
+            }
+            if !is_standard_genre(root) && reported_unknown.insert(root.to_lowercase()) {
+                map.issues
+                    .push(format!("Root genre '{}' is not a standard genre", root));
+            }
+            for genre in [variant, canonical] {
+                map.roots
+                    .insert(genre.trim().to_lowercase(), root.to_string());
+            }
+        }
+        Ok(map)
+    }
+
+    /// Builds a map from raw pairs, recording duplicate and unknown mappings.
+    ///
+    /// When a variant appears more than once the last mapping wins.
+    pub fn from_entries(entries: Vec<(String, String)>) -> Result<Self, String> {
+        let mut map = GenreMap::default();
+        let mut reported_unknown = HashSet::new();
+
+        for (variant, canonical) in entries {
+            let variant = variant.trim();
+            let canonical = canonical.trim();
+            if variant.is_empty() || canonical.is_empty() {
+                return Err(format!(
+                    "empty variant or canonical genre in mapping '{}' -> '{}'",
+                    variant, canonical
+                ));
+            }
+
+            let key = variant.to_lowercase();
+            if let Some(previous) = map.mappings.get(&key) {
+                map.issues.push(if previous == canonical {
+                    format!("Duplicate mapping for '{}'", variant)
+                } else {
+                    format!(
+                        "Conflicting mapping for '{}': '{}' replaced by '{}'",
+                        variant, previous, canonical
+                    )
+                });
+            }
+
+            if !is_standard_genre(canonical) && reported_unknown.insert(canonical.to_lowercase()) {
+                map.issues.push(format!(
+                    "Canonical genre '{}' is not a standard genre",
+                    canonical
+                ));
+            }
+
+            map.mappings.insert(key, canonical.to_string());
+        }
+
+        Ok(map)
+    }
+
+    /// Returns the canonical genre for `variant`, if mapped.
+    pub fn get(&self, variant: &str) -> Option<&str> {
+        self.mappings
+            .get(&variant.trim().to_lowercase())
+            .map(String::as_str)
+    }
+
+    /// Returns the user-defined root (cluster) genre for `genre`, if any.
+    pub fn root(&self, genre: &str) -> Option<&str> {
+        self.roots
+            .get(&genre.trim().to_lowercase())
+            .map(String::as_str)
+    }
+
+    /// Non-fatal problems found while loading the map.
+    pub fn issues(&self) -> &[String] {
+        &self.issues
+    }
+
+    pub fn len(&self) -> usize {
+        self.mappings.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.mappings.is_empty()
+    }
+}
+
+fn is_standard_genre(genre: &str) -> bool {
+    STANDARD_GENRES
+        .iter()
+        .any(|g| g.eq_ignore_ascii_case(genre))
+}
+
+/// A JSON mapping value: a bare canonical genre or one with its root.
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum JsonGenre {
+    Canonical(String),
+    Rooted { genre: String, root: String },
+}
+
+/// JSON object entries in document order, keeping duplicate keys so they can
+/// be reported instead of silently collapsing.
+struct MapEntries(Vec<(String, String, Option<String>)>);
+
+impl<'de> Deserialize<'de> for MapEntries {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct EntriesVisitor;
+
+        impl<'de> Visitor<'de> for EntriesVisitor {
+            type Value = MapEntries;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an object mapping genre variants to canonical genres")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<MapEntries, A::Error> {
+                let mut entries = Vec::new();
+                while let Some((variant, value)) = access.next_entry::<String, JsonGenre>()? {
+                    entries.push(match value {
+                        JsonGenre::Canonical(genre) => (variant, genre, None),
+                        JsonGenre::Rooted { genre, root } => (variant, genre, Some(root)),
+                    });
+                }
+                Ok(MapEntries(entries))
+            }
+        }
+
+        deserializer.deserialize_map(EntriesVisitor)
+    }
+}
+
+// ── Tests ───────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_tsv_skips_comments_and_blank_lines() {
+        let map =
+            GenreMap::from_tsv("# my genres\n\nsynthwave\tElectronic\nHIP HOP\tHip-Hop\n").unwrap();
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get("Synthwave"), Some("Electronic"));
+        assert_eq!(map.get("hip hop"), Some("Hip-Hop"));
+        assert!(map.issues().is_empty());
+    }
+
+    #[test]
+    fn test_from_tsv_rejects_malformed_line() {
+        let err = GenreMap::from_tsv("synthwave Electronic\n").unwrap_err();
+        assert!(err.contains("line 1"));
+    }
+
+    #[test]
+    fn test_from_json_reports_duplicates_and_unknown() {
+        let map = GenreMap::from_json(
+            r#"{"synthwave": "Electronic", "Synthwave": "Retrowave", "lofi": "Retrowave"}"#,
+        )
+        .unwrap();
+        assert_eq!(map.get("synthwave"), Some("Retrowave"));
+        assert_eq!(map.issues().len(), 2);
+        assert!(map.issues()[0].contains("Conflicting mapping for 'Synthwave'"));
+        assert!(map.issues()[1].contains("'Retrowave' is not a standard genre"));
+    }
+
+    #[test]
+    fn test_roots_from_tsv_and_json() {
+        let map = GenreMap::from_tsv("shoegaze\tShoegaze\tRock\nlofi\tElectronic\n").unwrap();
+        assert_eq!(map.root("Shoegaze"), Some("Rock"));
+        assert_eq!(map.root("lofi"), None);
+        assert!(map.issues()[0].contains("'Shoegaze' is not a standard genre"));
+
+        let map = GenreMap::from_json(
+            r#"{"synthwave": {"genre": "Synthwave", "root": "Electronic"}, "hip hop": "Hip-Hop"}"#,
+        )
+        .unwrap();
+        assert_eq!(map.get("synthwave"), Some("Synthwave"));
+        assert_eq!(map.root("Synthwave"), Some("Electronic"));
+        assert_eq!(map.root("hip hop"), None);
+    }
+
+    #[test]
+    fn test_from_tsv_rejects_extra_columns() {
+        assert!(GenreMap::from_tsv("a\tb\tc\td\n").is_err());
+    }
+
+    #[test]
+    fn test_from_json_rejects_non_object() {
+        assert!(GenreMap::from_json(r#"["rock"]"#).is_err());
+    }
+}
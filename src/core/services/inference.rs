@@ -201,33 +201,168 @@ fn clean_album_name(name: &str) -> String {
     cleaned.to_string()
 }
 
+/// Words that mark the part after " - " as an edition of the album rather than
+/// the album itself, e.g. "The Wall - Live" or "Abbey Road - 2019 Remaster".
+const ALBUM_TITLE_QUALIFIERS: &[&str] = &[
+    "live",
+    "remaster",
+    "remastered",
+    "deluxe",
+    "expanded",
+    "anniversary",
+    "bonus",
+    "acoustic",
+    "unplugged",
+    "demo",
+    "demos",
+    "instrumental",
+    "instrumentals",
+    "mono",
+    "stereo",
+    "disc",
+    "cd",
+    "vol",
+    "volume",
+    "part",
+    "pt",
+];
+
+/// Check if the text after an album title's " - " is an edition qualifier
+fn is_album_title_qualifier(text: &str) -> bool {
+    let lower = text.trim().to_lowercase();
+    let first_word = lower
+        .split(|c: char| !c.is_alphanumeric())
+        .next()
+        .unwrap_or("")
+        .trim_end_matches(|c: char| c.is_ascii_digit());
+
+    ALBUM_TITLE_QUALIFIERS.contains(&first_word)
+        || lower.contains("edition")
+        || lower.contains("remaster")
+}
+
+/// Split a track path into folder and file names, counted from the library
+/// root rather than the filesystem root.
+///
+/// Both `/` and `\` separate names, so Windows paths are handled the same
+/// on every platform. The root, drive letters (`C:`), UNC shares
+/// (`\\server\share`) and verbatim prefixes (`\\?\C:`, `\\?\UNC\server\share`)
+/// are dropped so they never count as an Artist or Album folder.
+fn path_segments(track_path: &Path) -> Vec<String> {
+    let text = track_path.to_string_lossy();
+    let is_separator = |c: char| c == '/' || c == '\\';
+    let is_unc = text.starts_with("\\\\") || (cfg!(windows) && text.starts_with("//"));
+    let mut segments = text.split(is_separator).filter(|s| !s.is_empty());
+
+    if is_unc {
+        let mut lookahead = segments.clone();
+        match lookahead.next() {
+            Some("?" | ".") => {
+                segments.next();
+                if lookahead
+                    .next()
+                    .is_some_and(|s| s.eq_ignore_ascii_case("UNC"))
+                {
+                    // \\?\UNC\server\share
+                    segments.nth(2);
+                } else {
+                    // \\?\C:
+                    segments.next();
+                }
+            }
+            // \\server\share
+            _ => {
+                segments.nth(1);
+            }
+        }
+    }
+
+    let mut segments: Vec<String> = segments.map(str::to_string).collect();
+    if segments.first().is_some_and(|s| is_drive(s)) {
+        segments.remove(0);
+    }
+    segments
+}
+
+/// Whether `segment` is a drive designator such as `C:`.
+fn is_drive(segment: &str) -> bool {
+    let bytes = segment.as_bytes();
+    bytes.len() == 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':'
+}
+
+/// A file name without its extension.
+fn file_stem(file_name: &str) -> &str {
+    match file_name.rsplit_once('.') {
+        Some((stem, _)) if !stem.is_empty() => stem,
+        _ => file_name,
+    }
+}
+
+/// Whether a folder name marks one disc of a multi-disc album, such as
+/// "CD1", "CD 2", "Disc 02" or "Disk 1 - Bonus".
+pub fn is_disc_folder(name: &str) -> bool {
+    let lower = name.trim().to_ascii_lowercase();
+    let Some(rest) = ["cd", "disc", "disk"]
+        .iter()
+        .find_map(|prefix| lower.strip_prefix(prefix))
+    else {
+        return false;
+    };
+    let rest = rest.trim_start_matches([' ', '_', '-', '.']);
+    let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+    digits > 0
+        && rest[digits..]
+            .chars()
+            .next()
+            .is_none_or(|c| !c.is_alphanumeric())
+}
+
+/// Decide whether a parent folder named "X - Y" is an "Artist - Album" pair.
+///
+/// A grandparent folder matching X confirms the split ("Pink Floyd/Pink Floyd -
+/// The Wall"). Otherwise the split is rejected when Y is an edition qualifier,
+/// so "Pink Floyd/The Wall - Live" keeps its full album title and the artist
+/// comes from the deeper Artist/Album structure instead.
+fn is_artist_album_folder(segments: &[String], folder_name: &str) -> bool {
+    let separators = [" - ", " – ", " — "];
+    let Some((left, right)) = separators
+        .iter()
+        .find_map(|sep| folder_name.split_once(sep))
+    else {
+        return false;
+    };
+
+    let grandparent = segments.len().checked_sub(3).map(|i| segments[i].as_str());
+    if grandparent.is_some_and(|g| clean_artist_name(left).eq_ignore_ascii_case(g.trim())) {
+        return true;
+    }
+
+    !is_album_title_qualifier(right)
+}
+
 /// Infer artist name from track file path
 pub fn infer_artist_from_path(track_path: &Path) -> Option<String> {
+    let components = path_segments(track_path);
+    let (file_name, folders) = components.split_last()?;
+
     // Strategy 1: Try to extract artist from parent directory name (pattern: "Artist - Album")
-    if let Some(parent) = track_path.parent()
-        && let Some(folder_name) = parent.file_name().and_then(|n| n.to_str())
+    if let Some(folder_name) = folders.last()
+        && is_artist_album_folder(&components, folder_name)
         && let Some(artist) = extract_artist_from_name(folder_name)
     {
         return Some(artist);
     }
 
     // Strategy 2: Try to extract artist from filename (pattern: "Artist - Title.ext")
-    if let Some(filename) = track_path.file_stem().and_then(|n| n.to_str())
-        && let Some(artist) = extract_artist_from_name(filename)
-    {
+    if let Some(artist) = extract_artist_from_name(file_stem(file_name)) {
         return Some(artist);
     }
 
     // Strategy 3: Handle common organized structures like Artist/Albums/Album/track
-    let components: Vec<&str> = track_path
-        .components()
-        .filter_map(|c| c.as_os_str().to_str())
-        .collect();
-
     // Check for Artist/Albums/Album/track or Artist/Singles & EPs/Album/track structure
     if components.len() >= 4 {
-        let grandparent = components[components.len() - 3];
-        let potential_artist = components[components.len() - 4];
+        let grandparent = &components[components.len() - 3];
+        let potential_artist = &components[components.len() - 4];
 
         // If grandparent is a collection folder, use its parent as artist
         if (grandparent == "Albums" || grandparent == "Singles & EPs" || grandparent == "Singles")
@@ -240,8 +375,8 @@ pub fn infer_artist_from_path(track_path: &Path) -> Option<String> {
 
     // Strategy 4: Legacy fallback - strict Artist/Album/track structure
     if components.len() >= 3 {
-        let album_name = components[components.len() - 2];
-        let potential_artist = components[components.len() - 3];
+        let album_name = &components[components.len() - 2];
+        let potential_artist = &components[components.len() - 3];
 
         if !potential_artist.is_empty() && !album_name.is_empty() && potential_artist != album_name
         {
@@ -256,12 +391,15 @@ pub fn infer_artist_from_path(track_path: &Path) -> Option<String> {
 
 /// Infer album name from track file path
 pub fn infer_album_from_path(track_path: &Path) -> Option<String> {
+    let components = path_segments(track_path);
+    let (file_name, folders) = components.split_last()?;
+
     // Strategy 1: Extract album from parent directory name
-    if let Some(parent) = track_path.parent()
-        && let Some(folder_name) = parent.file_name().and_then(|n| n.to_str())
-    {
+    if let Some(folder_name) = folders.last() {
         // Check for "Artist - Album" pattern
-        if let Some(album) = extract_album_from_name(folder_name) {
+        if is_artist_album_folder(&components, folder_name)
+            && let Some(album) = extract_album_from_name(folder_name)
+        {
             return Some(album);
         }
 
@@ -273,20 +411,46 @@ pub fn infer_album_from_path(track_path: &Path) -> Option<String> {
     }
 
     // Strategy 2: Try to extract album from filename (pattern: "Artist - Album")
-    if let Some(filename) = track_path.file_stem().and_then(|n| n.to_str()) {
-        if let Some(album) = extract_album_from_name(filename) {
-            return Some(album);
-        }
+    if let Some(album) = extract_album_from_name(file_stem(file_name)) {
+        return Some(album);
     }
 
     None
 }
 
+/// Infer the album from the album folder alone, for `scan --album-from folder`.
+///
+/// A disc folder such as "CD1" or "Disc 2" is skipped for the folder above
+/// it. Unlike [`infer_album_from_path`] the filename is never used, so `None`
+/// means the path names no album folder.
+pub fn infer_album_from_folder(track_path: &Path) -> Option<String> {
+    let components = path_segments(track_path);
+    let mut index = components.len().checked_sub(2)?;
+    if is_disc_folder(&components[index]) {
+        index = index.checked_sub(1)?;
+    }
+    let folder_name = components[index].as_str();
+    if matches!(folder_name, "." | "..") {
+        return None;
+    }
+
+    // The slice ends one segment below the album folder, as the Artist -
+    // Album check expects.
+    if is_artist_album_folder(&components[..index + 2], folder_name)
+        && let Some(album) = extract_album_from_name(folder_name)
+    {
+        return Some(album);
+    }
+    Some(clean_album_name(folder_name)).filter(|album| !album.is_empty())
+}
+
 /// Infer year from track file path
 pub fn infer_year_from_path(track_path: &Path) -> Option<u32> {
+    let components = path_segments(track_path);
+    let (file_name, folders) = components.split_last()?;
+
     // Strategy 1: Look for year in parent directory name
-    if let Some(parent) = track_path.parent()
-        && let Some(folder_name) = parent.file_name().and_then(|n| n.to_str())
+    if let Some(folder_name) = folders.last()
         && let Some(year) = extract_year_from_name(folder_name)
     {
         // Look for year patterns: "2008 - Album", "Album (2009)", "Artist 2024 - Album"
@@ -294,13 +458,20 @@ pub fn infer_year_from_path(track_path: &Path) -> Option<u32> {
     }
 
     // Strategy 2: Look for year in filename
-    if let Some(filename) = track_path.file_name().and_then(|n| n.to_str())
-        && let Some(year) = extract_year_from_name(filename)
-    {
-        return Some(year);
-    }
+    extract_year_from_name(file_name)
+}
 
-    None
+/// Infer the track number from a numeric-only filename such as "01.flac".
+///
+/// Such a filename carries no title, so callers should leave the title empty
+/// rather than use the number. Four-digit names are left to year inference.
+pub fn infer_track_number_from_path(track_path: &Path) -> Option<u32> {
+    let components = path_segments(track_path);
+    let stem = file_stem(components.last()?).trim();
+    if stem.is_empty() || stem.len() > 3 || !stem.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    stem.parse().ok().filter(|&n| n > 0)
 }
 
 /// Extract 4-digit year from a string
@@ -379,6 +550,48 @@ mod tests {
         assert_eq!(clean_artist_name("Artist Name [FLAC] 2023"), "Artist Name");
     }
 
+    #[test]
+    fn test_is_disc_folder() {
+        assert!(is_disc_folder("CD1"));
+        assert!(is_disc_folder("cd 2"));
+        assert!(is_disc_folder("Disc 02"));
+        assert!(is_disc_folder("Disk_1 - Bonus"));
+        assert!(!is_disc_folder("CD"));
+        assert!(!is_disc_folder("Discovery"));
+        assert!(!is_disc_folder("CD1A Sessions"));
+        assert!(!is_disc_folder("Abbey Road"));
+    }
+
+    #[test]
+    fn test_infer_album_from_folder_skips_disc_folders() {
+        assert_eq!(
+            infer_album_from_folder(Path::new("Music/Album/CD1/01.flac")),
+            Some("Album".to_string())
+        );
+        assert_eq!(
+            infer_album_from_folder(Path::new("Music/Band - Album/Disc 2/01.flac")),
+            Some("Album".to_string())
+        );
+        assert_eq!(
+            infer_album_from_folder(Path::new("Music/Album/01.flac")),
+            Some("Album".to_string())
+        );
+        assert_eq!(infer_album_from_folder(Path::new("CD1/01.flac")), None);
+    }
+
+    #[test]
+    fn test_infer_album_from_folder_ignores_filename() {
+        assert_eq!(
+            infer_album_from_folder(Path::new("Artist - Song.flac")),
+            None
+        );
+        assert_eq!(infer_album_from_folder(Path::new("./x.flac")), None);
+        assert_eq!(
+            infer_album_from_folder(Path::new("Flat/Artist - Song.flac")),
+            Some("Flat".to_string())
+        );
+    }
+
     #[test]
     fn test_extract_artist_from_name() {
         // Standard pattern
@@ -541,6 +754,58 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_infer_from_artist_album_single_folder() {
+        let path = PathBuf::from("Pink Floyd - The Wall/01 track.flac");
+        assert_eq!(
+            infer_artist_from_path(&path),
+            Some("Pink Floyd".to_string())
+        );
+        assert_eq!(infer_album_from_path(&path), Some("The Wall".to_string()));
+
+        let path = PathBuf::from("/music/Pink Floyd - The Wall/01 track.flac");
+        assert_eq!(
+            infer_artist_from_path(&path),
+            Some("Pink Floyd".to_string())
+        );
+        assert_eq!(infer_album_from_path(&path), Some("The Wall".to_string()));
+
+        // Grandparent confirms the artist even when the album looks like a qualifier
+        let path = PathBuf::from("/music/Nirvana/Nirvana - Live at Reading/01 track.flac");
+        assert_eq!(infer_artist_from_path(&path), Some("Nirvana".to_string()));
+        assert_eq!(
+            infer_album_from_path(&path),
+            Some("Live at Reading".to_string())
+        );
+    }
+
+    #[test]
+    fn test_album_title_with_separator_is_not_split() {
+        let path = PathBuf::from("/music/Pink Floyd/The Wall - Live/01 track.flac");
+        assert_eq!(
+            infer_artist_from_path(&path),
+            Some("Pink Floyd".to_string())
+        );
+        assert_eq!(
+            infer_album_from_path(&path),
+            Some("The Wall - Live".to_string())
+        );
+
+        let path = PathBuf::from("/music/The Beatles/Abbey Road - 2019 Remaster/01 track.flac");
+        assert_eq!(
+            infer_artist_from_path(&path),
+            Some("The Beatles".to_string())
+        );
+        assert_eq!(
+            infer_album_from_path(&path),
+            Some("Abbey Road - 2019 Remaster".to_string())
+        );
+
+        assert!(is_album_title_qualifier("Deluxe Edition"));
+        assert!(is_album_title_qualifier("CD2"));
+        assert!(!is_album_title_qualifier("The Wall"));
+    }
+
     #[test]
     fn test_infer_album_from_path() {
         // Valid Artist/Album/track.flac structure
@@ -562,4 +827,50 @@ mod tests {
             Some("The Dark Side of the Moon".to_string())
         );
     }
+
+    #[test]
+    fn test_infer_track_number_from_numeric_filename() {
+        let number = |p: &str| infer_track_number_from_path(&PathBuf::from(p));
+
+        assert_eq!(number("Artist/Album/01.flac"), Some(1));
+        assert_eq!(number("Artist/Album/12.flac"), Some(12));
+        assert_eq!(number("Artist/Album/101.mp3"), Some(101));
+        assert_eq!(number("Artist/Album/01 - Song.flac"), None);
+        assert_eq!(number("Artist/Album/1999.flac"), None);
+        assert_eq!(number("Artist/Album/00.flac"), None);
+    }
+
+    #[test]
+    fn test_infer_from_windows_paths() {
+        let paths = [
+            r"\\server\share\The Beatles\Abbey Road\01 - Come Together.flac",
+            r"\\?\UNC\server\share\The Beatles\Abbey Road\01 - Come Together.flac",
+            r"\\?\D:\The Beatles\Abbey Road\01 - Come Together.flac",
+            r"C:\The Beatles\Abbey Road\01 - Come Together.flac",
+            r"C:/The Beatles/Abbey Road/01 - Come Together.flac",
+        ];
+        for path in paths {
+            let path = PathBuf::from(path);
+            assert_eq!(
+                infer_artist_from_path(&path),
+                Some("The Beatles".to_string()),
+                "{}",
+                path.display()
+            );
+            assert_eq!(infer_album_from_path(&path), Some("Abbey Road".to_string()));
+        }
+
+        // The share and drive are not mistaken for an artist folder
+        let path = PathBuf::from(r"\\server\share\Abbey Road\01 - Song.flac");
+        assert_eq!(infer_artist_from_path(&path), None);
+        let path = PathBuf::from(r"C:\Abbey Road\01 - Song.flac");
+        assert_eq!(infer_artist_from_path(&path), None);
+        let path = PathBuf::from("/Abbey Road/01 - Song.flac");
+        assert_eq!(infer_artist_from_path(&path), None);
+
+        let path = PathBuf::from(r"\\nas\music\Artist\Albums\2008 - Album\07.flac");
+        assert_eq!(infer_artist_from_path(&path), Some("Artist".to_string()));
+        assert_eq!(infer_year_from_path(&path), Some(2008));
+        assert_eq!(infer_track_number_from_path(&path), Some(7));
+    }
 }
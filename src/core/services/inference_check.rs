@@ -0,0 +1,270 @@
+//! Folder inference reliability checks.
+//!
+//! Compares the artist embedded in each track with the artist inferred from
+//! its path, showing how far folder inference can be trusted for a library
+//! before relying on it for untagged files.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde_json::to_string_pretty;
+
+use crate::core::domain::models::{MetadataSource, Track};
+use crate::core::domain::with_schema_version;
+use crate::core::services::inference::infer_artist_from_path;
+use crate::core::services::scanner::scan_dir_with_metadata;
+
+/// Default number of folders listed in [`ArtistAgreementReport::worst_folders`].
+pub const DEFAULT_WORST_FOLDERS: usize = 10;
+
+/// Agreement between embedded and folder-inferred artists in one folder.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct FolderAgreement {
+    pub folder: String,
+    /// Tracks with both an embedded and a folder-inferred artist.
+    pub compared: usize,
+    pub agreeing: usize,
+    /// Share of compared tracks that agree, in percent.
+    pub percentage: f64,
+    /// Folder-inferred artist of the first disagreeing track.
+    pub inferred_artist: Option<String>,
+    /// Embedded artist of the first disagreeing track.
+    pub embedded_artist: Option<String>,
+}
+
+/// Library-wide agreement between embedded and folder-inferred artists.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct ArtistAgreementReport {
+    /// Tracks with both an embedded and a folder-inferred artist.
+    pub compared: usize,
+    pub agreeing: usize,
+    /// Share of compared tracks that agree, in percent (0 when none compared).
+    pub percentage: f64,
+    /// Folders with at least one disagreement, lowest agreement first.
+    pub worst_folders: Vec<FolderAgreement>,
+}
+
+/// Case- and whitespace-insensitive form of an artist name for comparison.
+fn comparable(name: &str) -> String {
+    name.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+fn percentage(part: usize, total: usize) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        part as f64 * 100.0 / total as f64
+    }
+}
+
+/// Compare each track's embedded artist with the artist inferred from its
+/// path and report how often they agree.
+///
+/// Only tracks whose artist comes from an embedded tag and whose path yields
+/// an inferred artist are compared. The inferred artist agrees when it
+/// matches the embedded artist or album artist, ignoring case and spacing.
+/// At most `worst` folders are listed, lowest agreement first.
+pub fn artist_agreement(tracks: &[Track], worst: usize) -> ArtistAgreementReport {
+    let mut folders: BTreeMap<String, FolderAgreement> = BTreeMap::new();
+
+    for track in tracks {
+        let Some(artist) = track
+            .metadata
+            .artist
+            .as_ref()
+            .filter(|a| a.source == MetadataSource::Embedded)
+        else {
+            continue;
+        };
+        let Some(inferred) = infer_artist_from_path(&track.file_path) else {
+            continue;
+        };
+
+        let inferred_key = comparable(&inferred);
+        let agrees = inferred_key == comparable(&artist.value)
+            || track
+                .metadata
+                .album_artist
+                .as_ref()
+                .is_some_and(|a| comparable(&a.value) == inferred_key);
+
+        let folder = track
+            .file_path
+            .parent()
+            .unwrap_or(Path::new(""))
+            .to_string_lossy()
+            .into_owned();
+        let entry = folders
+            .entry(folder.clone())
+            .or_insert_with(|| FolderAgreement {
+                folder,
+                compared: 0,
+                agreeing: 0,
+                percentage: 0.0,
+                inferred_artist: None,
+                embedded_artist: None,
+            });
+        entry.compared += 1;
+        if agrees {
+            entry.agreeing += 1;
+        } else if entry.inferred_artist.is_none() {
+            entry.inferred_artist = Some(inferred);
+            entry.embedded_artist = Some(artist.value.clone());
+        }
+    }
+
+    let compared = folders.values().map(|f| f.compared).sum();
+    let agreeing = folders.values().map(|f| f.agreeing).sum();
+
+    let mut worst_folders: Vec<FolderAgreement> = folders
+        .into_values()
+        .filter(|f| f.agreeing < f.compared)
+        .map(|mut f| {
+            f.percentage = percentage(f.agreeing, f.compared);
+            f
+        })
+        .collect();
+    worst_folders.sort_by(|a, b| {
+        a.percentage
+            .total_cmp(&b.percentage)
+            .then_with(|| b.compared.cmp(&a.compared))
+            .then_with(|| a.folder.cmp(&b.folder))
+    });
+    worst_folders.truncate(worst);
+
+    ArtistAgreementReport {
+        compared,
+        agreeing,
+        percentage: percentage(agreeing, compared),
+        worst_folders,
+    }
+}
+
+/// Format an [`ArtistAgreementReport`] as text.
+pub fn format_artist_agreement(report: &ArtistAgreementReport) -> String {
+    let mut out = String::new();
+
+    out.push_str("=== FOLDER INFERENCE CHECK ===\n");
+    if report.compared == 0 {
+        out.push_str("No tracks have both an embedded and a folder-inferred artist.\n");
+    } else {
+        out.push_str(&format!(
+            "Artist agreement: {:.1}% ({} of {} tracks)\n",
+            report.percentage, report.agreeing, report.compared
+        ));
+    }
+
+    if !report.worst_folders.is_empty() {
+        out.push_str("\nWorst folders:\n");
+        for folder in &report.worst_folders {
+            out.push_str(&format!(
+                "  {:.1}% ({}/{}) {}\n",
+                folder.percentage, folder.agreeing, folder.compared, folder.folder
+            ));
+            if let (Some(inferred), Some(embedded)) =
+                (&folder.inferred_artist, &folder.embedded_artist)
+            {
+                out.push_str(&format!(
+                    "    folder says '{}', tags say '{}'\n",
+                    inferred, embedded
+                ));
+            }
+        }
+    }
+    out.push_str("=== END FOLDER INFERENCE CHECK ===\n");
+
+    out
+}
+
+/// Scan `path` and report its [`artist_agreement`].
+pub fn artist_agreement_by_path(path: &Path, json: bool, worst: usize) -> Result<String, String> {
+    let tracks =
+        scan_dir_with_metadata(path).map_err(|e| format!("Failed to scan directory: {}", e))?;
+    let report = artist_agreement(&tracks, worst);
+
+    if json {
+        to_string_pretty(&with_schema_version(&report))
+            .map_err(|e| format!("Error serializing to JSON: {}", e))
+    } else {
+        Ok(format_artist_agreement(&report))
+    }
+}
+
+// ── Tests ───────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::builders::TrackMetadataBuilder;
+    use std::path::PathBuf;
+
+    fn track(path: &str, artist: &str) -> Track {
+        let metadata = TrackMetadataBuilder::new(path)
+            .artist(artist, MetadataSource::Embedded, 1.0)
+            .format("flac")
+            .build();
+        Track::new(PathBuf::from(path), metadata)
+    }
+
+    #[test]
+    fn test_artist_agreement_reports_percentage_and_worst_folders() {
+        let tracks = vec![
+            track("/music/Band/Record/01 Intro.flac", "Band"),
+            track("/music/Band/Record/02 Outro.flac", "the  band"),
+            track("/music/Band/Other/01 Song.flac", "Band"),
+            track("/music/Downloads/Misc/01 Song.flac", "Singer"),
+            track("/music/Downloads/Misc/02 Song.flac", "Downloads"),
+        ];
+
+        let report = artist_agreement(&tracks, 10);
+        assert_eq!(report.compared, 5);
+        assert_eq!(report.agreeing, 3);
+        assert_eq!(report.percentage, 60.0);
+
+        let folders: Vec<_> = report
+            .worst_folders
+            .iter()
+            .map(|f| (f.folder.as_str(), f.agreeing, f.compared))
+            .collect();
+        assert_eq!(
+            folders,
+            vec![
+                ("/music/Band/Record", 1, 2),
+                ("/music/Downloads/Misc", 1, 2),
+            ]
+        );
+        assert_eq!(
+            report.worst_folders[1].embedded_artist.as_deref(),
+            Some("Singer")
+        );
+        assert_eq!(
+            report.worst_folders[1].inferred_artist.as_deref(),
+            Some("Downloads")
+        );
+
+        assert_eq!(artist_agreement(&tracks, 1).worst_folders.len(), 1);
+    }
+
+    #[test]
+    fn test_artist_agreement_accepts_album_artist_and_skips_inferred() {
+        let compilation = TrackMetadataBuilder::new("/music/Various/Hits/01.flac")
+            .artist("Singer", MetadataSource::Embedded, 1.0)
+            .album_artist("Various", MetadataSource::Embedded, 1.0)
+            .build();
+        let untagged = TrackMetadataBuilder::new("/music/Band/Record/01.flac")
+            .artist("Band", MetadataSource::FolderInferred, 0.3)
+            .build();
+        let tracks = vec![
+            Track::new(PathBuf::from("/music/Various/Hits/01.flac"), compilation),
+            Track::new(PathBuf::from("/music/Band/Record/01.flac"), untagged),
+        ];
+
+        let report = artist_agreement(&tracks, 10);
+        assert_eq!(report.compared, 1);
+        assert_eq!(report.agreeing, 1);
+        assert!(report.worst_folders.is_empty());
+    }
+}
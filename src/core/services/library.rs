@@ -1,22 +1,168 @@
 //! Library hierarchy building from track collections.
 
-use crate::core::domain::models::{AlbumNode, ArtistNode, Library, Track, TrackNode};
+use crate::core::domain::models::{
+    AlbumNode, ArtistNode, Library, Track, TrackMetadata, TrackNode, YearNode,
+};
+use crate::core::services::inference::is_disc_folder;
 use std::collections::HashMap;
-use std::collections::HashSet;
-use std::path::PathBuf;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::path::{Component, Path, PathBuf};
+
+/// Artist name used for albums grouped as compilations.
+pub const VARIOUS_ARTISTS: &str = "Various Artists";
+
+/// Synthetic top-level node that soundtrack albums are grouped under.
+pub const SOUNDTRACKS: &str = "Soundtracks";
+
+/// Lowercase genre/album phrases that mark a soundtrack.
+const SOUNDTRACK_KEYWORDS: &[&str] = &["soundtrack", "original motion picture", "original score"];
+
+/// Default number of distinct track artists that makes an album a compilation.
+pub const DEFAULT_VA_THRESHOLD: usize = 3;
+
+/// Options controlling how tracks are grouped into the library hierarchy.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HierarchyOptions {
+    /// Distinct track artists within one album at which the album is grouped
+    /// under "Various Artists". `0` disables the artist-count rule.
+    pub va_threshold: usize,
+    /// Album artist values (case-insensitive) that always mark a compilation.
+    pub va_album_artists: Vec<String>,
+    /// Move albums detected by [`is_soundtrack`] under a synthetic
+    /// "Soundtracks" artist node.
+    pub group_soundtracks: bool,
+}
+
+impl Default for HierarchyOptions {
+    fn default() -> Self {
+        Self {
+            va_threshold: DEFAULT_VA_THRESHOLD,
+            va_album_artists: vec![
+                VARIOUS_ARTISTS.to_string(),
+                "VA".to_string(),
+                "Various".to_string(),
+                "Soundtrack".to_string(),
+            ],
+            group_soundtracks: false,
+        }
+    }
+}
+
+impl HierarchyOptions {
+    /// Returns true if the album artist is one of the forced compilation names.
+    fn is_va_album_artist(&self, album_artist: &str) -> bool {
+        let album_artist = album_artist.trim();
+        self.va_album_artists
+            .iter()
+            .any(|name| name.trim().eq_ignore_ascii_case(album_artist))
+    }
+
+    /// Returns true if the tracks of one album should be grouped as a compilation.
+    ///
+    /// A set compilation flag (iTunes `cpil`, Vorbis COMPILATION) or a forced
+    /// album artist always wins; a flag explicitly cleared on every flagged
+    /// track rules the album out before the artist-count heuristic runs.
+    fn is_compilation(&self, album_tracks: &[&Track]) -> bool {
+        let flags: Vec<bool> = album_tracks
+            .iter()
+            .filter_map(|t| t.metadata.compilation.as_ref().map(|c| c.value))
+            .collect();
+        if flags.contains(&true) {
+            return true;
+        }
+
+        let forced = album_tracks.iter().any(|t| {
+            t.metadata
+                .album_artist
+                .as_ref()
+                .is_some_and(|aa| self.is_va_album_artist(&aa.value))
+        });
+        if forced {
+            return true;
+        }
+
+        if !flags.is_empty() || self.va_threshold == 0 {
+            return false;
+        }
+
+        let distinct_artists: HashSet<&str> = album_tracks
+            .iter()
+            .filter_map(|t| t.metadata.artist.as_ref().map(|a| a.value.as_str()))
+            .collect();
+        distinct_artists.len() >= self.va_threshold
+    }
+}
+
+/// Longest leading run of path components shared by all `paths`; empty
+/// when there are no paths or they have nothing in common.
+pub fn common_ancestor<'a, I>(paths: I) -> PathBuf
+where
+    I: IntoIterator<Item = &'a Path>,
+{
+    let mut paths = paths.into_iter();
+    let Some(first) = paths.next() else {
+        return PathBuf::new();
+    };
+
+    let mut common: Vec<Component> = first.components().collect();
+    for path in paths {
+        let shared = common
+            .iter()
+            .zip(path.components())
+            .take_while(|(a, b)| **a == *b)
+            .count();
+        common.truncate(shared);
+    }
+    common.iter().collect()
+}
+
+/// Directory of an album: the [`common_ancestor`] of its tracks' folders,
+/// falling back to the first track's folder when they share none.
+fn album_path(tracks: &[Track]) -> PathBuf {
+    let common = common_ancestor(tracks.iter().filter_map(|t| t.file_path.parent()));
+    if !common.as_os_str().is_empty() {
+        return common;
+    }
+    tracks
+        .first()
+        .and_then(|t| t.file_path.parent())
+        .map(Path::to_path_buf)
+        .unwrap_or_default()
+}
 
 /// Build library hierarchy from flat track list
 pub fn build_library_hierarchy(tracks: Vec<Track>) -> Library {
+    build_library_hierarchy_with_options(tracks, &HierarchyOptions::default())
+}
+
+/// Build library hierarchy from flat track list using the given grouping options.
+///
+/// Albums (same album title in the same directory) are grouped under
+/// "Various Artists" when their album artist is one of
+/// `options.va_album_artists` or they contain at least `options.va_threshold`
+/// distinct track artists. Tracks without an album tag are never grouped.
+/// Compilations stay apart per directory, so two "Greatest Hits" folders are
+/// two albums under "Various Artists". With `options.group_soundtracks`, soundtrack albums are then moved under
+/// "Soundtracks".
+pub fn build_library_hierarchy_with_options(
+    tracks: Vec<Track>,
+    options: &HierarchyOptions,
+) -> Library {
+    let compilations = find_compilations(&tracks, options);
     let mut artists_map: HashMap<String, Vec<Track>> = HashMap::new();
 
     // Group tracks by artist
     for track in tracks {
-        let artist_name = track
-            .metadata
-            .artist
-            .as_ref()
-            .map(|a| a.value.clone())
-            .unwrap_or_else(|| "Unknown Artist".to_string());
+        let artist_name = if album_key(&track).is_some_and(|key| compilations.contains(&key)) {
+            VARIOUS_ARTISTS.to_string()
+        } else {
+            track
+                .metadata
+                .artist
+                .as_ref()
+                .map(|a| a.value.clone())
+                .unwrap_or_else(|| "Unknown Artist".to_string())
+        };
 
         artists_map.entry(artist_name).or_default().push(track);
     }
@@ -25,33 +171,38 @@ pub fn build_library_hierarchy(tracks: Vec<Track>) -> Library {
 
     // Build artist -> album -> track hierarchy
     for (artist_name, artist_tracks) in artists_map {
-        let mut albums_map: HashMap<String, Vec<Track>> = HashMap::new();
+        let mut albums_map: HashMap<(String, Option<PathBuf>), Vec<Track>> = HashMap::new();
 
-        // Group tracks by album
+        // Group tracks by album; compilations by their album key, as detected
         for track in artist_tracks {
-            let album_name = track
-                .metadata
-                .album
-                .as_ref()
-                .map(|a| a.value.clone())
-                .unwrap_or_else(|| "Unknown Album".to_string());
+            let key = match album_key(&track) {
+                Some(key) if compilations.contains(&key) => (key.0, Some(key.1)),
+                _ => (
+                    track
+                        .metadata
+                        .album
+                        .as_ref()
+                        .map(|a| a.value.clone())
+                        .unwrap_or_else(|| "Unknown Album".to_string()),
+                    None,
+                ),
+            };
 
-            albums_map.entry(album_name).or_default().push(track);
+            albums_map.entry(key).or_default().push(track);
         }
 
         let mut albums = Vec::new();
-        for (album_name, album_tracks) in albums_map {
+        for ((album_name, _), album_tracks) in albums_map {
             // Extract year from first track (assuming all tracks in album have same year)
             let year = album_tracks
                 .first()
                 .and_then(|t| t.metadata.year.as_ref())
                 .map(|y| y.value);
 
-            // Capture album path before moving tracks
-            let album_path = album_tracks
-                .first()
-                .map(|t| t.file_path.parent().unwrap().to_path_buf())
-                .unwrap_or_else(|| PathBuf::from(""));
+            // Capture album path before moving tracks: the deepest folder
+            // holding every track, so multi-folder albums (CD1/, CD2/) get
+            // their shared parent
+            let album_path = album_path(&album_tracks);
 
             let mut track_nodes = Vec::new();
             let mut album_files = HashSet::new(); // New: to collect file paths for the album
@@ -78,5 +229,403 @@ pub fn build_library_hierarchy(tracks: Vec<Track>) -> Library {
         });
     }
 
+    if options.group_soundtracks {
+        library = group_soundtracks(library);
+    }
+
+    library
+}
+
+/// Build a year -> artist -> album tree with the default grouping options.
+pub fn build_year_hierarchy(tracks: Vec<Track>) -> Vec<YearNode> {
+    build_year_hierarchy_with_options(tracks, &HierarchyOptions::default())
+}
+
+/// Group tracks by their numeric year, then by artist and album as in
+/// [`build_library_hierarchy_with_options`].
+///
+/// Years ascend, with the tracks without a year last under "Unknown Year".
+/// Artists and albums within a year are sorted by name. An album whose
+/// tracks carry different years appears under each of them.
+pub fn build_year_hierarchy_with_options(
+    tracks: Vec<Track>,
+    options: &HierarchyOptions,
+) -> Vec<YearNode> {
+    let mut by_year: BTreeMap<Option<u32>, Vec<Track>> = BTreeMap::new();
+    for track in tracks {
+        let year = track.metadata.year.as_ref().map(|y| y.value);
+        by_year.entry(year).or_default().push(track);
+    }
+
+    let mut years: Vec<YearNode> = by_year
+        .into_iter()
+        .map(|(year, tracks)| {
+            let mut artists = build_library_hierarchy_with_options(tracks, options).artists;
+            artists.sort_by(|a, b| a.name.cmp(&b.name));
+            for artist in &mut artists {
+                artist.albums.sort_by(|a, b| a.title.cmp(&b.title));
+            }
+            YearNode { year, artists }
+        })
+        .collect();
+    // `None` sorts first; move "Unknown Year" to the end
+    let unknown = usize::from(years.first().is_some_and(|y| y.year.is_none()));
+    years.rotate_left(unknown);
+    years
+}
+
+/// Returns true if the album looks like a soundtrack: a track genre or the
+/// album title mentions "Soundtrack", "Original Motion Picture", "Original
+/// Score" or the word "OST", or the album artist is "Soundtrack"/"OST".
+pub fn is_soundtrack(album: &AlbumNode) -> bool {
+    fn mentions_soundtrack(text: &str) -> bool {
+        let text = text.to_lowercase();
+        SOUNDTRACK_KEYWORDS.iter().any(|k| text.contains(k))
+            || text
+                .split(|c: char| !c.is_alphanumeric())
+                .any(|word| word == "ost")
+    }
+
+    mentions_soundtrack(&album.title)
+        || album.tracks.iter().any(|track| {
+            let metadata = &track.metadata;
+            metadata
+                .genre
+                .as_ref()
+                .is_some_and(|g| mentions_soundtrack(&g.value))
+                || metadata.album_artist.as_ref().is_some_and(|aa| {
+                    let aa = aa.value.trim();
+                    aa.eq_ignore_ascii_case("soundtrack") || aa.eq_ignore_ascii_case("ost")
+                })
+        })
+}
+
+/// Moves soundtrack albums from their artists to a "Soundtracks" node, merging
+/// parts of the same album (same title and directory) split across artists.
+fn group_soundtracks(library: Library) -> Library {
+    let mut soundtracks: Vec<AlbumNode> = Vec::new();
+    let mut artists = Vec::new();
+
+    for mut artist in library.artists {
+        let (moved, kept): (Vec<AlbumNode>, Vec<AlbumNode>) =
+            artist.albums.into_iter().partition(is_soundtrack);
+        for album in moved {
+            match soundtracks
+                .iter_mut()
+                .find(|a| a.title == album.title && a.path == album.path)
+            {
+                Some(existing) => {
+                    existing.tracks.extend(album.tracks);
+                    existing.files.extend(album.files);
+                }
+                None => soundtracks.push(album),
+            }
+        }
+        if !kept.is_empty() {
+            artist.albums = kept;
+            artists.push(artist);
+        }
+    }
+
+    let mut grouped = Library::new();
+    for artist in artists {
+        grouped.add_artist(artist);
+    }
+    if !soundtracks.is_empty() {
+        grouped.add_artist(ArtistNode {
+            name: SOUNDTRACKS.to_string(),
+            albums: soundtracks,
+        });
+    }
+    grouped
+}
+
+/// Album identity used for compilation detection: album title plus the
+/// album directory.
+///
+/// Tracks in disc folders (`CD1/`, `Disc 2/`) count from the folder above,
+/// the directory [`album_path`] gives a multi-disc album through
+/// [`common_ancestor`], so the discs are judged as one album.
+fn album_key(track: &Track) -> Option<(String, PathBuf)> {
+    let album = track.metadata.album.as_ref()?;
+    let mut dir = track.file_path.parent().unwrap_or(Path::new(""));
+    if dir
+        .file_name()
+        .is_some_and(|name| is_disc_folder(&name.to_string_lossy()))
+    {
+        dir = dir.parent().unwrap_or(dir);
+    }
+    Some((album.value.clone(), dir.to_path_buf()))
+}
+
+/// Returns the keys of albums that should be grouped as compilations.
+fn find_compilations(tracks: &[Track], options: &HierarchyOptions) -> HashSet<(String, PathBuf)> {
+    let mut albums: HashMap<(String, PathBuf), Vec<&Track>> = HashMap::new();
+    for track in tracks {
+        if let Some(key) = album_key(track) {
+            albums.entry(key).or_default().push(track);
+        }
+    }
+
+    albums
+        .into_iter()
+        .filter(|(_, album_tracks)| options.is_compilation(album_tracks))
+        .map(|(key, _)| key)
+        .collect()
+}
+
+/// Compact per-artist facts, far smaller than the full library tree.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+pub struct ArtistSummary {
+    pub name: String,
+    pub album_count: usize,
+    pub track_count: usize,
+    /// Sum of known track durations, in seconds.
+    pub total_duration: f64,
+    pub first_year: Option<u32>,
+    pub last_year: Option<u32>,
+    /// Distinct track genres, sorted.
+    pub genres: Vec<String>,
+}
+
+/// Summarize every artist in the library, largest (by track count) first.
+///
+/// Artists with the same track count are ordered by name.
+pub fn artist_summaries(library: &Library) -> Vec<ArtistSummary> {
+    let mut summaries: Vec<ArtistSummary> = library
+        .artists
+        .iter()
+        .map(|artist| {
+            let tracks = artist.albums.iter().flat_map(|album| &album.tracks);
+            let years: Vec<u32> = artist
+                .albums
+                .iter()
+                .filter_map(|album| album.year)
+                .chain(
+                    tracks
+                        .clone()
+                        .filter_map(|t| t.metadata.year.as_ref().map(|y| y.value)),
+                )
+                .collect();
+            let genres: BTreeSet<String> = tracks
+                .clone()
+                .filter_map(|t| t.metadata.genre.as_ref())
+                .flat_map(|g| split_genres(&g.value))
+                .collect();
+
+            ArtistSummary {
+                name: artist.name.clone(),
+                album_count: artist.albums.len(),
+                track_count: tracks.clone().count(),
+                total_duration: tracks
+                    .filter_map(|t| t.metadata.duration.as_ref().map(|d| d.value))
+                    .sum(),
+                first_year: years.iter().min().copied(),
+                last_year: years.iter().max().copied(),
+                genres: genres.into_iter().collect(),
+            }
+        })
+        .collect();
+
+    summaries.sort_by(|a, b| {
+        b.track_count
+            .cmp(&a.track_count)
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    summaries
+}
+
+/// Default number of entries in each ranked section of [`compute_library_stats`].
+pub const DEFAULT_STATS_TOP: usize = 10;
+
+/// A ranked name with the number of tracks it covers.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+pub struct RankedEntry {
+    pub name: String,
+    pub track_count: usize,
+}
+
+/// Library totals plus the largest artists, albums and genres.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+pub struct LibraryStats {
+    pub total_tracks: usize,
+    pub total_artists: usize,
+    pub total_albums: usize,
+    /// Sum of known track durations, in seconds.
+    pub total_duration: f64,
+    pub top_artists: Vec<RankedEntry>,
+    /// Albums are named "Artist - Album", since titles repeat across artists.
+    pub top_albums: Vec<RankedEntry>,
+    pub top_genres: Vec<RankedEntry>,
+}
+
+/// Compute library totals and rank artists, albums and genres by track
+/// count, keeping the `top` largest of each.
+///
+/// Entries with the same track count are ordered by name. Tracks without a
+/// genre don't count towards any genre.
+pub fn compute_library_stats(library: &Library, top: usize) -> LibraryStats {
+    let mut artists = BTreeMap::new();
+    let mut albums = BTreeMap::new();
+    for artist in &library.artists {
+        for album in &artist.albums {
+            *artists.entry(artist.name.clone()).or_insert(0) += album.tracks.len();
+            *albums
+                .entry(format!("{} - {}", artist.name, album.title))
+                .or_insert(0) += album.tracks.len();
+        }
+    }
+
+    let genres = genre_histogram(library);
+
+    LibraryStats {
+        total_tracks: library_tracks(library).count(),
+        total_artists: library.artists.len(),
+        total_albums: library.artists.iter().map(|a| a.albums.len()).sum(),
+        total_duration: library_tracks(library)
+            .filter_map(|t| t.metadata.duration.as_ref().map(|d| d.value))
+            .sum(),
+        top_artists: rank_entries(artists, top),
+        top_albums: rank_entries(albums, top),
+        top_genres: rank_entries(genres, top),
+    }
+}
+
+/// Separators between the values of a multi-valued genre tag; `\0` is the
+/// ID3v2.4 multi-value separator. `/` is not one: it belongs to single genres
+/// such as "Hip-Hop/Rap" or "R&B/Soul".
+const GENRE_SEPARATORS: &[char] = &[';', '\0'];
+
+/// Split a multi-valued genre tag such as "Rock; Alternative" into its
+/// trimmed values, dropping empty ones and repeats.
+pub fn split_genres(genre: &str) -> Vec<String> {
+    let mut values: Vec<String> = Vec::new();
+    for value in genre.split(GENRE_SEPARATORS).map(str::trim) {
+        if !value.is_empty() && !values.iter().any(|v| v == value) {
+            values.push(value.to_string());
+        }
+    }
+    values
+}
+
+/// SHA-256 of the library's artists, albums and track metadata, as lowercase
+/// hex.
+///
+/// Artists, albums and tracks are hashed in sorted order, so the hash only
+/// changes when the metadata or file paths do, not with scan or grouping
+/// order.
+pub fn library_content_hash(library: &Library) -> String {
+    use sha2::{Digest, Sha256};
+
+    let mut artists: Vec<&ArtistNode> = library.artists.iter().collect();
+    artists.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let mut hasher = Sha256::new();
+    for artist in artists {
+        let mut albums: Vec<&AlbumNode> = artist.albums.iter().collect();
+        albums.sort_by(|a, b| a.title.cmp(&b.title).then_with(|| a.path.cmp(&b.path)));
+        for album in albums {
+            let mut tracks: Vec<&TrackNode> = album.tracks.iter().collect();
+            tracks.sort_by(|a, b| a.file_path.cmp(&b.file_path));
+            let entry = serde_json::json!({
+                "artist": artist.name,
+                "album": album.title,
+                "year": album.year,
+                "tracks": tracks,
+            });
+            hasher.update(entry.to_string().as_bytes());
+            hasher.update(b"\n");
+        }
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+/// Count tracks per genre, counting a multi-valued tag once for each of its
+/// [`split_genres`] values. Tracks without a genre are left out.
+pub fn genre_histogram(library: &Library) -> BTreeMap<String, usize> {
+    let mut histogram = BTreeMap::new();
+    for (genre, tracks) in tracks_by_genre(library) {
+        histogram.insert(genre, tracks.len());
+    }
+    histogram
+}
+
+/// Track paths per genre, in library order. A track with a multi-valued
+/// genre tag is listed under each of its [`split_genres`] values.
+pub fn tracks_by_genre(library: &Library) -> BTreeMap<String, Vec<PathBuf>> {
+    let mut by_genre: BTreeMap<String, Vec<PathBuf>> = BTreeMap::new();
+    for track in library_tracks(library) {
+        let Some(genre) = &track.metadata.genre else {
+            continue;
+        };
+        for value in split_genres(&genre.value) {
+            by_genre
+                .entry(value)
+                .or_default()
+                .push(track.file_path.clone());
+        }
+    }
+    by_genre
+}
+
+/// Sort counts largest first (ties by name) and keep the first `top`.
+fn rank_entries(counts: BTreeMap<String, usize>, top: usize) -> Vec<RankedEntry> {
+    let mut entries: Vec<RankedEntry> = counts
+        .into_iter()
+        .map(|(name, track_count)| RankedEntry { name, track_count })
+        .collect();
+    // Stable sort keeps the BTreeMap's name order within equal counts
+    entries.sort_by(|a, b| b.track_count.cmp(&a.track_count));
+    entries.truncate(top);
+    entries
+}
+
+/// Which year tag dates a track for [`decade_histogram`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum YearSource {
+    /// The release year (`year`).
+    #[default]
+    Release,
+    /// The original release year when tagged, so reissues count toward the
+    /// decade they first came out; falls back to the release year.
+    Original,
+}
+
+impl YearSource {
+    pub fn year_of(self, metadata: &TrackMetadata) -> Option<u32> {
+        let original = match self {
+            YearSource::Release => None,
+            YearSource::Original => metadata.original_year.as_ref(),
+        };
+        original.or(metadata.year.as_ref()).map(|y| y.value)
+    }
+}
+
+/// Count tracks per decade (1950, 1960, ...) from each track's year tag.
+///
+/// Tracks without a year are left out; see [`count_tracks_without_year`].
+pub fn decade_histogram(library: &Library, source: YearSource) -> BTreeMap<u32, usize> {
+    let mut histogram = BTreeMap::new();
+    for track in library_tracks(library) {
+        if let Some(year) = source.year_of(&track.metadata) {
+            *histogram.entry(year / 10 * 10).or_insert(0) += 1;
+        }
+    }
+    histogram
+}
+
+/// Number of tracks without a year tag, the "unknown" decade.
+pub fn count_tracks_without_year(library: &Library, source: YearSource) -> usize {
+    library_tracks(library)
+        .filter(|track| source.year_of(&track.metadata).is_none())
+        .count()
+}
+
+/// Every track node in the library.
+fn library_tracks(library: &Library) -> impl Iterator<Item = &TrackNode> {
     library
+        .artists
+        .iter()
+        .flat_map(|artist| &artist.albums)
+        .flat_map(|album| &album.tracks)
 }
@@ -0,0 +1,290 @@
+//! Precomputed library facts for chained MCP prompts.
+//!
+//! Analysis prompts otherwise ask for `scan_directory`, `get_library_tree`
+//! and `emit_library_metadata` one after another, each rescanning the whole
+//! library. [`precompute_library_facts`] scans once and derives the summaries
+//! in parallel; [`LibraryFactsCache`] keeps the result until the library
+//! changes on disk, checking for changes at most once per
+//! [`FACTS_CACHE_TTL`].
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant, SystemTime};
+
+use walkdir::WalkDir;
+
+use crate::core::domain::models::Library;
+use crate::core::services::duplicates::{QualityTier, classify_metadata};
+use crate::core::services::library::{
+    ArtistSummary, DEFAULT_STATS_TOP, LibraryStats, YearSource, artist_summaries,
+    build_library_hierarchy, compute_library_stats, count_tracks_without_year, decade_histogram,
+    genre_histogram,
+};
+use crate::core::services::scanner::{ScanOptions, scan_with_options};
+
+/// Summary facts about a library, computed from a single scan.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct LibraryFacts {
+    pub stats: LibraryStats,
+    /// Track count per genre tag.
+    pub genres: BTreeMap<String, usize>,
+    /// Track count per decade (1990, 2000, ...).
+    pub decades: BTreeMap<u32, usize>,
+    /// Tracks without a year tag.
+    pub unknown_decade: usize,
+    /// Per-artist summaries, largest first.
+    pub artists: Vec<ArtistSummary>,
+    /// Track count per quality tier.
+    pub quality_tiers: BTreeMap<QualityTier, usize>,
+}
+
+/// Count tracks per [`QualityTier`].
+pub fn quality_tier_histogram(library: &Library) -> BTreeMap<QualityTier, usize> {
+    let mut histogram = BTreeMap::new();
+    for album in library.artists.iter().flat_map(|artist| &artist.albums) {
+        for track in &album.tracks {
+            *histogram
+                .entry(classify_metadata(&track.metadata))
+                .or_insert(0) += 1;
+        }
+    }
+    histogram
+}
+
+/// Derive [`LibraryFacts`] from an already built library, computing the
+/// independent summaries on separate threads.
+pub fn library_facts(library: &Library) -> LibraryFacts {
+    let ((stats, genres), ((decades, unknown_decade), (artists, quality_tiers))) = rayon::join(
+        || {
+            rayon::join(
+                || compute_library_stats(library, DEFAULT_STATS_TOP),
+                || genre_histogram(library),
+            )
+        },
+        || {
+            rayon::join(
+                || {
+                    (
+                        decade_histogram(library, YearSource::Release),
+                        count_tracks_without_year(library, YearSource::Release),
+                    )
+                },
+                || (artist_summaries(library), quality_tier_histogram(library)),
+            )
+        },
+    );
+
+    LibraryFacts {
+        stats,
+        genres,
+        decades,
+        unknown_decade,
+        artists,
+        quality_tiers,
+    }
+}
+
+/// Scan `path` once and compute its [`LibraryFacts`].
+pub fn precompute_library_facts(path: &Path) -> Result<LibraryFacts, String> {
+    if !path.exists() {
+        return Err(format!("Path does not exist: {}", path.display()));
+    }
+
+    let tracks = scan_with_options(path, &ScanOptions::default());
+    Ok(library_facts(&build_library_hierarchy(tracks)))
+}
+
+/// Latest modification time of `path` or anything below it.
+///
+/// Retagging a file changes only that file's mtime, so every entry is
+/// checked rather than just the directories. Symlinks are not followed.
+pub fn library_mtime(path: &Path) -> Option<SystemTime> {
+    WalkDir::new(path)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter_map(|entry| entry.metadata().ok()?.modified().ok())
+        .max()
+}
+
+/// How long cached facts are served before the library is walked again to
+/// look for changes.
+pub const FACTS_CACHE_TTL: Duration = Duration::from_secs(30);
+
+/// Libraries a [`LibraryFactsCache`] holds at once; beyond this the one
+/// checked longest ago is dropped.
+pub const FACTS_CACHE_MAX_ENTRIES: usize = 8;
+
+/// Cached facts with the library mtime they were computed at.
+#[derive(Debug)]
+struct CacheEntry {
+    mtime: Option<SystemTime>,
+    /// When `mtime` was last compared with the library.
+    checked: Instant,
+    facts: Arc<LibraryFacts>,
+}
+
+/// [`LibraryFacts`] cached per library path.
+///
+/// Within the TTL the cached facts are returned as they are; after it the
+/// library's [`library_mtime`] is compared and the facts recomputed if it
+/// changed. At most [`FACTS_CACHE_MAX_ENTRIES`] libraries are kept.
+#[derive(Debug)]
+pub struct LibraryFactsCache {
+    entries: Mutex<HashMap<PathBuf, CacheEntry>>,
+    ttl: Duration,
+}
+
+impl Default for LibraryFactsCache {
+    fn default() -> Self {
+        Self::with_ttl(FACTS_CACHE_TTL)
+    }
+}
+
+impl LibraryFactsCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A cache that trusts its facts for `ttl` before checking the library.
+    pub fn with_ttl(ttl: Duration) -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+            ttl,
+        }
+    }
+
+    /// Return the cached facts for `path`, recomputing them when the library
+    /// changed since they were cached.
+    pub fn get_or_compute(&self, path: &Path) -> Result<Arc<LibraryFacts>, String> {
+        if let Some(entry) = self.lock().get(path)
+            && entry.checked.elapsed() < self.ttl
+        {
+            return Ok(Arc::clone(&entry.facts));
+        }
+
+        let mtime = library_mtime(path);
+        if let Some(entry) = self.lock().get_mut(path)
+            && entry.mtime == mtime
+        {
+            entry.checked = Instant::now();
+            return Ok(Arc::clone(&entry.facts));
+        }
+
+        // Compute without holding the lock; a concurrent request for the same
+        // path may scan too, and the last result wins.
+        let facts = Arc::new(precompute_library_facts(path)?);
+        let mut entries = self.lock();
+        if !entries.contains_key(path) && entries.len() >= FACTS_CACHE_MAX_ENTRIES {
+            let oldest = entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.checked)
+                .map(|(path, _)| path.clone());
+            if let Some(oldest) = oldest {
+                entries.remove(&oldest);
+            }
+        }
+        entries.insert(
+            path.to_path_buf(),
+            CacheEntry {
+                mtime,
+                checked: Instant::now(),
+                facts: Arc::clone(&facts),
+            },
+        );
+        Ok(facts)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<PathBuf, CacheEntry>> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+// ── Tests ───────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_precompute_library_facts_summarizes_fixture() {
+        let facts = precompute_library_facts(Path::new("tests/fixtures/flac/simple")).unwrap();
+
+        assert_eq!(facts.stats.total_tracks, 2);
+        assert_eq!(facts.quality_tiers.get(&QualityTier::Lossless), Some(&2));
+        assert_eq!(
+            facts.decades.values().sum::<usize>() + facts.unknown_decade,
+            2
+        );
+        assert!(!facts.artists.is_empty());
+    }
+
+    #[test]
+    fn test_precompute_library_facts_missing_path() {
+        let err = precompute_library_facts(Path::new("/nonexistent/music")).unwrap_err();
+        assert!(err.contains("does not exist"));
+    }
+
+    #[test]
+    fn test_cache_reuses_facts_until_library_changes() {
+        let dir = TempDir::new().unwrap();
+        std::fs::copy(
+            "tests/fixtures/flac/simple/track1.flac",
+            dir.path().join("01.flac"),
+        )
+        .unwrap();
+        let cache = LibraryFactsCache::with_ttl(Duration::ZERO);
+
+        let first = cache.get_or_compute(dir.path()).unwrap();
+        let second = cache.get_or_compute(dir.path()).unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(first.stats.total_tracks, 1);
+
+        let added = dir.path().join("02.flac");
+        std::fs::copy("tests/fixtures/flac/simple/track2.flac", &added).unwrap();
+        let later = SystemTime::now() + Duration::from_secs(60);
+        std::fs::File::options()
+            .write(true)
+            .open(&added)
+            .unwrap()
+            .set_modified(later)
+            .unwrap();
+
+        let third = cache.get_or_compute(dir.path()).unwrap();
+        assert!(!Arc::ptr_eq(&first, &third));
+        assert_eq!(third.stats.total_tracks, 2);
+    }
+
+    #[test]
+    fn test_cache_skips_change_check_within_ttl() {
+        let dir = TempDir::new().unwrap();
+        let cache = LibraryFactsCache::new();
+
+        let first = cache.get_or_compute(dir.path()).unwrap();
+        std::fs::copy(
+            "tests/fixtures/flac/simple/track1.flac",
+            dir.path().join("01.flac"),
+        )
+        .unwrap();
+
+        let second = cache.get_or_compute(dir.path()).unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+    }
+
+    #[test]
+    fn test_cache_drops_oldest_library_beyond_cap() {
+        let dirs: Vec<TempDir> = (0..=FACTS_CACHE_MAX_ENTRIES)
+            .map(|_| TempDir::new().unwrap())
+            .collect();
+        let cache = LibraryFactsCache::new();
+
+        for dir in &dirs {
+            cache.get_or_compute(dir.path()).unwrap();
+        }
+
+        let entries = cache.lock();
+        assert_eq!(entries.len(), FACTS_CACHE_MAX_ENTRIES);
+        assert!(!entries.contains_key(dirs[0].path()));
+    }
+}
@@ -1,14 +1,26 @@
 //! Business logic services.
 
+pub mod album_info;
 pub mod apply_metadata;
+pub mod artist_aliases;
 pub mod cue;
 pub mod duplicates;
+pub mod explain;
 pub mod format_tree;
+pub mod genre_map;
 pub mod inference;
+pub mod inference_check;
 pub mod library;
+pub mod library_facts;
+pub mod mutation_summary;
 pub mod normalization;
+pub mod normalize_manifest;
+pub mod reorganize;
 pub mod scanner;
+pub mod toml_metadata;
+pub mod transcode_plan;
 pub mod validation;
+pub mod verify;
 
 // Re-export commonly used functions
 pub use inference::{infer_album_from_path, infer_artist_from_path};
@@ -0,0 +1,101 @@
+//! Shared per-file summary for commands that write tags.
+//!
+//! Every mutating command ends its text output with the same line, e.g.
+//! `3 files would be changed, 10 unchanged, 1 errors` for a dry run, so
+//! scripts can read the outcome the same way whichever command ran.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// What happened (or would happen) to one file.
+///
+/// Ordered by precedence: a file with one failed and one successful change
+/// counts as an error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FileOutcome {
+    Unchanged,
+    Changed,
+    Error,
+}
+
+/// Accumulates one [`FileOutcome`] per file across a command's changes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MutationSummary {
+    files: BTreeMap<PathBuf, FileOutcome>,
+}
+
+impl MutationSummary {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record an outcome for `path`, keeping the highest-precedence one
+    /// when the file was already recorded.
+    pub fn record(&mut self, path: &Path, outcome: FileOutcome) {
+        let entry = self.files.entry(path.to_path_buf()).or_insert(outcome);
+        *entry = (*entry).max(outcome);
+    }
+
+    pub fn changed(&self) -> usize {
+        self.count(FileOutcome::Changed)
+    }
+
+    pub fn unchanged(&self) -> usize {
+        self.count(FileOutcome::Unchanged)
+    }
+
+    pub fn errors(&self) -> usize {
+        self.count(FileOutcome::Error)
+    }
+
+    fn count(&self, outcome: FileOutcome) -> usize {
+        self.files.values().filter(|o| **o == outcome).count()
+    }
+
+    /// The summary line: "N files would be changed, M unchanged, K errors"
+    /// for a dry run, "N files changed, ..." otherwise.
+    pub fn line(&self, dry_run: bool) -> String {
+        format!(
+            "{} files {}, {} unchanged, {} errors",
+            self.changed(),
+            if dry_run {
+                "would be changed"
+            } else {
+                "changed"
+            },
+            self.unchanged(),
+            self.errors()
+        )
+    }
+}
+
+// ── Tests ───────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summary_counts_each_file_once_by_precedence() {
+        let mut summary = MutationSummary::new();
+        summary.record(Path::new("a.flac"), FileOutcome::Changed);
+        summary.record(Path::new("a.flac"), FileOutcome::Unchanged);
+        summary.record(Path::new("b.flac"), FileOutcome::Unchanged);
+        summary.record(Path::new("c.flac"), FileOutcome::Changed);
+        summary.record(Path::new("c.flac"), FileOutcome::Error);
+        summary.record(Path::new("d.flac"), FileOutcome::Unchanged);
+
+        assert_eq!(
+            (summary.changed(), summary.unchanged(), summary.errors()),
+            (1, 2, 1)
+        );
+        assert_eq!(
+            summary.line(true),
+            "1 files would be changed, 2 unchanged, 1 errors"
+        );
+        assert_eq!(
+            summary.line(false),
+            "1 files changed, 2 unchanged, 1 errors"
+        );
+    }
+}
@@ -1,10 +1,14 @@
 //! Text normalization services.
 
 use crate::adapters::audio_formats as formats;
-use crate::core::domain::models::Track;
+use crate::core::builders::TrackMetadataBuilder;
+use crate::core::domain::models::{MetadataSource, Track};
+use crate::core::services::genre_map::GenreMap;
+use crate::core::services::inference::infer_track_number_from_path;
+use crate::core::services::mutation_summary::{FileOutcome, MutationSummary};
 // Ensure Track is imported
 use crate::MetadataValue;
-use crate::core::services::scanner::{scan_dir, scan_dir_with_metadata};
+use crate::core::services::scanner::{scan_dir, scan_dir_with_metadata, scan_single_file};
 use serde::{Deserialize, Serialize};
 // Added for combined JSON output
 use std::path::PathBuf;
@@ -55,6 +59,61 @@ pub struct YearNormalizationReport {
     pub error: Option<String>,
 }
 
+/// A text field whose typographic punctuation was (or would be) converted to ASCII.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct PunctuationNormalizationReport {
+    pub original_path: PathBuf,
+    pub field: String,
+    pub original_value: String,
+    pub normalized_value: String,
+    pub changed: bool,
+}
+
+/// A title whose bracketed noise phrases, e.g. "(Official Video)", were (or
+/// would be) removed.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct NoisePhraseReport {
+    pub original_path: PathBuf,
+    pub original_title: String,
+    pub stripped_title: String,
+    pub changed: bool,
+}
+
+/// A comment that matched a ripper-signature pattern and was (or would be)
+/// removed.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct CommentStripReport {
+    pub original_path: PathBuf,
+    pub original_comment: String,
+    /// The pattern that matched the comment.
+    pub pattern: String,
+    /// Whether the comment was removed from the file (`--apply`).
+    pub applied: bool,
+    pub error: Option<String>,
+}
+
+/// An artist or album artist whose "Last, First" name order was (or would be)
+/// switched by `--artist-order`.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct ArtistOrderReport {
+    pub original_path: PathBuf,
+    pub field: String,
+    pub original_value: String,
+    pub reordered_value: String,
+}
+
+/// A genre outside the `--limit-genres` vocabulary and the allowed genre it
+/// was (or would be) remapped to.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct GenreConstraintReport {
+    pub original_path: PathBuf,
+    /// Genre as stored in the file.
+    pub original_genre: String,
+    /// Genre after normalization, before it was constrained.
+    pub normalized_genre: String,
+    pub constrained_genre: String,
+}
+
 // Combined report struct for JSON output
 #[derive(Debug, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema)]
 pub struct CombinedNormalizationReport {
@@ -63,9 +122,101 @@ pub struct CombinedNormalizationReport {
     pub artist_reports: Vec<ArtistNormalizationReport>,
     pub album_reports: Vec<AlbumNormalizationReport>,
     pub year_reports: Vec<YearNormalizationReport>,
+    /// Only filled when punctuation normalization is enabled.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub punctuation_reports: Vec<PunctuationNormalizationReport>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub noise_reports: Vec<NoisePhraseReport>,
+    /// Only filled when comment stripping is enabled.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub comment_reports: Vec<CommentStripReport>,
+    /// Only filled when an artist order is requested.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub artist_order_reports: Vec<ArtistOrderReport>,
+    /// Only filled when a genre vocabulary is given.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub genre_constraint_reports: Vec<GenreConstraintReport>,
     pub summary: String, // Or a more structured summary
 }
 
+/// Options for [`normalize_and_format_with_options`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NormalizeOptions<'a> {
+    /// User genre mappings that take precedence over the built-in ones.
+    pub genre_map: Option<&'a GenreMap>,
+    /// Also convert typographic punctuation in text tags to ASCII.
+    pub unicode_punctuation: bool,
+    /// Bracketed title phrases to strip; `None` uses [`DEFAULT_NOISE_PHRASES`].
+    pub strip_phrases: Option<&'a [String]>,
+    /// Remove comments containing one of these patterns; `None` leaves
+    /// comments alone. See [`DEFAULT_COMMENT_PATTERNS`].
+    pub strip_comments: Option<&'a [String]>,
+    /// Write comment removals to the files instead of only reporting them.
+    pub apply: bool,
+    /// Report artist names to switch to this order; `None` leaves the order alone.
+    pub artist_order: Option<ArtistOrder>,
+    /// Remap genres outside this set to their nearest member; `None` allows
+    /// any genre. See [`constrain_genre`].
+    pub allowed_genres: Option<&'a [String]>,
+}
+
+/// How a person's name is written in artist tags (`normalize --artist-order`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArtistOrder {
+    /// "Ludwig van Beethoven"
+    FirstLast,
+    /// "Beethoven, Ludwig van", as classical catalogues sort composers
+    LastFirst,
+}
+
+/// Parses an `--artist-order` value: `first-last` or `last-first`.
+pub fn parse_artist_order(input: &str) -> Result<ArtistOrder, String> {
+    match input.trim().to_ascii_lowercase().as_str() {
+        "first-last" => Ok(ArtistOrder::FirstLast),
+        "last-first" => Ok(ArtistOrder::LastFirst),
+        _ => Err(format!(
+            "invalid artist order '{input}' (expected 'first-last' or 'last-first')"
+        )),
+    }
+}
+
+/// Ripper and download-site signatures commonly left in comment tags.
+/// Matched case-insensitively anywhere in the comment.
+pub const DEFAULT_COMMENT_PATTERNS: &[&str] = &[
+    "Ripped by",
+    "Ripped with",
+    "Exact Audio Copy",
+    "dBpoweramp",
+    "X Lossless Decoder",
+    "CUERipper",
+    "Encoded by",
+    "Downloaded from",
+    "www.",
+    "http://",
+    "https://",
+];
+
+/// Bracketed phrases that rips and video downloads add to titles. Matched
+/// case-insensitively against the whole bracket content.
+pub const DEFAULT_NOISE_PHRASES: &[&str] = &[
+    "Official Video",
+    "Official Music Video",
+    "Official Audio",
+    "Official Lyric Video",
+    "Official Visualizer",
+    "Music Video",
+    "Lyric Video",
+    "Lyrics",
+    "With Lyrics",
+    "Visualizer",
+    "Audio",
+    "Video",
+    "HQ",
+    "HD",
+    "Full HD",
+    "4K",
+];
+
 pub const STANDARD_GENRES: &[&str] = &[
     "Acoustic",
     "Alternative",
@@ -272,11 +423,147 @@ const GENRE_ALIASES: &[(&[&str], &str)] = &[
     ),
 ];
 
+/// Built-in genre clusters: each root genre with the genres grouped under it.
+///
+/// Entries are lowercase; genres are matched as given and after normalization,
+/// so aliases such as "indie rock" (→ Indie) resolve through [`GENRE_ALIASES`].
+const GENRE_HIERARCHY: &[(&str, &[&str])] = &[
+    (
+        "Rock",
+        &[
+            "rock",
+            "alternative",
+            "indie",
+            "punk",
+            "metal",
+            "grunge",
+            "shoegaze",
+            "post-rock",
+            "progressive rock",
+            "hard rock",
+            "psychedelic rock",
+            "garage rock",
+            "britpop",
+            "emo",
+        ],
+    ),
+    (
+        "Electronic",
+        &[
+            "electronic",
+            "dance",
+            "house",
+            "techno",
+            "ambient",
+            "trance",
+            "drum and bass",
+            "dubstep",
+            "idm",
+            "synthwave",
+            "trip hop",
+            "breakbeat",
+            "eurodance",
+        ],
+    ),
+    (
+        "R&B",
+        &["r&b", "soul", "funk", "disco", "motown", "neo soul"],
+    ),
+    ("Hip-Hop", &["hip-hop", "trap", "grime", "boom bap"]),
+    ("Jazz", &["jazz", "swing", "bebop", "big band"]),
+    (
+        "Classical",
+        &[
+            "classical",
+            "opera",
+            "baroque",
+            "chamber music",
+            "orchestral",
+        ],
+    ),
+    (
+        "Folk",
+        &[
+            "folk",
+            "acoustic",
+            "singer-songwriter",
+            "americana",
+            "bluegrass",
+        ],
+    ),
+    ("Country", &["country"]),
+    ("Blues", &["blues"]),
+    ("Pop", &["pop", "synthpop", "electropop", "k-pop", "j-pop"]),
+    ("Reggae", &["reggae", "ska", "dub", "dancehall"]),
+    (
+        "World",
+        &["world", "latin", "afrobeat", "flamenco", "celtic"],
+    ),
+    ("Soundtrack", &["soundtrack", "musical", "score"]),
+    ("New Age", &["new age"]),
+    ("Avant-Garde", &["avant-garde", "experimental", "noise"]),
+    (
+        "Spoken Word",
+        &["spoken word", "speech", "podcast", "audiobook", "comedy"],
+    ),
+    ("Children's", &["children's"]),
+];
+
+/// Returns the root (cluster) genre of `genre` in the built-in hierarchy,
+/// e.g. "Indie Rock" → "Rock". For multi-valued genres such as "Pop/Rock" the
+/// first value decides.
+pub fn cluster_genre(genre: &str) -> Option<&'static str> {
+    fn lookup(genre: &str) -> Option<&'static str> {
+        let genre = genre.trim().to_lowercase();
+        GENRE_HIERARCHY
+            .iter()
+            .find(|(_, members)| members.contains(&genre.as_str()))
+            .map(|(root, _)| *root)
+    }
+
+    let first = genre.split('/').next()?;
+    lookup(first).or_else(|| lookup(&normalize_genre(first)?))
+}
+
+/// Like [`cluster_genre`], letting roots from a user [`GenreMap`] override the
+/// built-in hierarchy.
+pub fn cluster_genre_with_map<'a>(genre: &str, genre_map: Option<&'a GenreMap>) -> Option<&'a str> {
+    let first = genre.split('/').next()?;
+    if let Some(map) = genre_map {
+        let mapped = normalize_genre_with_map(first, Some(map));
+        if let Some(root) = map
+            .root(first)
+            .or_else(|| mapped.as_deref().and_then(|m| map.root(m)))
+        {
+            return Some(root);
+        }
+        if let Some(mapped) = mapped {
+            return cluster_genre(&mapped).or_else(|| cluster_genre(first));
+        }
+    }
+    cluster_genre(first)
+}
+
 pub fn normalize_genre(genre: &str) -> Option<String> {
+    normalize_genre_with_map(genre, None)
+}
+
+/// Normalizes a genre, consulting a user [`GenreMap`] before the built-in aliases.
+///
+/// The whole value is looked up first so that mappings containing `/` (such as
+/// `pop/rock`) apply; otherwise each `/`-separated part is mapped on its own.
+pub fn normalize_genre_with_map(genre: &str, genre_map: Option<&GenreMap>) -> Option<String> {
+    if let Some(canonical) = genre_map.and_then(|m| m.get(genre)) {
+        return Some(canonical.to_string());
+    }
+
     let normalized: Vec<String> = genre
         .trim()
         .split('/')
         .map(|g| {
+            if let Some(canonical) = genre_map.and_then(|m| m.get(g)) {
+                return canonical.to_string();
+            }
             let g = g.trim().to_lowercase();
             for (aliases, standard) in GENRE_ALIASES {
                 if aliases.iter().any(|a| *a == g) {
@@ -307,14 +594,53 @@ pub fn normalize_genre(genre: &str) -> Option<String> {
     }
 }
 
+/// Genre assigned by [`constrain_genre`] when nothing in the allowed set is close.
+pub const UNMATCHED_GENRE: &str = "Other";
+
+/// Maps `genre` onto a closed vocabulary: the result is always one of
+/// `allowed` (in its spelling) or [`UNMATCHED_GENRE`].
+///
+/// Tried in order: the genre itself, its normalized form, then its root in
+/// the genre hierarchy, e.g. "Shoegaze" → "Rock" when only "Rock" is allowed.
+/// For multi-valued genres such as "Pop/Rock" the first value that fits wins.
+pub fn constrain_genre(genre: &str, allowed: &[String]) -> String {
+    constrain_genre_with_map(genre, allowed, None)
+}
+
+/// Like [`constrain_genre`], normalizing and clustering with a user [`GenreMap`].
+pub fn constrain_genre_with_map(
+    genre: &str,
+    allowed: &[String],
+    genre_map: Option<&GenreMap>,
+) -> String {
+    let find = |candidate: &str| {
+        let candidate = candidate.trim();
+        allowed
+            .iter()
+            .find(|a| a.trim().eq_ignore_ascii_case(candidate))
+    };
+    let normalized =
+        |value: &str| normalize_genre_with_map(value, genre_map).and_then(|n| find(&n));
+    let fit = |value: &str| {
+        find(value)
+            .or_else(|| normalized(value))
+            .or_else(|| cluster_genre_with_map(value, genre_map).and_then(find))
+    };
+
+    // Whole value first so mappings such as `pop/rock` apply, then each part
+    find(genre)
+        .or_else(|| normalized(genre))
+        .or_else(|| genre.split('/').find_map(fit))
+        .map(|a| a.trim().to_string())
+        .unwrap_or_else(|| UNMATCHED_GENRE.to_string())
+}
+
 pub(crate) fn normalize_genres_internal(
     path: PathBuf,
+    genre_map: Option<&GenreMap>,
 ) -> Result<Vec<GenreNormalizationReport>, String> {
     let tracks = if path.is_file() {
-        vec![
-            formats::read_metadata(&path)
-                .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?,
-        ]
+        vec![scan_single_file(&path)?]
     } else if path.is_dir() {
         scan_dir(&path, false)
     } else {
@@ -329,7 +655,7 @@ pub(crate) fn normalize_genres_internal(
         let mut changed = false;
         let mut error = None;
         let normalized_genre = if let Some(ref genre_value) = original_genre {
-            match normalize_genre(genre_value) {
+            match normalize_genre_with_map(genre_value, genre_map) {
                 Some(new_genre) => {
                     if new_genre != *genre_value {
                         changed = true;
@@ -358,14 +684,37 @@ pub(crate) fn normalize_genres_internal(
     Ok(reports)
 }
 
+/// Constrain the normalized genre of every report to `allowed`, keeping the
+/// files whose genre changes.
+fn genre_constraint_reports(
+    genre_reports: &[GenreNormalizationReport],
+    allowed: &[String],
+    genre_map: Option<&GenreMap>,
+) -> Vec<GenreConstraintReport> {
+    genre_reports
+        .iter()
+        .filter_map(|r| {
+            let original_genre = r.original_genre.clone()?;
+            let normalized_genre = r
+                .normalized_genre
+                .clone()
+                .unwrap_or_else(|| original_genre.clone());
+            let constrained_genre = constrain_genre_with_map(&normalized_genre, allowed, genre_map);
+            (constrained_genre != normalized_genre).then(|| GenreConstraintReport {
+                original_path: r.original_path.clone(),
+                original_genre,
+                normalized_genre,
+                constrained_genre,
+            })
+        })
+        .collect()
+}
+
 pub(crate) fn normalize_artists_internal(
     path: PathBuf,
 ) -> Result<Vec<ArtistNormalizationReport>, String> {
     let tracks = if path.is_file() {
-        vec![
-            formats::read_metadata(&path)
-                .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?,
-        ]
+        vec![scan_single_file(&path)?]
     } else if path.is_dir() {
         scan_dir(&path, false)
     } else {
@@ -406,10 +755,7 @@ pub(crate) fn normalize_albums_internal(
     path: PathBuf,
 ) -> Result<Vec<AlbumNormalizationReport>, String> {
     let tracks = if path.is_file() {
-        vec![
-            formats::read_metadata(&path)
-                .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?,
-        ]
+        vec![scan_single_file(&path)?]
     } else if path.is_dir() {
         scan_dir(&path, false)
     } else {
@@ -450,10 +796,7 @@ pub(crate) fn normalize_years_internal(
     path: PathBuf,
 ) -> Result<Vec<YearNormalizationReport>, String> {
     let tracks = if path.is_file() {
-        vec![
-            formats::read_metadata(&path)
-                .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?,
-        ]
+        vec![scan_single_file(&path)?]
     } else if path.is_dir() {
         scan_dir(&path, false)
     } else {
@@ -486,6 +829,335 @@ pub(crate) fn normalize_years_internal(
     Ok(reports)
 }
 
+/// Reports typographic punctuation in the title, artist, album and album
+/// artist tags of each track.
+pub(crate) fn normalize_punctuation_internal(
+    path: PathBuf,
+) -> Result<Vec<PunctuationNormalizationReport>, String> {
+    let tracks = if path.is_file() {
+        vec![scan_single_file(&path)?]
+    } else if path.is_dir() {
+        scan_dir(&path, false)
+    } else {
+        return Err(format!("Path does not exist: {}", path.display()));
+    };
+
+    let mut reports = Vec::new();
+
+    for track in tracks {
+        let metadata = &track.metadata;
+        let fields = [
+            ("title", &metadata.title),
+            ("artist", &metadata.artist),
+            ("album", &metadata.album),
+            ("album_artist", &metadata.album_artist),
+        ];
+        for (field, value) in fields {
+            let Some(value) = value else {
+                continue;
+            };
+            let normalized = normalize_unicode_punctuation(&value.value);
+            reports.push(PunctuationNormalizationReport {
+                original_path: track.file_path.clone(),
+                field: field.to_string(),
+                changed: normalized != value.value,
+                original_value: value.value.clone(),
+                normalized_value: normalized,
+            });
+        }
+    }
+
+    Ok(reports)
+}
+
+/// Converts typographic punctuation to its ASCII equivalent: curly quotes and
+/// primes become `'` or `"`, dashes and the minus sign become `-`, and an
+/// ellipsis becomes `...`.
+///
+/// Whitespace is left untouched.
+pub fn normalize_unicode_punctuation(input: &str) -> String {
+    let mut result = String::with_capacity(input.len());
+
+    for c in input.chars() {
+        match c {
+            '\u{2018}' | '\u{2019}' | '\u{201A}' | '\u{201B}' | '\u{2032}' => result.push('\''),
+            '\u{201C}' | '\u{201D}' | '\u{201E}' | '\u{201F}' | '\u{2033}' => result.push('"'),
+            '\u{2010}' | '\u{2011}' | '\u{2012}' | '\u{2013}' | '\u{2014}' | '\u{2015}'
+            | '\u{2212}' => result.push('-'),
+            '\u{2026}' => result.push_str("..."),
+            _ => result.push(c),
+        }
+    }
+
+    result
+}
+
+/// Reports titles containing bracketed noise phrases.
+pub(crate) fn normalize_noise_internal<S: AsRef<str>>(
+    path: PathBuf,
+    phrases: &[S],
+) -> Result<Vec<NoisePhraseReport>, String> {
+    let tracks = if path.is_file() {
+        vec![scan_single_file(&path)?]
+    } else if path.is_dir() {
+        scan_dir(&path, false)
+    } else {
+        return Err(format!("Path does not exist: {}", path.display()));
+    };
+
+    let reports = tracks
+        .into_iter()
+        .filter_map(|track| {
+            let title = track.metadata.title?.value;
+            let stripped = strip_noise_phrases(&title, phrases);
+            Some(NoisePhraseReport {
+                original_path: track.file_path,
+                changed: stripped != title,
+                original_title: title,
+                stripped_title: stripped,
+            })
+        })
+        .collect();
+
+    Ok(reports)
+}
+
+/// Removes `(...)`, `[...]` and `{...}` groups whose content matches one of
+/// `phrases` (case-insensitive), e.g. "Song (Official Video) [HQ]" -> "Song".
+///
+/// Other parentheticals such as "(Live at Wembley)" or "(feat. X)" are kept.
+/// Unbalanced brackets are left as they are.
+pub fn strip_noise_phrases<S: AsRef<str>>(title: &str, phrases: &[S]) -> String {
+    let is_noise = |inner: &str| {
+        let inner = inner.trim();
+        phrases
+            .iter()
+            .any(|p| p.as_ref().trim().eq_ignore_ascii_case(inner))
+    };
+
+    let mut result = String::with_capacity(title.len());
+    let mut rest = title;
+    while let Some(open) = rest.find(['(', '[', '{']) {
+        let close_char = match rest.as_bytes()[open] {
+            b'(' => ')',
+            b'[' => ']',
+            _ => '}',
+        };
+        let Some(close) = rest[open + 1..].find(close_char).map(|i| open + 1 + i) else {
+            break;
+        };
+        result.push_str(&rest[..open]);
+        if !is_noise(&rest[open + 1..close]) {
+            result.push_str(&rest[open..=close]);
+        }
+        rest = &rest[close + 1..];
+    }
+    result.push_str(rest);
+
+    if result == title {
+        return result;
+    }
+    let collapsed = result.split_whitespace().collect::<Vec<_>>().join(" ");
+    collapsed.trim_end_matches([' ', '-']).to_string()
+}
+
+/// Returns the first of `patterns` found in `comment`, ignoring case. Blank
+/// patterns never match.
+pub fn matching_comment_pattern<'a, S: AsRef<str>>(
+    comment: &str,
+    patterns: &'a [S],
+) -> Option<&'a str> {
+    let comment = comment.to_lowercase();
+    patterns
+        .iter()
+        .map(|p| p.as_ref())
+        .find(|p| !p.trim().is_empty() && comment.contains(&p.to_lowercase()))
+}
+
+/// Reports comments matching `patterns` and, with `apply`, removes them from
+/// the files. Only the comment tag is written.
+pub(crate) fn normalize_comments_internal<S: AsRef<str>>(
+    path: PathBuf,
+    patterns: &[S],
+    apply: bool,
+) -> Result<Vec<CommentStripReport>, String> {
+    let tracks = if path.is_file() {
+        vec![scan_single_file(&path)?]
+    } else if path.is_dir() {
+        scan_dir(&path, false)
+    } else {
+        return Err(format!("Path does not exist: {}", path.display()));
+    };
+
+    let reports = tracks
+        .into_iter()
+        .filter_map(|track| {
+            let comment = track.metadata.comment?.value;
+            let pattern = matching_comment_pattern(&comment, patterns)?.to_string();
+            let error = if apply {
+                // An empty comment tells the format handlers to remove the tag
+                let update = TrackMetadataBuilder::new(&track.file_path)
+                    .comment("", MetadataSource::UserEdited, 1.0)
+                    .format(track.metadata.format)
+                    .build();
+                formats::write_metadata(&track.file_path, &update)
+                    .err()
+                    .map(|e| e.to_string())
+            } else {
+                None
+            };
+            Some(CommentStripReport {
+                original_path: track.file_path,
+                original_comment: comment,
+                pattern,
+                applied: apply && error.is_none(),
+                error,
+            })
+        })
+        .collect();
+
+    Ok(reports)
+}
+
+/// Lowercase name particles that may follow the first names in "Last, First"
+/// ("Beethoven, Ludwig van").
+const NAME_PARTICLES: &[&str] = &[
+    "van", "von", "der", "den", "de", "di", "da", "du", "del", "della", "la", "le", "y", "zu",
+];
+
+/// Whether `part` reads as a short run of name words: one to three words,
+/// each capitalized (initials like "J.S." count) or a name particle.
+fn is_name_part(part: &str) -> bool {
+    let words: Vec<&str> = part.split_whitespace().collect();
+    (1..=3).contains(&words.len())
+        && words.iter().all(|word| {
+            NAME_PARTICLES.contains(word)
+                || (word.chars().next().is_some_and(char::is_uppercase)
+                    && word
+                        .chars()
+                        .all(|c| c.is_alphabetic() || matches!(c, '.' | '-' | '\'')))
+        })
+}
+
+/// Splits "Beethoven, Ludwig van" into `("Beethoven", "Ludwig van")`.
+///
+/// Conservative so band names survive: exactly one comma, and both sides must
+/// pass [`is_name_part`], which rules out "Earth, Wind & Fire" and
+/// "Tyler, the Creator".
+pub fn split_last_first(name: &str) -> Option<(&str, &str)> {
+    let (last, first) = name.split_once(',')?;
+    let (last, first) = (last.trim(), first.trim());
+    (!first.contains(',') && is_name_part(last) && is_name_part(first)).then_some((last, first))
+}
+
+/// Converts a "Last, First" name to "First Last". `None` when `name` isn't in
+/// that form (see [`split_last_first`]).
+pub fn last_first_to_first_last(name: &str) -> Option<String> {
+    split_last_first(name).map(|(last, first)| format!("{} {}", first, last))
+}
+
+/// Reordering proposals for the artist and album artist tags of `tracks`.
+///
+/// `first-last` rewrites every "Last, First" name. `last-first` can't tell
+/// "John Williams" from "Pink Floyd", so it only rewrites names whose
+/// "Last, First" form is already used elsewhere in `tracks`.
+pub fn artist_order_reports(tracks: &[Track], order: ArtistOrder) -> Vec<ArtistOrderReport> {
+    let fields = |track: &Track| {
+        [
+            ("artist", track.metadata.artist.as_ref()),
+            ("album_artist", track.metadata.album_artist.as_ref()),
+        ]
+        .into_iter()
+        .filter_map(|(field, value)| value.map(|v| (field, v.value.clone())))
+        .collect::<Vec<_>>()
+    };
+
+    // "First Last" -> "Last, First" for every name already written last-first
+    let known: std::collections::HashMap<String, String> = tracks
+        .iter()
+        .flat_map(fields)
+        .filter_map(|(_, value)| Some((last_first_to_first_last(&value)?, value)))
+        .collect();
+
+    tracks
+        .iter()
+        .flat_map(|track| {
+            fields(track)
+                .into_iter()
+                .filter_map(|(field, value)| {
+                    let reordered = match order {
+                        ArtistOrder::FirstLast => last_first_to_first_last(&value)?,
+                        ArtistOrder::LastFirst => known.get(&value)?.clone(),
+                    };
+                    Some(ArtistOrderReport {
+                        original_path: track.file_path.clone(),
+                        field: field.to_string(),
+                        original_value: value,
+                        reordered_value: reordered,
+                    })
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+pub(crate) fn normalize_artist_order_internal(
+    path: PathBuf,
+    order: ArtistOrder,
+) -> Result<Vec<ArtistOrderReport>, String> {
+    let tracks = if path.is_file() {
+        vec![scan_single_file(&path)?]
+    } else if path.is_dir() {
+        scan_dir(&path, false)
+    } else {
+        return Err(format!("Path does not exist: {}", path.display()));
+    };
+
+    Ok(artist_order_reports(&tracks, order))
+}
+
+/// Title markers that introduce featured artists, matched case-insensitively
+/// at the start of a word.
+const FEATURING_MARKERS: &[&str] = &["featuring ", "feat. ", "feat ", "ft. ", "ft "];
+
+/// Featured artist names from a title such as "Song (feat. A & B)" or
+/// "Song ft. A, B - Remix", in order.
+///
+/// The list runs from the first marker to the next bracket or " - ", and is
+/// split on ",", "&" and " and ".
+pub fn extract_featured_artists(title: &str) -> Vec<String> {
+    // ASCII lowercasing keeps byte offsets valid for `title`
+    let lower = title.to_ascii_lowercase();
+    let found = lower.char_indices().find_map(|(i, _)| {
+        let at_word_start = !lower[..i]
+            .chars()
+            .next_back()
+            .is_some_and(char::is_alphanumeric);
+        FEATURING_MARKERS
+            .iter()
+            .find(|m| at_word_start && lower[i..].starts_with(**m))
+            .map(|m| i + m.len())
+    });
+    let Some(start) = found else {
+        return Vec::new();
+    };
+
+    let rest = &title[start..];
+    let end = [rest.find(['(', ')', '[', ']', '{', '}']), rest.find(" - ")]
+        .into_iter()
+        .flatten()
+        .min()
+        .unwrap_or(rest.len());
+
+    rest[..end]
+        .split([',', '&'])
+        .flat_map(|part| part.split(" and "))
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
 pub fn to_title_case(input: &str) -> String {
     let mut result = String::with_capacity(input.len());
     let mut capitalize_next = true;
@@ -509,6 +1181,16 @@ pub fn to_title_case(input: &str) -> String {
     result
 }
 
+/// Transliterates `input` to ASCII for use in file names, e.g. `é` → `e`,
+/// `ß` → `ss`, `Чайковский` → `Chaikovskii`.
+///
+/// Only meant for destination filenames on devices that cannot handle
+/// non-ASCII paths; embedded tags keep their full Unicode values. Characters
+/// without a known transliteration are replaced with `_`.
+pub fn ascii_transliterate(input: &str) -> String {
+    deunicode::deunicode_with_tofu(input, "_")
+}
+
 /// Normalize track titles to title case with options
 pub(crate) fn normalize_titles_internal(
     path: PathBuf,
@@ -518,14 +1200,14 @@ pub(crate) fn normalize_titles_internal(
     // Check if path is a file or directory
     if path.is_file() {
         // Single file
-        match formats::read_metadata(&path) {
+        match scan_single_file(&path) {
             Ok(track) => reports.push(normalize_single_track(track)),
             Err(e) => reports.push(TitleNormalizationReport {
                 original_path: path.clone(),
                 original_title: None,
                 normalized_title: None,
                 changed: false,
-                error: Some(format!("Failed to read {}: {}", path.display(), e)),
+                error: Some(e),
             }),
         }
     } else if path.is_dir() {
@@ -555,24 +1237,154 @@ pub(crate) fn normalize_titles_internal(
 
 /// Orchestrates title and genre normalization and formats the output.
 pub fn normalize_and_format(path: PathBuf, json: bool) -> Result<String, String> {
+    normalize_and_format_with_genre_map(path, json, None)
+}
+
+/// Like [`normalize_and_format`], with user genre mappings taking precedence
+/// over the built-in ones.
+pub fn normalize_and_format_with_genre_map(
+    path: PathBuf,
+    json: bool,
+    genre_map: Option<&GenreMap>,
+) -> Result<String, String> {
+    let options = NormalizeOptions {
+        genre_map,
+        ..NormalizeOptions::default()
+    };
+    normalize_and_format_with_options(path, json, &options)
+}
+
+/// Run every normalization enabled by `options` on `path` and collect the
+/// reports without formatting them.
+pub fn normalization_report(
+    path: PathBuf,
+    options: &NormalizeOptions,
+) -> Result<CombinedNormalizationReport, String> {
     let title_reports = normalize_titles_internal(path.clone())?;
-    let genre_reports = normalize_genres_internal(path.clone())?;
+    let genre_reports = normalize_genres_internal(path.clone(), options.genre_map)?;
     let artist_reports = normalize_artists_internal(path.clone())?;
     let album_reports = normalize_albums_internal(path.clone())?;
+    let punctuation_reports = if options.unicode_punctuation {
+        normalize_punctuation_internal(path.clone())?
+    } else {
+        Vec::new()
+    };
+    let noise_reports = match options.strip_phrases {
+        Some(phrases) => normalize_noise_internal(path.clone(), phrases)?,
+        None => normalize_noise_internal(path.clone(), DEFAULT_NOISE_PHRASES)?,
+    };
+    let comment_reports = match options.strip_comments {
+        Some(patterns) => normalize_comments_internal(path.clone(), patterns, options.apply)?,
+        None => Vec::new(),
+    };
+    let artist_order_reports = match options.artist_order {
+        Some(order) => normalize_artist_order_internal(path.clone(), order)?,
+        None => Vec::new(),
+    };
+    let genre_constraint_reports = match options.allowed_genres {
+        Some(allowed) => genre_constraint_reports(&genre_reports, allowed, options.genre_map),
+        None => Vec::new(),
+    };
     let year_reports = normalize_years_internal(path)?;
 
+    Ok(CombinedNormalizationReport {
+        title_reports,
+        genre_reports,
+        artist_reports,
+        album_reports,
+        year_reports,
+        punctuation_reports,
+        noise_reports,
+        comment_reports,
+        artist_order_reports,
+        genre_constraint_reports,
+        summary: "Combined normalization report".to_string(),
+    })
+}
+
+impl CombinedNormalizationReport {
+    /// Per-file outcome of the report.
+    ///
+    /// Without `applied` every proposed change counts; with it only comment
+    /// removals were written, so other proposals count as unchanged.
+    pub fn mutation_summary(&self, applied: bool) -> MutationSummary {
+        let mut summary = MutationSummary::new();
+        let mut record = |path: &PathBuf, changed: bool, error: bool| {
+            let outcome = if error {
+                FileOutcome::Error
+            } else if changed && !applied {
+                FileOutcome::Changed
+            } else {
+                FileOutcome::Unchanged
+            };
+            summary.record(path, outcome);
+        };
+        for r in &self.title_reports {
+            record(&r.original_path, r.changed, r.error.is_some());
+        }
+        for r in &self.genre_reports {
+            record(&r.original_path, r.changed, r.error.is_some());
+        }
+        for r in &self.artist_reports {
+            record(&r.original_path, r.changed, r.error.is_some());
+        }
+        for r in &self.album_reports {
+            record(&r.original_path, r.changed, r.error.is_some());
+        }
+        for r in &self.year_reports {
+            record(&r.original_path, r.changed, r.error.is_some());
+        }
+        for r in &self.punctuation_reports {
+            record(&r.original_path, r.changed, false);
+        }
+        for r in &self.noise_reports {
+            record(&r.original_path, r.changed, false);
+        }
+        for r in &self.artist_order_reports {
+            record(&r.original_path, true, false);
+        }
+        for r in &self.genre_constraint_reports {
+            record(&r.original_path, true, false);
+        }
+        for r in &self.comment_reports {
+            let outcome = match (&r.error, r.applied) {
+                (Some(_), _) => FileOutcome::Error,
+                (None, true) => FileOutcome::Changed,
+                (None, false) if applied => FileOutcome::Unchanged,
+                (None, false) => FileOutcome::Changed,
+            };
+            summary.record(&r.original_path, outcome);
+        }
+        summary
+    }
+}
+
+/// Like [`normalize_and_format`], configured by `options`.
+pub fn normalize_and_format_with_options(
+    path: PathBuf,
+    json: bool,
+    options: &NormalizeOptions,
+) -> Result<String, String> {
+    let report = normalization_report(path, options)?;
+    let summary = report.mutation_summary(options.apply);
+
     if json {
-        let combined_report = CombinedNormalizationReport {
+        serde_json::to_string_pretty(&report)
+            .map_err(|e| format!("Error serializing combined reports to JSON: {}", e))
+    } else {
+        let CombinedNormalizationReport {
             title_reports,
             genre_reports,
             artist_reports,
             album_reports,
             year_reports,
-            summary: "Combined normalization report".to_string(),
-        };
-        serde_json::to_string_pretty(&combined_report)
-            .map_err(|e| format!("Error serializing combined reports to JSON: {}", e))
-    } else {
+            punctuation_reports,
+            noise_reports,
+            comment_reports,
+            artist_order_reports,
+            genre_constraint_reports,
+            ..
+        } = report;
         let mut out = String::new();
 
         // Title reports
@@ -764,6 +1576,139 @@ pub fn normalize_and_format(path: PathBuf, json: bool) -> Result<String, String>
             year_updated_count, year_no_change_count, year_error_count
         ));
 
+        if options.unicode_punctuation {
+            let mut punctuation_updated_count = 0;
+            let mut punctuation_no_change_count = 0;
+
+            out.push_str("\n--- Punctuation Normalization ---\n");
+            for report in punctuation_reports {
+                if report.changed {
+                    out.push_str(&format!(
+                        "NORMALIZED: {} '{}' -> '{}' in {}\n",
+                        report.field,
+                        report.original_value,
+                        report.normalized_value,
+                        report.original_path.display()
+                    ));
+                    punctuation_updated_count += 1;
+                } else {
+                    punctuation_no_change_count += 1;
+                }
+            }
+            out.push_str(&format!(
+                "Punctuation Summary: {} normalized, {} no change\n",
+                punctuation_updated_count, punctuation_no_change_count
+            ));
+        }
+
+        let mut noise_updated_count = 0;
+        let mut noise_no_change_count = 0;
+
+        out.push_str("\n--- Title Noise Phrases ---\n");
+        for report in noise_reports {
+            if report.changed {
+                out.push_str(&format!(
+                    "STRIPPED: Title '{}' -> '{}' in {}\n",
+                    report.original_title,
+                    report.stripped_title,
+                    report.original_path.display()
+                ));
+                noise_updated_count += 1;
+            } else {
+                noise_no_change_count += 1;
+            }
+        }
+        out.push_str(&format!(
+            "Noise Phrase Summary: {} stripped, {} no change\n",
+            noise_updated_count, noise_no_change_count
+        ));
+
+        if let Some(order) = options.artist_order {
+            out.push_str(&format!(
+                "\n--- Artist Order ({}) ---\n",
+                match order {
+                    ArtistOrder::FirstLast => "first-last",
+                    ArtistOrder::LastFirst => "last-first",
+                }
+            ));
+            for report in &artist_order_reports {
+                out.push_str(&format!(
+                    "REORDER: {} '{}' -> '{}' in {}\n",
+                    report.field,
+                    report.original_value,
+                    report.reordered_value,
+                    report.original_path.display()
+                ));
+            }
+            out.push_str(&format!(
+                "Artist Order Summary: {} to reorder (write them with --report-only and apply-manifest)\n",
+                artist_order_reports.len()
+            ));
+        }
+
+        if let Some(allowed) = options.allowed_genres {
+            out.push_str(&format!(
+                "\n--- Genre Vocabulary ({} allowed) ---\n",
+                allowed.len()
+            ));
+            for report in &genre_constraint_reports {
+                out.push_str(&format!(
+                    "REMAPPED: Genre '{}' -> '{}' in {}\n",
+                    report.normalized_genre,
+                    report.constrained_genre,
+                    report.original_path.display()
+                ));
+            }
+            out.push_str(&format!(
+                "Genre Vocabulary Summary: {} remapped\n",
+                genre_constraint_reports.len()
+            ));
+        }
+
+        if options.strip_comments.is_some() {
+            let mut comment_stripped_count = 0;
+            let mut comment_error_count = 0;
+
+            out.push_str("\n--- Comment Stripping ---\n");
+            for report in comment_reports {
+                if let Some(ref error) = report.error {
+                    out.push_str(&format!(
+                        "ERROR: {} for {}\n",
+                        error,
+                        report.original_path.display()
+                    ));
+                    comment_error_count += 1;
+                } else {
+                    out.push_str(&format!(
+                        "{}: Comment '{}' (matched '{}') in {}\n",
+                        if report.applied {
+                            "STRIPPED"
+                        } else {
+                            "WOULD STRIP"
+                        },
+                        report.original_comment,
+                        report.pattern,
+                        report.original_path.display()
+                    ));
+                    comment_stripped_count += 1;
+                }
+            }
+            if options.apply {
+                out.push_str(&format!(
+                    "Comment Summary: {} stripped, {} errors\n",
+                    comment_stripped_count, comment_error_count
+                ));
+            } else {
+                out.push_str(&format!(
+                    "Comment Summary: {} to strip (use --apply to write)\n",
+                    comment_stripped_count
+                ));
+            }
+        }
+
+        out.push('\n');
+        out.push_str(&summary.line(!options.apply));
+        out.push('\n');
         Ok(out)
     }
 }
@@ -777,8 +1722,11 @@ fn normalize_single_track(track: Track) -> TitleNormalizationReport {
         title.clone() // Clone here to own the string
     } else {
         if let Some(file_stem_str) = original_path.file_stem().and_then(|s| s.to_str()) {
-            // Check if file_stem is empty or just an extension
-            if file_stem_str.is_empty() || file_stem_str.starts_with('.') {
+            // Check if file_stem is empty, just an extension, or just a track number
+            if file_stem_str.is_empty()
+                || file_stem_str.starts_with('.')
+                || infer_track_number_from_path(&original_path).is_some()
+            {
                 return TitleNormalizationReport {
                     original_path,
                     original_title: original_title_from_metadata, // This is None
@@ -832,6 +1780,52 @@ mod tests {
     use super::*;
     use crate::core::domain::models::TrackMetadata;
 
+    #[test]
+    fn test_last_first_to_first_last_spares_band_names() {
+        assert_eq!(
+            last_first_to_first_last("Beethoven, Ludwig van").as_deref(),
+            Some("Ludwig van Beethoven")
+        );
+        assert_eq!(
+            last_first_to_first_last("Bach, J.S.").as_deref(),
+            Some("J.S. Bach")
+        );
+        assert_eq!(last_first_to_first_last("Earth, Wind & Fire"), None);
+        assert_eq!(
+            last_first_to_first_last("Crosby, Stills, Nash & Young"),
+            None
+        );
+        assert_eq!(last_first_to_first_last("Tyler, the Creator"), None);
+        assert_eq!(last_first_to_first_last("Ludwig van Beethoven"), None);
+        assert_eq!(parse_artist_order("Last-First"), Ok(ArtistOrder::LastFirst));
+        assert!(parse_artist_order("surname").is_err());
+    }
+
+    #[test]
+    fn test_artist_order_reports_last_first_only_known_names() {
+        let track = |path: &str, artist: &str| {
+            crate::core::builders::TrackBuilder::new(path)
+                .artist(artist)
+                .build()
+        };
+        let tracks = vec![
+            track("a.flac", "Beethoven, Ludwig van"),
+            track("b.flac", "Ludwig van Beethoven"),
+            track("c.flac", "Pink Floyd"),
+        ];
+
+        let first_last = artist_order_reports(&tracks, ArtistOrder::FirstLast);
+        assert_eq!(first_last.len(), 1);
+        assert_eq!(first_last[0].original_path, PathBuf::from("a.flac"));
+        assert_eq!(first_last[0].reordered_value, "Ludwig van Beethoven");
+
+        let last_first = artist_order_reports(&tracks, ArtistOrder::LastFirst);
+        assert_eq!(last_first.len(), 1);
+        assert_eq!(last_first[0].original_path, PathBuf::from("b.flac"));
+        assert_eq!(last_first[0].field, "artist");
+        assert_eq!(last_first[0].reordered_value, "Beethoven, Ludwig van");
+    }
+
     #[test]
     fn test_to_title_case() {
         assert_eq!(to_title_case("hello world"), "Hello World");
@@ -908,6 +1902,37 @@ mod tests {
         assert_eq!(normalize_genre(""), None);
     }
 
+    #[test]
+    fn test_constrain_genre_prefers_exact_then_hierarchy() {
+        let allowed = vec!["Rock".to_string(), "Jazz".to_string(), "indie".to_string()];
+        assert_eq!(constrain_genre("ROCK", &allowed), "Rock");
+        assert_eq!(constrain_genre("bebop", &allowed), "Jazz");
+        assert_eq!(constrain_genre("Shoegaze", &allowed), "Rock");
+        // Allowed entries keep their own spelling
+        assert_eq!(constrain_genre("indie rock", &allowed), "indie");
+        // Values that fit nowhere are skipped
+        assert_eq!(constrain_genre("Polka/Jazz", &allowed), "Jazz");
+    }
+
+    #[test]
+    fn test_constrain_genre_falls_back_to_other() {
+        let allowed = vec!["Rock".to_string()];
+        assert_eq!(constrain_genre("Techno", &allowed), UNMATCHED_GENRE);
+        assert_eq!(constrain_genre("Custom Genre", &allowed), "Other");
+        assert_eq!(constrain_genre("Rock", &[]), "Other");
+    }
+
+    #[test]
+    fn test_constrain_genre_with_map_uses_user_roots() {
+        let map = GenreMap::from_tsv("synthwave\tSynthwave\tRetro").unwrap();
+        let allowed = vec!["Retro".to_string(), "Electronic".to_string()];
+        assert_eq!(
+            constrain_genre_with_map("synthwave", &allowed, Some(&map)),
+            "Retro"
+        );
+        assert_eq!(constrain_genre("synthwave", &allowed), "Electronic");
+    }
+
     #[test]
     fn test_normalize_genre_jazz_aliases() {
         assert_eq!(normalize_genre("smooth jazz"), Some("Jazz".to_string()));
@@ -921,16 +1946,9 @@ mod tests {
             file_path: PathBuf::from("/music/artist/album/track.flac"),
             metadata: TrackMetadata {
                 title: Some(MetadataValue::user_set("a test title".to_string())),
-                artist: None,
-                album: None,
-                album_artist: None,
-                track_number: None,
-                disc_number: None,
-                year: None,
-                genre: None,
-                duration: None,
                 format: "flac".to_string(),
                 path: PathBuf::from(""),
+                ..Default::default()
             },
             checksum: None,
         };
@@ -947,16 +1965,9 @@ mod tests {
             file_path: PathBuf::from("/music/artist/album/track.flac"),
             metadata: TrackMetadata {
                 title: Some(MetadataValue::user_set("Already Normalized".to_string())),
-                artist: None,
-                album: None,
-                album_artist: None,
-                track_number: None,
-                disc_number: None,
-                year: None,
-                genre: None,
-                duration: None,
                 format: "flac".to_string(),
                 path: PathBuf::from(""),
+                ..Default::default()
             },
             checksum: None,
         };
@@ -979,16 +1990,9 @@ mod tests {
             file_path: PathBuf::from("/music/file_without_title.flac"),
             metadata: TrackMetadata {
                 title: None, // Explicitly no title in metadata
-                artist: None,
-                album: None,
-                album_artist: None,
-                track_number: None,
-                disc_number: None,
-                year: None,
-                genre: None,
-                duration: None,
                 format: "flac".to_string(),
                 path: PathBuf::from(""),
+                ..Default::default()
             },
             checksum: None,
         };
@@ -1011,17 +2015,9 @@ mod tests {
         let track = Track {
             file_path: PathBuf::from("/music/.flac"), // File with no stem
             metadata: TrackMetadata {
-                title: None,
-                artist: None,
-                album: None,
-                album_artist: None,
-                track_number: None,
-                disc_number: None,
-                year: None,
-                genre: None,
-                duration: None,
                 format: "flac".to_string(),
                 path: PathBuf::from(""),
+                ..Default::default()
             },
             checksum: None,
         };
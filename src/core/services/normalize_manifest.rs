@@ -0,0 +1,480 @@
+//! Two-phase normalization through a reviewable change manifest.
+//!
+//! `normalize --report-only` records every proposed change in a JSON
+//! manifest without touching any file. `apply-manifest` (or `normalize
+//! --apply-manifest` for part of a library) writes the reviewed manifest
+//! back, but only to files whose tags still hold the recorded old values.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use serde_json::to_string_pretty;
+
+use crate::adapters::audio_formats as formats;
+use crate::core::builders::TrackMetadataBuilder;
+use crate::core::domain::models::{MetadataSource, TrackMetadata};
+use crate::core::domain::schema_version::SCHEMA_VERSION;
+use crate::core::domain::with_schema_version;
+use crate::core::services::mutation_summary::{FileOutcome, MutationSummary};
+use crate::core::services::normalization::CombinedNormalizationReport;
+
+/// Tag fields a manifest can change.
+pub const MANIFEST_FIELDS: &[&str] = &[
+    "title",
+    "artist",
+    "album",
+    "album_artist",
+    "genre",
+    "comment",
+];
+
+/// One proposed tag change.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct ManifestChange {
+    pub path: PathBuf,
+    /// One of [`MANIFEST_FIELDS`].
+    pub field: String,
+    /// Tag value when the manifest was written; `None` when the tag was absent.
+    pub old: Option<String>,
+    /// Value to write; `None` removes the tag (comments only).
+    pub new: Option<String>,
+    /// Normalization rule that proposed the change, e.g. `title_case`.
+    pub rule: String,
+}
+
+/// Every change proposed by one `normalize --report-only` run.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct NormalizeManifest {
+    pub changes: Vec<ManifestChange>,
+}
+
+/// Collects manifest changes, keeping the first change per file and field.
+#[derive(Default)]
+struct ManifestBuilder {
+    manifest: NormalizeManifest,
+    /// `(path, field)` pairs already in `manifest`, so a push is O(1)
+    seen: HashSet<(PathBuf, String)>,
+}
+
+impl ManifestBuilder {
+    fn push(
+        &mut self,
+        path: &Path,
+        field: &str,
+        old: Option<String>,
+        new: Option<String>,
+        rule: &str,
+    ) {
+        if new.is_none() && field != "comment" {
+            return;
+        }
+        if self.seen.insert((path.to_path_buf(), field.to_string())) {
+            self.manifest.changes.push(ManifestChange {
+                path: path.to_path_buf(),
+                field: field.to_string(),
+                old,
+                new,
+                rule: rule.to_string(),
+            });
+        }
+    }
+}
+
+/// What happened to one [`ManifestChange`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ManifestApplyStatus {
+    /// Written to the file.
+    Applied,
+    /// Not written because the file could not be read or written, or
+    /// another change to the same file conflicted.
+    Skipped,
+    /// Not written because the field no longer holds the recorded old value.
+    Conflicted,
+}
+
+/// Outcome of applying one [`ManifestChange`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct ManifestApplyResult {
+    pub path: PathBuf,
+    pub field: String,
+    pub old: Option<String>,
+    pub new: Option<String>,
+    pub status: ManifestApplyStatus,
+    pub error: Option<String>,
+}
+
+impl NormalizeManifest {
+    /// Collect the changed fields of a normalization report.
+    ///
+    /// A field gets at most one change per file: when several rules touch it
+    /// (e.g. title case and noise phrases), the first one listed wins and the
+    /// others show up on the next run after applying.
+    pub fn from_report(report: &CombinedNormalizationReport) -> Self {
+        let mut manifest = ManifestBuilder::default();
+
+        // Requested explicitly, so it wins over title casing the artist
+        for r in &report.artist_order_reports {
+            manifest.push(
+                &r.original_path,
+                &r.field,
+                Some(r.original_value.clone()),
+                Some(r.reordered_value.clone()),
+                "artist_order",
+            );
+        }
+        for r in report.title_reports.iter().filter(|r| r.changed) {
+            // The report falls back to the file name when the tag is missing
+            let old = formats::read_metadata(&r.original_path)
+                .ok()
+                .and_then(|track| track.metadata.title)
+                .map(|title| title.value);
+            manifest.push(
+                &r.original_path,
+                "title",
+                old,
+                r.normalized_title.clone(),
+                "title_case",
+            );
+        }
+        for r in report.noise_reports.iter().filter(|r| r.changed) {
+            manifest.push(
+                &r.original_path,
+                "title",
+                Some(r.original_title.clone()),
+                Some(r.stripped_title.clone()),
+                "noise_phrases",
+            );
+        }
+        // The vocabulary is the stricter rule, so it wins over plain normalization
+        for r in &report.genre_constraint_reports {
+            manifest.push(
+                &r.original_path,
+                "genre",
+                Some(r.original_genre.clone()),
+                Some(r.constrained_genre.clone()),
+                "genre_vocabulary",
+            );
+        }
+        for r in report.genre_reports.iter().filter(|r| r.changed) {
+            manifest.push(
+                &r.original_path,
+                "genre",
+                r.original_genre.clone(),
+                r.normalized_genre.clone(),
+                "genre",
+            );
+        }
+        for r in report.artist_reports.iter().filter(|r| r.changed) {
+            manifest.push(
+                &r.original_path,
+                "artist",
+                r.original_artist.clone(),
+                r.normalized_artist.clone(),
+                "title_case",
+            );
+        }
+        for r in report.album_reports.iter().filter(|r| r.changed) {
+            manifest.push(
+                &r.original_path,
+                "album",
+                r.original_album.clone(),
+                r.normalized_album.clone(),
+                "title_case",
+            );
+        }
+        for r in report.punctuation_reports.iter().filter(|r| r.changed) {
+            manifest.push(
+                &r.original_path,
+                &r.field,
+                Some(r.original_value.clone()),
+                Some(r.normalized_value.clone()),
+                "unicode_punctuation",
+            );
+        }
+        for r in report
+            .comment_reports
+            .iter()
+            .filter(|r| !r.applied && r.error.is_none())
+        {
+            manifest.push(
+                &r.original_path,
+                "comment",
+                Some(r.original_comment.clone()),
+                None,
+                "strip_comment",
+            );
+        }
+
+        manifest.manifest
+    }
+
+    /// Serialize as JSON with the `__schema_version` envelope field.
+    pub fn to_json(&self) -> Result<String, String> {
+        to_string_pretty(&with_schema_version(self))
+            .map_err(|e| format!("Error serializing manifest to JSON: {}", e))
+    }
+
+    /// Parse a manifest written by [`NormalizeManifest::to_json`], rejecting
+    /// unknown fields and removals of anything but a comment.
+    ///
+    /// The `__schema_version` envelope field is optional; when present it must
+    /// match [`SCHEMA_VERSION`].
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let mut value: serde_json::Value =
+            serde_json::from_str(json).map_err(|e| format!("Invalid manifest JSON: {}", e))?;
+
+        if let Some(version) = value
+            .as_object_mut()
+            .and_then(|object| object.remove("__schema_version"))
+            && version.as_str() != Some(SCHEMA_VERSION)
+        {
+            return Err(format!(
+                "Unsupported schema version {} (expected \"{}\")",
+                version, SCHEMA_VERSION
+            ));
+        }
+
+        let manifest: Self =
+            serde_json::from_value(value).map_err(|e| format!("Invalid manifest JSON: {}", e))?;
+        for (i, change) in manifest.changes.iter().enumerate() {
+            if !MANIFEST_FIELDS.contains(&change.field.as_str()) {
+                return Err(format!(
+                    "Change {}: unsupported field '{}' (expected one of: {})",
+                    i + 1,
+                    change.field,
+                    MANIFEST_FIELDS.join(", ")
+                ));
+            }
+            if change.new.is_none() && change.field != "comment" {
+                return Err(format!(
+                    "Change {}: only the comment can be removed, '{}' needs a new value",
+                    i + 1,
+                    change.field
+                ));
+            }
+        }
+        Ok(manifest)
+    }
+
+    /// Read and validate a manifest file.
+    pub fn from_file(path: &Path) -> Result<Self, String> {
+        let json = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read manifest {}: {}", path.display(), e))?;
+        Self::from_json(&json)
+    }
+
+    /// The changes for files under `scope` (or `scope` itself when it is a
+    /// file).
+    pub fn within(&self, scope: &Path) -> Self {
+        Self {
+            changes: self
+                .changes
+                .iter()
+                .filter(|c| c.path.starts_with(scope))
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+/// Current value of a manifest field.
+fn field_value(metadata: &TrackMetadata, field: &str) -> Option<String> {
+    let value = match field {
+        "title" => &metadata.title,
+        "artist" => &metadata.artist,
+        "album" => &metadata.album,
+        "album_artist" => &metadata.album_artist,
+        "genre" => &metadata.genre,
+        "comment" => &metadata.comment,
+        _ => return None,
+    };
+    value.as_ref().map(|v| v.value.clone())
+}
+
+/// Apply every change of `manifest`, grouped per file.
+///
+/// A file is only written when every one of its changes still matches the
+/// file's current tags; otherwise the mismatching changes are
+/// [`Conflicted`](ManifestApplyStatus::Conflicted), the rest of that file's
+/// changes are [`Skipped`](ManifestApplyStatus::Skipped), and the file is
+/// left alone.
+pub fn apply_manifest(manifest: &NormalizeManifest) -> Vec<ManifestApplyResult> {
+    let mut files: Vec<(&Path, Vec<&ManifestChange>)> = Vec::new();
+    let mut index: HashMap<&Path, usize> = HashMap::new();
+    for change in &manifest.changes {
+        let i = *index.entry(&change.path).or_insert_with(|| {
+            files.push((&change.path, Vec::new()));
+            files.len() - 1
+        });
+        files[i].1.push(change);
+    }
+
+    let mut results = Vec::new();
+    for (path, changes) in files {
+        let outcomes = apply_file_changes(path, &changes);
+        for (change, (status, error)) in changes.into_iter().zip(outcomes) {
+            results.push(ManifestApplyResult {
+                path: change.path.clone(),
+                field: change.field.clone(),
+                old: change.old.clone(),
+                new: change.new.clone(),
+                status,
+                error,
+            });
+        }
+    }
+    results
+}
+
+type ChangeOutcome = (ManifestApplyStatus, Option<String>);
+
+/// Validate and write one file's changes, returning an outcome per change.
+fn apply_file_changes(path: &Path, changes: &[&ManifestChange]) -> Vec<ChangeOutcome> {
+    let skipped = |error: String| (ManifestApplyStatus::Skipped, Some(error));
+
+    let track = match formats::read_metadata(path) {
+        Ok(track) => track,
+        Err(e) => return vec![skipped(format!("Failed to read: {}", e)); changes.len()],
+    };
+
+    let conflicts: Vec<Option<String>> = changes
+        .iter()
+        .map(|change| {
+            let current = field_value(&track.metadata, &change.field);
+            (current != change.old).then(|| {
+                format!(
+                    "{} is now '{}', manifest expected '{}'",
+                    change.field,
+                    current.unwrap_or_default(),
+                    change.old.clone().unwrap_or_default()
+                )
+            })
+        })
+        .collect();
+    if conflicts.iter().any(Option::is_some) {
+        return conflicts
+            .into_iter()
+            .map(|conflict| match conflict {
+                Some(error) => (ManifestApplyStatus::Conflicted, Some(error)),
+                None => skipped(
+                    "another field of this file changed since the manifest was written".to_string(),
+                ),
+            })
+            .collect();
+    }
+
+    let mut update = TrackMetadataBuilder::new(path).format(track.metadata.format);
+    for change in changes {
+        // An empty comment tells the format handlers to remove the tag
+        let value = change.new.clone().unwrap_or_default();
+        let source = MetadataSource::UserEdited;
+        update = match change.field.as_str() {
+            "title" => update.title(value, source, 1.0),
+            "artist" => update.artist(value, source, 1.0),
+            "album" => update.album(value, source, 1.0),
+            "album_artist" => update.album_artist(value, source, 1.0),
+            "genre" => update.genre(value, source, 1.0),
+            _ => update.comment(value, source, 1.0),
+        };
+    }
+
+    let outcome = match formats::write_metadata(path, &update.build()) {
+        Ok(()) => (ManifestApplyStatus::Applied, None),
+        Err(e) => skipped(e.to_string()),
+    };
+    vec![outcome; changes.len()]
+}
+
+/// Format apply results as text, one line per change, the applied, skipped
+/// and conflicted counts, and the per-file [`MutationSummary`] line.
+pub fn format_apply_results(results: &[ManifestApplyResult]) -> String {
+    let mut out = String::new();
+    for result in results {
+        let label = match result.status {
+            ManifestApplyStatus::Applied => "APPLIED",
+            ManifestApplyStatus::Skipped => "SKIPPED",
+            ManifestApplyStatus::Conflicted => "CONFLICT",
+        };
+        out.push_str(&format!(
+            "{}: {} '{}' -> '{}' in {}",
+            label,
+            result.field,
+            result.old.as_deref().unwrap_or_default(),
+            result.new.as_deref().unwrap_or_default(),
+            result.path.display()
+        ));
+        if let Some(ref error) = result.error {
+            out.push_str(&format!(" ({})", error));
+        }
+        out.push('\n');
+    }
+    let count = |status| results.iter().filter(|r| r.status == status).count();
+    out.push_str(&format!(
+        "Manifest Summary: {} applied, {} skipped, {} conflicted",
+        count(ManifestApplyStatus::Applied),
+        count(ManifestApplyStatus::Skipped),
+        count(ManifestApplyStatus::Conflicted)
+    ));
+
+    // Conflicted and skipped files were left alone because of a problem
+    let mut summary = MutationSummary::new();
+    for result in results {
+        let outcome = match result.status {
+            ManifestApplyStatus::Applied => FileOutcome::Changed,
+            _ => FileOutcome::Error,
+        };
+        summary.record(&result.path, outcome);
+    }
+    out.push('\n');
+    out.push_str(&summary.line(false));
+    out
+}
+
+// ── Tests ───────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(field: &str, new: Option<&str>) -> ManifestChange {
+        ManifestChange {
+            path: PathBuf::from("/music/a.flac"),
+            field: field.to_string(),
+            old: Some("old".to_string()),
+            new: new.map(str::to_string),
+            rule: "title_case".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_manifest_json_round_trip() {
+        let manifest = NormalizeManifest {
+            changes: vec![change("title", Some("New")), change("comment", None)],
+        };
+
+        let json = manifest.to_json().unwrap();
+        assert!(json.contains("\"__schema_version\""));
+        assert_eq!(NormalizeManifest::from_json(&json).unwrap(), manifest);
+    }
+
+    #[test]
+    fn test_manifest_rejects_invalid_changes() {
+        let unknown = NormalizeManifest {
+            changes: vec![change("year", Some("2001"))],
+        };
+        let err = NormalizeManifest::from_json(&unknown.to_json().unwrap()).unwrap_err();
+        assert!(err.contains("unsupported field 'year'"));
+
+        let removal = NormalizeManifest {
+            changes: vec![change("title", None)],
+        };
+        let err = NormalizeManifest::from_json(&removal.to_json().unwrap()).unwrap_err();
+        assert!(err.contains("only the comment can be removed"));
+
+        let err = NormalizeManifest::from_json(r#"{"__schema_version": "0.1", "changes": []}"#)
+            .unwrap_err();
+        assert!(err.contains("Unsupported schema version"));
+    }
+}
@@ -0,0 +1,297 @@
+//! Move plans that bring a library in line with a path template.
+//!
+//! Each file's destination is its metadata expanded into a template such as
+//! `{artist}/{album}/{track} - {title}.{ext}`, relative to the library root
+//! (see [`expand_template`]), optionally transliterated to ASCII for devices
+//! that cannot handle other filenames. Planning never touches the files; only
+//! [`apply_reorganization`] moves them, and never over an existing file.
+
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::core::services::normalization::ascii_transliterate;
+use crate::core::services::scanner::scan_dir_with_metadata;
+use crate::core::services::validation::structure_validation::{
+    album_track_widths, check_template, expand_template, sanitize_component,
+};
+
+/// What happens (or happened) to one file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MoveStatus {
+    /// Would be moved; the plan has not been applied.
+    Planned,
+    Moved,
+    /// Already at its template path.
+    InPlace,
+    /// Left alone; `message` says why.
+    Skipped,
+    Error,
+}
+
+/// One file of a [`ReorganizePlan`].
+#[derive(Debug, Clone, PartialEq, Serialize, schemars::JsonSchema)]
+pub struct PlannedMove {
+    pub source: PathBuf,
+    /// `None` when the template could not be expanded for this file.
+    pub destination: Option<PathBuf>,
+    pub status: MoveStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// Moves for every audio file under `root`, sorted by source path.
+#[derive(Debug, Clone, PartialEq, Serialize, schemars::JsonSchema)]
+pub struct ReorganizePlan {
+    pub root: PathBuf,
+    pub template: String,
+    pub applied: bool,
+    pub moves: Vec<PlannedMove>,
+}
+
+impl ReorganizePlan {
+    /// Paths the plan reads or writes: every source and destination.
+    pub fn touched_paths(&self) -> impl Iterator<Item = &Path> {
+        self.moves
+            .iter()
+            .flat_map(|m| std::iter::once(m.source.as_path()).chain(m.destination.as_deref()))
+    }
+
+    pub fn count(&self, status: MoveStatus) -> usize {
+        self.moves.iter().filter(|m| m.status == status).count()
+    }
+}
+
+/// Plan moving every audio file under `root` to `root/<template path>`.
+///
+/// A file is skipped when its metadata can't fill the template, when the
+/// expanded path would leave `root`, when its destination already exists,
+/// or when another file is planned for the same destination.
+///
+/// With `ascii`, destination paths are transliterated to ASCII (`é` → `e`,
+/// `Кино` → `Kino`); this only affects filenames, the tags are not touched.
+pub fn plan_reorganization(
+    root: &Path,
+    template: &str,
+    ascii: bool,
+) -> Result<ReorganizePlan, String> {
+    check_template(template)?;
+    if !root.is_dir() {
+        return Err(format!("Not a directory: {}", root.display()));
+    }
+    let mut tracks =
+        scan_dir_with_metadata(root).map_err(|e| format!("Failed to scan directory: {}", e))?;
+    tracks.sort_by(|a, b| a.file_path.cmp(&b.file_path));
+
+    let mut moves: Vec<PlannedMove> = tracks
+        .iter()
+        .zip(album_track_widths(&tracks))
+        .map(|(track, width)| {
+            let source = track.file_path.clone();
+            let relative = match expand_template(template, &track.metadata, &source, width) {
+                Ok(relative) if ascii => ascii_path(&relative),
+                Ok(relative) => PathBuf::from(relative),
+                Err(missing) => {
+                    return skipped(
+                        source,
+                        None,
+                        format!("Missing metadata: {}", missing.join(", ")),
+                    );
+                }
+            };
+            if relative
+                .components()
+                .any(|c| !matches!(c, Component::Normal(_)))
+            {
+                return skipped(
+                    source,
+                    Some(root.join(relative)),
+                    "Template path leaves the library root".to_string(),
+                );
+            }
+
+            let destination = root.join(relative);
+            let status = if destination == source {
+                MoveStatus::InPlace
+            } else {
+                MoveStatus::Planned
+            };
+            PlannedMove {
+                source,
+                destination: Some(destination),
+                status,
+                message: None,
+            }
+        })
+        .collect();
+
+    // The first file (by path) keeps a contested destination
+    let mut claimed: HashMap<PathBuf, PathBuf> = HashMap::new();
+    for planned in &mut moves {
+        let Some(destination) = planned.destination.clone() else {
+            continue;
+        };
+        if planned.status == MoveStatus::Skipped {
+            continue;
+        }
+        if let Some(first) = claimed.get(&destination) {
+            planned.status = MoveStatus::Skipped;
+            planned.message = Some(format!("Destination also planned for {}", first.display()));
+            continue;
+        }
+        claimed.insert(destination.clone(), planned.source.clone());
+        if planned.status == MoveStatus::Planned && destination.exists() {
+            planned.status = MoveStatus::Skipped;
+            planned.message = Some("Destination already exists".to_string());
+        }
+    }
+
+    Ok(ReorganizePlan {
+        root: root.to_path_buf(),
+        template: template.to_string(),
+        applied: false,
+        moves,
+    })
+}
+
+/// Transliterates each component of an expanded template path to ASCII.
+///
+/// Components are sanitized again, so a transliteration such as `½` → `1/2`
+/// cannot add a folder.
+fn ascii_path(relative: &str) -> PathBuf {
+    relative
+        .split('/')
+        .map(|component| sanitize_component(&ascii_transliterate(component)))
+        .collect()
+}
+
+fn skipped(source: PathBuf, destination: Option<PathBuf>, message: String) -> PlannedMove {
+    PlannedMove {
+        source,
+        destination,
+        status: MoveStatus::Skipped,
+        message: Some(message),
+    }
+}
+
+/// Carry out the planned moves, creating destination folders as needed.
+///
+/// A destination that appeared since planning is left alone and reported as
+/// an error; other moves go ahead.
+pub fn apply_reorganization(plan: &mut ReorganizePlan) {
+    for planned in &mut plan.moves {
+        if planned.status != MoveStatus::Planned {
+            continue;
+        }
+        let Some(destination) = &planned.destination else {
+            continue;
+        };
+        let result = if destination.exists() {
+            Err("Destination already exists".to_string())
+        } else {
+            destination
+                .parent()
+                .map_or(Ok(()), std::fs::create_dir_all)
+                .and_then(|()| std::fs::rename(&planned.source, destination))
+                .map_err(|e| e.to_string())
+        };
+        match result {
+            Ok(()) => planned.status = MoveStatus::Moved,
+            Err(e) => {
+                planned.status = MoveStatus::Error;
+                planned.message = Some(e);
+            }
+        }
+    }
+    plan.applied = true;
+}
+
+// ── Tests ───────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const TEMPLATE: &str = "{artist}/{album}/{track} - {title}.{ext}";
+
+    fn library() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        for name in ["a.flac", "b.flac"] {
+            std::fs::copy(
+                "tests/fixtures/flac/simple/track1.flac",
+                dir.path().join(name),
+            )
+            .unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn test_plan_skips_second_file_for_same_destination() {
+        let dir = library();
+        let plan = plan_reorganization(dir.path(), TEMPLATE, false).unwrap();
+
+        assert!(!plan.applied);
+        assert_eq!(plan.moves.len(), 2);
+        assert_eq!(plan.moves[0].status, MoveStatus::Planned);
+        assert!(
+            plan.moves[0]
+                .destination
+                .as_ref()
+                .unwrap()
+                .ends_with("Test Artist/Test Album/01 - Test Apply Behavior.flac")
+        );
+        assert_eq!(plan.moves[1].status, MoveStatus::Skipped);
+        assert!(dir.path().join("a.flac").exists());
+    }
+
+    #[test]
+    fn test_apply_moves_planned_files() {
+        let dir = library();
+        let mut plan = plan_reorganization(dir.path(), TEMPLATE, false).unwrap();
+        apply_reorganization(&mut plan);
+
+        assert!(plan.applied);
+        assert_eq!(plan.count(MoveStatus::Moved), 1);
+        assert!(!dir.path().join("a.flac").exists());
+        assert!(plan.moves[0].destination.as_ref().unwrap().exists());
+
+        let again = plan_reorganization(dir.path(), TEMPLATE, false).unwrap();
+        assert_eq!(again.count(MoveStatus::InPlace), 1);
+    }
+
+    #[test]
+    fn test_plan_transliterates_destination_to_ascii() {
+        let dir = TempDir::new().unwrap();
+        let source = dir.path().join("a.flac");
+        std::fs::copy("tests/fixtures/flac/simple/track1.flac", &source).unwrap();
+        let mut metadata = crate::adapters::audio_formats::read_metadata(&source)
+            .unwrap()
+            .metadata;
+        metadata.artist = Some(crate::MetadataValue::embedded("Кино".to_string()));
+        metadata.title = Some(crate::MetadataValue::embedded("Café ½".to_string()));
+        crate::adapters::audio_formats::write_metadata(&source, &metadata).unwrap();
+
+        let plan = plan_reorganization(dir.path(), TEMPLATE, true).unwrap();
+
+        assert_eq!(
+            plan.moves[0].destination.as_deref(),
+            Some(
+                dir.path()
+                    .join("Kino/Test Album/01 - Cafe 1_2.flac")
+                    .as_path()
+            )
+        );
+        let tags = crate::adapters::audio_formats::read_metadata(&source).unwrap();
+        assert_eq!(tags.metadata.artist.unwrap().value, "Кино");
+    }
+
+    #[test]
+    fn test_plan_rejects_unknown_placeholders() {
+        let dir = library();
+        assert!(plan_reorganization(dir.path(), "{artist}/{label}.{ext}", false).is_err());
+    }
+}
@@ -2,6 +2,8 @@
 
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use glob::Pattern;
 use log::{error, warn};
@@ -13,7 +15,10 @@ use crate::core::domain::models::{
     FOLDER_INFERRED_CONFIDENCE, MetadataSource, MetadataValue, Track, TrackMetadata,
 };
 use crate::core::services::cue::parse_cue_file;
-use crate::core::services::inference::{infer_album_from_path, infer_artist_from_path};
+use crate::core::services::inference::{
+    infer_album_from_folder, infer_album_from_path, infer_artist_from_path,
+    infer_track_number_from_path,
+};
 
 // ── Shared helpers ──────────────────────────────────────────────────────────
 
@@ -22,6 +27,27 @@ fn supported_extensions() -> HashSet<String> {
     formats::get_supported_extensions().into_iter().collect()
 }
 
+/// Runs `f` on a pool of `threads` workers, or on the global pool (one
+/// worker per core) when `threads` is `None`.
+fn with_thread_pool<R: Send>(threads: Option<usize>, f: impl FnOnce() -> R + Send) -> R {
+    let pool = threads.and_then(|n| {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(n)
+            .build()
+            .inspect_err(|e| warn!(target: "music_chore", "Cannot start {n} scan threads: {e}"))
+            .ok()
+    });
+    match pool {
+        Some(pool) => pool.install(f),
+        None => f(),
+    }
+}
+
+/// Locks a mutex shared by scan workers, ignoring poisoning by a panicked worker.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
 /// Returns `true` when `path` has a supported audio extension.
 fn is_supported(path: &Path, exts: &HashSet<String>) -> bool {
     path.extension()
@@ -29,9 +55,12 @@ fn is_supported(path: &Path, exts: &HashSet<String>) -> bool {
         .is_some_and(|e| exts.contains(&e.to_lowercase()))
 }
 
-/// Returns `true` for known audio extensions we don't currently support.
+/// Returns `true` for audio extensions musicctl has handlers for, so files of a
+/// format whose handler is compiled out are reported as unsupported.
 fn has_known_audio_ext(path: &Path) -> bool {
-    const KNOWN: &[&str] = &["mp3", "flac", "wav", "dsf", "wv"];
+    const KNOWN: &[&str] = &[
+        "mp3", "flac", "wav", "ogg", "opus", "m4a", "dsf", "wv", "ape",
+    ];
     path.extension()
         .and_then(|e| e.to_str())
         .is_some_and(|e| KNOWN.contains(&e.to_lowercase().as_str()))
@@ -65,6 +94,27 @@ fn matches_any_pattern(path: &Path, patterns: &[String]) -> bool {
     })
 }
 
+/// Size of `path` in bytes when it exceeds `limit`, from a `stat` only.
+fn size_over_limit(path: &Path, limit: Option<u64>) -> Option<u64> {
+    let limit = limit?;
+    std::fs::metadata(path)
+        .ok()
+        .map(|m| m.len())
+        .filter(|&len| len > limit)
+}
+
+/// Returns `true` when no cutoff is given or `path` was modified after it.
+///
+/// Files whose modification time cannot be read are excluded when filtering.
+fn modified_after(path: &Path, since: Option<SystemTime>) -> bool {
+    let Some(since) = since else {
+        return true;
+    };
+    std::fs::metadata(path)
+        .and_then(|m| m.modified())
+        .is_ok_and(|modified| modified > since)
+}
+
 /// Lowercased file extension, or `"unknown"`.
 fn file_format(path: &Path) -> String {
     path.extension()
@@ -133,10 +183,15 @@ fn cleaned_filename(name: &str) -> String {
 /// 1. Folder-inferred album from directory structure
 /// 2. Heuristic extraction from filename
 /// 3. Cleaned filename as last resort
-fn infer_album(path: &Path) -> Option<MetadataValue<String>> {
+///
+/// Steps 2 and 3 only run with `filename_fallback`.
+fn infer_album(path: &Path, filename_fallback: bool) -> Option<MetadataValue<String>> {
     if let Some(album) = infer_album_from_path(path) {
         return Some(MetadataValue::inferred(album, FOLDER_INFERRED_CONFIDENCE));
     }
+    if !filename_fallback {
+        return None;
+    }
     let stem = path.file_stem().and_then(|n| n.to_str())?;
     if let Some(album) = album_from_filename(stem) {
         return Some(MetadataValue::inferred(album, FOLDER_INFERRED_CONFIDENCE));
@@ -146,29 +201,35 @@ fn infer_album(path: &Path) -> Option<MetadataValue<String>> {
 }
 
 /// Builds `TrackMetadata` from path inference only (no embedded tag reading).
-fn inferred_metadata(path: &Path) -> TrackMetadata {
+///
+/// A numeric-only filename ("01.flac") gives the track number and no title.
+pub(crate) fn inferred_metadata(path: &Path, filename_album_fallback: bool) -> TrackMetadata {
+    let track_number = infer_track_number_from_path(path);
     TrackMetadata {
         title: path
             .file_stem()
             .and_then(|n| n.to_str())
+            .filter(|_| track_number.is_none())
             .map(|s| MetadataValue::inferred(s.to_string(), FOLDER_INFERRED_CONFIDENCE)),
         artist: infer_artist_from_path(path)
             .map(|a| MetadataValue::inferred(a, FOLDER_INFERRED_CONFIDENCE)),
-        album: infer_album(path),
-        album_artist: None,
-        track_number: None,
-        disc_number: None,
-        year: None,
-        genre: None,
-        duration: None,
+        album: infer_album(path, filename_album_fallback),
+        track_number: track_number.map(|n| MetadataValue::inferred(n, FOLDER_INFERRED_CONFIDENCE)),
         format: file_format(path),
         path: path.to_path_buf(),
+        ..Default::default()
     }
 }
 
 /// Reads embedded tags, then fills any missing fields via path inference.
-fn full_metadata(path: &Path) -> TrackMetadata {
-    let embedded = formats::read_metadata(path).ok();
+///
+/// Also returns the read error, if any, for the scan error log.
+fn full_metadata(path: &Path, filename_album_fallback: bool) -> (TrackMetadata, Option<String>) {
+    let (embedded, read_error) = match formats::read_metadata(path) {
+        Ok(track) => (Some(track), None),
+        // The error log record already names the file
+        Err(e) => (None, Some(e.kind().to_string())),
+    };
 
     let mut md = match embedded {
         Some(track) => TrackMetadata {
@@ -177,17 +238,9 @@ fn full_metadata(path: &Path) -> TrackMetadata {
             ..track.metadata
         },
         None => TrackMetadata {
-            title: None,
-            artist: None,
-            album: None,
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
-            year: None,
-            genre: None,
-            duration: None,
             format: file_format(path),
             path: path.to_path_buf(),
+            ..Default::default()
         },
     };
 
@@ -197,10 +250,14 @@ fn full_metadata(path: &Path) -> TrackMetadata {
             .map(|a| MetadataValue::inferred(a, FOLDER_INFERRED_CONFIDENCE));
     }
     if md.album.is_none() {
-        md.album = infer_album(path);
+        md.album = infer_album(path, filename_album_fallback);
+    }
+    if md.track_number.is_none() {
+        md.track_number = infer_track_number_from_path(path)
+            .map(|n| MetadataValue::inferred(n, FOLDER_INFERRED_CONFIDENCE));
     }
 
-    md
+    (md, read_error)
 }
 
 // ── Display helpers ─────────────────────────────────────────────────────────
@@ -287,7 +344,7 @@ pub fn scan_dir_immediate(base: &Path) -> Vec<PathBuf> {
 /// Recursively scan and return file paths, skipping symlinks.
 pub fn scan_dir_paths(base: &Path) -> Vec<PathBuf> {
     let exts = supported_extensions();
-    let mut paths: Vec<PathBuf> = walk(base, None, false)
+    let mut paths: Vec<PathBuf> = walk(base, None, false, &[])
         .map(|e| e.into_path())
         .filter(|p| {
             !is_symlink(p) && p.is_file() && is_supported(p, &exts) && {
@@ -306,9 +363,13 @@ pub fn scan_dir_paths(base: &Path) -> Vec<PathBuf> {
 
 /// Scan and read full metadata for all supported files under `base`.
 pub fn scan_dir_with_metadata(base: &Path) -> Result<Vec<Track>, String> {
+    if base.is_file() {
+        return scan_single_file(base).map(|track| vec![track]);
+    }
+
     let mut map = BTreeMap::new();
 
-    for entry in walk(base, None, false) {
+    for entry in walk(base, None, false, &[]) {
         let path = entry.path();
         if is_symlink(path) || !path.is_file() || !formats::is_format_supported(path) {
             continue;
@@ -322,11 +383,7 @@ pub fn scan_dir_with_metadata(base: &Path) -> Result<Vec<Track>, String> {
                 map.insert(path.to_path_buf(), track);
             }
             Err(e) => {
-                eprintln!(
-                    "Warning: Failed to read metadata for {}: {}",
-                    path.display(),
-                    e
-                );
+                eprintln!("Warning: Failed to read metadata for {}", e);
             }
         }
     }
@@ -334,41 +391,83 @@ pub fn scan_dir_with_metadata(base: &Path) -> Result<Vec<Track>, String> {
     Ok(map.into_values().collect())
 }
 
+/// Read one audio file as a one-track scan, for commands pointed at a file
+/// instead of a library directory.
+///
+/// Unlike a directory scan, which skips what it can't read, this fails with
+/// the reason: unsupported format, empty or unreadable file.
+pub fn scan_single_file(path: &Path) -> Result<Track, String> {
+    if !formats::is_format_supported(path) {
+        let mut supported: Vec<String> = supported_extensions().into_iter().collect();
+        supported.sort();
+        return Err(format!(
+            "Unsupported audio format: {} (supported: {})",
+            path.display(),
+            supported.join(", ")
+        ));
+    }
+    validate_file(path).map_err(|e| format!("Cannot read {}: {}", path.display(), e))?;
+    formats::read_metadata(path).map_err(|e| format!("Failed to read {}", e))
+}
+
+/// How duplicate detection fingerprints a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChecksumMode {
+    /// SHA-256 of the file bytes: only byte-identical copies match.
+    #[default]
+    FileBytes,
+    /// MD5 of the decoded PCM samples, so a FLAC and a WAV of the same audio
+    /// match. Only FLAC and PCM WAV files have one; other files are skipped.
+    PcmContent,
+}
+
 /// Scan for tracks and detect duplicates by checksum.
 pub fn scan_with_duplicates(
     base: &Path,
     verbose: bool,
     parallel: Option<usize>,
 ) -> (Vec<Track>, Vec<Vec<Track>>) {
-    if let Some(threads) = parallel {
-        let _ = rayon::ThreadPoolBuilder::new()
-            .num_threads(threads)
-            .build_global();
-    }
-
-    let tracks = scan_dir(base, true);
+    scan_with_duplicates_with_mode(base, verbose, parallel, ChecksumMode::FileBytes)
+}
 
-    let all: Vec<Track> = tracks
-        .into_par_iter()
-        .map(|mut track| {
-            if verbose {
-                println!("Scanning {}...", track.file_path.display());
-            }
-            match track.calculate_checksum() {
-                Ok(cs) => {
-                    track.checksum = Some(cs);
+/// Like [`scan_with_duplicates`], fingerprinting files with the given mode.
+pub fn scan_with_duplicates_with_mode(
+    base: &Path,
+    verbose: bool,
+    parallel: Option<usize>,
+    mode: ChecksumMode,
+) -> (Vec<Track>, Vec<Vec<Track>>) {
+    let tracks = canonical_dedupe(scan_dir(base, true));
+
+    let all: Vec<Track> = with_thread_pool(parallel, || {
+        tracks
+            .into_par_iter()
+            .map(|mut track| {
+                if verbose {
+                    println!("Scanning {}...", track.file_path.display());
                 }
-                Err(e) => {
-                    eprintln!(
-                        "Warning: checksum failed for {}: {}",
-                        track.file_path.display(),
-                        e,
-                    );
+                let checksum: Result<Option<String>, Box<dyn std::error::Error>> = match mode {
+                    ChecksumMode::FileBytes => track.calculate_checksum().map(Some),
+                    ChecksumMode::PcmContent => {
+                        formats::pcm::pcm_checksum(&track.file_path).map_err(Into::into)
+                    }
+                };
+                match checksum {
+                    Ok(cs) => {
+                        track.checksum = cs;
+                    }
+                    Err(e) => {
+                        eprintln!(
+                            "Warning: checksum failed for {}: {}",
+                            track.file_path.display(),
+                            e,
+                        );
+                    }
                 }
-            }
-            track
-        })
-        .collect();
+                track
+            })
+            .collect()
+    });
 
     let mut by_checksum: HashMap<String, Vec<Track>> = HashMap::new();
     for track in &all {
@@ -425,12 +524,209 @@ pub fn scan_tracks(path: PathBuf, json_output: bool) -> Result<String, String> {
     }
 }
 
+/// Which side wins when a CUE sheet and a file's embedded tags both give a
+/// title, artist, album, album artist, track number, year or genre.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CuePrecedence {
+    /// Embedded tags win for split albums (one audio file per CUE track), the
+    /// CUE wins for single-file albums, whose tags describe the whole image.
+    #[default]
+    Auto,
+    /// CUE values win; embedded tags only fill gaps.
+    Cue,
+    /// Embedded tags win; CUE values only fill gaps.
+    Embedded,
+}
+
+impl CuePrecedence {
+    /// Whether embedded tags win for a sheet referencing `audio_files` files.
+    pub fn embedded_wins(self, audio_files: usize) -> bool {
+        match self {
+            CuePrecedence::Auto => audio_files > 1,
+            CuePrecedence::Cue => false,
+            CuePrecedence::Embedded => true,
+        }
+    }
+}
+
+/// Parses a `--cue-precedence` value: `auto`, `cue` or `embedded`.
+pub fn parse_cue_precedence(input: &str) -> Result<CuePrecedence, String> {
+    match input.to_ascii_lowercase().as_str() {
+        "auto" => Ok(CuePrecedence::Auto),
+        "cue" => Ok(CuePrecedence::Cue),
+        "embedded" => Ok(CuePrecedence::Embedded),
+        _ => Err(format!(
+            "Invalid CUE precedence '{input}': expected auto, cue or embedded"
+        )),
+    }
+}
+
+/// Where a scanned track's album comes from (`scan --album-from`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AlbumFrom {
+    /// The embedded album tag; the folder name only fills a missing tag.
+    #[default]
+    Tag,
+    /// The album folder name, as cleaned by folder inference, skipping disc
+    /// folders such as `CD1`. The tag (or CUE title) is kept when the path
+    /// has no album folder.
+    Folder,
+}
+
+/// Parses a `--album-from` value: `tag` or `folder`.
+pub fn parse_album_from(input: &str) -> Result<AlbumFrom, String> {
+    match input.to_ascii_lowercase().as_str() {
+        "tag" => Ok(AlbumFrom::Tag),
+        "folder" => Ok(AlbumFrom::Folder),
+        _ => Err(format!(
+            "Invalid album source '{input}': expected tag or folder"
+        )),
+    }
+}
+
+/// Replaces the album with the album folder's name, if the path has one.
+fn prefer_folder_album(md: &mut TrackMetadata, path: &Path) {
+    if let Some(album) = infer_album_from_folder(path) {
+        md.album = Some(MetadataValue::inferred(album, FOLDER_INFERRED_CONFIDENCE));
+    }
+}
+
+/// Combines a track built from a CUE sheet with the tags embedded in its
+/// audio file.
+///
+/// The fields both can provide come from the winning side and fall back to
+/// the other; only values actually read from the tags count as embedded, so
+/// folder-inferred guesses never beat the CUE. From the rest of the embedded
+/// tags only stream properties and album-level fields are kept: an image
+/// file's per-track tags (ARTISTS, track gain, recording ID, BPM, ...) do not
+/// describe the individual CUE tracks. The duration and path come from the CUE.
+pub fn merge_cue_metadata(
+    cue: TrackMetadata,
+    embedded: TrackMetadata,
+    embedded_wins: bool,
+) -> TrackMetadata {
+    fn pick<T>(
+        cue: Option<MetadataValue<T>>,
+        embedded: Option<MetadataValue<T>>,
+        embedded_wins: bool,
+    ) -> Option<MetadataValue<T>> {
+        let embedded = embedded.filter(|v| v.source == MetadataSource::Embedded);
+        if embedded_wins {
+            embedded.or(cue)
+        } else {
+            cue.or(embedded)
+        }
+    }
+
+    TrackMetadata {
+        title: pick(cue.title, embedded.title, embedded_wins),
+        artist: pick(cue.artist, embedded.artist, embedded_wins),
+        album: pick(cue.album, embedded.album, embedded_wins),
+        album_artist: pick(cue.album_artist, embedded.album_artist, embedded_wins),
+        track_number: pick(cue.track_number, embedded.track_number, embedded_wins),
+        year: pick(cue.year, embedded.year, embedded_wins),
+        genre: pick(cue.genre, embedded.genre, embedded_wins),
+        duration: cue.duration,
+        album_gain: embedded.album_gain,
+        album_peak: embedded.album_peak,
+        sample_rate: embedded.sample_rate,
+        bit_depth: embedded.bit_depth,
+        lossy: embedded.lossy,
+        media: embedded.media,
+        barcode: embedded.barcode,
+        compilation: embedded.compilation,
+        original_year: embedded.original_year,
+        musicbrainz_release_id: embedded.musicbrainz_release_id,
+        format: cue.format,
+        path: cue.path,
+        ..Default::default()
+    }
+}
+
+/// Options for [`scan_with_options`].
+#[derive(Debug, Clone, Default)]
+pub struct ScanOptions {
+    /// Maximum recursion depth (`None` = unlimited, `0` = immediate files only).
+    pub max_depth: Option<usize>,
+    /// Follow symbolic links while walking.
+    pub follow_symlinks: bool,
+    /// Glob patterns for paths to exclude.
+    pub exclude_patterns: Vec<String>,
+    /// Directories skipped with everything under them, without being walked.
+    /// Relative paths are taken relative to the scan base.
+    pub exclude_under: Vec<PathBuf>,
+    /// Skip reading file metadata; rely solely on paths.
+    pub skip_metadata: bool,
+    /// Only include files modified strictly after this time.
+    pub modified_since: Option<SystemTime>,
+    /// Drop tracks that resolve to a file already seen via another path.
+    pub dedupe_paths: bool,
+    /// Record progress in this checkpoint file and resume from it when it
+    /// already exists. The checkpoint is deleted once the scan completes.
+    pub checkpoint: Option<PathBuf>,
+    /// Write a JSON-lines record for every skipped or unreadable file here.
+    pub error_log: Option<PathBuf>,
+    /// Number of threads reading metadata (`None` = one per core, `1` = serial).
+    pub concurrency: Option<usize>,
+    /// Report audio files next to a CUE sheet as single tracks instead of
+    /// expanding them into the sheet's virtual tracks.
+    pub skip_cue_expansion: bool,
+    /// File name of the CUE sheet to use when a directory holds several;
+    /// otherwise the first by name is used.
+    pub preferred_cue: Option<String>,
+    /// Whether CUE or embedded values win for CUE-expanded tracks.
+    pub cue_precedence: CuePrecedence,
+    /// Whether the embedded album tag or the album folder name wins.
+    pub album_from: AlbumFrom,
+    /// Order of the returned tracks.
+    pub sort: ScanSort,
+    /// Skip individual audio files larger than this many bytes without
+    /// reading them (`None` = unlimited). CUE-backed images are not limited.
+    pub max_file_size: Option<u64>,
+    /// Clear inferred title, artist, album and genre values with a lower
+    /// confidence than this (`0.0` keeps everything).
+    pub drop_below: f32,
+    /// Leave the album empty instead of deriving it from the file name when
+    /// neither the tags nor the folders name one.
+    pub skip_filename_album_fallback: bool,
+}
+
+/// Order of [`scan_with_options`] results.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ScanSort {
+    /// By file name; stable across metadata edits.
+    #[default]
+    Filename,
+    /// By artist, album, disc, track number and title (see [`compare_by_metadata`]).
+    Metadata,
+}
+
+/// Orders tracks by artist, album, disc, track number and title.
+///
+/// Text is compared case-insensitively and tracks missing a value sort
+/// before those that have one. Ties fall back to the full path so the order
+/// is always deterministic.
+pub fn compare_by_metadata(a: &Track, b: &Track) -> std::cmp::Ordering {
+    fn text(value: &Option<MetadataValue<String>>) -> Option<String> {
+        value.as_ref().map(|v| v.value.to_lowercase())
+    }
+    fn number(value: &Option<MetadataValue<u32>>) -> Option<u32> {
+        value.as_ref().map(|v| v.value)
+    }
+    let (ma, mb) = (&a.metadata, &b.metadata);
+    text(&ma.artist)
+        .cmp(&text(&mb.artist))
+        .then_with(|| text(&ma.album).cmp(&text(&mb.album)))
+        .then_with(|| number(&ma.disc_number).cmp(&number(&mb.disc_number)))
+        .then_with(|| number(&ma.track_number).cmp(&number(&mb.track_number)))
+        .then_with(|| text(&ma.title).cmp(&text(&mb.title)))
+        .then_with(|| a.file_path.cmp(&b.file_path))
+}
+
 /// Full-featured directory scan with depth limit, symlink handling, exclude
 /// patterns, and optional metadata reading.
 ///
-/// - CUE sheets in album directories are parsed first (unless `skip_metadata`).
-/// - Files in CUE-handled directories are not re-scanned individually.
-/// - Results are sorted by filename for deterministic output.
+/// Equivalent to [`scan_with_options`] without a modification-time filter.
 pub fn scan_dir_with_options(
     base: &Path,
     max_depth: Option<usize>,
@@ -438,25 +734,119 @@ pub fn scan_dir_with_options(
     exclude_patterns: Vec<String>,
     skip_metadata: bool,
 ) -> Vec<Track> {
+    scan_with_options(
+        base,
+        &ScanOptions {
+            max_depth,
+            follow_symlinks,
+            exclude_patterns,
+            skip_metadata,
+            ..ScanOptions::default()
+        },
+    )
+}
+
+/// Scans `base` according to `options`.
+///
+/// - Directories in `exclude_under` are not walked at all.
+/// - CUE sheets in album directories are parsed first (unless `skip_metadata`
+///   or `skip_cue_expansion`).
+/// - A directory with several CUE sheets uses `preferred_cue` or the first by
+///   name, and is reported as [`ScanErrorKind::MultipleCueFiles`].
+/// - CUE tracks are merged with their files' embedded tags, the winner
+///   chosen by `cue_precedence`.
+/// - Files in CUE-handled directories are not re-scanned individually.
+/// - With `modified_since`, only audio files modified after that time are kept.
+/// - With `max_file_size`, larger audio files are skipped before any read and
+///   reported as [`ScanErrorKind::TooLarge`].
+/// - With `checkpoint`, files already recorded there are not read again,
+///   unless they changed size or modification time since, or were recorded
+///   under different metadata options.
+/// - With `error_log`, per-file problems are recorded as [`ScanErrorRecord`]s.
+/// - With [`AlbumFrom::Folder`], the album folder name replaces the album tag
+///   or CUE title, for CUE tracks too.
+/// - Metadata is read on `concurrency` threads (default: one per core).
+/// - Results are sorted by filename, or by metadata with [`ScanSort::Metadata`].
+pub fn scan_with_options(base: &Path, options: &ScanOptions) -> Vec<Track> {
+    scan_profiled(base, options, None)
+}
+
+/// Like [`scan_with_options`], also timing each scan phase.
+pub fn scan_with_profile(base: &Path, options: &ScanOptions) -> (Vec<Track>, ScanProfile) {
+    let mut profile = ScanProfile::default();
+    let tracks = scan_profiled(base, options, Some(&mut profile));
+    (tracks, profile)
+}
+
+fn scan_profiled(
+    base: &Path,
+    options: &ScanOptions,
+    profile: Option<&mut ScanProfile>,
+) -> Vec<Track> {
+    let ScanOptions {
+        max_depth,
+        follow_symlinks,
+        ref exclude_patterns,
+        ref exclude_under,
+        skip_metadata,
+        modified_since,
+        dedupe_paths,
+        ref checkpoint,
+        ref error_log,
+        concurrency,
+        skip_cue_expansion,
+        ref preferred_cue,
+        cue_precedence,
+        album_from,
+        sort,
+        max_file_size: _,
+        drop_below,
+        skip_filename_album_fallback,
+    } = *options;
     let exts = supported_extensions();
+    let mut error_log = error_log.as_deref().and_then(open_error_log);
     let mut tracks = Vec::new();
     let mut cue_dirs: HashSet<PathBuf> = HashSet::new();
+    let mut clock = profile.is_some().then(Instant::now);
+    let exclude_under = excluded_dirs(base, exclude_under);
 
     // ── Pass 1: CUE-based tracks ────────────────────────────────────────
-    if !skip_metadata {
-        for entry in walk(base, max_depth, follow_symlinks) {
+    if !skip_metadata && !skip_cue_expansion {
+        for entry in walk(base, max_depth, follow_symlinks, &exclude_under) {
             let path = entry.path();
-            if matches_any_pattern(path, &exclude_patterns) || !path.is_dir() {
+            if matches_any_pattern(path, exclude_patterns) || !path.is_dir() {
                 continue;
             }
 
-            let Some(cue_path) = find_cue_in_dir(path) else {
+            let cues = cue_files_in_dir(path);
+            let Some(cue_path) = choose_cue(&cues, preferred_cue.as_deref()).cloned() else {
                 continue;
             };
+            if cues.len() > 1 {
+                let names: Vec<String> = cues
+                    .iter()
+                    .filter_map(|c| c.file_name())
+                    .map(|n| n.to_string_lossy().into_owned())
+                    .collect();
+                let message = format!(
+                    "{} CUE sheets in one directory ({}); using {}",
+                    cues.len(),
+                    names.join(", "),
+                    cue_path.file_name().unwrap_or_default().to_string_lossy(),
+                );
+                warn!(target: "music_chore", "{}: {}", path.display(), message);
+                log_scan_error(
+                    &mut error_log,
+                    path,
+                    ScanErrorKind::MultipleCueFiles,
+                    message,
+                );
+            }
             let cue = match parse_cue_file(&cue_path) {
                 Ok(c) => c,
                 Err(e) => {
                     log::debug!(target: "music_chore", "Failed to parse CUE {}: {e}", cue_path.display());
+                    log_scan_error(&mut error_log, &cue_path, ScanErrorKind::CueParse, e);
                     continue;
                 }
             };
@@ -482,9 +872,20 @@ pub fn scan_dir_with_options(
                 .as_deref()
                 .map(|g| MetadataValue::cue_inferred(g.to_string(), 1.0));
 
+            let audio_files: HashSet<&str> = cue
+                .tracks
+                .iter()
+                .filter_map(|t| t.file.as_deref())
+                .collect();
+            let embedded_wins = cue_precedence.embedded_wins(audio_files.len());
+            let mut embedded: HashMap<PathBuf, Option<TrackMetadata>> = HashMap::new();
+
             for ct in cue.tracks {
                 let Some(audio_name) = ct.file else { continue };
                 let audio_path = dir.join(&audio_name);
+                if !modified_after(&audio_path, modified_since) {
+                    continue;
+                }
                 let basic = read_basic_info(&audio_path).ok();
 
                 let artist = ct
@@ -499,23 +900,43 @@ pub fn scan_dir_with_options(
                     album: album.clone(),
                     album_artist: cue_performer.clone(),
                     track_number: Some(MetadataValue::cue_inferred(ct.number, 1.0)),
-                    disc_number: None,
                     year: year.clone(),
                     genre: genre.clone(),
                     duration: basic.as_ref().and_then(|b| b.duration.clone()),
                     format: basic.map_or("unknown".to_string(), |b| b.format),
                     path: audio_path.clone(),
+                    ..Default::default()
                 };
+                let tags = embedded.entry(audio_path.clone()).or_insert_with(|| {
+                    formats::read_metadata(&audio_path).ok().map(|t| t.metadata)
+                });
+                let mut md = match tags {
+                    Some(tags) => merge_cue_metadata(md, tags.clone(), embedded_wins),
+                    None => md,
+                };
+                if album_from == AlbumFrom::Folder {
+                    prefer_folder_album(&mut md, &audio_path);
+                }
                 tracks.push(Track::new(audio_path, md));
             }
             cue_dirs.insert(dir);
         }
     }
 
+    let cue_time = lap(&mut clock);
+
     // ── Pass 2: individual audio files ──────────────────────────────────
-    for entry in walk(base, max_depth, follow_symlinks) {
+    let mut resumed = checkpoint
+        .as_deref()
+        .map(load_checkpoint)
+        .unwrap_or_default();
+    let checkpoint_writer = checkpoint.as_deref().and_then(open_checkpoint);
+    let checkpoint_options = CheckpointOptions::from(options);
+    let mut pending = Vec::new();
+
+    for entry in walk(base, max_depth, follow_symlinks, &exclude_under) {
         let path = entry.path();
-        if matches_any_pattern(path, &exclude_patterns)
+        if matches_any_pattern(path, exclude_patterns)
             || !path.is_file()
             || is_symlink(path)
             || path.parent().is_some_and(|p| cue_dirs.contains(p))
@@ -523,56 +944,790 @@ pub fn scan_dir_with_options(
             continue;
         }
 
-        if !is_supported(path, &exts) {
-            if has_known_audio_ext(path) {
-                warn!(
-                    target: "music_chore",
-                    "Unsupported audio format: {} (supported: {})",
-                    path.display(),
-                    exts.iter().cloned().collect::<Vec<_>>().join(", "),
-                );
-            }
+        if !accept_audio_file(path, &exts, options, &mut error_log) {
             continue;
         }
 
-        if let Err(e) = validate_file(path) {
-            log::debug!(target: "music_chore", "Skipping invalid file {}: {}", path.display(), e);
+        if let Some(entry) = resumed.remove(path)
+            && entry.is_current(&checkpoint_options)
+        {
+            tracks.push(entry.track);
             continue;
         }
+        pending.push(path.to_path_buf());
+    }
 
-        let md = if skip_metadata {
-            inferred_metadata(path)
-        } else {
-            full_metadata(path)
+    let discovery_time = lap(&mut clock);
+
+    // Metadata reads are the slow part, so they run on the worker pool.
+    let error_log = Mutex::new(error_log);
+    let checkpoint_writer = Mutex::new(checkpoint_writer);
+    let read: Vec<Track> = with_thread_pool(concurrency, || {
+        pending
+            .par_iter()
+            .map(|path| {
+                let md = if skip_metadata {
+                    inferred_metadata(path, !skip_filename_album_fallback)
+                } else {
+                    let (mut md, read_error) = full_metadata(path, !skip_filename_album_fallback);
+                    if album_from == AlbumFrom::Folder {
+                        prefer_folder_album(&mut md, path);
+                    }
+                    if let Some(message) = read_error {
+                        log_scan_error(
+                            &mut lock(&error_log),
+                            path,
+                            ScanErrorKind::MetadataRead,
+                            message,
+                        );
+                    }
+                    md
+                };
+                let track = Track::new(path.clone(), md);
+                if let Some(writer) = lock(&checkpoint_writer).as_mut() {
+                    append_checkpoint(writer, &track, checkpoint_options);
+                }
+                track
+            })
+            .collect()
+    });
+    tracks.extend(read);
+    let metadata_time = lap(&mut clock);
+
+    if let Some(checkpoint) = checkpoint {
+        drop(checkpoint_writer);
+        if let Err(e) = std::fs::remove_file(checkpoint) {
+            log::debug!(target: "music_chore", "Could not remove checkpoint {}: {e}", checkpoint.display());
+        }
+    }
+
+    if drop_below > 0.0 {
+        for track in &mut tracks {
+            track.metadata.drop_inferred_below(drop_below);
+        }
+    }
+
+    match sort {
+        ScanSort::Filename => {
+            tracks.sort_by(|a, b| a.file_path.file_name().cmp(&b.file_path.file_name()))
+        }
+        ScanSort::Metadata => tracks.sort_by(compare_by_metadata),
+    }
+    if dedupe_paths {
+        tracks = canonical_dedupe(tracks);
+    }
+
+    if let Some(profile) = profile {
+        *profile = ScanProfile {
+            cue: cue_time,
+            discovery: discovery_time,
+            metadata: metadata_time,
+            sort: lap(&mut clock),
+            files: tracks.len(),
+        };
+    }
+    tracks
+}
+
+/// Pass-2 checks for a walked regular file: a supported extension, within
+/// `max_file_size`, a valid audio file and modified after `modified_since`.
+/// Rejections worth reporting go to the error log.
+fn accept_audio_file(
+    path: &Path,
+    exts: &HashSet<String>,
+    options: &ScanOptions,
+    error_log: &mut Option<std::fs::File>,
+) -> bool {
+    if !is_supported(path, exts) {
+        if has_known_audio_ext(path) {
+            let supported = exts.iter().cloned().collect::<Vec<_>>().join(", ");
+            warn!(
+                target: "music_chore",
+                "Unsupported audio format: {} (supported: {})",
+                path.display(),
+                supported,
+            );
+            log_scan_error(
+                error_log,
+                path,
+                ScanErrorKind::UnsupportedFormat,
+                format!("Unsupported audio format (supported: {})", supported),
+            );
+        }
+        return false;
+    }
+
+    if let Some(size) = size_over_limit(path, options.max_file_size) {
+        let message = format!(
+            "File is {} bytes, over the {} byte limit",
+            size,
+            options.max_file_size.unwrap_or_default()
+        );
+        warn!(target: "music_chore", "Skipping {}: {}", path.display(), message);
+        log_scan_error(error_log, path, ScanErrorKind::TooLarge, message);
+        return false;
+    }
+
+    if let Err(e) = validate_file(path) {
+        log::debug!(target: "music_chore", "Skipping invalid file {}: {}", path.display(), e);
+        log_scan_error(error_log, path, ScanErrorKind::InvalidFile, e);
+        return false;
+    }
+
+    modified_after(path, options.modified_since)
+}
+
+/// Audio file paths under `base` that [`scan_with_options`] would consider,
+/// found with the same discovery filters but without reading any metadata.
+///
+/// `max_depth`, `follow_symlinks`, `exclude_patterns`, `exclude_under`,
+/// `modified_since`, `max_file_size` and `error_log` apply; the metadata and
+/// CUE options do not. Paths are sorted.
+pub fn scan_paths_with_options(base: &Path, options: &ScanOptions) -> Vec<PathBuf> {
+    scan_paths_profiled(base, options, None)
+}
+
+/// Like [`scan_paths_with_options`], also timing discovery and sorting.
+pub fn scan_paths_with_profile(base: &Path, options: &ScanOptions) -> (Vec<PathBuf>, ScanProfile) {
+    let mut profile = ScanProfile::default();
+    let paths = scan_paths_profiled(base, options, Some(&mut profile));
+    (paths, profile)
+}
+
+fn scan_paths_profiled(
+    base: &Path,
+    options: &ScanOptions,
+    profile: Option<&mut ScanProfile>,
+) -> Vec<PathBuf> {
+    let exts = supported_extensions();
+    let mut error_log = options.error_log.as_deref().and_then(open_error_log);
+    let mut clock = profile.is_some().then(Instant::now);
+    let exclude_under = excluded_dirs(base, &options.exclude_under);
+
+    let mut paths: Vec<PathBuf> = walk(
+        base,
+        options.max_depth,
+        options.follow_symlinks,
+        &exclude_under,
+    )
+    .map(|e| e.into_path())
+    .filter(|p| {
+        !matches_any_pattern(p, &options.exclude_patterns)
+            && p.is_file()
+            && !is_symlink(p)
+            && accept_audio_file(p, &exts, options, &mut error_log)
+    })
+    .collect();
+    let discovery_time = lap(&mut clock);
+
+    paths.sort();
+    if let Some(profile) = profile {
+        *profile = ScanProfile {
+            discovery: discovery_time,
+            sort: lap(&mut clock),
+            files: paths.len(),
+            ..ScanProfile::default()
         };
-        tracks.push(Track::new(path.to_path_buf(), md));
     }
+    paths
+}
+
+// ── Scan profiling ──────────────────────────────────────────────────────────
+
+/// Time spent in each phase of a [`scan_with_profile`] run.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ScanProfile {
+    /// Parsing CUE sheets and reading the audio files they reference.
+    pub cue: Duration,
+    /// Walking the directory tree and filtering candidate files.
+    pub discovery: Duration,
+    /// Reading tags and stream properties (or inferring them from paths).
+    pub metadata: Duration,
+    /// Sorting and path de-duplication.
+    pub sort: Duration,
+    /// Tracks returned by the scan.
+    pub files: usize,
+}
+
+impl ScanProfile {
+    /// Total time across all phases.
+    pub fn total(&self) -> Duration {
+        self.cue + self.discovery + self.metadata + self.sort
+    }
+
+    /// Tracks returned per second of total scan time.
+    pub fn files_per_second(&self) -> f64 {
+        let secs = self.total().as_secs_f64();
+        if secs > 0.0 {
+            self.files as f64 / secs
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Format a [`ScanProfile`] as a phase timing table.
+pub fn format_scan_profile(profile: &ScanProfile) -> String {
+    let phases = [
+        ("discovery", profile.discovery),
+        ("cue sheets", profile.cue),
+        ("metadata", profile.metadata),
+        ("sorting", profile.sort),
+        ("total", profile.total()),
+    ];
+    let mut out = String::from("Scan profile:\n");
+    for (name, duration) in phases {
+        out.push_str(&format!(
+            "  {:<10} {:>9.3}s\n",
+            name,
+            duration.as_secs_f64()
+        ));
+    }
+    out.push_str(&format!(
+        "  {} files, {:.1} files/s",
+        profile.files,
+        profile.files_per_second()
+    ));
+    out
+}
+
+/// Time since the clock's last lap, restarting it; zero when not profiling.
+fn lap(clock: &mut Option<Instant>) -> Duration {
+    match clock {
+        Some(last) => {
+            let now = Instant::now();
+            let elapsed = now - *last;
+            *last = now;
+            elapsed
+        }
+        None => Duration::ZERO,
+    }
+}
+
+// ── Scan checkpoints ────────────────────────────────────────────────────────
+
+/// Default checkpoint location for scanning `base`.
+///
+/// Checkpoints live in the user cache directory (`$XDG_CACHE_HOME`, else
+/// `~/.cache`, else the system temp dir) under `musicctl/checkpoints`, named
+/// by a hash of the canonical base path so different libraries never collide.
+pub fn default_checkpoint_path(base: &Path) -> PathBuf {
+    use sha2::{Digest, Sha256};
+
+    let cache_dir = std::env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+        .unwrap_or_else(std::env::temp_dir);
+
+    let canonical = base.canonicalize().unwrap_or_else(|_| base.to_path_buf());
+    let digest = Sha256::digest(canonical.to_string_lossy().as_bytes());
+    let name: String = digest[..8].iter().map(|b| format!("{b:02x}")).collect();
+
+    cache_dir
+        .join("musicctl")
+        .join("checkpoints")
+        .join(format!("scan-{name}.jsonl"))
+}
+
+/// System directories that never hold a music library on their own.
+#[cfg(windows)]
+const DANGEROUS_SCAN_ROOTS: &[&str] = &[r"C:\Windows", r"C:\Program Files", r"C:\Users"];
+#[cfg(not(windows))]
+const DANGEROUS_SCAN_ROOTS: &[&str] = &[
+    "/home", "/Users", "/usr", "/var", "/etc", "/opt", "/proc", "/sys", "/dev", "/Volumes",
+];
+
+/// Whether scanning `path` would walk far more than a music library: a
+/// filesystem root (`/`, `C:\`), the user's home directory, or a system
+/// directory such as `/usr` or `C:\Windows`. `scan` refuses these without
+/// `--force`.
+pub fn is_dangerous_scan_root(path: &Path) -> bool {
+    let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    if path.parent().is_none() {
+        return true;
+    }
+    let home = std::env::var_os(if cfg!(windows) { "USERPROFILE" } else { "HOME" })
+        .map(PathBuf::from)
+        .filter(|home| !home.as_os_str().is_empty());
+    if let Some(home) = home {
+        let home = home.canonicalize().unwrap_or(home);
+        if path == home {
+            return true;
+        }
+    }
+    DANGEROUS_SCAN_ROOTS.iter().any(|root| {
+        let root = Path::new(root);
+        path == root || root.canonicalize().is_ok_and(|r| path == r)
+    })
+}
+
+/// The scan options a checkpointed track's metadata depends on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+struct CheckpointOptions {
+    skip_metadata: bool,
+    album_from_folder: bool,
+    filename_album_fallback: bool,
+}
+
+impl From<&ScanOptions> for CheckpointOptions {
+    fn from(options: &ScanOptions) -> Self {
+        Self {
+            skip_metadata: options.skip_metadata,
+            album_from_folder: options.album_from == AlbumFrom::Folder,
+            filename_album_fallback: !options.skip_filename_album_fallback,
+        }
+    }
+}
+
+/// One checkpoint line: a scanned track with the state of its file and the
+/// options it was read with.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct CheckpointEntry {
+    track: Track,
+    modified: Option<SystemTime>,
+    size: u64,
+    options: CheckpointOptions,
+}
+
+impl CheckpointEntry {
+    /// Records `track` as its file is now; `None` when the file cannot be
+    /// stat'ed.
+    fn new(track: Track, options: CheckpointOptions) -> Option<Self> {
+        let meta = std::fs::metadata(&track.file_path).ok()?;
+        Some(Self {
+            modified: meta.modified().ok(),
+            size: meta.len(),
+            track,
+            options,
+        })
+    }
+
+    /// Whether the entry can stand in for reading its file again: same
+    /// options, and the file's size and modification time are unchanged.
+    fn is_current(&self, options: &CheckpointOptions) -> bool {
+        self.options == *options
+            && std::fs::metadata(&self.track.file_path)
+                .is_ok_and(|meta| meta.len() == self.size && meta.modified().ok() == self.modified)
+    }
+}
+
+/// Serializes the checkpoint line `scan_with_options` records for `track`
+/// when scanning with `options`, or `None` when its file cannot be stat'ed.
+pub fn checkpoint_line(track: &Track, options: &ScanOptions) -> Option<String> {
+    let entry = CheckpointEntry::new(track.clone(), CheckpointOptions::from(options))?;
+    serde_json::to_string(&entry).ok()
+}
+
+/// Loads the entries recorded in a checkpoint (one JSON entry per line).
+///
+/// A missing file yields nothing; unparseable lines, such as one cut short by
+/// an interrupted write or left by an older version, are skipped.
+fn load_checkpoint(path: &Path) -> HashMap<PathBuf, CheckpointEntry> {
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return HashMap::new();
+    };
+
+    content
+        .lines()
+        .filter_map(|line| serde_json::from_str::<CheckpointEntry>(line).ok())
+        .map(|entry| (entry.track.file_path.clone(), entry))
+        .collect()
+}
+
+/// Opens a checkpoint for appending, creating it and its directory if needed.
+fn open_checkpoint(path: &Path) -> Option<std::fs::File> {
+    if let Some(parent) = path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .inspect_err(
+            |e| warn!(target: "music_chore", "Cannot write checkpoint {}: {e}", path.display()),
+        )
+        .ok()
+}
+
+/// Appends one scanned track to the checkpoint.
+fn append_checkpoint(writer: &mut std::fs::File, track: &Track, options: CheckpointOptions) {
+    use std::io::Write;
+
+    let entry = CheckpointEntry::new(track.clone(), options);
+    if let Some(mut line) = entry.and_then(|entry| serde_json::to_string(&entry).ok()) {
+        line.push('\n');
+        if let Err(e) = writer.write_all(line.as_bytes()) {
+            warn!(target: "music_chore", "Failed to update checkpoint: {e}");
+        }
+    }
+}
+
+// ── Scan error log ──────────────────────────────────────────────────────────
+
+/// Why a file was skipped or only partially read during a scan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScanErrorKind {
+    /// Audio file of a format without a compiled-in handler.
+    UnsupportedFormat,
+    /// Empty or unreadable file; skipped.
+    InvalidFile,
+    /// Tags could not be read; the track relies on path inference.
+    MetadataRead,
+    /// CUE sheet could not be parsed; its directory is scanned file by file.
+    CueParse,
+    /// Directory holds several CUE sheets; only one of them was used.
+    MultipleCueFiles,
+    /// File is larger than the scan's size limit; skipped unread.
+    TooLarge,
+}
+
+/// One line of the scan error log.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ScanErrorRecord {
+    pub path: PathBuf,
+    pub error_kind: ScanErrorKind,
+    pub message: String,
+}
+
+/// Creates (or truncates) the error log, including its directory.
+fn open_error_log(path: &Path) -> Option<std::fs::File> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    std::fs::File::create(path)
+        .inspect_err(
+            |e| warn!(target: "music_chore", "Cannot write error log {}: {e}", path.display()),
+        )
+        .ok()
+}
+
+/// Appends one record to the error log, if one is open.
+fn log_scan_error(
+    writer: &mut Option<std::fs::File>,
+    path: &Path,
+    error_kind: ScanErrorKind,
+    message: impl ToString,
+) {
+    use std::io::Write;
+
+    let Some(writer) = writer.as_mut() else {
+        return;
+    };
+    let record = ScanErrorRecord {
+        path: path.to_path_buf(),
+        error_kind,
+        message: message.to_string(),
+    };
+    if let Ok(mut line) = serde_json::to_string(&record) {
+        line.push('\n');
+        if let Err(e) = writer.write_all(line.as_bytes()) {
+            warn!(target: "music_chore", "Failed to update error log: {e}");
+        }
+    }
+}
+
+/// Removes tracks that refer to a file already present under another path.
+///
+/// Paths differing only in case on case-insensitive filesystems, or reached
+/// through followed symlinks, resolve to the same file. On Unix files are
+/// identified by device and inode; elsewhere by canonicalized path. The
+/// first occurrence is kept and the original order preserved.
+pub fn canonical_dedupe(tracks: Vec<Track>) -> Vec<Track> {
+    let mut seen = HashSet::new();
+    tracks
+        .into_iter()
+        .filter(|t| seen.insert(file_key(&t.file_path)))
+        .collect()
+}
+
+/// Returns `track_path` relative to `root`.
+///
+/// Both paths are compared as given first and canonicalized as a fallback, so
+/// a relative scan base still rebases onto an absolute root. Errors when the
+/// track does not live under the root.
+pub fn rebase_path(track_path: &Path, root: &Path) -> Result<PathBuf, String> {
+    if let Ok(rel) = track_path.strip_prefix(root) {
+        return Ok(rel.to_path_buf());
+    }
+    if let (Ok(track), Ok(root_canon)) = (track_path.canonicalize(), root.canonicalize())
+        && let Ok(rel) = track.strip_prefix(&root_canon)
+    {
+        return Ok(rel.to_path_buf());
+    }
+    Err(format!(
+        "Track {} is not under root {}",
+        track_path.display(),
+        root.display()
+    ))
+}
 
-    tracks.sort_by(|a, b| a.file_path.file_name().cmp(&b.file_path.file_name()));
+/// Rewrites every track's file and metadata path relative to `root`.
+///
+/// Used by `scan --root`; fails on the first track outside the root.
+pub fn rebase_tracks(tracks: Vec<Track>, root: &Path) -> Result<Vec<Track>, String> {
     tracks
+        .into_iter()
+        .map(|mut track| {
+            let rel = rebase_path(&track.file_path, root)?;
+            track.metadata.path = rel.clone();
+            track.file_path = rel;
+            Ok(track)
+        })
+        .collect()
+}
+
+/// Parses a `--sort` value: `filename` or `metadata`.
+///
+/// The error message is shown to the user as-is.
+pub fn parse_scan_sort(input: &str) -> Result<ScanSort, String> {
+    match input.trim().to_ascii_lowercase().as_str() {
+        "filename" => Ok(ScanSort::Filename),
+        "metadata" => Ok(ScanSort::Metadata),
+        _ => Err(format!(
+            "invalid sort order '{input}' (expected 'filename' or 'metadata')"
+        )),
+    }
+}
+
+/// Parses a file size such as `500000`, `300K`, `200M` or `1.5G`.
+///
+/// Suffixes are binary multiples (`K` = 1024 bytes) and may be followed by
+/// `B` or `iB`. Used by `--max-file-size`; the error message is shown to the
+/// user as-is.
+pub fn parse_file_size(input: &str) -> Result<u64, String> {
+    let err = || format!("invalid file size '{input}' (expected e.g. 500M or 2G)");
+    let upper = input.trim().to_ascii_uppercase();
+    let trimmed = upper
+        .strip_suffix("IB")
+        .or_else(|| upper.strip_suffix('B'))
+        .unwrap_or(&upper);
+    let (number, multiplier) = match trimmed.char_indices().last() {
+        Some((i, 'K')) => (&trimmed[..i], 1u64 << 10),
+        Some((i, 'M')) => (&trimmed[..i], 1 << 20),
+        Some((i, 'G')) => (&trimmed[..i], 1 << 30),
+        Some((i, 'T')) => (&trimmed[..i], 1 << 40),
+        _ => (trimmed, 1),
+    };
+    let value: f64 = number.trim().parse().map_err(|_| err())?;
+    if !value.is_finite() || value <= 0.0 {
+        return Err(err());
+    }
+    Ok((value * multiplier as f64).round() as u64)
+}
+
+/// Parses a worker thread count, which must be at least 1.
+///
+/// Used by `--concurrency`; the error message is shown to the user as-is.
+pub fn parse_concurrency(input: &str) -> Result<usize, String> {
+    match input.trim().parse::<usize>() {
+        Ok(n) if n >= 1 => Ok(n),
+        _ => Err(format!(
+            "invalid thread count '{input}' (expected a whole number >= 1)"
+        )),
+    }
+}
+
+/// Parses a confidence threshold between 0 and 1.
+///
+/// Used by `scan --drop-below`; the error message is shown to the user as-is.
+pub fn parse_confidence(input: &str) -> Result<f32, String> {
+    match input.trim().parse::<f32>() {
+        Ok(n) if (0.0..=1.0).contains(&n) => Ok(n),
+        _ => Err(format!(
+            "invalid confidence '{input}' (expected a number from 0 to 1)"
+        )),
+    }
+}
+
+/// Parses an RFC 3339 timestamp such as `2024-05-01T12:00:00Z` or
+/// `2024-05-01T12:00:00.5+02:00` into a [`SystemTime`].
+///
+/// Used by `scan --since`; the error message is shown to the user as-is.
+pub fn parse_rfc3339(input: &str) -> Result<SystemTime, String> {
+    let err =
+        || format!("invalid RFC 3339 timestamp '{input}' (expected e.g. 2024-05-01T12:00:00Z)");
+    let s = input.trim().as_bytes();
+    if s.len() < 20 || s[4] != b'-' || s[7] != b'-' || s[13] != b':' || s[16] != b':' {
+        return Err(err());
+    }
+    if !matches!(s[10], b'T' | b't' | b' ') {
+        return Err(err());
+    }
+
+    let num = |range: std::ops::Range<usize>| -> Option<i64> {
+        let digits = s.get(range)?;
+        digits
+            .iter()
+            .all(u8::is_ascii_digit)
+            .then(|| std::str::from_utf8(digits).ok()?.parse().ok())
+            .flatten()
+    };
+    let (Some(year), Some(month), Some(day), Some(hour), Some(minute), Some(second)) = (
+        num(0..4),
+        num(5..7),
+        num(8..10),
+        num(11..13),
+        num(14..16),
+        num(17..19),
+    ) else {
+        return Err(err());
+    };
+
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        2 => 28,
+        _ => return Err(err()),
+    };
+    if day < 1 || day > days_in_month || hour > 23 || minute > 59 || second > 60 {
+        return Err(err());
+    }
+
+    // Optional fractional seconds, then a mandatory offset
+    let mut pos = 19;
+    let mut nanos: u32 = 0;
+    if s[pos] == b'.' {
+        let start = pos + 1;
+        pos = start;
+        while pos < s.len() && s[pos].is_ascii_digit() {
+            pos += 1;
+        }
+        if pos == start {
+            return Err(err());
+        }
+        let frac = &s[start..pos.min(start + 9)];
+        let value: u32 = std::str::from_utf8(frac)
+            .ok()
+            .and_then(|f| f.parse().ok())
+            .ok_or_else(err)?;
+        nanos = value * 10u32.pow(9 - frac.len() as u32);
+    }
+
+    let offset_secs = match &s[pos..] {
+        [b'Z' | b'z'] => 0,
+        [sign @ (b'+' | b'-'), _, _, b':', _, _] => {
+            let (Some(oh), Some(om)) = (num(pos + 1..pos + 3), num(pos + 4..pos + 6)) else {
+                return Err(err());
+            };
+            if oh > 23 || om > 59 {
+                return Err(err());
+            }
+            let secs = oh * 3600 + om * 60;
+            if *sign == b'+' { secs } else { -secs }
+        }
+        _ => return Err(err()),
+    };
+
+    // Days since the Unix epoch (proleptic Gregorian calendar)
+    let (y, m) = if month <= 2 {
+        (year - 1, month + 9)
+    } else {
+        (year, month - 3)
+    };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * m + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146_097 + doe - 719_468;
+
+    let secs = days * 86_400 + hour * 3600 + minute * 60 + second.min(59) - offset_secs;
+    let time = if secs >= 0 {
+        UNIX_EPOCH.checked_add(Duration::new(secs as u64, nanos))
+    } else {
+        UNIX_EPOCH
+            .checked_sub(Duration::from_secs(secs.unsigned_abs()))
+            .and_then(|t| t.checked_add(Duration::from_nanos(nanos as u64)))
+    };
+    time.ok_or_else(err)
 }
 
 // ── Walk helpers ────────────────────────────────────────────────────────────
 
 /// Constructs a filtered directory walker with the given settings.
+///
+/// Directories in `exclude_under` are pruned: the walk never descends into
+/// them. They must be spelled the way the walk reaches them (see
+/// [`excluded_dirs`]).
 fn walk(
     base: &Path,
     max_depth: Option<usize>,
     follow_symlinks: bool,
+    exclude_under: &[PathBuf],
 ) -> impl Iterator<Item = walkdir::DirEntry> {
     let mut w = WalkDir::new(base).follow_links(follow_symlinks);
     if let Some(d) = max_depth {
         w = w.max_depth(d + 1); // WalkDir counts the base directory as depth 0
     }
-    w.into_iter().filter_map(|e| e.ok())
+    let exclude_under = exclude_under.to_vec();
+    w.into_iter()
+        .filter_entry(move |e| !exclude_under.iter().any(|dir| e.path().starts_with(dir)))
+        .filter_map(|e| e.ok())
 }
 
-/// Finds the first `.cue` file in a directory (non-recursive).
-fn find_cue_in_dir(dir: &Path) -> Option<PathBuf> {
-    std::fs::read_dir(dir).ok()?.flatten().find_map(|e| {
-        let p = e.path();
-        p.extension()
-            .is_some_and(|ext| ext.eq_ignore_ascii_case("cue"))
-            .then_some(p)
-    })
+/// Resolves `exclude_under` directories to paths as the walk from `base`
+/// reaches them. Relative paths are joined to `base` unless they already
+/// start with it, e.g. `music/Incoming` when scanning `music`.
+fn excluded_dirs(base: &Path, exclude_under: &[PathBuf]) -> Vec<PathBuf> {
+    exclude_under
+        .iter()
+        .map(|dir| {
+            if dir.is_absolute() || dir.starts_with(base) {
+                dir.clone()
+            } else {
+                base.join(dir)
+            }
+        })
+        .collect()
+}
+
+/// Identity of a file on disk, independent of the path used to reach it.
+#[derive(Debug, PartialEq, Eq, Hash)]
+enum FileKey {
+    #[cfg(unix)]
+    Inode(u64, u64),
+    Path(PathBuf),
+}
+
+fn file_key(path: &Path) -> FileKey {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+        if let Ok(m) = std::fs::metadata(path) {
+            return FileKey::Inode(m.dev(), m.ino());
+        }
+    }
+    FileKey::Path(std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf()))
+}
+
+/// Lists the `.cue` files in a directory (non-recursive), sorted by path.
+fn cue_files_in_dir(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut cues: Vec<PathBuf> = entries
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| {
+            p.extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("cue"))
+        })
+        .collect();
+    cues.sort();
+    cues
+}
+
+/// Picks the CUE sheet named `preferred` (case-insensitive) if present,
+/// otherwise the first one by file name.
+fn choose_cue<'a>(cues: &'a [PathBuf], preferred: Option<&str>) -> Option<&'a PathBuf> {
+    preferred
+        .and_then(|name| {
+            cues.iter().find(|c| {
+                c.file_name()
+                    .and_then(|n| n.to_str())
+                    .is_some_and(|n| n.eq_ignore_ascii_case(name))
+            })
+        })
+        .or_else(|| cues.first())
 }
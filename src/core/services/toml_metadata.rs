@@ -0,0 +1,281 @@
+//! Hand-editable TOML export of track metadata.
+//!
+//! `emit --format toml` writes one `[[tracks]]` table per file with only the
+//! fields `write --set` understands. `write-from-toml` reads the edited
+//! document back and writes every field whose value differs from the file's
+//! current tag.
+
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::adapters::audio_formats as formats;
+use crate::core::domain::models::{MetadataValue, Track, TrackMetadata};
+use crate::core::domain::schema_version::SCHEMA_VERSION;
+use crate::core::services::apply_metadata::write_metadata_by_path;
+use crate::core::services::mutation_summary::{FileOutcome, MutationSummary};
+use crate::core::services::scanner::scan_dir_with_metadata;
+
+/// Editable fields of one track; absent fields are left untouched on write.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TomlTrack {
+    pub path: PathBuf,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub artist: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub album: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub album_artist: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub track_number: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disc_number: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub year: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub genre: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub media: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub barcode: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+
+/// A TOML metadata document: the schema version and one table per track.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TomlDocument {
+    #[serde(
+        rename = "__schema_version",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub schema_version: Option<String>,
+    #[serde(default)]
+    pub tracks: Vec<TomlTrack>,
+}
+
+impl TomlTrack {
+    pub fn from_metadata(path: &Path, metadata: &TrackMetadata) -> Self {
+        let text = |v: &Option<MetadataValue<String>>| v.as_ref().map(|v| v.value.clone());
+        let number = |v: &Option<MetadataValue<u32>>| v.as_ref().map(|v| v.value);
+        Self {
+            path: path.to_path_buf(),
+            title: text(&metadata.title),
+            artist: text(&metadata.artist),
+            album: text(&metadata.album),
+            album_artist: text(&metadata.album_artist),
+            track_number: number(&metadata.track_number),
+            disc_number: number(&metadata.disc_number),
+            year: number(&metadata.year),
+            genre: text(&metadata.genre),
+            media: text(&metadata.media),
+            barcode: text(&metadata.barcode),
+            comment: text(&metadata.comment),
+        }
+    }
+
+    /// `(write --set key, value)` pairs in document order.
+    fn fields(&self) -> [(&'static str, Option<String>); 11] {
+        [
+            ("title", self.title.clone()),
+            ("artist", self.artist.clone()),
+            ("album", self.album.clone()),
+            ("album_artist", self.album_artist.clone()),
+            ("track_number", self.track_number.map(|n| n.to_string())),
+            ("disc_number", self.disc_number.map(|n| n.to_string())),
+            ("year", self.year.map(|n| n.to_string())),
+            ("genre", self.genre.clone()),
+            ("media", self.media.clone()),
+            ("barcode", self.barcode.clone()),
+            ("comment", self.comment.clone()),
+        ]
+    }
+
+    /// `key=value` updates for every field set here that differs from `current`.
+    pub fn changes_from(&self, current: &TomlTrack) -> Vec<String> {
+        self.fields()
+            .into_iter()
+            .zip(current.fields())
+            .filter_map(|((key, new), (_, old))| match new {
+                Some(new) if Some(&new) != old.as_ref() => Some(format!("{}={}", key, new)),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+impl TomlDocument {
+    pub fn from_tracks(tracks: &[Track]) -> Self {
+        Self {
+            schema_version: Some(SCHEMA_VERSION.to_string()),
+            tracks: tracks
+                .iter()
+                .map(|t| TomlTrack::from_metadata(&t.file_path, &t.metadata))
+                .collect(),
+        }
+    }
+
+    pub fn to_toml(&self) -> Result<String, String> {
+        toml::to_string_pretty(self).map_err(|e| format!("Error serializing to TOML: {}", e))
+    }
+
+    /// Parse a document written by [`TomlDocument::to_toml`], rejecting
+    /// unknown keys. The `__schema_version` key is optional; when present it
+    /// must match [`SCHEMA_VERSION`].
+    pub fn from_toml(input: &str) -> Result<Self, String> {
+        let document: Self = toml::from_str(input).map_err(|e| format!("Invalid TOML: {}", e))?;
+        if let Some(version) = &document.schema_version
+            && version != SCHEMA_VERSION
+        {
+            return Err(format!(
+                "Unsupported schema version \"{}\" (expected \"{}\")",
+                version, SCHEMA_VERSION
+            ));
+        }
+        Ok(document)
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self, String> {
+        let input = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        Self::from_toml(&input)
+    }
+}
+
+/// Emit the tracks under `path` (or the single file `path`) as TOML.
+pub fn emit_toml_by_path(path: &Path) -> Result<String, String> {
+    let tracks =
+        scan_dir_with_metadata(path).map_err(|e| format!("Failed to scan directory: {}", e))?;
+    TomlDocument::from_tracks(&tracks).to_toml()
+}
+
+/// Outcome of writing one [`TomlTrack`] back.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TomlWriteResult {
+    pub path: PathBuf,
+    /// `key=value` updates that differ from the file's tags.
+    pub changes: Vec<String>,
+    pub error: Option<String>,
+}
+
+/// Write every changed field of `document` back to its file.
+///
+/// Without `apply` nothing is written and the results only list the changes.
+pub fn write_from_toml(document: &TomlDocument, apply: bool) -> Vec<TomlWriteResult> {
+    document
+        .tracks
+        .iter()
+        .map(|edited| {
+            let mut result = TomlWriteResult {
+                path: edited.path.clone(),
+                changes: Vec::new(),
+                error: None,
+            };
+            let current = match formats::read_metadata(&edited.path) {
+                Ok(track) => TomlTrack::from_metadata(&edited.path, &track.metadata),
+                Err(e) => {
+                    result.error = Some(format!("Failed to read: {}", e));
+                    return result;
+                }
+            };
+            result.changes = edited.changes_from(&current);
+            if apply && !result.changes.is_empty() {
+                result.error =
+                    write_metadata_by_path(&edited.path, result.changes.clone(), true, false)
+                        .err()
+                        .map(|e| e.to_string());
+            }
+            result
+        })
+        .collect()
+}
+
+/// Format write results as text, one block per changed file, and the
+/// [`MutationSummary`] line.
+pub fn format_toml_write_results(results: &[TomlWriteResult], apply: bool) -> String {
+    let mut out = String::new();
+    let mut summary = MutationSummary::new();
+    for result in results {
+        if let Some(error) = &result.error {
+            summary.record(&result.path, FileOutcome::Error);
+            let _ = writeln!(out, "ERROR {}: {}", result.path.display(), error);
+            continue;
+        }
+        if result.changes.is_empty() {
+            summary.record(&result.path, FileOutcome::Unchanged);
+            continue;
+        }
+        summary.record(&result.path, FileOutcome::Changed);
+        let verb = if apply { "UPDATED" } else { "WOULD UPDATE" };
+        let _ = writeln!(out, "{} {}", verb, result.path.display());
+        for change in &result.changes {
+            let _ = writeln!(out, "  {}", change);
+        }
+    }
+    out.push_str(&summary.line(!apply));
+    out
+}
+
+// ── Tests ───────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn track() -> TomlTrack {
+        TomlTrack {
+            path: PathBuf::from("Artist/Album/01 Song.flac"),
+            title: Some("Song".to_string()),
+            artist: Some("Artist".to_string()),
+            track_number: Some(1),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_toml_round_trip_skips_missing_fields() {
+        let document = TomlDocument {
+            schema_version: Some(SCHEMA_VERSION.to_string()),
+            tracks: vec![track()],
+        };
+        let toml = document.to_toml().unwrap();
+
+        assert!(toml.contains("[[tracks]]"));
+        assert!(toml.contains("title = \"Song\""));
+        assert!(toml.contains("track_number = 1"));
+        assert!(!toml.contains("album ="));
+        assert!(!toml.contains("comment ="));
+        assert_eq!(TomlDocument::from_toml(&toml).unwrap(), document);
+    }
+
+    #[test]
+    fn test_from_toml_rejects_unknown_fields_and_versions() {
+        let err =
+            TomlDocument::from_toml("[[tracks]]\npath = \"a.flac\"\ntitel = \"x\"\n").unwrap_err();
+        assert!(err.contains("titel"));
+
+        let err = TomlDocument::from_toml("__schema_version = \"0.1\"\n").unwrap_err();
+        assert!(err.contains("Unsupported schema version"));
+    }
+
+    #[test]
+    fn test_changes_from_only_lists_edited_fields() {
+        let current = track();
+        let mut edited = track();
+        edited.title = Some("Song (Live)".to_string());
+        edited.artist = None;
+        edited.year = Some(1999);
+
+        assert_eq!(
+            edited.changes_from(&current),
+            vec!["title=Song (Live)".to_string(), "year=1999".to_string()]
+        );
+        assert!(current.changes_from(&current).is_empty());
+    }
+}
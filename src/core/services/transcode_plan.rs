@@ -0,0 +1,179 @@
+//! Lossy-to-lossless upgrade planning.
+//!
+//! Lists the lossy tracks in a library so the user knows which files to
+//! source again in a lossless format. Nothing is transcoded; the plan is
+//! derived purely from existing metadata.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use serde_json::to_string_pretty;
+
+use crate::core::domain::models::{Library, TrackNode};
+use crate::core::domain::with_schema_version;
+use crate::core::services::duplicates::{QualityTier, classify_metadata};
+use crate::core::services::library::build_library_hierarchy;
+use crate::core::services::scanner::scan_dir_with_metadata;
+
+/// A lossy track worth replacing with a lossless copy.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct UpgradeCandidate {
+    pub file_path: PathBuf,
+    pub artist: String,
+    pub album: String,
+    pub title: Option<String>,
+    pub track_number: Option<u32>,
+    pub format: String,
+    pub quality: QualityTier,
+    /// Whether any track of the same album is already lossless.
+    pub album_has_lossless: bool,
+    /// A lossless track of the same album with the same title, if one exists.
+    pub lossless_version: Option<PathBuf>,
+}
+
+/// List every non-lossless track in `library`.
+///
+/// Candidates are ordered by how many tracks their artist has in the library,
+/// most first, so upgrades focus on the artists listened to the most; ties
+/// fall back to artist, album, track number and path.
+pub fn transcode_plan(library: &Library) -> Vec<UpgradeCandidate> {
+    let mut candidates = Vec::new();
+    let mut artist_track_counts: HashMap<&str, usize> = HashMap::new();
+
+    for artist in &library.artists {
+        for album in &artist.albums {
+            *artist_track_counts.entry(&artist.name).or_default() += album.tracks.len();
+
+            let lossless: Vec<&TrackNode> = album
+                .tracks
+                .iter()
+                .filter(|t| t.metadata.is_lossless())
+                .collect();
+
+            for track in &album.tracks {
+                if track.metadata.is_lossless() {
+                    continue;
+                }
+                let quality = classify_metadata(&track.metadata);
+
+                let title = track.metadata.title.as_ref().map(|t| t.value.clone());
+                let lossless_version = title.as_deref().and_then(|title| {
+                    lossless
+                        .iter()
+                        .find(|l| {
+                            l.metadata
+                                .title
+                                .as_ref()
+                                .is_some_and(|t| t.value.trim().eq_ignore_ascii_case(title.trim()))
+                        })
+                        .map(|l| l.file_path.clone())
+                });
+
+                candidates.push(UpgradeCandidate {
+                    file_path: track.file_path.clone(),
+                    artist: artist.name.clone(),
+                    album: album.title.clone(),
+                    title,
+                    track_number: track.metadata.track_number.as_ref().map(|n| n.value),
+                    format: track.metadata.format.clone(),
+                    quality,
+                    album_has_lossless: !lossless.is_empty(),
+                    lossless_version,
+                });
+            }
+        }
+    }
+
+    candidates.sort_by(|a, b| {
+        let count = |c: &UpgradeCandidate| artist_track_counts.get(c.artist.as_str()).copied();
+        count(b)
+            .cmp(&count(a))
+            .then_with(|| a.artist.cmp(&b.artist))
+            .then_with(|| a.album.cmp(&b.album))
+            .then_with(|| a.track_number.cmp(&b.track_number))
+            .then_with(|| a.file_path.cmp(&b.file_path))
+    });
+    candidates
+}
+
+/// JSON envelope for `transcode-plan`.
+#[derive(serde::Serialize)]
+struct TranscodePlanOutput<'a> {
+    candidates: &'a [UpgradeCandidate],
+}
+
+/// Scan `path` and emit its [`transcode_plan`] as JSON.
+pub fn transcode_plan_by_path(path: &Path) -> Result<String, String> {
+    let tracks =
+        scan_dir_with_metadata(path).map_err(|e| format!("Failed to scan directory: {}", e))?;
+    let library = build_library_hierarchy(tracks);
+    let candidates = transcode_plan(&library);
+
+    to_string_pretty(&with_schema_version(TranscodePlanOutput {
+        candidates: &candidates,
+    }))
+    .map_err(|e| format!("Error serializing to JSON: {}", e))
+}
+
+// ── Tests ───────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::builders::TrackMetadataBuilder;
+    use crate::core::domain::models::{MetadataSource, Track};
+
+    fn track(artist: &str, album: &str, title: &str, number: u32, format: &str) -> Track {
+        let path = format!(
+            "/music/{}/{}/{:02} {}.{}",
+            artist, album, number, title, format
+        );
+        let metadata = TrackMetadataBuilder::new(&path)
+            .artist(artist, MetadataSource::Embedded, 1.0)
+            .album(album, MetadataSource::Embedded, 1.0)
+            .title(title, MetadataSource::Embedded, 1.0)
+            .track_number(number, MetadataSource::Embedded, 1.0)
+            .format(format)
+            .build();
+        Track::new(PathBuf::from(path), metadata)
+    }
+
+    #[test]
+    fn test_transcode_plan_lists_lossy_tracks_with_lossless_matches() {
+        let library = build_library_hierarchy(vec![
+            track("Band", "Record", "Intro", 1, "flac"),
+            track("Band", "Record", "Intro", 1, "mp3"),
+            track("Band", "Record", "Outro", 2, "mp3"),
+            track("Band", "Record", "Hidden", 3, "wav"),
+        ]);
+
+        let plan = transcode_plan(&library);
+        assert_eq!(plan.len(), 2);
+        assert_eq!(plan[0].title.as_deref(), Some("Intro"));
+        assert_eq!(plan[0].quality, QualityTier::Lossy);
+        assert!(plan[0].album_has_lossless);
+        assert_eq!(
+            plan[0].lossless_version,
+            Some(PathBuf::from("/music/Band/Record/01 Intro.flac"))
+        );
+        assert_eq!(plan[1].title.as_deref(), Some("Outro"));
+        assert!(plan[1].album_has_lossless);
+        assert_eq!(plan[1].lossless_version, None);
+    }
+
+    #[test]
+    fn test_transcode_plan_prioritizes_frequent_artists() {
+        let library = build_library_hierarchy(vec![
+            track("Alpha", "One", "Song", 1, "mp3"),
+            track("Zulu", "Two", "First", 1, "ogg"),
+            track("Zulu", "Two", "Second", 2, "m4a"),
+            track("Zulu", "Two", "Third", 3, "flac"),
+        ]);
+
+        let plan = transcode_plan(&library);
+        let order: Vec<_> = plan.iter().map(|c| c.artist.as_str()).collect();
+        assert_eq!(order, vec!["Zulu", "Zulu", "Alpha"]);
+        assert_eq!(plan[0].track_number, Some(1));
+        assert!(!plan[2].album_has_lossless);
+    }
+}
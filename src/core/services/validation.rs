@@ -1,31 +1,94 @@
 use crate::adapters::audio_formats::read_metadata;
+use crate::core::domain::models::{
+    AlbumNode, Library, MetadataSource, MetadataValue, Track, TrackMetadata,
+};
 use crate::core::domain::with_schema_version;
-use crate::core::services::scanner::scan_dir;
+use crate::core::services::library::build_library_hierarchy;
+use crate::core::services::normalization::extract_featured_artists;
+use crate::core::services::scanner::{scan_dir, scan_single_file};
 use serde_json::to_string_pretty;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 pub mod metadata_validation;
+pub mod structure_validation;
+
+use structure_validation::{StructureViolation, check_template, validate_structure};
 
 #[derive(Debug, serde::Serialize)]
 pub struct ValidationResult {
     pub valid: bool,
-    pub errors: Vec<ValidationError>,
-    pub warnings: Vec<ValidationWarning>,
+    pub errors: Vec<ValidationIssue>,
+    pub warnings: Vec<ValidationIssue>,
+    pub structure_violations: Vec<StructureViolation>,
+    pub placeholders: PlaceholderReport,
     pub summary: ValidationSummary,
 }
 
-#[derive(Debug, serde::Serialize)]
-pub struct ValidationError {
-    pub file_path: String,
-    pub field: String,
-    pub message: String,
+/// How serious a validation issue is; ordered from least to most severe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
 }
 
-#[derive(Debug, serde::Serialize)]
-pub struct ValidationWarning {
-    pub file_path: String,
-    pub field: String,
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Info => write!(f, "info"),
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// Parse a severity name given on the command line (`info`, `warning` or
+/// `error`).
+pub fn parse_severity(value: &str) -> Result<Severity, String> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "info" => Ok(Severity::Info),
+        "warning" | "warn" => Ok(Severity::Warning),
+        "error" => Ok(Severity::Error),
+        other => Err(format!(
+            "Unknown severity '{}' (expected 'info', 'warning' or 'error')",
+            other
+        )),
+    }
+}
+
+/// A single validation finding. `category` names the field or check that
+/// produced it, e.g. `title`, `sample_rate` or `structure`.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct ValidationIssue {
+    pub severity: Severity,
+    pub category: String,
     pub message: String,
+    /// File, or album directory for album-level checks
+    pub path: String,
+}
+
+impl From<&StructureViolation> for ValidationIssue {
+    fn from(violation: &StructureViolation) -> Self {
+        ValidationIssue {
+            severity: Severity::Warning,
+            category: "structure".to_string(),
+            message: violation.message.clone(),
+            path: violation.file_path.clone(),
+        }
+    }
+}
+
+/// Tracks that would be grouped under the "Unknown Artist" / "Unknown Album"
+/// placeholders because the metadata could not be read or inferred.
+#[derive(Debug, Default, serde::Serialize)]
+pub struct PlaceholderReport {
+    pub unknown_artist_files: Vec<String>,
+    pub unknown_album_files: Vec<String>,
+    /// Tracks missing an artist, an album, or both.
+    pub affected_tracks: usize,
+    /// Share of all validated tracks that are affected, in percent.
+    pub percentage: f64,
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -35,8 +98,91 @@ pub struct ValidationSummary {
     pub files_with_errors: usize,
     pub files_with_warnings: usize,
 }
+/// Options for [`validate_path_with_options`].
+#[derive(Debug, Clone, Default)]
+pub struct ValidateOptions {
+    /// Path template each file's location is checked against.
+    pub template: Option<String>,
+    /// Fail when more than this percentage of tracks land under the
+    /// "Unknown Artist" / "Unknown Album" placeholders.
+    pub fail_on_unknown: Option<f64>,
+    /// Fail when any issue at or above this severity is found.
+    pub fail_on: Option<Severity>,
+    /// Stop at the first file with an issue at or above `fail_on`
+    /// (`error` when unset) instead of validating the whole library.
+    pub fail_fast: bool,
+    /// Warn about `initial_key` tags that aren't in standard, Camelot or
+    /// Open Key notation.
+    pub check_keys: bool,
+    /// Warn about featured artists named in a title ("feat. X") that the
+    /// artist and album artist tags don't mention.
+    pub check_featured: bool,
+}
+
+impl ValidationResult {
+    /// Every issue in the report, most severe first. Structure violations
+    /// are included as [`Severity::Warning`] issues.
+    pub fn issues(&self) -> Vec<ValidationIssue> {
+        let mut issues: Vec<ValidationIssue> = self
+            .errors
+            .iter()
+            .chain(&self.warnings)
+            .cloned()
+            .chain(self.structure_violations.iter().map(ValidationIssue::from))
+            .collect();
+        issues.sort_by(|a, b| b.severity.cmp(&a.severity));
+        issues
+    }
+
+    /// Number of issues at or above `severity`.
+    pub fn count_at_or_above(&self, severity: Severity) -> usize {
+        self.issues()
+            .iter()
+            .filter(|issue| issue.severity >= severity)
+            .count()
+    }
+}
+
 pub fn validate_path(path: &PathBuf, json: bool) -> Result<String, String> {
-    let tracks = scan_dir(path, false);
+    validate_path_with_options(path, json, &ValidateOptions::default())
+}
+
+/// Validate a library and, when `template` is given, check that each file's
+/// location matches the path derived from its metadata (e.g.
+/// `{artist}/{album}/{track} - {title}.{ext}`).
+pub fn validate_path_with_template(
+    path: &PathBuf,
+    json: bool,
+    template: Option<&str>,
+) -> Result<String, String> {
+    let options = ValidateOptions {
+        template: template.map(str::to_string),
+        ..ValidateOptions::default()
+    };
+    validate_path_with_options(path, json, &options)
+}
+
+/// Validate a library according to `options`.
+///
+/// When `fail_on_unknown` is exceeded, or an issue at or above `fail_on` is
+/// found, the report is returned as the error so callers can print it and
+/// exit unsuccessfully. With `fail_fast` that report covers only the first
+/// offending file.
+pub fn validate_path_with_options(
+    path: &PathBuf,
+    json: bool,
+    options: &ValidateOptions,
+) -> Result<String, String> {
+    let template = options.template.as_deref();
+    if let Some(template) = template {
+        check_template(template)?;
+    }
+
+    let tracks = if path.is_file() {
+        vec![scan_single_file(path)?]
+    } else {
+        scan_dir(path, false)
+    };
     let total_scanned = tracks.len();
 
     if tracks.is_empty() {
@@ -47,11 +193,34 @@ pub fn validate_path(path: &PathBuf, json: bool) -> Result<String, String> {
         });
     }
 
-    // Read metadata for validation
-    let tracks_with_metadata: Vec<crate::Track> = tracks
-        .into_iter()
-        .filter_map(|track| read_metadata(&track.file_path).ok())
-        .collect();
+    let fail_on = options
+        .fail_on
+        .or(options.fail_fast.then_some(Severity::Error));
+
+    // Read metadata for validation; CUE-sourced tracks keep their CUE fields
+    let mut tracks_with_metadata: Vec<crate::Track> = Vec::new();
+    for track in tracks {
+        let track = if is_cue_sourced(&track) {
+            merge_cue_track(track)
+        } else {
+            match read_metadata(&track.file_path) {
+                Ok(track) => track,
+                Err(_) => continue,
+            }
+        };
+
+        // Album-level checks need every track, so fail-fast only looks at
+        // per-file issues while reading
+        if options.fail_fast
+            && let Some(severity) = fail_on
+        {
+            let single = validate_tracks(vec![track.clone()]);
+            if single.count_at_or_above(severity) > 0 {
+                return Err(render_validation(&single, json));
+            }
+        }
+        tracks_with_metadata.push(track);
+    }
 
     if tracks_with_metadata.is_empty() {
         return Err(if json {
@@ -64,16 +233,113 @@ pub fn validate_path(path: &PathBuf, json: bool) -> Result<String, String> {
         });
     }
 
-    let validation_results = validate_tracks(tracks_with_metadata);
+    // A CUE image holds many tracks in one file, so it can't match a per-track template
+    let structure_violations = template
+        .map(|t| {
+            let per_file: Vec<Track> = tracks_with_metadata
+                .iter()
+                .filter(|track| !is_cue_sourced(track))
+                .cloned()
+                .collect();
+            validate_structure(&per_file, t)
+        })
+        .unwrap_or_default();
+    let key_warnings = if options.check_keys {
+        validate_initial_keys(&tracks_with_metadata)
+    } else {
+        Vec::new()
+    };
+    let featured_warnings = if options.check_featured {
+        validate_featured_artists(&tracks_with_metadata)
+    } else {
+        Vec::new()
+    };
+    let mut validation_results = validate_tracks(tracks_with_metadata);
+    validation_results.warnings.extend(key_warnings);
+    validation_results.warnings.extend(featured_warnings);
+    validation_results.structure_violations = structure_violations;
+
+    let result = render_validation(&validation_results, json);
 
-    let result = if json {
-        let wrapper = with_schema_version(&validation_results);
+    if let Some(severity) = fail_on {
+        let count = validation_results.count_at_or_above(severity);
+        if count > 0 {
+            return Err(if json {
+                result
+            } else {
+                format!(
+                    "{}\n❌ {} issue(s) at or above severity '{}'",
+                    result, count, severity
+                )
+            });
+        }
+    }
+
+    match options.fail_on_unknown {
+        Some(limit) if validation_results.placeholders.percentage > limit => Err(if json {
+            result
+        } else {
+            format!(
+                "{}\n❌ {:.1}% of tracks fall under Unknown Artist/Album (limit {}%)",
+                result, validation_results.placeholders.percentage, limit
+            )
+        }),
+        _ => Ok(result),
+    }
+}
+
+/// Validate the tracks of an in-memory library, e.g. one loaded from JSON,
+/// without reading any files.
+pub fn validate_library(library: &Library, json: bool) -> String {
+    render_validation(&validate_tracks(library.tracks()), json)
+}
+
+fn render_validation(results: &ValidationResult, json: bool) -> String {
+    if json {
+        let wrapper = with_schema_version(results);
         to_string_pretty(&wrapper)
             .unwrap_or_else(|e| format!("Error serializing validation results: {}", e))
     } else {
-        build_validation_results(&validation_results)
+        build_validation_results(results)
+    }
+}
+
+/// Returns `true` for tracks the scanner built from a CUE sheet.
+///
+/// Their track number (and usually title) come from the CUE, not the file.
+pub fn is_cue_sourced(track: &Track) -> bool {
+    track
+        .metadata
+        .track_number
+        .as_ref()
+        .is_some_and(|n| n.source == MetadataSource::CueInferred)
+}
+
+/// Combine a CUE-sourced track with the tags embedded in its audio file.
+///
+/// The CUE's title and track number always win, since the embedded tags of a
+/// single-file album describe the whole image. Other fields prefer embedded
+/// values and fall back to what the CUE provided.
+fn merge_cue_track(cue_track: Track) -> Track {
+    let Ok(embedded) = read_metadata(&cue_track.file_path) else {
+        return cue_track;
     };
-    Ok(result)
+    let cue = cue_track.metadata;
+    let md = embedded.metadata;
+
+    Track {
+        metadata: TrackMetadata {
+            title: cue.title.or(md.title),
+            track_number: cue.track_number,
+            artist: md.artist.or(cue.artist),
+            album: md.album.or(cue.album),
+            album_artist: md.album_artist.or(cue.album_artist),
+            year: md.year.or(cue.year),
+            genre: md.genre.or(cue.genre),
+            ..md
+        },
+        ..cue_track
+    }
 }
 
 /// Print validation results in human-readable format
@@ -106,8 +372,8 @@ fn build_validation_results(results: &ValidationResult) -> String {
     if !results.errors.is_empty() {
         output.push_str("\n🔴 ERRORS:\n");
         for error in &results.errors {
-            output.push_str(&format!("  File: {}\n", error.file_path));
-            output.push_str(&format!("  Field: {}\n", error.field));
+            output.push_str(&format!("  File: {}\n", error.path));
+            output.push_str(&format!("  Field: {}\n", error.category));
             output.push_str(&format!("  Issue: {}\n\n", error.message));
         }
     }
@@ -115,12 +381,37 @@ fn build_validation_results(results: &ValidationResult) -> String {
     if !results.warnings.is_empty() {
         output.push_str("🟡 WARNINGS:\n");
         for warning in &results.warnings {
-            output.push_str(&format!("  File: {}\n", warning.file_path));
-            output.push_str(&format!("  Field: {}\n", warning.field));
+            output.push_str(&format!("  File: {}\n", warning.path));
+            output.push_str(&format!("  Field: {}\n", warning.category));
             output.push_str(&format!("  Issue: {}\n", warning.message));
         }
     }
 
+    if !results.structure_violations.is_empty() {
+        output.push_str(&format!(
+            "📁 STRUCTURE ({} files not matching template):\n",
+            results.structure_violations.len()
+        ));
+        for violation in &results.structure_violations {
+            output.push_str(&format!("  File: {}\n", violation.file_path));
+            output.push_str(&format!("  Issue: {}\n", violation.message));
+        }
+    }
+
+    let placeholders = &results.placeholders;
+    if placeholders.affected_tracks > 0 {
+        output.push_str(&format!(
+            "❓ UNKNOWN PLACEHOLDERS ({} tracks, {:.1}%):\n",
+            placeholders.affected_tracks, placeholders.percentage
+        ));
+        for file in &placeholders.unknown_artist_files {
+            output.push_str(&format!("  Unknown Artist: {}\n", file));
+        }
+        for file in &placeholders.unknown_album_files {
+            output.push_str(&format!("  Unknown Album: {}\n", file));
+        }
+    }
+
     output.push_str("=== END VALIDATION ===\n");
 
     output
@@ -140,27 +431,30 @@ pub fn validate_tracks(tracks: Vec<crate::Track>) -> ValidationResult {
 
         // Check for required fields: title, artist, album
         if track.metadata.title.is_none() {
-            errors.push(ValidationError {
-                file_path: file_path.clone(),
-                field: "title".to_string(),
+            errors.push(ValidationIssue {
+                severity: Severity::Error,
+                path: file_path.clone(),
+                category: "title".to_string(),
                 message: "Missing required field: title".to_string(),
             });
             has_error = true;
         }
 
         if track.metadata.artist.is_none() {
-            errors.push(ValidationError {
-                file_path: file_path.clone(),
-                field: "artist".to_string(),
+            errors.push(ValidationIssue {
+                severity: Severity::Error,
+                path: file_path.clone(),
+                category: "artist".to_string(),
                 message: "Missing required field: artist".to_string(),
             });
             has_error = true;
         }
 
         if track.metadata.album.is_none() {
-            errors.push(ValidationError {
-                file_path: file_path.clone(),
-                field: "album".to_string(),
+            errors.push(ValidationIssue {
+                severity: Severity::Error,
+                path: file_path.clone(),
+                category: "album".to_string(),
                 message: "Missing required field: album".to_string(),
             });
             has_error = true;
@@ -169,9 +463,10 @@ pub fn validate_tracks(tracks: Vec<crate::Track>) -> ValidationResult {
         // Check for empty or whitespace-only fields
         if let Some(ref title) = track.metadata.title {
             if title.value.trim().is_empty() {
-                errors.push(ValidationError {
-                    file_path: file_path.clone(),
-                    field: "title".to_string(),
+                errors.push(ValidationIssue {
+                    severity: Severity::Error,
+                    path: file_path.clone(),
+                    category: "title".to_string(),
                     message: "Title field is empty".to_string(),
                 });
                 has_error = true;
@@ -180,9 +475,10 @@ pub fn validate_tracks(tracks: Vec<crate::Track>) -> ValidationResult {
 
         if let Some(ref artist) = track.metadata.artist {
             if artist.value.trim().is_empty() {
-                errors.push(ValidationError {
-                    file_path: file_path.clone(),
-                    field: "artist".to_string(),
+                errors.push(ValidationIssue {
+                    severity: Severity::Error,
+                    path: file_path.clone(),
+                    category: "artist".to_string(),
                     message: "Artist field is empty".to_string(),
                 });
                 has_error = true;
@@ -191,9 +487,10 @@ pub fn validate_tracks(tracks: Vec<crate::Track>) -> ValidationResult {
 
         if let Some(ref album) = track.metadata.album {
             if album.value.trim().is_empty() {
-                errors.push(ValidationError {
-                    file_path: file_path.clone(),
-                    field: "album".to_string(),
+                errors.push(ValidationIssue {
+                    severity: Severity::Error,
+                    path: file_path.clone(),
+                    category: "album".to_string(),
                     message: "Album field is empty".to_string(),
                 });
                 has_error = true;
@@ -202,18 +499,20 @@ pub fn validate_tracks(tracks: Vec<crate::Track>) -> ValidationResult {
 
         // Warnings for recommended fields
         if track.metadata.track_number.is_none() {
-            warnings.push(ValidationWarning {
-                file_path: file_path.clone(),
-                field: "track_number".to_string(),
+            warnings.push(ValidationIssue {
+                severity: Severity::Warning,
+                path: file_path.clone(),
+                category: "track_number".to_string(),
                 message: "Missing recommended field: track_number".to_string(),
             });
             has_warning = true;
         }
 
         if track.metadata.year.is_none() {
-            warnings.push(ValidationWarning {
-                file_path: file_path.clone(),
-                field: "year".to_string(),
+            warnings.push(ValidationIssue {
+                severity: Severity::Warning,
+                path: file_path.clone(),
+                category: "year".to_string(),
                 message: "Missing recommended field: year".to_string(),
             });
             has_warning = true;
@@ -222,9 +521,10 @@ pub fn validate_tracks(tracks: Vec<crate::Track>) -> ValidationResult {
         // Check for reasonable year ranges
         if let Some(ref year) = track.metadata.year {
             if year.value < 1900 || year.value > 2100 {
-                warnings.push(ValidationWarning {
-                    file_path: file_path.clone(),
-                    field: "year".to_string(),
+                warnings.push(ValidationIssue {
+                    severity: Severity::Warning,
+                    path: file_path.clone(),
+                    category: "year".to_string(),
                     message: format!("Year {} seems unusual (expected 1900-2100)", year.value),
                 });
                 has_warning = true;
@@ -234,9 +534,10 @@ pub fn validate_tracks(tracks: Vec<crate::Track>) -> ValidationResult {
         // Check for reasonable track numbers
         if let Some(ref track_number) = track.metadata.track_number {
             if track_number.value == 0 || track_number.value > 99 {
-                warnings.push(ValidationWarning {
-                    file_path: file_path.clone(),
-                    field: "track_number".to_string(),
+                warnings.push(ValidationIssue {
+                    severity: Severity::Warning,
+                    path: file_path.clone(),
+                    category: "track_number".to_string(),
                     message: format!(
                         "Track number {} seems unusual (expected 1-99)",
                         track_number.value
@@ -249,15 +550,28 @@ pub fn validate_tracks(tracks: Vec<crate::Track>) -> ValidationResult {
         // Check for very long titles
         if let Some(ref title) = track.metadata.title {
             if title.value.len() > 200 {
-                warnings.push(ValidationWarning {
-                    file_path: file_path.clone(),
-                    field: "title".to_string(),
+                warnings.push(ValidationIssue {
+                    severity: Severity::Warning,
+                    path: file_path.clone(),
+                    category: "title".to_string(),
                     message: format!("Title is very long ({} characters)", title.value.len()),
                 });
                 has_warning = true;
             }
         }
 
+        // Check the filename's leading number against the embedded track number
+        if let Some(warning) = validate_track_number_vs_filename(track) {
+            warnings.push(warning);
+            has_warning = true;
+        }
+
+        // Check for clipping-prone ReplayGain peaks
+        if let Some(warning) = clipping_warning(&track.file_path, &track.metadata) {
+            warnings.push(warning);
+            has_warning = true;
+        }
+
         if has_error {
             files_with_errors.insert(file_path.clone());
         }
@@ -266,6 +580,13 @@ pub fn validate_tracks(tracks: Vec<crate::Track>) -> ValidationResult {
         }
     }
 
+    // Album-level checks; these don't count towards per-file totals
+    let library = build_library_hierarchy(tracks.clone());
+    warnings.extend(validate_mixed_sample_rates(&library));
+    warnings.extend(validate_library_disc_numbering(&library));
+    warnings.extend(validate_album_genres(&library));
+    warnings.extend(validate_album_consistency_in_folder(&tracks));
+
     let total_files = tracks.len();
     let valid_files = total_files - files_with_errors.len();
     let summary = ValidationSummary {
@@ -279,6 +600,467 @@ pub fn validate_tracks(tracks: Vec<crate::Track>) -> ValidationResult {
         valid: errors.is_empty(),
         errors,
         warnings,
+        structure_violations: Vec::new(),
+        placeholders: validate_placeholders(&tracks),
         summary,
     }
 }
+
+/// Count tracks that `build_library_hierarchy` would file under the
+/// "Unknown Artist" or "Unknown Album" placeholders.
+pub fn validate_placeholders(tracks: &[Track]) -> PlaceholderReport {
+    let is_missing =
+        |v: Option<&MetadataValue<String>>| v.is_none_or(|v| v.value.trim().is_empty());
+
+    let mut report = PlaceholderReport::default();
+    for track in tracks {
+        let file_path = track.file_path.to_string_lossy().to_string();
+        let no_artist = is_missing(track.metadata.artist.as_ref());
+        let no_album = is_missing(track.metadata.album.as_ref());
+
+        if no_artist {
+            report.unknown_artist_files.push(file_path.clone());
+        }
+        if no_album {
+            report.unknown_album_files.push(file_path);
+        }
+        if no_artist || no_album {
+            report.affected_tracks += 1;
+        }
+    }
+
+    if !tracks.is_empty() {
+        report.percentage = report.affected_tracks as f64 * 100.0 / tracks.len() as f64;
+    }
+    report
+}
+
+/// Peak level (linear, 1.0 = full scale) at or above which a track may clip.
+pub const CLIPPING_PEAK_THRESHOLD: f64 = 1.0;
+
+/// Report tracks whose ReplayGain track peak (or album peak when the track
+/// peak is absent) reaches full scale and may clip on playback.
+pub fn validate_clipping(library: &Library) -> Vec<ValidationIssue> {
+    library
+        .artists
+        .iter()
+        .flat_map(|artist| &artist.albums)
+        .flat_map(|album| &album.tracks)
+        .filter_map(|track| clipping_warning(&track.file_path, &track.metadata))
+        .collect()
+}
+
+fn clipping_warning(file_path: &Path, metadata: &TrackMetadata) -> Option<ValidationIssue> {
+    let (field, peak) = match (&metadata.track_peak, &metadata.album_peak) {
+        (Some(peak), _) => ("track_peak", peak.value),
+        (None, Some(peak)) => ("album_peak", peak.value),
+        (None, None) => return None,
+    };
+
+    (peak >= CLIPPING_PEAK_THRESHOLD).then(|| ValidationIssue {
+        severity: Severity::Warning,
+        path: file_path.to_string_lossy().to_string(),
+        category: field.to_string(),
+        message: format!("Peak {} reaches full scale; track may clip", peak),
+    })
+}
+
+/// Report albums whose tracks don't all share one sample rate and bit depth,
+/// e.g. a single downsampled track on a 96 kHz/24-bit release.
+pub fn validate_mixed_sample_rates(library: &Library) -> Vec<ValidationIssue> {
+    library
+        .artists
+        .iter()
+        .flat_map(|artist| &artist.albums)
+        .filter_map(validate_mixed_sample_rate)
+        .collect()
+}
+
+/// Check a single album for mixed sample rates or bit depths.
+///
+/// Tracks with an unknown sample rate are ignored, and albums where no track
+/// has a known sample rate are skipped. Bit depth only counts where known, as
+/// lossy formats don't report one.
+pub fn validate_mixed_sample_rate(album: &AlbumNode) -> Option<ValidationIssue> {
+    let formats: Vec<(u32, Option<u32>)> = album
+        .tracks
+        .iter()
+        .filter_map(|track| {
+            let metadata = &track.metadata;
+            let sample_rate = metadata.sample_rate.as_ref()?.value;
+            Some((sample_rate, metadata.bit_depth.as_ref().map(|b| b.value)))
+        })
+        .collect();
+
+    let mut rates: Vec<u32> = formats.iter().map(|&(rate, _)| rate).collect();
+    rates.sort_unstable();
+    rates.dedup();
+    let mut depths: Vec<u32> = formats.iter().filter_map(|&(_, bits)| bits).collect();
+    depths.sort_unstable();
+    depths.dedup();
+
+    if rates.len() <= 1 && depths.len() <= 1 {
+        return None;
+    }
+
+    let mut counts: std::collections::BTreeMap<(u32, Option<u32>), usize> =
+        std::collections::BTreeMap::new();
+    for format in formats {
+        *counts.entry(format).or_default() += 1;
+    }
+    let breakdown: Vec<String> = counts
+        .into_iter()
+        .map(|((rate, bits), count)| {
+            let bits = bits.map(|b| format!("/{}-bit", b)).unwrap_or_default();
+            let noun = if count == 1 { "track" } else { "tracks" };
+            format!("{} Hz{} ({} {})", rate, bits, count, noun)
+        })
+        .collect();
+
+    Some(ValidationIssue {
+        severity: Severity::Warning,
+        path: album.path.to_string_lossy().to_string(),
+        category: "sample_rate".to_string(),
+        message: format!(
+            "Album '{}' mixes sample rates/bit depths: {}",
+            album.title,
+            breakdown.join(", ")
+        ),
+    })
+}
+
+/// Report albums where a few tracks disagree with the album's majority genre
+/// (see [`validate_album_genre_consistency`]).
+pub fn validate_album_genres(library: &Library) -> Vec<ValidationIssue> {
+    library
+        .artists
+        .iter()
+        .flat_map(|artist| &artist.albums)
+        .filter_map(validate_album_genre_consistency)
+        .collect()
+}
+
+/// Check a single album for tracks whose genre differs from a clear majority,
+/// e.g. one "Pop" track on an otherwise "Rock" album.
+///
+/// Only reported when more than half of the tagged tracks share one genre.
+/// Albums where fewer than half the tracks have a genre tag are skipped.
+pub fn validate_album_genre_consistency(album: &AlbumNode) -> Option<ValidationIssue> {
+    let genres: Vec<(&str, &Path)> = album
+        .tracks
+        .iter()
+        .filter_map(|track| {
+            let genre = track.metadata.genre.as_ref()?.value.trim();
+            (!genre.is_empty()).then_some((genre, track.file_path.as_path()))
+        })
+        .collect();
+    if genres.len() * 2 < album.tracks.len() {
+        return None;
+    }
+
+    let mut counts: std::collections::BTreeMap<&str, usize> = std::collections::BTreeMap::new();
+    for &(genre, _) in &genres {
+        *counts.entry(genre).or_default() += 1;
+    }
+    let (&majority, &majority_count) = counts.iter().max_by_key(|&(_, count)| *count)?;
+    if counts.len() < 2 || majority_count * 2 <= genres.len() {
+        return None;
+    }
+
+    let minorities: Vec<String> = genres
+        .iter()
+        .filter(|&&(genre, _)| genre != majority)
+        .map(|(genre, path)| {
+            let file = path.file_name().unwrap_or(path.as_os_str());
+            format!("'{}' ({})", genre, file.to_string_lossy())
+        })
+        .collect();
+
+    Some(ValidationIssue {
+        severity: Severity::Warning,
+        path: album.path.to_string_lossy().to_string(),
+        category: "genre".to_string(),
+        message: format!(
+            "Album '{}' is mostly '{}' ({} of {} tagged tracks) but has: {}",
+            album.title,
+            majority,
+            majority_count,
+            genres.len(),
+            minorities.join(", ")
+        ),
+    })
+}
+
+/// Report albums whose disc numbers don't run 1..N (see
+/// [`validate_disc_numbering`]).
+pub fn validate_library_disc_numbering(library: &Library) -> Vec<ValidationIssue> {
+    library
+        .artists
+        .iter()
+        .flat_map(|artist| &artist.albums)
+        .flat_map(validate_disc_numbering)
+        .collect()
+}
+
+/// Check that a multi-disc album's disc numbers run 1..N.
+///
+/// Reports discs missing below the highest disc number, and discs that look
+/// duplicated because two tracks on them share a track number. Albums where
+/// no track has a disc number are skipped.
+pub fn validate_disc_numbering(album: &AlbumNode) -> Vec<ValidationIssue> {
+    let mut discs: std::collections::BTreeMap<u32, Vec<Option<u32>>> =
+        std::collections::BTreeMap::new();
+    for track in &album.tracks {
+        if let Some(disc) = track.metadata.disc_number.as_ref() {
+            discs
+                .entry(disc.value)
+                .or_default()
+                .push(track.metadata.track_number.as_ref().map(|t| t.value));
+        }
+    }
+    let Some(&highest) = discs.keys().next_back() else {
+        return Vec::new();
+    };
+
+    let disc_list = discs
+        .keys()
+        .map(u32::to_string)
+        .collect::<Vec<_>>()
+        .join(", ");
+    let issue = |message: String| ValidationIssue {
+        severity: Severity::Warning,
+        path: album.path.to_string_lossy().to_string(),
+        category: "disc_number".to_string(),
+        message,
+    };
+    let mut issues = Vec::new();
+
+    let missing: Vec<String> = (1..=highest)
+        .filter(|disc| !discs.contains_key(disc))
+        .map(|disc| disc.to_string())
+        .collect();
+    if !missing.is_empty() {
+        issues.push(issue(format!(
+            "Album '{}' has discs [{}]: missing disc(s) {}",
+            album.title,
+            disc_list,
+            missing.join(", ")
+        )));
+    }
+
+    for (disc, numbers) in discs {
+        let mut numbers: Vec<u32> = numbers.into_iter().flatten().collect();
+        let total = numbers.len();
+        numbers.sort_unstable();
+        numbers.dedup();
+        if numbers.len() < total {
+            issues.push(issue(format!(
+                "Album '{}' has discs [{}]: disc {} looks duplicated (track numbers repeat)",
+                album.title, disc_list, disc
+            )));
+        }
+    }
+
+    issues
+}
+
+/// Share of a folder's album-tagged tracks one album value must hold before
+/// the other values are reported as mistagged.
+const ALBUM_MAJORITY_SHARE: f64 = 0.75;
+
+/// Report tracks whose album tag disagrees with the clear majority of their
+/// folder, e.g. one track tagged "Album B" next to four tagged "Album A".
+///
+/// Folders without a clear majority (at least [`ALBUM_MAJORITY_SHARE`] of the
+/// tracks with an album tag) are skipped, since they may legitimately hold
+/// several albums.
+pub fn validate_album_consistency_in_folder(tracks: &[Track]) -> Vec<ValidationIssue> {
+    let mut folders: std::collections::BTreeMap<&Path, Vec<(&Track, &str)>> =
+        std::collections::BTreeMap::new();
+    for track in tracks {
+        let Some(album) = track.metadata.album.as_ref() else {
+            continue;
+        };
+        let album = album.value.trim();
+        if album.is_empty() {
+            continue;
+        }
+        let folder = track.file_path.parent().unwrap_or(Path::new(""));
+        folders.entry(folder).or_default().push((track, album));
+    }
+
+    let mut warnings = Vec::new();
+    for folder_tracks in folders.values() {
+        let mut counts: std::collections::BTreeMap<&str, usize> = std::collections::BTreeMap::new();
+        for &(_, album) in folder_tracks {
+            *counts.entry(album).or_default() += 1;
+        }
+        if counts.len() < 2 {
+            continue;
+        }
+
+        let Some((&majority, &majority_count)) = counts.iter().max_by_key(|&(_, count)| *count)
+        else {
+            continue;
+        };
+        let total = folder_tracks.len();
+        if (majority_count as f64) < total as f64 * ALBUM_MAJORITY_SHARE {
+            continue;
+        }
+
+        for &(track, album) in folder_tracks {
+            if album != majority {
+                warnings.push(ValidationIssue {
+                    severity: Severity::Warning,
+                    path: track.file_path.to_string_lossy().to_string(),
+                    category: "album".to_string(),
+                    message: format!(
+                        "Album '{}' differs from '{}' used by {} of {} tracks in this folder",
+                        album, majority, majority_count, total
+                    ),
+                });
+            }
+        }
+    }
+
+    warnings
+}
+
+/// Parse the track number a filename starts with, e.g. `05 - Song.flac` → 5.
+///
+/// Disc-prefixed names such as `1-05 Song.flac` yield the track part (5). At
+/// most three digits are read, and the number must be followed by a separator
+/// or the end of the stem, so names like `2Pac - Song` or `1999 - Song` are
+/// not mistaken for track numbers.
+pub fn parse_leading_track_number(filename: &str) -> Option<u32> {
+    let stem = Path::new(filename)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or(filename)
+        .trim_start();
+
+    fn split_digits(s: &str) -> (&str, &str) {
+        s.split_at(s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len()))
+    }
+
+    let (mut digits, mut rest) = split_digits(stem);
+    if let Some(track_part) = rest.strip_prefix(['-', '.']) {
+        let (next_digits, next_rest) = split_digits(track_part);
+        if !next_digits.is_empty() {
+            (digits, rest) = (next_digits, next_rest);
+        }
+    }
+
+    let separated = rest.chars().next().is_none_or(|c| !c.is_alphanumeric());
+    if digits.is_empty() || digits.len() > 3 || !separated {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+/// Warn when a track's filename number disagrees with its embedded
+/// `track_number`. Nothing is reported unless both are present, or for
+/// CUE-sourced tracks, whose numbers don't come from the file.
+pub fn validate_track_number_vs_filename(track: &Track) -> Option<ValidationIssue> {
+    if is_cue_sourced(track) {
+        return None;
+    }
+    let embedded = track.metadata.track_number.as_ref()?;
+    let from_filename =
+        parse_leading_track_number(&track.file_path.file_name()?.to_string_lossy())?;
+
+    (from_filename != embedded.value).then(|| ValidationIssue {
+        severity: Severity::Warning,
+        path: track.file_path.to_string_lossy().to_string(),
+        category: "track_number".to_string(),
+        message: format!(
+            "Filename suggests track {} but embedded track number is {}",
+            from_filename, embedded.value
+        ),
+    })
+}
+
+/// Returns `true` for a musical key in a common notation: standard (`A`,
+/// `F#m`, `Bb minor`), Camelot (`8A`, `12B`) or Open Key (`1d`, `10m`).
+pub fn is_standard_key(key: &str) -> bool {
+    let key = key.trim();
+
+    // Camelot and Open Key: a wheel position 1-12 followed by a letter
+    let digits = key.len() - key.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    if digits > 0 {
+        let wheel_ok = key[..digits]
+            .parse::<u32>()
+            .is_ok_and(|n| (1..=12).contains(&n));
+        let mode = &key[digits..];
+        return wheel_ok && ["A", "B", "d", "m"].contains(&mode);
+    }
+
+    let mut chars = key.chars();
+    if !chars
+        .next()
+        .is_some_and(|c| matches!(c.to_ascii_uppercase(), 'A'..='G'))
+    {
+        return false;
+    }
+    let rest = chars.as_str();
+    let rest = rest
+        .strip_prefix(['#', 'b', '♯', '♭'])
+        .unwrap_or(rest)
+        .trim_start();
+    ["", "m", "min", "minor", "maj", "major"].contains(&rest.to_lowercase().as_str())
+}
+
+/// Warn about tracks whose `initial_key` isn't in a notation accepted by
+/// [`is_standard_key`].
+pub fn validate_initial_keys(tracks: &[Track]) -> Vec<ValidationIssue> {
+    tracks
+        .iter()
+        .filter_map(|track| {
+            let key = track.metadata.initial_key.as_ref()?;
+            (!is_standard_key(&key.value)).then(|| ValidationIssue {
+                severity: Severity::Warning,
+                path: track.file_path.to_string_lossy().to_string(),
+                category: "initial_key".to_string(),
+                message: format!("Key '{}' is not in a standard notation", key.value),
+            })
+        })
+        .collect()
+}
+
+/// Warn about tracks whose title features an artist (see
+/// [`extract_featured_artists`]) that neither the track artists (see
+/// [`TrackMetadata::artist_names`]) nor the album artist tag mentions.
+/// Matching ignores case; nothing is changed.
+pub fn validate_featured_artists(tracks: &[Track]) -> Vec<ValidationIssue> {
+    tracks
+        .iter()
+        .filter_map(|track| {
+            let title = &track.metadata.title.as_ref()?.value;
+            let credited: Vec<String> = track
+                .metadata
+                .artist_names()
+                .into_iter()
+                .chain(track.metadata.album_artist.iter().map(|v| v.value.clone()))
+                .map(|name| name.to_lowercase())
+                .collect();
+            let missing: Vec<String> = extract_featured_artists(title)
+                .into_iter()
+                .filter(|name| {
+                    let name = name.to_lowercase();
+                    !credited.iter().any(|artist| artist.contains(&name))
+                })
+                .collect();
+
+            (!missing.is_empty()).then(|| ValidationIssue {
+                severity: Severity::Warning,
+                path: track.file_path.to_string_lossy().to_string(),
+                category: "featured_artist".to_string(),
+                message: format!(
+                    "Title '{}' features {} but the artist tags don't credit them",
+                    title,
+                    missing.join(", ")
+                ),
+            })
+        })
+        .collect()
+}
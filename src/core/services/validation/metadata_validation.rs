@@ -201,7 +201,6 @@ mod tests {
             title: Some(MetadataValue::embedded("Test Title".to_string())),
             artist: Some(MetadataValue::embedded("Test Artist".to_string())),
             album: Some(MetadataValue::embedded("Test Album".to_string())),
-            album_artist: None,
             track_number: Some(MetadataValue::embedded(1)),
             disc_number: Some(MetadataValue::embedded(1)),
             year: Some(MetadataValue::embedded(2023)),
@@ -209,6 +208,7 @@ mod tests {
             duration: Some(MetadataValue::embedded(180.0)),
             format: "flac".to_string(),
             path: file_path.clone(),
+            ..Default::default()
         };
 
         let track = Track::new(file_path, metadata);
@@ -225,7 +225,6 @@ mod tests {
             title: None, // Missing required field
             artist: Some(MetadataValue::embedded("Test Artist".to_string())),
             album: Some(MetadataValue::embedded("Test Album".to_string())),
-            album_artist: None,
             track_number: Some(MetadataValue::embedded(1)),
             disc_number: Some(MetadataValue::embedded(1)),
             year: Some(MetadataValue::embedded(2023)),
@@ -233,6 +232,7 @@ mod tests {
             duration: Some(MetadataValue::embedded(180.0)),
             format: "flac".to_string(),
             path: file_path.clone(),
+            ..Default::default()
         };
 
         let track = Track::new(file_path, metadata);
@@ -249,7 +249,6 @@ mod tests {
             title: Some(MetadataValue::embedded("Test Title".to_string())),
             artist: Some(MetadataValue::embedded("Test Artist".to_string())),
             album: Some(MetadataValue::embedded("Test Album".to_string())),
-            album_artist: None,
             track_number: Some(MetadataValue::embedded(1000)), // Invalid: > 999
             disc_number: Some(MetadataValue::embedded(1)),
             year: Some(MetadataValue::embedded(2023)),
@@ -257,6 +256,7 @@ mod tests {
             duration: Some(MetadataValue::embedded(180.0)),
             format: "flac".to_string(),
             path: file_path.clone(),
+            ..Default::default()
         };
 
         let track = Track::new(file_path, metadata);
@@ -273,7 +273,6 @@ mod tests {
             title: Some(MetadataValue::embedded("".to_string())), // Empty string
             artist: Some(MetadataValue::embedded("Test Artist".to_string())),
             album: Some(MetadataValue::embedded("Test Album".to_string())),
-            album_artist: None,
             track_number: Some(MetadataValue::embedded(1)),
             disc_number: Some(MetadataValue::embedded(1)),
             year: Some(MetadataValue::embedded(2023)),
@@ -281,6 +280,7 @@ mod tests {
             duration: Some(MetadataValue::embedded(180.0)),
             format: "flac".to_string(),
             path: file_path.clone(),
+            ..Default::default()
         };
 
         let track = Track::new(file_path, metadata);
@@ -0,0 +1,294 @@
+//! Folder-structure conformance checks against a path template.
+
+use crate::core::domain::models::{Track, TrackMetadata, TrackNode};
+use crate::core::services::cue::{format_track_number, track_number_width};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Placeholders understood by [`expand_template`].
+pub const TEMPLATE_PLACEHOLDERS: &[&str] = &[
+    "artist",
+    "album_artist",
+    "album",
+    "title",
+    "track",
+    "disc",
+    "year",
+    "genre",
+    "ext",
+];
+
+/// A file whose location does not match the expected template path
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct StructureViolation {
+    pub file_path: String,
+    /// Path expected from the template, relative to the library root
+    pub expected: Option<String>,
+    /// Trailing components of the actual path, compared against `expected`
+    pub actual: String,
+    pub message: String,
+}
+
+/// Check that a template only uses known placeholders and balanced braces.
+pub fn check_template(template: &str) -> Result<(), String> {
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        let Some(close) = rest[open..].find('}') else {
+            return Err(format!("Unclosed placeholder in template: {}", template));
+        };
+        let name = &rest[open + 1..open + close];
+        if !TEMPLATE_PLACEHOLDERS.contains(&name) {
+            return Err(format!(
+                "Unknown placeholder {{{}}} (supported: {})",
+                name,
+                TEMPLATE_PLACEHOLDERS.join(", ")
+            ));
+        }
+        rest = &rest[open + close + 1..];
+    }
+    if rest.contains('}') {
+        return Err(format!("Unmatched '}}' in template: {}", template));
+    }
+    Ok(())
+}
+
+/// Replace characters that cannot appear in a path component.
+pub(crate) fn sanitize_component(value: &str) -> String {
+    value
+        .trim()
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            c => c,
+        })
+        .collect()
+}
+
+/// Zero-padding width of `{track}` for each of `tracks`, in order.
+///
+/// Tracks are grouped into albums by album artist and album tag (by folder
+/// when the album tag is missing), and each album gets its
+/// [`track_number_width`], so a 120-track album expands to `001`..`120`.
+pub(crate) fn album_track_widths(tracks: &[Track]) -> Vec<usize> {
+    fn album_key(track: &Track) -> (Option<&str>, Option<&str>, Option<&Path>) {
+        let metadata = &track.metadata;
+        let artist = metadata
+            .album_artist
+            .as_ref()
+            .or(metadata.artist.as_ref())
+            .map(|mv| mv.value.as_str());
+        match &metadata.album {
+            Some(album) => (artist, Some(album.value.as_str()), None),
+            None => (artist, None, track.file_path.parent()),
+        }
+    }
+
+    let mut albums: HashMap<_, Vec<TrackNode>> = HashMap::new();
+    for track in tracks {
+        albums.entry(album_key(track)).or_default().push(TrackNode {
+            file_path: track.file_path.clone(),
+            metadata: track.metadata.clone(),
+        });
+    }
+    let widths: HashMap<_, usize> = albums
+        .into_iter()
+        .map(|(key, nodes)| (key, track_number_width(&nodes)))
+        .collect();
+
+    tracks
+        .iter()
+        .map(|track| widths[&album_key(track)])
+        .collect()
+}
+
+/// Value for a single placeholder, or `None` when the metadata lacks it.
+fn placeholder_value(
+    name: &str,
+    metadata: &TrackMetadata,
+    path: &Path,
+    track_width: usize,
+) -> Option<String> {
+    let text = |v: Option<&crate::MetadataValue<String>>| v.map(|mv| sanitize_component(&mv.value));
+    match name {
+        "artist" => text(metadata.artist.as_ref()),
+        "album_artist" => text(metadata.album_artist.as_ref().or(metadata.artist.as_ref())),
+        "album" => text(metadata.album.as_ref()),
+        "title" => text(metadata.title.as_ref()),
+        "genre" => text(metadata.genre.as_ref()),
+        "track" => metadata
+            .track_number
+            .as_ref()
+            .map(|mv| format_track_number(mv.value, track_width)),
+        "disc" => metadata.disc_number.as_ref().map(|mv| mv.value.to_string()),
+        "year" => metadata.year.as_ref().map(|mv| mv.value.to_string()),
+        "ext" => path.extension().map(|e| e.to_string_lossy().into_owned()),
+        _ => None,
+    }
+}
+
+/// Expand `template` (e.g. `{artist}/{album}/{track} - {title}.{ext}`) for a track.
+///
+/// `{track}` is zero-padded to `track_width` digits (see [`album_track_widths`]).
+/// Returns the names of missing placeholders when the metadata cannot fill them.
+pub fn expand_template(
+    template: &str,
+    metadata: &TrackMetadata,
+    path: &Path,
+    track_width: usize,
+) -> Result<String, Vec<String>> {
+    let mut out = String::new();
+    let mut missing = Vec::new();
+    let mut rest = template;
+
+    while let Some(open) = rest.find('{') {
+        out.push_str(&rest[..open]);
+        let Some(close) = rest[open..].find('}') else {
+            break;
+        };
+        let name = &rest[open + 1..open + close];
+        match placeholder_value(name, metadata, path, track_width) {
+            Some(value) => out.push_str(&value),
+            None => missing.push(name.to_string()),
+        }
+        rest = &rest[open + close + 1..];
+    }
+    out.push_str(rest);
+
+    if missing.is_empty() {
+        Ok(out)
+    } else {
+        Err(missing)
+    }
+}
+
+/// Check each track's path against the path derived from its metadata.
+///
+/// Only the trailing path components are compared (as many as the template
+/// has), so the library root location does not matter.
+pub fn validate_structure(tracks: &[Track], template: &str) -> Vec<StructureViolation> {
+    let depth = template.split('/').filter(|c| !c.is_empty()).count();
+    let mut violations = Vec::new();
+
+    for (track, width) in tracks.iter().zip(album_track_widths(tracks)) {
+        let components: Vec<String> = track
+            .file_path
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().into_owned())
+            .collect();
+        let actual = components[components.len().saturating_sub(depth)..].join("/");
+        let file_path = track.file_path.to_string_lossy().to_string();
+
+        match expand_template(template, &track.metadata, &track.file_path, width) {
+            Ok(expected) if expected == actual => {}
+            Ok(expected) => violations.push(StructureViolation {
+                file_path,
+                message: format!("Expected '{}' but found '{}'", expected, actual),
+                expected: Some(expected),
+                actual,
+            }),
+            Err(missing) => violations.push(StructureViolation {
+                file_path,
+                expected: None,
+                actual,
+                message: format!(
+                    "Cannot derive expected path; missing metadata: {}",
+                    missing.join(", ")
+                ),
+            }),
+        }
+    }
+
+    violations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MetadataValue;
+    use crate::core::builders::TrackMetadataBuilder;
+    use crate::core::domain::models::MetadataSource;
+    use std::path::PathBuf;
+
+    const TEMPLATE: &str = "{artist}/{album}/{track} - {title}.{ext}";
+
+    fn track(path: &str, track_number: Option<u32>) -> Track {
+        let mut builder = TrackMetadataBuilder::new(path)
+            .artist("Artist", MetadataSource::Embedded, 1.0)
+            .album("Album", MetadataSource::Embedded, 1.0)
+            .title("Song", MetadataSource::Embedded, 1.0);
+        if let Some(n) = track_number {
+            builder = builder.track_number(n, MetadataSource::Embedded, 1.0);
+        }
+        Track::new(PathBuf::from(path), builder.build())
+    }
+
+    #[test]
+    fn test_conforming_path_has_no_violation() {
+        let tracks = vec![track("/music/Artist/Album/03 - Song.flac", Some(3))];
+        assert!(validate_structure(&tracks, TEMPLATE).is_empty());
+    }
+
+    #[test]
+    fn test_misplaced_file_is_reported() {
+        let tracks = vec![track("/music/Other/Album/03 - Song.flac", Some(3))];
+        let violations = validate_structure(&tracks, TEMPLATE);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(
+            violations[0].expected.as_deref(),
+            Some("Artist/Album/03 - Song.flac")
+        );
+        assert_eq!(violations[0].actual, "Other/Album/03 - Song.flac");
+    }
+
+    #[test]
+    fn test_missing_metadata_is_reported() {
+        let tracks = vec![track("/music/Artist/Album/Song.flac", None)];
+        let violations = validate_structure(&tracks, TEMPLATE);
+
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].expected.is_none());
+        assert!(violations[0].message.contains("track"));
+    }
+
+    #[test]
+    fn test_expand_template_sanitizes_components() {
+        let mut metadata = track("/x/a.mp3", Some(1)).metadata;
+        metadata.album = Some(MetadataValue::embedded("AC/DC: Live".to_string()));
+        let expanded =
+            expand_template("{album}/{track}.{ext}", &metadata, Path::new("/x/a.mp3"), 2);
+        assert_eq!(expanded, Ok("AC_DC_ Live/01.mp3".to_string()));
+    }
+
+    #[test]
+    fn test_track_width_follows_album_track_count() {
+        let mut tracks: Vec<Track> = (1..=120)
+            .map(|n| {
+                track(
+                    &format!("/music/Artist/Album/{:03} - Song.flac", n),
+                    Some(n),
+                )
+            })
+            .collect();
+        tracks.push(track("/music/Artist/Album/Song.flac", None));
+        let mut single = track("/music/Artist/Single/01 - Song.flac", Some(1));
+        single.metadata.album = Some(MetadataValue::embedded("Single".to_string()));
+        tracks.push(single);
+
+        let widths = album_track_widths(&tracks);
+        assert!(widths[..121].iter().all(|&w| w == 3));
+        assert_eq!(widths[121], 2);
+
+        let violations = validate_structure(&tracks, TEMPLATE);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].expected.is_none());
+    }
+
+    #[test]
+    fn test_check_template() {
+        assert!(check_template(TEMPLATE).is_ok());
+        assert!(check_template("{artist}/{albun}").is_err());
+        assert!(check_template("{artist/{album}").is_err());
+        assert!(check_template("{artist}}").is_err());
+    }
+}
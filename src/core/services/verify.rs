@@ -0,0 +1,261 @@
+//! Audio file integrity checks.
+//!
+//! Each supported audio file must be non-empty and parse with its format
+//! handler (container, stream properties and tags). This is a structural
+//! check: the audio itself is not decoded, except by `--analyze-silence`
+//! (built with the `silence` feature), which measures leading and trailing
+//! silence from the decoded samples.
+
+use std::path::{Path, PathBuf};
+
+use serde_json::to_string_pretty;
+use walkdir::WalkDir;
+
+use crate::adapters::audio_formats::{is_format_supported, read_metadata};
+
+/// Trailing silence longer than this (seconds) is flagged in the text report.
+pub const TRAILING_SILENCE_LIMIT: f64 = 5.0;
+
+/// Outcome of verifying one file.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct VerifyResult {
+    pub path: PathBuf,
+    pub ok: bool,
+    /// Why the file failed; `None` when `ok`.
+    pub error: Option<String>,
+    /// Measured silence, with `--analyze-silence` and a decodable codec.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub silence: Option<Silence>,
+}
+
+/// Leading and trailing silence of a track, in seconds.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Silence {
+    pub leading: f64,
+    pub trailing: f64,
+}
+
+impl Silence {
+    pub fn has_long_tail(&self) -> bool {
+        self.trailing > TRAILING_SILENCE_LIMIT
+    }
+}
+
+/// Whether this build can decode audio for `--analyze-silence`.
+pub const SILENCE_ANALYSIS_AVAILABLE: bool = cfg!(feature = "silence");
+
+/// Verify a single audio file.
+pub fn verify_file(path: &Path) -> VerifyResult {
+    let error = match std::fs::metadata(path) {
+        Err(e) => Some(e.to_string()),
+        Ok(m) if m.len() == 0 => Some("File is empty".to_string()),
+        Ok(_) => read_metadata(path).err().map(|e| e.kind().to_string()),
+    };
+    VerifyResult {
+        path: path.to_path_buf(),
+        ok: error.is_none(),
+        error,
+        silence: None,
+    }
+}
+
+/// [`verify_file`], then decode a file that passed to measure its silence.
+/// A decode failure fails the file.
+#[cfg(feature = "silence")]
+pub fn verify_file_with_silence(path: &Path) -> VerifyResult {
+    let mut result = verify_file(path);
+    if result.ok {
+        match crate::adapters::audio_formats::silence::measure_silence(path) {
+            Ok(span) => {
+                result.silence = span.map(|(leading, trailing)| Silence { leading, trailing })
+            }
+            Err(e) => {
+                result.ok = false;
+                result.error = Some(e.to_string());
+            }
+        }
+    }
+    result
+}
+
+/// Verify every supported audio file under `base` (or `base` itself when it
+/// is a file), sorted by path. Symlinks are not followed.
+pub fn verify_path(base: &Path) -> Vec<VerifyResult> {
+    verify_paths_with(base, verify_file)
+}
+
+/// [`verify_path`] with [`verify_file_with_silence`] for each file.
+#[cfg(feature = "silence")]
+pub fn verify_path_with_silence(base: &Path) -> Result<Vec<VerifyResult>, String> {
+    Ok(verify_paths_with(base, verify_file_with_silence))
+}
+
+/// Without the `silence` feature there is no decoder to measure silence with.
+#[cfg(not(feature = "silence"))]
+pub fn verify_path_with_silence(_base: &Path) -> Result<Vec<VerifyResult>, String> {
+    Err(
+        "--analyze-silence needs a build with the `silence` feature (cargo build --features silence)"
+            .to_string(),
+    )
+}
+
+fn verify_paths_with(base: &Path, verify: fn(&Path) -> VerifyResult) -> Vec<VerifyResult> {
+    let mut paths: Vec<PathBuf> = WalkDir::new(base)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|e| e.file_type().is_file() && is_format_supported(e.path()))
+        .map(|e| e.into_path())
+        .collect();
+    paths.sort();
+    paths.iter().map(|p| verify(p)).collect()
+}
+
+/// Format verify results as text, one line per file and a pass/fail summary.
+pub fn format_verify_results(results: &[VerifyResult]) -> String {
+    let mut out = String::new();
+    for result in results {
+        match (&result.error, &result.silence) {
+            (Some(e), _) => out.push_str(&format!("❌ {}: {}\n", result.path.display(), e)),
+            (None, Some(silence)) if silence.has_long_tail() => out.push_str(&format!(
+                "⚠️ {}: {:.1}s trailing silence\n",
+                result.path.display(),
+                silence.trailing
+            )),
+            (None, _) => out.push_str(&format!("✅ {}\n", result.path.display())),
+        }
+    }
+    let failed = results.iter().filter(|r| !r.ok).count();
+    out.push_str(&format!(
+        "\n{} passed, {} failed ({} files)",
+        results.len() - failed,
+        failed,
+        results.len()
+    ));
+    let long_tails = results
+        .iter()
+        .filter(|r| r.silence.is_some_and(|s| s.has_long_tail()))
+        .count();
+    if long_tails > 0 {
+        out.push_str(&format!(
+            "\n{} tracks with more than {}s of trailing silence",
+            long_tails, TRAILING_SILENCE_LIMIT
+        ));
+    }
+    out
+}
+
+/// Verify `path` and render the results as text or a JSON array of
+/// [`VerifyResult`]s. With `analyze_silence` each file is also decoded to
+/// measure its silence; that needs the `silence` feature.
+///
+/// Returns `Err` with the same report when any file failed.
+pub fn verify_by_path(path: &Path, json: bool, analyze_silence: bool) -> Result<String, String> {
+    let results = if analyze_silence {
+        verify_path_with_silence(path)?
+    } else {
+        verify_path(path)
+    };
+    let report = if json {
+        to_string_pretty(&results).map_err(|e| format!("Error serializing to JSON: {}", e))?
+    } else {
+        format_verify_results(&results)
+    };
+
+    if results.iter().all(|r| r.ok) {
+        Ok(report)
+    } else {
+        Err(report)
+    }
+}
+
+// ── Tests ───────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_verify_path_reports_each_file() {
+        let dir = TempDir::new().unwrap();
+        std::fs::copy(
+            "tests/fixtures/flac/simple/track1.flac",
+            dir.path().join("good.flac"),
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("empty.flac"), b"").unwrap();
+        std::fs::write(dir.path().join("notes.txt"), b"ignored").unwrap();
+
+        let results = verify_path(dir.path());
+
+        assert_eq!(results.len(), 2);
+        assert!(results[0].path.ends_with("empty.flac"));
+        assert!(!results[0].ok);
+        assert_eq!(results[0].error.as_deref(), Some("File is empty"));
+        assert!(results[1].ok);
+        assert!(results[1].error.is_none());
+    }
+
+    #[test]
+    fn test_verify_by_path_json_array_and_text_summary() {
+        let dir = TempDir::new().unwrap();
+        std::fs::copy(
+            "tests/fixtures/flac/simple/track1.flac",
+            dir.path().join("good.flac"),
+        )
+        .unwrap();
+
+        let json = verify_by_path(dir.path(), true, false).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed[0]["ok"], true);
+        assert!(parsed[0]["error"].is_null());
+
+        std::fs::write(dir.path().join("corrupt.flac"), b"not a flac").unwrap();
+        let text = verify_by_path(dir.path(), false, false).unwrap_err();
+        assert!(text.contains("❌"));
+        assert!(text.ends_with("1 passed, 1 failed (2 files)"));
+    }
+
+    #[test]
+    fn test_format_verify_results_flags_long_trailing_silence() {
+        let result = |name: &str, trailing: f64| VerifyResult {
+            path: PathBuf::from(name),
+            ok: true,
+            error: None,
+            silence: Some(Silence {
+                leading: 0.0,
+                trailing,
+            }),
+        };
+        let results = vec![result("short.flac", 1.5), result("tail.flac", 7.4)];
+
+        let text = format_verify_results(&results);
+        assert!(text.contains("✅ short.flac"));
+        assert!(text.contains("⚠️ tail.flac: 7.4s trailing silence"));
+        assert!(text.contains("2 passed, 0 failed (2 files)"));
+        assert!(text.ends_with("1 tracks with more than 5s of trailing silence"));
+
+        let json = serde_json::to_value(&results).unwrap();
+        assert_eq!(json[1]["silence"]["trailing"], 7.4);
+    }
+
+    #[cfg(feature = "silence")]
+    #[test]
+    fn test_verify_path_with_silence_measures_decodable_files() {
+        let results = verify_path_with_silence(Path::new("tests/fixtures/flac/simple")).unwrap();
+
+        assert!(!results.is_empty());
+        for result in results {
+            assert!(result.ok, "{:?}", result.error);
+            let silence = result.silence.unwrap();
+            assert!(silence.leading >= 0.0 && silence.trailing >= 0.0);
+        }
+    }
+
+    #[cfg(not(feature = "silence"))]
+    #[test]
+    fn test_analyze_silence_needs_the_feature() {
+        let err = verify_by_path(Path::new("tests/fixtures/flac/simple"), false, true).unwrap_err();
+        assert!(err.contains("`silence` feature"));
+    }
+}
@@ -3,7 +3,7 @@
 //! This module handles environment variable configuration and validation.
 
 use std::env;
-use std::path::PathBuf;
+use std::path::{Component, Path, PathBuf};
 use std::time::Duration;
 
 #[derive(Debug, Clone)]
@@ -16,6 +16,8 @@ pub struct Config {
     pub scan_timeout: Duration,
     /// Allowed paths for security
     pub allowed_paths: Vec<PathBuf>,
+    /// Let tools that change files (e.g. `reorganize` with `apply`) write
+    pub allow_writes: bool,
 }
 
 impl Default for Config {
@@ -25,6 +27,7 @@ impl Default for Config {
             default_library_path: None,
             scan_timeout: Duration::from_secs(300),
             allowed_paths: vec![],
+            allow_writes: false,
         }
     }
 }
@@ -60,20 +63,36 @@ impl Config {
                 .collect();
         }
 
+        // File-changing tools stay read-only unless explicitly enabled
+        if let Ok(allow_writes) = env::var("MUSIC_ALLOW_WRITES") {
+            config.allow_writes = matches!(
+                allow_writes.trim().to_lowercase().as_str(),
+                "1" | "true" | "yes"
+            );
+        }
+
         config
     }
 
     /// Validate that a path is allowed based on security configuration
+    ///
+    /// Paths containing `..` are refused, and symlinks are resolved before the
+    /// comparison so a link inside an allowed directory can't point outside it.
     pub fn is_path_allowed(&self, path: &PathBuf) -> bool {
         // If no allowed paths are configured, allow everything (backwards compatibility)
         if self.allowed_paths.is_empty() {
             return true;
         }
 
-        // Check if the path is under any of the allowed paths
+        if path.components().any(|c| c == Component::ParentDir) {
+            return false;
+        }
+
+        // Check if the resolved path is under any of the allowed paths
+        let resolved = resolve_existing_prefix(path);
         self.allowed_paths
             .iter()
-            .any(|allowed| path.starts_with(allowed) || path == allowed)
+            .any(|allowed| resolved.starts_with(resolve_existing_prefix(allowed)))
     }
 
     /// Get the default library path or return an error if none is set
@@ -98,6 +117,29 @@ impl Config {
     }
 }
 
+/// Canonicalize the nearest existing ancestor of `path` and re-append the
+/// rest, so a path that doesn't exist yet (e.g. a move destination) is checked
+/// where it would actually land.
+fn resolve_existing_prefix(path: &Path) -> PathBuf {
+    let mut existing = path;
+    let mut missing = Vec::new();
+    loop {
+        if let Ok(resolved) = existing.canonicalize() {
+            return missing
+                .iter()
+                .rev()
+                .fold(resolved, |acc, name| acc.join(name));
+        }
+        match (existing.parent(), existing.file_name()) {
+            (Some(parent), Some(name)) => {
+                missing.push(name);
+                existing = parent;
+            }
+            _ => return path.to_path_buf(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -110,6 +152,7 @@ mod tests {
         assert!(config.default_library_path.is_none());
         assert_eq!(config.scan_timeout, Duration::from_secs(300));
         assert!(config.allowed_paths.is_empty());
+        assert!(!config.allow_writes);
     }
 
     #[test]
@@ -120,6 +163,7 @@ mod tests {
             env::set_var("MUSIC_LIBRARY_PATH", "/test/music");
             env::set_var("MUSIC_SCAN_TIMEOUT", "600");
             env::set_var("MUSIC_ALLOWED_PATHS", "/music,/backup/music");
+            env::set_var("MUSIC_ALLOW_WRITES", "true");
         }
 
         let config = Config::from_env();
@@ -133,6 +177,7 @@ mod tests {
             config.allowed_paths,
             vec![PathBuf::from("/music"), PathBuf::from("/backup/music")]
         );
+        assert!(config.allow_writes);
 
         // Clean up
         unsafe {
@@ -140,6 +185,7 @@ mod tests {
             env::remove_var("MUSIC_LIBRARY_PATH");
             env::remove_var("MUSIC_SCAN_TIMEOUT");
             env::remove_var("MUSIC_ALLOWED_PATHS");
+            env::remove_var("MUSIC_ALLOW_WRITES");
         }
     }
 
@@ -158,8 +204,30 @@ mod tests {
         assert!(config.is_path_allowed(&PathBuf::from("/backup/music/artist")));
 
         assert!(!config.is_path_allowed(&PathBuf::from("/other/path")));
-        // Note: Path traversal detection requires more sophisticated validation
-        // This simple prefix check focuses on basic security
+        assert!(!config.is_path_allowed(&PathBuf::from("/music/../etc")));
+    }
+
+    #[test]
+    fn test_path_validation_resolves_traversal_and_symlinks() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let allowed = dir.path().join("allowed");
+        let outside = dir.path().join("outside");
+        std::fs::create_dir_all(&allowed).unwrap();
+        std::fs::create_dir_all(&outside).unwrap();
+        let config = Config {
+            allowed_paths: vec![allowed.clone()],
+            ..Config::default()
+        };
+
+        assert!(config.is_path_allowed(&allowed.join("Artist/Album/01.flac")));
+        assert!(!config.is_path_allowed(&allowed.join("../outside")));
+
+        #[cfg(unix)]
+        {
+            std::os::unix::fs::symlink(&outside, allowed.join("link")).unwrap();
+            assert!(!config.is_path_allowed(&allowed.join("link")));
+            assert!(!config.is_path_allowed(&allowed.join("link/new/01.flac")));
+        }
     }
 
     #[test]
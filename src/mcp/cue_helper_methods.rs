@@ -1,6 +1,6 @@
 use crate::core::services::cue::{
-    CueGenerationError, CueValidationResult, format_cue_validation_result, generate_cue_for_path,
-    parse_cue_file, validate_cue_consistency,
+    CueCharset, CueGenerationError, CueValidationResult, encode_cue_content,
+    format_cue_validation_result, generate_cue_for_path, parse_cue_file, validate_cue_consistency,
 };
 use crate::mcp::call_tool_result::CallToolResultExt;
 use crate::mcp::music_chore_server_impl::to_json_call_response;
@@ -13,6 +13,7 @@ pub(crate) async fn handle_cue_generate(
     output: Option<PathBuf>,
     dry_run: bool,
     force: bool,
+    charset: CueCharset,
 ) -> Result<CallToolResult, McpError> {
     match generate_cue_for_path(path, output) {
         Ok(result) => {
@@ -30,11 +31,16 @@ pub(crate) async fn handle_cue_generate(
                     result.cue_content
                 )))
             } else {
-                match std::fs::write(&result.output_path, &result.cue_content) {
-                    Ok(_) => Ok(CallToolResult::success_text(format!(
-                        "Cue file written to: {}",
-                        result.output_path.display()
-                    ))),
+                let (bytes, unmappable) = encode_cue_content(&result.cue_content, charset);
+                let mut message = format!("Cue file written to: {}", result.output_path.display());
+                if !unmappable.is_empty() {
+                    let chars: String = unmappable.into_iter().collect();
+                    message.push_str(&format!(
+                        "\nWarning: characters not representable in the chosen charset were written as '?': {chars}"
+                    ));
+                }
+                match std::fs::write(&result.output_path, bytes) {
+                    Ok(_) => Ok(CallToolResult::success_text(message)),
                     Err(e) => Ok(CallToolResult::error_text(format!(
                         "Error writing cue file: {e}"
                     ))),
@@ -67,6 +73,15 @@ pub(crate) async fn handle_cue_parse(
                 if let Some(title) = &cue_file.title {
                     output.push_str(&format!("  Title: {title}\n"));
                 }
+                if let Some(catalog) = &cue_file.catalog {
+                    output.push_str(&format!("  Catalog: {catalog}\n"));
+                }
+                if let Some(disc_id) = &cue_file.disc_id {
+                    output.push_str(&format!("  Disc ID: {disc_id}\n"));
+                }
+                if let Some(comment) = &cue_file.comment {
+                    output.push_str(&format!("  Comment: {comment}\n"));
+                }
                 if !cue_file.files.is_empty() {
                     output.push_str("  Files:\n");
                     for file in &cue_file.files {
@@ -165,7 +180,7 @@ mod tests {
         let track1 = album_dir.join("01. Track 1.flac");
         fs::copy("tests/fixtures/flac/simple/track1.flac", &track1).unwrap();
 
-        let result = handle_cue_generate(&album_dir, None, true, false)
+        let result = handle_cue_generate(&album_dir, None, true, false, CueCharset::Utf8)
             .await
             .expect("Should succeed");
         assert!(!result.is_error.unwrap_or(false));
@@ -184,7 +199,7 @@ mod tests {
         let track1 = album_dir.join("01. Track 1.flac");
         fs::copy("tests/fixtures/flac/simple/track1.flac", &track1).unwrap();
 
-        let result = handle_cue_generate(&album_dir, None, false, false)
+        let result = handle_cue_generate(&album_dir, None, false, false, CueCharset::Utf8)
             .await
             .expect("Should succeed");
         assert!(!result.is_error.unwrap_or(false));
@@ -198,13 +213,41 @@ mod tests {
         assert!(content.contains("TRACK 01"));
     }
 
+    #[tokio::test]
+    async fn test_handle_cue_generate_windows_1252() {
+        let temp_dir = TempDir::new().unwrap();
+        let album_dir = temp_dir.path().join("Album");
+        fs::create_dir_all(&album_dir).unwrap();
+        fs::copy(
+            "tests/fixtures/flac/simple/track1.flac",
+            album_dir.join("01. Café.flac"),
+        )
+        .unwrap();
+        let cue_path = album_dir.join("album.cue");
+
+        let result = handle_cue_generate(
+            &album_dir,
+            Some(cue_path.clone()),
+            false,
+            false,
+            CueCharset::Windows1252,
+        )
+        .await
+        .expect("Should succeed");
+        assert!(!result.is_error.unwrap_or(false));
+
+        let bytes = fs::read(cue_path).unwrap();
+        assert!(bytes.windows(4).any(|w| w == b"Caf\xE9"));
+        assert!(String::from_utf8(bytes).is_err());
+    }
+
     #[tokio::test]
     async fn test_handle_cue_generate_no_music_files() {
         let temp_dir = TempDir::new().unwrap();
         let empty_dir = temp_dir.path().join("Empty");
         fs::create_dir_all(&empty_dir).unwrap();
 
-        let result = handle_cue_generate(&empty_dir, None, false, false)
+        let result = handle_cue_generate(&empty_dir, None, false, false, CueCharset::Utf8)
             .await
             .expect("Should return error Result");
         assert!(result.is_error.unwrap_or(false));
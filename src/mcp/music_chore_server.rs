@@ -1,3 +1,4 @@
+use crate::core::services::library_facts::LibraryFactsCache;
 use crate::mcp::config::Config;
 
 use rmcp::handler::server::router::prompt::PromptRouter;
@@ -12,12 +13,15 @@ use rmcp::{
     service::RequestContext,
     tool_handler,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct MusicChoreServer {
     pub(crate) tool_router: ToolRouter<Self>,
     pub(crate) prompt_router: PromptRouter<Self>,
     pub(crate) config: Config,
+    /// Library facts shared by every clone of the server for this session.
+    pub(crate) facts_cache: Arc<LibraryFactsCache>,
 }
 
 impl Default for MusicChoreServer {
@@ -1,15 +1,18 @@
 use crate::mcp::config::Config;
 use crate::mcp::params::{
     CueParams, EmitLibraryMetadataParams, FindDuplicatesParams, GetLibraryTreeParams,
-    NormalizeParams, ReadFileMetadataParams, ScanDirectoryParams, ScanDirectoryResponse,
-    ValidateLibraryParams,
+    LibraryFactsParams, NormalizeParams, ReadFileMetadataParams, ReorganizeParams,
+    ScanDirectoryParams, ScanDirectoryResponse, ValidateLibraryParams,
 };
 
 use crate::adapters::audio_formats::read_metadata;
 use crate::build_library_hierarchy;
+use crate::core::services::cue::{CueCharset, parse_cue_charset};
 use crate::core::services::duplicates::find_duplicates;
 use crate::core::services::format_tree::{emit_by_path, format_library_output};
+use crate::core::services::library_facts::LibraryFactsCache;
 use crate::core::services::normalization::normalize_and_format;
+use crate::core::services::reorganize::{apply_reorganization, plan_reorganization};
 use crate::core::services::scanner::{
     format_track_name_for_scan_output, scan_dir, scan_dir_with_options,
 };
@@ -21,7 +24,7 @@ use crate::mcp::prompts::{
     cue_sheet_assistant_prompt, duplicate_resolution_prompt, library_health_check_prompt,
     listen_now_prompt, metadata_cleanup_guide_prompt, web_perfect_match_prompt,
 };
-use crate::presentation::cli::commands::validate_path;
+use crate::presentation::cli::commands::validate_path_with_template;
 use rmcp::model::PromptMessageContent;
 use rmcp::{
     ErrorData as McpError, ErrorData,
@@ -31,6 +34,7 @@ use rmcp::{
 };
 use serde_json::to_string_pretty;
 use std::path::PathBuf;
+use std::sync::Arc;
 // ─── Helper traits & functions ───────────────────────────────────────────────
 
 /// Serialize `value` to pretty JSON, mapping errors to `McpError`.
@@ -80,6 +84,7 @@ impl MusicChoreServer {
             tool_router: Self::tool_router(),
             prompt_router: Self::prompt_router(),
             config,
+            facts_cache: Arc::new(LibraryFactsCache::new()),
         }
     }
 
@@ -264,6 +269,24 @@ impl MusicChoreServer {
         }
     }
 
+    #[tool(
+        description = "Get cached library facts (stats, genre, decade and quality-tier histograms, artist summaries) computed from one scan; rescanned only when files change"
+    )]
+    async fn get_library_facts(
+        &self,
+        params: Parameters<LibraryFactsParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let path = match self.resolve_path_for_tool(params.0.path) {
+            Ok(p) => p,
+            Err(e) => return Ok(e),
+        };
+
+        match self.facts_cache.get_or_compute(&path) {
+            Ok(facts) => to_json_call_response(facts.as_ref()),
+            Err(e) => Ok(CallToolResult::error_text(e)),
+        }
+    }
+
     #[tool(description = "Validate music library for common issues and inconsistencies")]
     async fn validate_library(
         &self,
@@ -275,7 +298,8 @@ impl MusicChoreServer {
         };
 
         let json_output = params.0.json_output.unwrap_or(false);
-        match validate_path(&path, json_output) {
+        let template = params.0.template.as_deref();
+        match validate_path_with_template(&path, json_output, template) {
             Ok(result) => Ok(CallToolResult::success_text(result)),
             Err(result) => Ok(CallToolResult::error_text(result)),
         }
@@ -300,6 +324,42 @@ impl MusicChoreServer {
         }
     }
 
+    #[tool(
+        description = "Plan moving files to a metadata path template (e.g. {artist}/{album}/{track} - {title}.{ext}) and return the move plan; moves only with apply=true when the server allows writes"
+    )]
+    async fn reorganize(
+        &self,
+        params: Parameters<ReorganizeParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let path = match self.resolve_path_for_tool(params.0.path) {
+            Ok(p) => p,
+            Err(e) => return Ok(e),
+        };
+
+        // Plan from the resolved root so destinations are checked where they land
+        let path = path.canonicalize().unwrap_or(path);
+        let ascii = params.0.ascii.unwrap_or(false);
+        let mut plan = match plan_reorganization(&path, &params.0.template, ascii) {
+            Ok(plan) => plan,
+            Err(e) => return Ok(CallToolResult::error_text(e)),
+        };
+        for touched in plan.touched_paths() {
+            if let Err(e) = self.validate_path(&touched.to_path_buf()) {
+                return Ok(CallToolResult::error_text(e.to_string()));
+            }
+        }
+
+        if params.0.apply.unwrap_or(false) {
+            if !self.config.allow_writes {
+                return Ok(CallToolResult::error_text(
+                    "Writes are disabled; set MUSIC_ALLOW_WRITES=true on the server to apply a reorganization",
+                ));
+            }
+            apply_reorganization(&mut plan);
+        }
+        to_json_call_response(&plan)
+    }
+
     #[tool(description = "Generate, parse, or validate .cue files")]
     async fn cue_file(&self, params: Parameters<CueParams>) -> Result<CallToolResult, McpError> {
         let path = match self.resolve_path_for_tool(params.0.path) {
@@ -312,6 +372,11 @@ impl MusicChoreServer {
         let force = params.0.force.unwrap_or(false);
         let audio_dir = params.0.audio_dir.map(PathBuf::from);
         let json_output = params.0.json_output.unwrap_or(false);
+        let charset = match params.0.charset.as_deref().map(parse_cue_charset) {
+            Some(Ok(charset)) => charset,
+            Some(Err(e)) => return Ok(CallToolResult::error_text(e)),
+            None => CueCharset::default(),
+        };
 
         // Validate audio directory path if provided
         if let Some(ref audio_path) = audio_dir {
@@ -322,7 +387,14 @@ impl MusicChoreServer {
 
         match operation.as_str() {
             "generate" => {
-                handle_cue_generate(&path, params.0.output.map(PathBuf::from), dry_run, force).await
+                handle_cue_generate(
+                    &path,
+                    params.0.output.map(PathBuf::from),
+                    dry_run,
+                    force,
+                    charset,
+                )
+                .await
             }
             "parse" => handle_cue_parse(&path, json_output).await,
             "validate" => handle_cue_validate(&path, audio_dir, json_output).await,
@@ -36,10 +36,16 @@ pub struct EmitLibraryMetadataParams {
     pub(crate) json_output: Option<bool>,
 }
 
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct LibraryFactsParams {
+    pub(crate) path: Option<String>,
+}
+
 #[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
 pub struct ValidateLibraryParams {
     pub(crate) path: Option<String>,
     pub(crate) json_output: Option<bool>,
+    pub(crate) template: Option<String>,
 }
 
 #[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
@@ -50,6 +56,17 @@ pub struct FindDuplicatesParams {
     pub(crate) parallel: Option<usize>,
 }
 
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct ReorganizeParams {
+    pub(crate) path: Option<String>,
+    /// Destination template relative to `path`, e.g. `{artist}/{album}/{track} - {title}.{ext}`
+    pub(crate) template: String,
+    /// Move the files; also requires MUSIC_ALLOW_WRITES on the server (default: plan only)
+    pub(crate) apply: Option<bool>,
+    /// Transliterate destination paths to ASCII (é → e, ß → ss); tags keep full Unicode
+    pub(crate) ascii: Option<bool>,
+}
+
 #[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
 pub struct CueParams {
     pub(crate) path: Option<String>,
@@ -59,4 +76,6 @@ pub struct CueParams {
     pub(crate) force: Option<bool>,
     pub(crate) audio_dir: Option<String>,
     pub(crate) json_output: Option<bool>,
+    /// Encoding of a generated file: `utf-8` (default) or `windows-1252`
+    pub(crate) charset: Option<String>,
 }
@@ -6,9 +6,9 @@ Mood/activity: "{mood}".
 Novelty preference: "{novelty}".
 
 Steps:
-1. Use `scan_directory` with `json_output: true` to get all tracks.
+1. Use `get_library_facts` for genre, decade, quality and per-artist summaries (cached across calls).
 2. Use `get_library_tree` with `json_output: true` to understand artist/album context.
-3. Use `emit_library_metadata` with `json_output: true` to compare genres, years, and metadata quality.
+3. Use `scan_directory` with `json_output: true` only when you need individual track durations to build the queue.
 
 Decision framework:
 - Build three options: **Comfort Pick** (familiar), **Balanced Pick**, **Discovery Pick** (less obvious).
@@ -39,10 +39,9 @@ Genre filter: "{genre_clause}".
 Max results: {max_results}.
 
 Steps:
-1. Use `scan_directory` with `json_output: true`.
-2. Use `get_library_tree` with `json_output: true`.
-3. Use `emit_library_metadata` with `json_output: true`.
-4. Use web search/knowledge to find candidates outside my library.
+1. Use `get_library_facts` for the genre, decade and artist summaries (cached across calls).
+2. Use `get_library_tree` with `json_output: true` if you need album-level detail.
+3. Use web search/knowledge to find candidates outside my library.
 
 Strict matching protocol:
 - Derive a taste fingerprint (artists, subgenres, decades, intensity, instrumentation hints).
@@ -68,7 +67,7 @@ pub(crate) fn library_health_check_prompt(path: String) -> String {
         r#"Perform a comprehensive health check on my music library at "{path}".
 
 Run these tools in sequence:
-1. `scan_directory` with `json_output: true` — get full track inventory.
+1. `get_library_facts` — totals, genre/decade/quality-tier histograms and artist summaries.
 2. `validate_library` with `json_output: true` — identify metadata issues.
 3. `find_duplicates` with `json_output: true` — detect duplicate files.
 4. `get_library_tree` — check organizational structure.
@@ -105,7 +104,7 @@ pub(crate) fn duplicate_resolution_prompt(path: String) -> String {
         r#"Help me resolve duplicate files in my music library at "{path}".
 
 Steps:
-1. Use `find_duplicates` with `json_output: true`.
+1. Use `find_duplicates` with `json_output: true`. Each group's `tracks` are ranked by quality tier, metadata completeness, then path length; `recommended_keep` points at the suggested copy.
 2. Use `scan_directory` with `json_output: true` for metadata on affected files.
 3. Use `read_file_metadata` for close calls.
 
@@ -1,6 +1,8 @@
 //! CLI command definitions and handlers.
 
-pub(crate) use crate::core::services::validation::validate_path;
+pub(crate) use crate::core::services::validation::{
+    ValidateOptions, validate_path_with_options, validate_path_with_template,
+};
 use clap::{Parser, Subcommand};
 use std::path::PathBuf;
 
@@ -8,7 +10,7 @@ use std::path::PathBuf;
 #[command(name = "musicctl")]
 #[command(about = "Deterministic, AI‑friendly music metadata compiler.")]
 #[command(
-    long_about = "A CLI tool for organizing and normalizing local music libraries using existing file metadata and directory structure only.\n\nSupported audio formats: .flac, .mp3, .wav, .ogg, .m4a, .dsf, .wv"
+    long_about = "A CLI tool for organizing and normalizing local music libraries using existing file metadata and directory structure only.\n\nSupported audio formats: .flac, .mp3, .wav, .ogg, .opus, .m4a, .dsf, .wv"
 )]
 #[command(disable_version_flag = true)]
 pub struct Cli {
@@ -16,6 +18,14 @@ pub struct Cli {
     #[arg(short = 'v', long = "version")]
     pub version: bool,
 
+    /// List the supported audio formats, their MIME types and read/write support
+    #[arg(long)]
+    pub list_formats: bool,
+
+    /// Force human-readable output, overriding MUSIC_CHORE_FORMAT=json
+    #[arg(long, global = true)]
+    pub text: bool,
+
     #[command(subcommand)]
     pub command: Option<Commands>,
 }
@@ -35,15 +45,74 @@ pub enum Commands {
         /// Exclude files matching the given glob pattern(s).
         #[arg(long, value_name = "PATTERN")]
         exclude: Vec<String>,
+        /// Skip everything under this directory without walking into it (repeatable; relative to the scan path).
+        #[arg(long, value_name = "DIR")]
+        exclude_under: Vec<PathBuf>,
         /// Output JSON instead of a simple tree.
         #[arg(long)]
         json: bool,
+        /// Output compact single-line JSON instead of pretty-printed (implies --json).
+        #[arg(long)]
+        compact: bool,
         /// Emit progress output during scanning.
         #[arg(long)]
         verbose: bool,
         /// Skip reading file metadata; rely solely on filenames.
         #[arg(long)]
         skip_metadata: bool,
+        /// Only include files modified after this RFC 3339 time (e.g. 2024-05-01T00:00:00Z).
+        #[arg(long, value_name = "RFC3339", value_parser = crate::core::services::scanner::parse_rfc3339)]
+        since: Option<std::time::SystemTime>,
+        /// Drop files reached twice via symlinks or case-only path differences.
+        #[arg(long)]
+        dedupe_paths: bool,
+        /// Keep a checkpoint of scanned files and continue an interrupted scan from it;
+        /// files changed since they were checkpointed are read again.
+        #[arg(long)]
+        resume: bool,
+        /// Write a JSON-lines record (path, error_kind, message) for each skipped or unreadable file.
+        #[arg(long, value_name = "FILE")]
+        error_log: Option<PathBuf>,
+        /// Number of threads reading metadata (default: one per core; 1 scans serially).
+        #[arg(long, value_name = "N", value_parser = crate::core::services::scanner::parse_concurrency)]
+        concurrency: Option<usize>,
+        /// Report an audio file with a .cue sheet as one track instead of the sheet's tracks.
+        #[arg(long)]
+        no_cue_expand: bool,
+        /// CUE sheet file name to use in directories that contain several (default: first by name).
+        #[arg(long, value_name = "NAME")]
+        prefer_cue: Option<String>,
+        /// Which values win when a CUE sheet and a file's own tags disagree: `auto` (default;
+        /// embedded for split albums, CUE for single-file albums), `cue` or `embedded`.
+        #[arg(long, value_name = "SIDE", default_value = "auto", value_parser = crate::core::services::scanner::parse_cue_precedence)]
+        cue_precedence: crate::core::services::scanner::CuePrecedence,
+        /// Which album wins when the tag and the album folder name differ: `tag` (default) or `folder`.
+        #[arg(long, value_name = "SOURCE", default_value = "tag", value_parser = crate::core::services::scanner::parse_album_from)]
+        album_from: crate::core::services::scanner::AlbumFrom,
+        /// Output order: `filename` (default) or `metadata` (artist, album, disc, track, title).
+        #[arg(long, value_name = "ORDER", default_value = "filename", value_parser = crate::core::services::scanner::parse_scan_sort)]
+        sort: crate::core::services::scanner::ScanSort,
+        /// Skip audio files larger than SIZE (e.g. 500M, 2G) without reading them.
+        #[arg(long, value_name = "SIZE", value_parser = crate::core::services::scanner::parse_file_size)]
+        max_file_size: Option<u64>,
+        /// Print track paths relative to this library root (fails if a track is outside it).
+        #[arg(long, value_name = "PATH")]
+        root: Option<PathBuf>,
+        /// Only list discovered audio file paths, one per line, without reading any metadata. Discovery filters (--exclude, --exclude-under, --max-depth, --follow-symlinks, --since, --max-file-size, --error-log) still apply.
+        #[arg(long)]
+        paths_only: bool,
+        /// Print time spent discovering, reading and sorting files (to stderr), plus files/second.
+        #[arg(long)]
+        profile: bool,
+        /// Clear inferred title, artist, album and genre values with a confidence below this (0-1).
+        #[arg(long, value_name = "CONFIDENCE", default_value_t = 0.0, value_parser = crate::core::services::scanner::parse_confidence)]
+        drop_below: f32,
+        /// Leave the album unknown instead of deriving it from the file name.
+        #[arg(long)]
+        no_filename_album_fallback: bool,
+        /// Scan even a filesystem root, home directory or system directory.
+        #[arg(long)]
+        force: bool,
     },
     /// Show a human‑friendly tree view.
     Tree {
@@ -52,11 +121,29 @@ pub enum Commands {
         /// Output JSON instead of a simple tree
         #[arg(long)]
         json: bool,
+        /// Minimum distinct track artists for an album to be grouped under "Various Artists" (0 disables). A non-default value switches to the artist/album tree.
+        #[arg(long, value_name = "N", default_value_t = crate::core::services::library::DEFAULT_VA_THRESHOLD)]
+        va_threshold: usize,
+        /// Additional album artist value that always marks a compilation (repeatable). Switches to the artist/album tree.
+        #[arg(long, value_name = "NAME")]
+        va_artist: Vec<String>,
+        /// Group soundtrack albums (OST, "Original Motion Picture", genre Soundtrack) under "Soundtracks".
+        #[arg(long)]
+        group_soundtracks: bool,
+        /// Deepest level shown: `artist`, `album` or `track` (default). Shallower levels switch to the artist/album tree with counts.
+        #[arg(long, value_name = "LEVEL", default_value = "track", conflicts_with = "json", value_parser = crate::core::services::format_tree::parse_tree_depth)]
+        depth: crate::core::services::format_tree::TreeDepth,
+        /// Top level of the tree: `artist` (default) or `year` (year, then artist and album; tracks without a year under "Unknown Year").
+        #[arg(long, value_name = "FIELD", default_value = "artist", value_parser = crate::core::services::format_tree::parse_tree_group_by)]
+        group_by: crate::core::services::format_tree::TreeGroupBy,
     },
     /// Read metadata from a single file.
     Read {
         /// Path to the file.
         file: PathBuf,
+        /// Show, per field, the embedded value next to what path inference would give.
+        #[arg(long)]
+        explain: bool,
     },
     /// Write metadata to a file.
     Write {
@@ -79,6 +166,82 @@ pub enum Commands {
         /// Output JSON instead of a human-readable format.
         #[arg(long)]
         json: bool,
+        /// TSV or JSON file of `variant -> canonical` genre mappings that override the built-in ones.
+        #[arg(long, value_name = "FILE")]
+        genre_map: Option<PathBuf>,
+        /// Also convert smart quotes, dashes and ellipses in text tags to ASCII.
+        #[arg(long)]
+        normalize_unicode_punctuation: bool,
+        /// Comma-separated bracketed title phrases to strip, replacing the default noise list.
+        #[arg(long, value_name = "PHRASES", value_delimiter = ',')]
+        strip_phrases: Vec<String>,
+        /// Remove comment tags that contain a ripper signature such as "Ripped by" or "www.".
+        #[arg(long)]
+        strip_comments: bool,
+        /// Comma-separated comment patterns (case-insensitive substrings), replacing the default list.
+        #[arg(
+            long,
+            value_name = "PATTERNS",
+            value_delimiter = ',',
+            requires = "strip_comments"
+        )]
+        comment_patterns: Vec<String>,
+        /// Report artist and album artist names to switch between "Last, First" (`last-first`) and "First Last" (`first-last`).
+        #[arg(long, value_name = "ORDER", value_parser = crate::core::services::normalization::parse_artist_order)]
+        artist_order: Option<crate::core::services::normalization::ArtistOrder>,
+        /// Comma-separated allowed genres; any other genre is remapped to its nearest allowed genre (via the genre hierarchy) or "Other".
+        #[arg(long, value_name = "GENRES", value_delimiter = ',')]
+        limit_genres: Vec<String>,
+        /// Write the --strip-comments removals to the files (other fields are only reported).
+        #[arg(long, requires = "strip_comments")]
+        apply: bool,
+        /// Write every proposed change to this JSON manifest for review instead of printing a report.
+        #[arg(long, value_name = "FILE", conflicts_with_all = ["apply", "apply_manifest"])]
+        report_only: Option<PathBuf>,
+        /// Apply a --report-only manifest to files under PATH, skipping files whose tags changed since.
+        #[arg(long, value_name = "FILE", conflicts_with = "apply")]
+        apply_manifest: Option<PathBuf>,
+    },
+    /// Apply a manifest written by `normalize --report-only`, skipping changes whose file no longer holds the recorded old value.
+    ApplyManifest {
+        /// Manifest JSON file.
+        manifest: PathBuf,
+        /// Output JSON instead of a human-readable format.
+        #[arg(long)]
+        json: bool,
+    },
+    /// Write fields edited in an `emit --format toml` document back to the files.
+    WriteFromToml {
+        /// TOML file written by `emit --format toml`.
+        file: PathBuf,
+        /// Write the changes (default: only list them).
+        #[arg(long)]
+        apply: bool,
+    },
+    /// Write an album.nfo summary (tags, track listing, quality) into every leaf album folder.
+    WriteAlbumInfo {
+        /// Base directory to scan.
+        path: PathBuf,
+        /// Print each file's content instead of writing it.
+        #[arg(long)]
+        dry_run: bool,
+        /// Overwrite an existing album.nfo with different content.
+        #[arg(long)]
+        force: bool,
+    },
+    /// List artists spelled several ways ("Beatles", "The Beatles") and rewrite them to a chosen spelling.
+    ArtistAliases {
+        /// Base directory to scan.
+        path: PathBuf,
+        /// Rewrite the other spellings of this name's cluster to it (repeatable); clusters without one are left alone.
+        #[arg(long, value_name = "NAME")]
+        canonical_artist: Vec<String>,
+        /// Write the rewrites (default: only list the affected files).
+        #[arg(long, requires = "canonical_artist")]
+        apply: bool,
+        /// Output JSON instead of a human-readable format.
+        #[arg(long)]
+        json: bool,
     },
     /// Emit library metadata in structured JSON format.
     Emit {
@@ -87,6 +250,55 @@ pub enum Commands {
         /// Output JSON instead of a simple tree
         #[arg(long)]
         json: bool,
+        /// Output compact single-line JSON instead of pretty-printed (implies --json).
+        #[arg(long)]
+        compact: bool,
+        /// Emit one summary per artist (albums, tracks, duration, years, genres) instead of the full library.
+        #[arg(long)]
+        by_artist: bool,
+        /// Emit track counts per decade (tracks without a year count as "unknown") instead of the full library.
+        #[arg(long, conflicts_with_all = ["by_artist", "genre_roots"])]
+        decades: bool,
+        /// List the tracks under each genre instead of the full library; multi-valued tags ("Rock; Alternative") count under each genre.
+        #[arg(long, conflicts_with_all = ["by_artist", "decades", "genre_roots"])]
+        by_genre: bool,
+        /// Date reissues by their original release year (ORIGINALYEAR/ORIGINALDATE) when tagged.
+        #[arg(long, requires = "decades")]
+        original_year: bool,
+        /// Minimum distinct track artists for an album to be grouped under "Various Artists" (0 disables).
+        #[arg(long, value_name = "N", default_value_t = crate::core::services::library::DEFAULT_VA_THRESHOLD)]
+        va_threshold: usize,
+        /// Additional album artist value that always marks a compilation (repeatable).
+        #[arg(long, value_name = "NAME")]
+        va_artist: Vec<String>,
+        /// Group soundtrack albums (OST, "Original Motion Picture", genre Soundtrack) under "Soundtracks".
+        #[arg(long)]
+        group_soundtracks: bool,
+        /// Add a `genre_root` field (e.g. "Rock" for "Indie Rock") to each track (implies --json).
+        #[arg(long, conflicts_with = "by_artist")]
+        genre_roots: bool,
+        /// TSV or JSON genre map whose root column overrides the built-in genre hierarchy.
+        #[arg(long, value_name = "FILE", requires = "genre_roots")]
+        genre_map: Option<PathBuf>,
+        /// Emit editable track fields in another format: `toml` (edit, then load with write-from-toml).
+        #[arg(long, value_name = "FORMAT", value_parser = ["toml"], conflicts_with_all = ["json", "compact", "by_artist", "decades", "by_genre", "genre_roots"])]
+        format: Option<String>,
+    },
+    /// Show library totals and the largest artists, albums and genres by track count.
+    Stats {
+        /// Base directory to scan.
+        path: PathBuf,
+        /// Output JSON instead of human-readable format.
+        #[arg(long)]
+        json: bool,
+        /// Number of entries shown in each ranked section.
+        #[arg(long, value_name = "N", default_value_t = crate::core::services::library::DEFAULT_STATS_TOP)]
+        top: usize,
+    },
+    /// Print a SHA-256 hash of the library's metadata that only changes when tags or file paths do.
+    LibraryHash {
+        /// Base directory to scan.
+        path: PathBuf,
     },
     /// Generate, parse, or validate .cue files.
     Cue {
@@ -99,22 +311,35 @@ pub enum Commands {
         /// Validate .cue file against audio files.
         #[arg(long)]
         validate: bool,
+        /// Check that the INDEX timestamps of a .cue file fit the length of its audio file(s).
+        #[arg(long)]
+        check_duration: bool,
         /// Path to album directory, .cue file, or audio directory depending on operation.
         path: PathBuf,
         /// Output path for .cue file (--generate only, defaults to album directory).
+        #[arg(conflicts_with = "recursive")]
         output: Option<PathBuf>,
+        /// Generate a .cue file for every directory under PATH that holds audio files (--generate only).
+        #[arg(long)]
+        recursive: bool,
+        /// Where each .cue file goes with --recursive, relative to PATH; placeholders {album_dir}, {album}, {artist} (default: "{album_dir}/{album}.cue", next to the audio).
+        #[arg(long, value_name = "TEMPLATE", requires = "recursive")]
+        output_template: Option<String>,
         /// Show what would be done without actually writing (--generate only).
         #[arg(long)]
         dry_run: bool,
         /// Overwrite existing .cue file (--generate only).
         #[arg(long)]
         force: bool,
-        /// Path to directory containing audio files (--validate only, defaults to .cue file directory).
+        /// Path to directory containing audio files (--validate and --check-duration, defaults to .cue file directory).
         #[arg(long)]
         audio_dir: Option<PathBuf>,
-        /// Output JSON instead of human-readable format (--parse and --validate only).
+        /// Output JSON instead of human-readable format (--parse, --validate and --check-duration).
         #[arg(long)]
         json: bool,
+        /// Encoding of the written .cue file: utf-8 or windows-1252 (--generate only).
+        #[arg(long, alias = "cue-charset", value_name = "CHARSET", default_value = "utf-8", value_parser = crate::core::services::cue::parse_cue_charset)]
+        charset: crate::core::services::cue::CueCharset,
     },
     /// Validate metadata completeness and consistency.
     Validate {
@@ -123,6 +348,24 @@ pub enum Commands {
         /// Output JSON instead of human-readable format.
         #[arg(long)]
         json: bool,
+        /// Also check file locations against a path template, e.g. "{artist}/{album}/{track} - {title}.{ext}".
+        #[arg(long, value_name = "TEMPLATE")]
+        template: Option<String>,
+        /// Exit with an error when more than PCT percent of tracks fall under Unknown Artist/Album.
+        #[arg(long, value_name = "PCT")]
+        fail_on_unknown: Option<f64>,
+        /// Exit with an error when any issue at or above SEVERITY (info, warning, error) is found.
+        #[arg(long, value_name = "SEVERITY", value_parser = crate::core::services::validation::parse_severity)]
+        fail_on: Option<crate::core::services::validation::Severity>,
+        /// Stop at the first file with an issue at or above --fail-on (default: error) and exit with an error.
+        #[arg(long)]
+        fail_fast: bool,
+        /// Warn about key tags not in standard (F#m), Camelot (8A) or Open Key (1d) notation.
+        #[arg(long)]
+        check_keys: bool,
+        /// Warn when a title's "feat. X" names an artist missing from the artist/album artist tags.
+        #[arg(long)]
+        check_featured: bool,
     },
     /// Detect duplicate tracks by checksum.
     Duplicates {
@@ -134,8 +377,139 @@ pub enum Commands {
         /// Show more detailed information about duplicates.
         #[arg(long, short)]
         verbose: bool,
-        /// Number of threads to use for parallel processing.
-        #[arg(long, short = 'p')]
+        /// Number of threads to use for parallel processing (default: one per core).
+        #[arg(long, short = 'p', visible_alias = "concurrency", value_name = "N", value_parser = crate::core::services::scanner::parse_concurrency)]
         parallel: Option<usize>,
+        /// Compare decoded audio instead of file bytes, so FLAC and WAV copies of the same
+        /// recording match. Only FLAC and PCM WAV files are compared; WAV audio is read in full.
+        #[arg(long)]
+        pcm_content: bool,
+        /// Group tracks with the same artist and title and a similar duration instead of
+        /// comparing checksums, to find the same song from different rips.
+        #[arg(long, conflicts_with = "pcm_content")]
+        near: bool,
+        /// Width in seconds of the duration buckets --near compares.
+        #[arg(long, value_name = "SECS", default_value_t = crate::core::services::duplicates::DEFAULT_DURATION_TOLERANCE, requires = "near")]
+        duration_tolerance: f64,
+        /// List file names (e.g. "unknown.flac") that occur in more than one folder instead of
+        /// comparing contents. Files are not read, so identical names may hold different audio.
+        #[arg(long, conflicts_with_all = ["pcm_content", "near"])]
+        filenames: bool,
+    },
+    /// List lossy tracks worth re-sourcing in a lossless format (JSON, no transcoding).
+    TranscodePlan {
+        /// Base directory to scan.
+        path: PathBuf,
     },
+    /// Check that every audio file is non-empty and parses with its format handler.
+    Verify {
+        /// File or directory to verify.
+        path: PathBuf,
+        /// Output a JSON array of per-file results instead of text.
+        #[arg(long)]
+        json: bool,
+        /// Decode each file and report leading/trailing silence, flagging more than 5s of trailing silence (needs the `silence` feature).
+        #[arg(long)]
+        analyze_silence: bool,
+    },
+    /// Report how often folder-inferred artists agree with embedded artist tags.
+    InferenceCheck {
+        /// Base directory to scan.
+        path: PathBuf,
+        /// Output JSON instead of human-readable format.
+        #[arg(long)]
+        json: bool,
+        /// Number of least-agreeing folders to list.
+        #[arg(long, value_name = "N", default_value_t = crate::core::services::inference_check::DEFAULT_WORST_FOLDERS)]
+        worst: usize,
+    },
+    /// Load a library JSON written by `emit --json` and analyse it without touching the music files.
+    Load {
+        /// Library JSON file to load.
+        file: PathBuf,
+        /// Show per-artist statistics instead of the tree.
+        #[arg(long, conflicts_with = "validate")]
+        stats: bool,
+        /// Validate the loaded metadata instead of showing the tree.
+        #[arg(long)]
+        validate: bool,
+        /// Output JSON instead of a human-readable format.
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+/// Environment variable selecting the default output format (`json` or `text`).
+pub const OUTPUT_FORMAT_ENV: &str = "MUSIC_CHORE_FORMAT";
+
+/// Output format of commands that support both text and JSON.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+impl OutputFormat {
+    /// Parses a `MUSIC_CHORE_FORMAT` value, ignoring case and surrounding whitespace.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "json" => Some(Self::Json),
+            "text" => Some(Self::Text),
+            _ => None,
+        }
+    }
+}
+
+/// Resolves the output format with precedence CLI flag > environment variable
+/// > built-in default (text). `--json` wins over `--text` if both are given.
+pub fn resolve_output_format(
+    json: bool,
+    text: bool,
+    env_value: Option<OutputFormat>,
+) -> OutputFormat {
+    if json {
+        OutputFormat::Json
+    } else if text {
+        OutputFormat::Text
+    } else {
+        env_value.unwrap_or(OutputFormat::Text)
+    }
+}
+
+impl Commands {
+    /// Whether options were given that only work with text output (`tree
+    /// --depth artist|album`), so the `MUSIC_CHORE_FORMAT` default must not
+    /// switch the command to JSON.
+    pub fn requires_text_output(&self) -> bool {
+        matches!(
+            self,
+            Commands::Tree { depth, .. }
+                if *depth != crate::core::services::format_tree::TreeDepth::Track
+        )
+    }
+
+    /// The `--json` flag of commands that offer text and JSON output.
+    pub fn json_flag_mut(&mut self) -> Option<&mut bool> {
+        match self {
+            Commands::Scan { json, .. }
+            | Commands::Tree { json, .. }
+            | Commands::Normalize { json, .. }
+            | Commands::ApplyManifest { json, .. }
+            | Commands::ArtistAliases { json, .. }
+            | Commands::Emit { json, .. }
+            | Commands::Stats { json, .. }
+            | Commands::Cue { json, .. }
+            | Commands::Validate { json, .. }
+            | Commands::Duplicates { json, .. }
+            | Commands::InferenceCheck { json, .. }
+            | Commands::Verify { json, .. }
+            | Commands::Load { json, .. } => Some(json),
+            Commands::Read { .. }
+            | Commands::Write { .. }
+            | Commands::WriteFromToml { .. }
+            | Commands::WriteAlbumInfo { .. }
+            | Commands::LibraryHash { .. }
+            | Commands::TranscodePlan { .. } => None,
+        }
+    }
 }
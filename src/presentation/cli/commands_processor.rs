@@ -1,19 +1,57 @@
 use crate::adapters::audio_formats::{get_supported_extensions, read_metadata};
-use crate::core::domain::with_schema_version;
+use crate::core::domain::{Library, with_schema_version};
+use crate::core::services::album_info::{
+    AlbumInfoStatus, format_album_info_results, write_album_info,
+};
 use crate::core::services::apply_metadata::write_metadata_by_path;
+use crate::core::services::artist_aliases::{
+    apply_artist_canonicalization, detect_artist_aliases_by_path, format_artist_aliases,
+    format_artist_rewrites,
+};
 use crate::core::services::cue::{
-    CueGenerationError, format_cue_validation_result, generate_cue_for_path, parse_cue_file,
-    validate_cue_consistency,
+    CueCharset, CueDurationStatus, CueGenerationError, DEFAULT_CUE_OUTPUT_TEMPLATE,
+    encode_cue_content, format_cue_duration_report, format_cue_validation_result,
+    generate_cue_for_path, generate_cues_recursive, parse_cue_file, validate_cue_consistency,
+    validate_cue_durations,
+};
+use crate::core::services::duplicates::{
+    find_duplicates_with_mode, find_filename_collisions_by_path, find_near_duplicates_by_path,
+};
+use crate::core::services::explain::explain_track;
+use crate::core::services::format_tree::{
+    TreeDepth, TreeGroupBy, emit_artist_summaries, emit_artist_summaries_by_path,
+    emit_by_path_with_genre_roots, emit_by_path_with_options, emit_decade_histogram_by_path,
+    emit_library_stats_by_path, emit_tracks_by_genre_by_path, format_library_output,
+    format_library_output_with_depth, format_tree_output, format_year_hierarchy,
+    library_hash_by_path,
+};
+use crate::core::services::genre_map::GenreMap;
+use crate::core::services::inference_check::artist_agreement_by_path;
+use crate::core::services::library::{
+    HierarchyOptions, YearSource, build_library_hierarchy_with_options,
+    build_year_hierarchy_with_options,
+};
+use crate::core::services::normalization::{
+    DEFAULT_COMMENT_PATTERNS, NormalizeOptions, normalization_report,
+    normalize_and_format_with_options,
+};
+use crate::core::services::normalize_manifest::{
+    ManifestApplyStatus, NormalizeManifest, apply_manifest, format_apply_results,
 };
-use crate::core::services::duplicates::find_duplicates;
-use crate::core::services::format_tree::{emit_by_path, format_tree_output};
-use crate::core::services::library::build_library_hierarchy;
-use crate::core::services::normalization::normalize_and_format;
 use crate::core::services::scanner::{
-    format_track_name_for_scan_output, scan_dir, scan_dir_with_options,
+    ChecksumMode, ScanOptions, default_checkpoint_path, format_scan_profile,
+    format_track_name_for_scan_output, is_dangerous_scan_root, rebase_path, rebase_tracks,
+    scan_dir, scan_paths_with_options, scan_paths_with_profile, scan_single_file,
+    scan_with_options, scan_with_profile,
+};
+use crate::core::services::toml_metadata::{
+    TomlDocument, emit_toml_by_path, format_toml_write_results, write_from_toml,
 };
+use crate::core::services::transcode_plan::transcode_plan_by_path;
+use crate::core::services::validation::validate_library;
+use crate::core::services::verify::{SILENCE_ANALYSIS_AVAILABLE, verify_by_path};
 use crate::presentation::cli::Commands;
-use crate::presentation::cli::commands::validate_path;
+use crate::presentation::cli::commands::{ValidateOptions, validate_path_with_options};
 use serde_json::to_string_pretty;
 use std::io::Write;
 use std::path::{Path, PathBuf};
@@ -26,28 +64,83 @@ pub fn handle_command(command: Commands) -> Result<(), i32> {
             max_depth,
             follow_symlinks,
             exclude,
+            exclude_under,
             json,
+            compact,
             verbose,
             skip_metadata,
+            since,
+            dedupe_paths,
+            resume,
+            error_log,
+            concurrency,
+            no_cue_expand,
+            prefer_cue,
+            cue_precedence,
+            album_from,
+            sort,
+            max_file_size,
+            root,
+            paths_only,
+            profile,
+            drop_below,
+            no_filename_album_fallback,
+            force,
         } => {
-            match handle_scan(
-                path,
+            if !force && is_dangerous_scan_root(&path) {
+                eprintln!(
+                    "Error: Refusing to scan {}: it is a filesystem root, home or system directory and would walk far more than a music library. Pass --force to scan it anyway.",
+                    path.display()
+                );
+                return Err(1);
+            }
+            let options = ScanOptions {
                 max_depth,
                 follow_symlinks,
-                exclude,
-                json,
-                verbose,
+                exclude_patterns: exclude,
+                exclude_under,
                 skip_metadata,
-            ) {
+                modified_since: since,
+                dedupe_paths,
+                checkpoint: resume.then(|| default_checkpoint_path(&path)),
+                error_log,
+                concurrency,
+                skip_cue_expansion: no_cue_expand,
+                preferred_cue: prefer_cue,
+                cue_precedence,
+                album_from,
+                sort,
+                max_file_size,
+                drop_below,
+                skip_filename_album_fallback: no_filename_album_fallback,
+            };
+            if paths_only {
+                return handle_scan_paths(path, &options, json, compact, root, profile);
+            }
+            match handle_scan(path, options, json, compact, verbose, root, profile) {
                 Ok(()) => Ok(()),
                 Err(_) => Err(1),
             }
         }
-        Commands::Tree { path, json } => match handle_tree(path, json) {
+        Commands::Tree {
+            path,
+            json,
+            va_threshold,
+            va_artist,
+            group_soundtracks,
+            depth,
+            group_by,
+        } => match handle_tree(
+            path,
+            json,
+            hierarchy_options(va_threshold, va_artist, group_soundtracks),
+            depth,
+            group_by,
+        ) {
             Ok(()) => Ok(()),
             Err(_) => Err(1),
         },
-        Commands::Read { file } => match handle_read(file) {
+        Commands::Read { file, explain } => match handle_read(file, explain) {
             Ok(()) => Ok(()),
             Err(_) => Err(1),
         },
@@ -60,11 +153,116 @@ pub fn handle_command(command: Commands) -> Result<(), i32> {
             Ok(()) => Ok(()),
             Err(_) => Err(1),
         },
-        Commands::Normalize { path, json } => match handle_normalize_and_format(path, json) {
+        Commands::Normalize {
+            path,
+            json,
+            genre_map,
+            normalize_unicode_punctuation,
+            strip_phrases,
+            strip_comments,
+            comment_patterns,
+            artist_order,
+            limit_genres,
+            apply,
+            report_only,
+            apply_manifest,
+        } => {
+            if let Some(manifest) = apply_manifest {
+                return handle_normalize_apply_manifest(path, manifest, json);
+            }
+            let mode = match report_only {
+                Some(manifest) => NormalizeMode::ReportOnly(manifest),
+                None if apply => NormalizeMode::Apply,
+                None => NormalizeMode::Report,
+            };
+            let strip_comments: Option<Vec<String>> = strip_comments.then(|| {
+                if comment_patterns.is_empty() {
+                    DEFAULT_COMMENT_PATTERNS
+                        .iter()
+                        .map(|p| p.to_string())
+                        .collect()
+                } else {
+                    comment_patterns
+                }
+            });
+            let options = NormalizeOptions {
+                unicode_punctuation: normalize_unicode_punctuation,
+                strip_phrases: (!strip_phrases.is_empty()).then_some(strip_phrases.as_slice()),
+                strip_comments: strip_comments.as_deref(),
+                artist_order,
+                allowed_genres: (!limit_genres.is_empty()).then_some(limit_genres.as_slice()),
+                ..NormalizeOptions::default()
+            };
+            match handle_normalize_and_format(path, json, genre_map, options, mode) {
+                Ok(()) => Ok(()),
+                Err(_) => Err(1),
+            }
+        }
+        Commands::ApplyManifest { manifest, json } => handle_apply_manifest(manifest, json),
+        Commands::WriteFromToml { file, apply } => handle_write_from_toml(file, apply),
+        Commands::WriteAlbumInfo {
+            path,
+            dry_run,
+            force,
+        } => handle_write_album_info(path, dry_run, force),
+        Commands::ArtistAliases {
+            path,
+            canonical_artist,
+            apply,
+            json,
+        } => handle_artist_aliases(path, canonical_artist, apply, json),
+        Commands::Emit {
+            path,
+            format: Some(_),
+            ..
+        } => handle_emit_toml(path),
+        Commands::Emit {
+            path,
+            json,
+            compact,
+            by_genre: true,
+            va_threshold,
+            va_artist,
+            group_soundtracks,
+            ..
+        } => handle_emit_by_genre(
+            path,
+            json,
+            compact,
+            hierarchy_options(va_threshold, va_artist, group_soundtracks),
+        ),
+        Commands::Emit {
+            path,
+            json,
+            compact,
+            by_artist,
+            decades,
+            by_genre: false,
+            original_year,
+            va_threshold,
+            va_artist,
+            group_soundtracks,
+            genre_roots,
+            genre_map,
+            format: None,
+        } => match handle_emit(
+            path,
+            json,
+            compact,
+            by_artist,
+            decades.then_some(if original_year {
+                YearSource::Original
+            } else {
+                YearSource::Release
+            }),
+            hierarchy_options(va_threshold, va_artist, group_soundtracks),
+            genre_roots.then_some(genre_map),
+        ) {
             Ok(()) => Ok(()),
             Err(_) => Err(1),
         },
-        Commands::Emit { path, json } => match handle_emit(path, json) {
+        Commands::LibraryHash { path } => handle_library_hash(path),
+        Commands::Stats { path, json, top } => match handle_stats(path, json, top) {
             Ok(()) => Ok(()),
             Err(_) => Err(1),
         },
@@ -78,10 +276,16 @@ pub fn handle_command(command: Commands) -> Result<(), i32> {
             generate,
             parse,
             validate,
+            check_duration,
+            charset,
+            recursive,
+            output_template,
         } => {
             match handle_cue(CueParams {
                 path,
                 output,
+                recursive,
+                output_template,
                 dry_run,
                 force,
                 audio_dir,
@@ -89,21 +293,87 @@ pub fn handle_command(command: Commands) -> Result<(), i32> {
                 generate,
                 parse,
                 validate,
+                check_duration,
+                charset,
             }) {
                 Ok(()) => Ok(()),
                 Err(_) => Err(1),
             }
         }
-        Commands::Validate { path, json } => match handle_validate(path, json) {
+        Commands::Validate {
+            path,
+            json,
+            template,
+            fail_on_unknown,
+            fail_on,
+            fail_fast,
+            check_keys,
+            check_featured,
+        } => match handle_validate(
+            path,
+            json,
+            ValidateOptions {
+                template,
+                fail_on_unknown,
+                fail_on,
+                fail_fast,
+                check_keys,
+                check_featured,
+            },
+        ) {
             Ok(()) => Ok(()),
             Err(_) => Err(1),
         },
+        Commands::Duplicates {
+            path,
+            json,
+            filenames: true,
+            ..
+        } => handle_filename_collisions(path, json),
         Commands::Duplicates {
             path,
             json,
             verbose,
             parallel,
-        } => match handle_duplicates(path, json, verbose, parallel) {
+            pcm_content,
+            near,
+            duration_tolerance,
+            filenames: false,
+        } => match handle_duplicates(
+            path,
+            json,
+            verbose,
+            parallel,
+            pcm_content,
+            near.then_some(duration_tolerance),
+        ) {
+            Ok(()) => Ok(()),
+            Err(_) => Err(1),
+        },
+        Commands::TranscodePlan { path } => match handle_transcode_plan(path) {
+            Ok(()) => Ok(()),
+            Err(_) => Err(1),
+        },
+        Commands::Verify {
+            path,
+            json,
+            analyze_silence,
+        } => match handle_verify(path, json, analyze_silence) {
+            Ok(()) => Ok(()),
+            Err(_) => Err(1),
+        },
+        Commands::InferenceCheck { path, json, worst } => {
+            match handle_inference_check(path, json, worst) {
+                Ok(()) => Ok(()),
+                Err(_) => Err(1),
+            }
+        }
+        Commands::Load {
+            file,
+            stats,
+            validate,
+            json,
+        } => match handle_load(file, stats, validate, json) {
             Ok(()) => Ok(()),
             Err(_) => Err(1),
         },
@@ -112,30 +382,50 @@ pub fn handle_command(command: Commands) -> Result<(), i32> {
 
 pub fn handle_scan(
     path: PathBuf,
-    max_depth: Option<usize>,
-    follow_symlinks: bool,
-    exclude: Vec<String>,
+    options: ScanOptions,
     json: bool,
+    compact: bool,
     verbose: bool,
-    skip_metadata: bool,
+    root: Option<PathBuf>,
+    profile: bool,
 ) -> Result<(), i32> {
     if !path.exists() {
         eprintln!("Error: Path does not exist: {}", path.display());
         return Err(1);
     }
 
-    let tracks = scan_dir_with_options(&path, max_depth, follow_symlinks, exclude, skip_metadata);
+    let mut tracks = if profile {
+        let (tracks, scan_profile) = scan_with_profile(&path, &options);
+        eprintln!("{}", format_scan_profile(&scan_profile));
+        tracks
+    } else {
+        scan_with_options(&path, &options)
+    };
 
     if tracks.is_empty() {
         if path.is_file() {
-            eprintln!("Error: Path is not a directory: {}", path.display());
-            return Err(1);
+            // Say why the file was left out rather than "no music found"
+            if let Err(e) = scan_single_file(&path) {
+                eprintln!("Error: {}", e);
+                return Err(1);
+            }
+            eprintln!("File excluded by the scan options: {}", path.display());
         } else {
             eprintln!("No music files found in directory: {}", path.display());
         }
         return Ok(());
     }
 
+    if let Some(root) = root {
+        tracks = match rebase_tracks(tracks, &root) {
+            Ok(tracks) => tracks,
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                return Err(1);
+            }
+        };
+    }
+
     if verbose {
         eprintln!(
             "Scanned {} music files from {}",
@@ -144,8 +434,13 @@ pub fn handle_scan(
         );
     }
 
-    if json {
-        match to_string_pretty(&tracks) {
+    if json || compact {
+        let serialized = if compact {
+            serde_json::to_string(&tracks)
+        } else {
+            to_string_pretty(&tracks)
+        };
+        match serialized {
             Ok(s) => println!("{}", s),
             Err(e) => {
                 eprintln!("Error serializing to JSON: {}", e);
@@ -163,121 +458,669 @@ pub fn handle_scan(
             eprintln!("Successfully processed {} music files.", tracks.len());
         }
     }
-
-    Ok(())
+
+    Ok(())
+}
+
+/// List audio file paths under `path` without reading metadata (`scan --paths-only`).
+pub fn handle_scan_paths(
+    path: PathBuf,
+    options: &ScanOptions,
+    json: bool,
+    compact: bool,
+    root: Option<PathBuf>,
+    profile: bool,
+) -> Result<(), i32> {
+    if !path.exists() {
+        eprintln!("Error: Path does not exist: {}", path.display());
+        return Err(1);
+    }
+
+    let mut paths = if profile {
+        let (paths, scan_profile) = scan_paths_with_profile(&path, options);
+        eprintln!("{}", format_scan_profile(&scan_profile));
+        paths
+    } else {
+        scan_paths_with_options(&path, options)
+    };
+    if let Some(root) = root {
+        paths = match paths.iter().map(|p| rebase_path(p, &root)).collect() {
+            Ok(paths) => paths,
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                return Err(1);
+            }
+        };
+    }
+
+    if json || compact {
+        let serialized = if compact {
+            serde_json::to_string(&paths)
+        } else {
+            to_string_pretty(&paths)
+        };
+        match serialized {
+            Ok(s) => println!("{}", s),
+            Err(e) => {
+                eprintln!("Error serializing to JSON: {}", e);
+                return Err(1);
+            }
+        }
+    } else {
+        for path in &paths {
+            println!("{}", path.display());
+        }
+    }
+
+    Ok(())
+}
+
+/// Builds hierarchy options from CLI flags; extra VA names extend the defaults.
+fn hierarchy_options(
+    va_threshold: usize,
+    va_artist: Vec<String>,
+    group_soundtracks: bool,
+) -> HierarchyOptions {
+    let mut options = HierarchyOptions {
+        va_threshold,
+        group_soundtracks,
+        ..HierarchyOptions::default()
+    };
+    options.va_album_artists.extend(va_artist);
+    options
+}
+
+pub fn handle_tree(
+    path: PathBuf,
+    json: bool,
+    options: HierarchyOptions,
+    depth: TreeDepth,
+    group_by: TreeGroupBy,
+) -> Result<(), i32> {
+    if !path.exists() {
+        eprintln!("Error: Path does not exist: {}", path.display());
+        return Err(1);
+    }
+
+    if group_by == TreeGroupBy::Year {
+        let tracks = scan_dir(&path, false);
+        let years = build_year_hierarchy_with_options(tracks, &options);
+        if !json {
+            println!("{}", format_year_hierarchy(&years, depth));
+            return Ok(());
+        }
+        return match to_string_pretty(&with_schema_version(&years)) {
+            Ok(s) => {
+                println!("{}", s);
+                Ok(())
+            }
+            Err(e) => {
+                eprintln!("Error serializing to JSON: {}", e);
+                Err(1)
+            }
+        };
+    }
+
+    if json {
+        let tracks = scan_dir(&path, false);
+        let library = build_library_hierarchy_with_options(tracks, &options);
+        let wrapper = with_schema_version(&library);
+        match to_string_pretty(&wrapper) {
+            Ok(s) => println!("{}", s),
+            Err(e) => {
+                eprintln!("Error serializing to JSON: {}", e);
+                return Err(1);
+            }
+        }
+    } else if options != HierarchyOptions::default() || depth != TreeDepth::Track {
+        // Grouping options and depth are metadata-based, so show the artist/album view
+        let tracks = scan_dir(&path, false);
+        let library = build_library_hierarchy_with_options(tracks, &options);
+        println!("{}", format_library_output_with_depth(&library, depth));
+    } else {
+        println!("{}", format_tree_output(&path));
+    }
+
+    Ok(())
+}
+
+pub fn handle_read(file: PathBuf, explain: bool) -> Result<(), i32> {
+    if !file.exists() {
+        eprintln!("Error: File does not exist: {}", file.display());
+        return Err(1);
+    }
+
+    if explain {
+        let explanations = explain_track(&file).map_err(|e| {
+            eprintln!("{}", e);
+            1
+        })?;
+        return match to_string_pretty(&with_schema_version(&explanations)) {
+            Ok(s) => {
+                println!("{}", s);
+                Ok(())
+            }
+            Err(e) => {
+                eprintln!("Error serializing explanation: {}", e);
+                Err(1)
+            }
+        };
+    }
+
+    match read_metadata(&file) {
+        Ok(track) => {
+            let wrapper = with_schema_version(&track);
+            match to_string_pretty(&wrapper) {
+                Ok(s) => println!("{}", s),
+                Err(e) => {
+                    eprintln!("Error serializing track: {}", e);
+                    return Err(1);
+                }
+            }
+        }
+        Err(e) => {
+            eprintln!("Error reading metadata: {}", e);
+            return Err(1);
+        }
+    }
+
+    Ok(())
+}
+
+pub fn handle_write(
+    file: PathBuf,
+    set: Vec<String>,
+    apply: bool,
+    dry_run: bool,
+) -> Result<(), i32> {
+    // Validate that both flags are not used simultaneously
+    if apply && dry_run {
+        eprintln!("Error: Cannot use both --apply and --dry-run flags simultaneously");
+        return Err(1);
+    }
+
+    // Only ask for confirmation if --apply is explicitly specified
+    if apply {
+        // Use a simplified confirmation that doesn't require the complex error type
+        if atty::is(atty::Stream::Stdin) {
+            print!("Apply metadata changes to {}? (y/N): ", file.display());
+            if let Err(_) = std::io::stdout().flush() {
+                // If we can't flush, continue anyway
+            }
+
+            let mut input = String::new();
+            match std::io::stdin().read_line(&mut input) {
+                Ok(_) => {
+                    let input = input.trim().to_lowercase();
+                    if input != "y" && input != "yes" {
+                        eprintln!("Operation cancelled by user.");
+                        return Ok(());
+                    }
+                }
+                Err(_) => {
+                    // If we can't read input, continue without confirmation
+                    eprintln!("Warning: Could not read confirmation input, proceeding with apply.");
+                }
+            }
+        } else {
+            // If not in a TTY environment (like in tests), skip confirmation but warn
+            eprintln!(
+                "Warning: Running in non-interactive mode. Skipping confirmation for --apply."
+            );
+        }
+    }
+
+    match write_metadata_by_path(&file, set, apply, dry_run) {
+        Ok(result) => println!("{}", result),
+        Err(e) => {
+            eprintln!("{}", e);
+            return Err(1);
+        }
+    }
+
+    Ok(())
+}
+
+/// What `normalize` does with the proposed changes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NormalizeMode {
+    /// Print a report; nothing is written.
+    Report,
+    /// Print a report and write the comment removals (`--apply`).
+    Apply,
+    /// Write the proposed changes to this manifest file (`--report-only`).
+    ReportOnly(PathBuf),
+}
+
+/// Run `normalize` with `options`; the genre map file and `--apply` are
+/// filled in from `genre_map` and `mode`.
+pub fn handle_normalize_and_format(
+    path: PathBuf,
+    json: bool,
+    genre_map: Option<PathBuf>,
+    options: NormalizeOptions,
+    mode: NormalizeMode,
+) -> Result<(), i32> {
+    if !path.exists() {
+        eprintln!("Error: Path does not exist: {}", path.display());
+        return Err(1);
+    }
+
+    let genre_map = match genre_map.map(|p| GenreMap::from_file(&p)).transpose() {
+        Ok(map) => map,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return Err(1);
+        }
+    };
+    for issue in genre_map.iter().flat_map(|m| m.issues()) {
+        eprintln!("Warning: genre map: {}", issue);
+    }
+
+    let options = NormalizeOptions {
+        genre_map: genre_map.as_ref(),
+        apply: mode == NormalizeMode::Apply,
+        ..options
+    };
+
+    if let NormalizeMode::ReportOnly(manifest_path) = mode {
+        let manifest = match normalization_report(path, &options) {
+            Ok(report) => NormalizeManifest::from_report(&report),
+            Err(e) => {
+                eprintln!("{}", e);
+                return Err(1);
+            }
+        };
+        let written = manifest
+            .to_json()
+            .and_then(|json| std::fs::write(&manifest_path, json).map_err(|e| e.to_string()));
+        return match written {
+            Ok(()) => {
+                println!(
+                    "Wrote {} proposed changes to {}",
+                    manifest.changes.len(),
+                    manifest_path.display()
+                );
+                Ok(())
+            }
+            Err(e) => {
+                eprintln!(
+                    "Error: Failed to write manifest {}: {}",
+                    manifest_path.display(),
+                    e
+                );
+                Err(1)
+            }
+        };
+    }
+
+    match normalize_and_format_with_options(path, json, &options) {
+        Ok(result) => {
+            println!("{}", result);
+            Ok(())
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            Err(1)
+        }
+    }
+}
+
+/// Apply a `normalize --report-only` manifest (`apply-manifest`).
+pub fn handle_apply_manifest(manifest: PathBuf, json: bool) -> Result<(), i32> {
+    if !manifest.exists() {
+        eprintln!("Error: Path does not exist: {}", manifest.display());
+        return Err(1);
+    }
+
+    match NormalizeManifest::from_file(&manifest) {
+        Ok(manifest) => print_manifest_apply(&manifest, json),
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            Err(1)
+        }
+    }
+}
+
+/// Apply the changes of a manifest for files under `path`
+/// (`normalize --apply-manifest`).
+pub fn handle_normalize_apply_manifest(
+    path: PathBuf,
+    manifest: PathBuf,
+    json: bool,
+) -> Result<(), i32> {
+    if !path.exists() {
+        eprintln!("Error: Path does not exist: {}", path.display());
+        return Err(1);
+    }
+
+    match NormalizeManifest::from_file(&manifest) {
+        Ok(manifest) => print_manifest_apply(&manifest.within(&path), json),
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            Err(1)
+        }
+    }
+}
+
+/// Apply `manifest` and print the results; fails unless every change applied.
+fn print_manifest_apply(manifest: &NormalizeManifest, json: bool) -> Result<(), i32> {
+    let results = apply_manifest(manifest);
+    if json {
+        match to_string_pretty(&results) {
+            Ok(s) => println!("{}", s),
+            Err(e) => {
+                eprintln!("Error serializing to JSON: {}", e);
+                return Err(1);
+            }
+        }
+    } else {
+        println!("{}", format_apply_results(&results));
+    }
+
+    if results
+        .iter()
+        .all(|r| r.status == ManifestApplyStatus::Applied)
+    {
+        Ok(())
+    } else {
+        Err(1)
+    }
+}
+
+pub fn handle_emit(
+    path: PathBuf,
+    json: bool,
+    compact: bool,
+    by_artist: bool,
+    decades: Option<YearSource>,
+    options: HierarchyOptions,
+    genre_roots: Option<Option<PathBuf>>,
+) -> Result<(), i32> {
+    if !path.exists() {
+        eprintln!("Error: Path does not exist: {}", path.display());
+        return Err(1);
+    }
+
+    let output = if let Some(genre_map) = genre_roots {
+        let genre_map = match genre_map.map(|p| GenreMap::from_file(&p)).transpose() {
+            Ok(map) => map,
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                return Err(1);
+            }
+        };
+        for issue in genre_map.iter().flat_map(|m| m.issues()) {
+            eprintln!("Warning: genre map: {}", issue);
+        }
+        emit_by_path_with_genre_roots(&path, compact, &options, genre_map.as_ref())
+    } else if by_artist {
+        emit_artist_summaries_by_path(&path, json || compact, compact, &options)
+    } else if let Some(source) = decades {
+        emit_decade_histogram_by_path(&path, json || compact, compact, &options, source)
+    } else {
+        emit_by_path_with_options(&path, json || compact, compact, &options)
+    };
+
+    match output {
+        Ok(result) => println!("{}", result),
+        Err(err) => {
+            eprintln!("{}", err);
+            return Err(1);
+        }
+    }
+
+    Ok(())
+}
+
+pub fn handle_emit_by_genre(
+    path: PathBuf,
+    json: bool,
+    compact: bool,
+    options: HierarchyOptions,
+) -> Result<(), i32> {
+    if !path.exists() {
+        eprintln!("Error: Path does not exist: {}", path.display());
+        return Err(1);
+    }
+
+    match emit_tracks_by_genre_by_path(&path, json || compact, compact, &options) {
+        Ok(result) => {
+            println!("{}", result);
+            Ok(())
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            Err(1)
+        }
+    }
+}
+
+pub fn handle_emit_toml(path: PathBuf) -> Result<(), i32> {
+    if !path.exists() {
+        eprintln!("Error: Path does not exist: {}", path.display());
+        return Err(1);
+    }
+
+    match emit_toml_by_path(&path) {
+        Ok(toml) => {
+            print!("{}", toml);
+            Ok(())
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            Err(1)
+        }
+    }
+}
+
+pub fn handle_write_from_toml(file: PathBuf, apply: bool) -> Result<(), i32> {
+    if !file.exists() {
+        eprintln!("Error: Path does not exist: {}", file.display());
+        return Err(1);
+    }
+
+    let document = match TomlDocument::from_file(&file) {
+        Ok(document) => document,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return Err(1);
+        }
+    };
+
+    let results = write_from_toml(&document, apply);
+    println!("{}", format_toml_write_results(&results, apply));
+    if results.iter().any(|r| r.error.is_some()) {
+        return Err(1);
+    }
+    Ok(())
+}
+
+pub fn handle_write_album_info(path: PathBuf, dry_run: bool, force: bool) -> Result<(), i32> {
+    if !path.exists() {
+        eprintln!("Error: Path does not exist: {}", path.display());
+        return Err(1);
+    }
+
+    let results = match write_album_info(&path, dry_run, force) {
+        Ok(results) => results,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return Err(1);
+        }
+    };
+    println!("{}", format_album_info_results(&results, dry_run));
+    if results
+        .iter()
+        .any(|r| matches!(r.status, AlbumInfoStatus::Error(_)))
+    {
+        return Err(1);
+    }
+    Ok(())
+}
+
+pub fn handle_artist_aliases(
+    path: PathBuf,
+    canonical_artist: Vec<String>,
+    apply: bool,
+    json: bool,
+) -> Result<(), i32> {
+    if !path.exists() {
+        eprintln!("Error: Path does not exist: {}", path.display());
+        return Err(1);
+    }
+
+    let clusters = match detect_artist_aliases_by_path(&path) {
+        Ok(clusters) => clusters,
+        Err(e) => {
+            eprintln!("{}", e);
+            return Err(1);
+        }
+    };
+
+    if canonical_artist.is_empty() {
+        let output = if json {
+            to_string_pretty(&with_schema_version(&clusters))
+                .map_err(|e| format!("Error serializing to JSON: {}", e))
+        } else {
+            Ok(format_artist_aliases(&clusters).trim_end().to_string())
+        };
+        return match output {
+            Ok(result) => {
+                println!("{}", result);
+                Ok(())
+            }
+            Err(e) => {
+                eprintln!("{}", e);
+                Err(1)
+            }
+        };
+    }
+
+    let results = match apply_artist_canonicalization(&clusters, &canonical_artist, apply) {
+        Ok(results) => results,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return Err(1);
+        }
+    };
+    if json {
+        match to_string_pretty(&with_schema_version(&results)) {
+            Ok(result) => println!("{}", result),
+            Err(e) => {
+                eprintln!("Error serializing to JSON: {}", e);
+                return Err(1);
+            }
+        }
+    } else {
+        println!("{}", format_artist_rewrites(&results, apply));
+    }
+    if results.iter().any(|r| r.error.is_some()) {
+        return Err(1);
+    }
+    Ok(())
+}
+
+pub fn handle_duplicates(
+    path: PathBuf,
+    json: bool,
+    verbose: bool,
+    parallel: Option<usize>,
+    pcm_content: bool,
+    near_tolerance: Option<f64>,
+) -> Result<(), i32> {
+    if !path.exists() {
+        eprintln!("Error: Path does not exist: {}", path.display());
+        return Err(1);
+    }
+
+    let mode = if pcm_content {
+        ChecksumMode::PcmContent
+    } else {
+        ChecksumMode::FileBytes
+    };
+    let result = match near_tolerance {
+        Some(tolerance) => find_near_duplicates_by_path(&path, json, verbose, tolerance),
+        None => find_duplicates_with_mode(&path, json, verbose, parallel, mode),
+    };
+    match result {
+        Ok(value) => {
+            println!("{}", value);
+            Ok(())
+        }
+        Err(value) => {
+            eprintln!("{}", value);
+            Err(1)
+        }
+    }
 }
 
-pub fn handle_tree(path: PathBuf, json: bool) -> Result<(), i32> {
+pub fn handle_filename_collisions(path: PathBuf, json: bool) -> Result<(), i32> {
     if !path.exists() {
         eprintln!("Error: Path does not exist: {}", path.display());
         return Err(1);
     }
 
-    if json {
-        let tracks = scan_dir(&path, false);
-        let library = build_library_hierarchy(tracks);
-        let wrapper = with_schema_version(&library);
-        match to_string_pretty(&wrapper) {
-            Ok(s) => println!("{}", s),
-            Err(e) => {
-                eprintln!("Error serializing to JSON: {}", e);
-                return Err(1);
-            }
+    match find_filename_collisions_by_path(&path, json) {
+        Ok(value) => {
+            println!("{}", value);
+            Ok(())
+        }
+        Err(value) => {
+            eprintln!("{}", value);
+            Err(1)
         }
-    } else {
-        println!("{}", format_tree_output(&path));
     }
-
-    Ok(())
 }
 
-pub fn handle_read(file: PathBuf) -> Result<(), i32> {
-    if !file.exists() {
-        eprintln!("Error: File does not exist: {}", file.display());
+pub fn handle_transcode_plan(path: PathBuf) -> Result<(), i32> {
+    if !path.exists() {
+        eprintln!("Error: Path does not exist: {}", path.display());
         return Err(1);
     }
 
-    match read_metadata(&file) {
-        Ok(track) => {
-            let wrapper = with_schema_version(&track);
-            match to_string_pretty(&wrapper) {
-                Ok(s) => println!("{}", s),
-                Err(e) => {
-                    eprintln!("Error serializing track: {}", e);
-                    return Err(1);
-                }
-            }
+    match transcode_plan_by_path(&path) {
+        Ok(value) => {
+            println!("{}", value);
+            Ok(())
         }
-        Err(e) => {
-            eprintln!("Error reading metadata: {}", e);
-            return Err(1);
+        Err(value) => {
+            eprintln!("{}", value);
+            Err(1)
         }
     }
-
-    Ok(())
 }
 
-pub fn handle_write(
-    file: PathBuf,
-    set: Vec<String>,
-    apply: bool,
-    dry_run: bool,
-) -> Result<(), i32> {
-    // Validate that both flags are not used simultaneously
-    if apply && dry_run {
-        eprintln!("Error: Cannot use both --apply and --dry-run flags simultaneously");
+pub fn handle_verify(path: PathBuf, json: bool, analyze_silence: bool) -> Result<(), i32> {
+    if !path.exists() {
+        eprintln!("Error: Path does not exist: {}", path.display());
         return Err(1);
     }
-
-    // Only ask for confirmation if --apply is explicitly specified
-    if apply {
-        // Use a simplified confirmation that doesn't require the complex error type
-        if atty::is(atty::Stream::Stdin) {
-            print!("Apply metadata changes to {}? (y/N): ", file.display());
-            if let Err(_) = std::io::stdout().flush() {
-                // If we can't flush, continue anyway
-            }
-
-            let mut input = String::new();
-            match std::io::stdin().read_line(&mut input) {
-                Ok(_) => {
-                    let input = input.trim().to_lowercase();
-                    if input != "y" && input != "yes" {
-                        eprintln!("Operation cancelled by user.");
-                        return Ok(());
-                    }
-                }
-                Err(_) => {
-                    // If we can't read input, continue without confirmation
-                    eprintln!("Warning: Could not read confirmation input, proceeding with apply.");
-                }
-            }
-        } else {
-            // If not in a TTY environment (like in tests), skip confirmation but warn
-            eprintln!(
-                "Warning: Running in non-interactive mode. Skipping confirmation for --apply."
-            );
-        }
+    if analyze_silence && !SILENCE_ANALYSIS_AVAILABLE {
+        eprintln!("Error: --analyze-silence needs a build with the `silence` feature");
+        return Err(1);
     }
 
-    match write_metadata_by_path(&file, set, apply, dry_run) {
-        Ok(result) => println!("{}", result),
-        Err(e) => {
-            eprintln!("{}", e);
-            return Err(1);
+    // Failures still print the full report to stdout so it can be consumed
+    match verify_by_path(&path, json, analyze_silence) {
+        Ok(report) => {
+            println!("{}", report);
+            Ok(())
+        }
+        Err(report) => {
+            println!("{}", report);
+            Err(1)
         }
     }
-
-    Ok(())
 }
 
-pub fn handle_normalize_and_format(path: PathBuf, json: bool) -> Result<(), i32> {
+pub fn handle_inference_check(path: PathBuf, json: bool, worst: usize) -> Result<(), i32> {
     if !path.exists() {
         eprintln!("Error: Path does not exist: {}", path.display());
         return Err(1);
     }
 
-    match normalize_and_format(path, json) {
+    match artist_agreement_by_path(&path, json, worst) {
         Ok(result) => {
             println!("{}", result);
             Ok(())
@@ -289,53 +1132,88 @@ pub fn handle_normalize_and_format(path: PathBuf, json: bool) -> Result<(), i32>
     }
 }
 
-pub fn handle_emit(path: PathBuf, json: bool) -> Result<(), i32> {
+pub fn handle_stats(path: PathBuf, json: bool, top: usize) -> Result<(), i32> {
     if !path.exists() {
         eprintln!("Error: Path does not exist: {}", path.display());
         return Err(1);
     }
 
-    match emit_by_path(&path, json) {
-        Ok(result) => println!("{}", result),
-        Err(err) => {
-            eprintln!("{}", err);
-            return Err(1);
+    match emit_library_stats_by_path(&path, json, top, &HierarchyOptions::default()) {
+        Ok(result) => {
+            println!("{}", result);
+            Ok(())
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            Err(1)
         }
     }
-
-    Ok(())
 }
 
-pub fn handle_duplicates(
-    path: PathBuf,
-    json: bool,
-    verbose: bool,
-    parallel: Option<usize>,
-) -> Result<(), i32> {
+pub fn handle_library_hash(path: PathBuf) -> Result<(), i32> {
     if !path.exists() {
         eprintln!("Error: Path does not exist: {}", path.display());
         return Err(1);
     }
 
-    match find_duplicates(&path, json, verbose, parallel) {
-        Ok(value) => {
-            println!("{}", value);
+    match library_hash_by_path(&path) {
+        Ok(hash) => {
+            println!("{}", hash);
             Ok(())
         }
-        Err(value) => {
-            eprintln!("{}", value);
+        Err(e) => {
+            eprintln!("{}", e);
+            Err(1)
+        }
+    }
+}
+
+pub fn handle_load(file: PathBuf, stats: bool, validate: bool, json: bool) -> Result<(), i32> {
+    let contents = match std::fs::read_to_string(&file) {
+        Ok(contents) => contents,
+        Err(e) => {
+            eprintln!("Error: Failed to read {}: {}", file.display(), e);
+            return Err(1);
+        }
+    };
+    let library = match Library::from_json(&contents) {
+        Ok(library) => library,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return Err(1);
+        }
+    };
+
+    let output = if validate {
+        Ok(validate_library(&library, json))
+    } else if stats {
+        emit_artist_summaries(&library, json, false)
+    } else if json {
+        to_string_pretty(&with_schema_version(&library))
+            .map_err(|e| format!("Error serializing to JSON: {}", e))
+    } else {
+        Ok(format_library_output(&library))
+    };
+
+    match output {
+        Ok(result) => {
+            println!("{}", result);
+            Ok(())
+        }
+        Err(e) => {
+            eprintln!("{}", e);
             Err(1)
         }
     }
 }
 
-fn handle_validate(path: PathBuf, json: bool) -> Result<(), i32> {
+fn handle_validate(path: PathBuf, json: bool, options: ValidateOptions) -> Result<(), i32> {
     if !path.exists() {
         eprintln!("Error: Path does not exist: {}", path.display());
         return Err(1);
     }
 
-    match validate_path(&path, json) {
+    match validate_path_with_options(&path, json, &options) {
         Ok(value) => {
             println!("{}", value);
             Ok(())
@@ -350,6 +1228,8 @@ fn handle_validate(path: PathBuf, json: bool) -> Result<(), i32> {
 struct CueParams {
     path: PathBuf,
     output: Option<PathBuf>,
+    recursive: bool,
+    output_template: Option<String>,
     dry_run: bool,
     force: bool,
     audio_dir: Option<PathBuf>,
@@ -357,28 +1237,49 @@ struct CueParams {
     generate: bool,
     parse: bool,
     validate: bool,
+    check_duration: bool,
+    charset: CueCharset,
 }
 
 fn handle_cue(params: CueParams) -> Result<(), i32> {
     let generate = params.generate;
-    let operation_count = generate as u8 + params.parse as u8 + params.validate as u8;
+    let operation_count =
+        generate as u8 + params.parse as u8 + params.validate as u8 + params.check_duration as u8;
 
     if operation_count == 0 {
-        eprintln!("Error: Must specify --generate, --parse, or --validate");
+        eprintln!("Error: Must specify --generate, --parse, --validate, or --check-duration");
         return Err(1);
     }
 
     if operation_count > 1 {
-        eprintln!("Error: Can only specify one of --generate, --parse, or --validate");
+        eprintln!(
+            "Error: Can only specify one of --generate, --parse, --validate, or --check-duration"
+        );
         return Err(1);
     }
 
-    if generate {
-        handle_cue_generate(params.path, params.output, params.dry_run, params.force)?;
+    if generate && params.recursive {
+        handle_cue_generate_recursive(
+            params.path,
+            params.output_template.as_deref(),
+            params.dry_run,
+            params.force,
+            params.charset,
+        )?;
+    } else if generate {
+        handle_cue_generate(
+            params.path,
+            params.output,
+            params.dry_run,
+            params.force,
+            params.charset,
+        )?;
     } else if params.parse {
         handle_cue_parse(params.path, params.json)?;
     } else if params.validate {
         handle_cue_validate(params.path, params.audio_dir, params.json)?;
+    } else if params.check_duration {
+        handle_cue_check_duration(params.path, params.audio_dir, params.json)?;
     }
 
     Ok(())
@@ -389,6 +1290,7 @@ fn handle_cue_generate(
     output: Option<PathBuf>,
     dry_run: bool,
     force: bool,
+    charset: CueCharset,
 ) -> Result<(), i32> {
     match generate_cue_for_path(&path, output) {
         Ok(result) => {
@@ -405,7 +1307,15 @@ fn handle_cue_generate(
                 println!("---");
                 println!("Would write to: {}", result.output_path.display());
             } else {
-                match std::fs::write(&result.output_path, &result.cue_content) {
+                let (bytes, unmappable) = encode_cue_content(&result.cue_content, charset);
+                if !unmappable.is_empty() {
+                    let chars: String = unmappable.into_iter().collect();
+                    eprintln!(
+                        "Warning: characters not representable in the chosen charset were written as '?': {}",
+                        chars
+                    );
+                }
+                match std::fs::write(&result.output_path, bytes) {
                     Ok(_) => println!("Cue file written to: {}", result.output_path.display()),
                     Err(e) => {
                         eprintln!("Error writing cue file: {}", e);
@@ -432,6 +1342,78 @@ fn handle_cue_generate(
     }
 }
 
+fn handle_cue_generate_recursive(
+    path: PathBuf,
+    output_template: Option<&str>,
+    dry_run: bool,
+    force: bool,
+    charset: CueCharset,
+) -> Result<(), i32> {
+    if !path.is_dir() {
+        eprintln!("Error: Not a directory: {}", path.display());
+        return Err(1);
+    }
+
+    let template = output_template.unwrap_or(DEFAULT_CUE_OUTPUT_TEMPLATE);
+    let generation = match generate_cues_recursive(&path, template) {
+        Ok(generation) => generation,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return Err(1);
+        }
+    };
+    if generation.results.is_empty() && generation.errors.is_empty() {
+        eprintln!("No music files found in directory");
+        return Err(1);
+    }
+
+    let mut failed = !generation.errors.is_empty();
+    for error in &generation.errors {
+        eprintln!("Error: {}", error);
+    }
+    for result in &generation.results {
+        if dry_run {
+            println!("Would write to: {}", result.output_path.display());
+            continue;
+        }
+        if result.output_path.exists() && !force {
+            eprintln!(
+                "Error: Cue file already exists at '{}'. Use --force to overwrite.",
+                result.output_path.display()
+            );
+            failed = true;
+            continue;
+        }
+        let (bytes, unmappable) = encode_cue_content(&result.cue_content, charset);
+        if !unmappable.is_empty() {
+            let chars: String = unmappable.into_iter().collect();
+            eprintln!(
+                "Warning: characters not representable in the chosen charset were written as '?' in {}: {}",
+                result.output_path.display(),
+                chars
+            );
+        }
+        let written = result
+            .output_path
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|()| std::fs::write(&result.output_path, bytes));
+        match written {
+            Ok(()) => println!("Cue file written to: {}", result.output_path.display()),
+            Err(e) => {
+                eprintln!(
+                    "Error writing cue file {}: {}",
+                    result.output_path.display(),
+                    e
+                );
+                failed = true;
+            }
+        }
+    }
+
+    if failed { Err(1) } else { Ok(()) }
+}
+
 fn handle_cue_parse(path: PathBuf, json: bool) -> Result<(), i32> {
     if !path.exists() {
         eprintln!("Error: File does not exist: {}", path.display());
@@ -457,6 +1439,15 @@ fn handle_cue_parse(path: PathBuf, json: bool) -> Result<(), i32> {
                 if let Some(title) = &cue_file.title {
                     println!("  Title: {}", title);
                 }
+                if let Some(catalog) = &cue_file.catalog {
+                    println!("  Catalog: {}", catalog);
+                }
+                if let Some(disc_id) = &cue_file.disc_id {
+                    println!("  Disc ID: {}", disc_id);
+                }
+                if let Some(comment) = &cue_file.comment {
+                    println!("  Comment: {}", comment);
+                }
                 if !cue_file.files.is_empty() {
                     println!("  Files:");
                     for file in &cue_file.files {
@@ -545,6 +1536,54 @@ fn handle_cue_validate(path: PathBuf, audio_dir: Option<PathBuf>, json: bool) ->
     Ok(())
 }
 
+fn handle_cue_check_duration(
+    path: PathBuf,
+    audio_dir: Option<PathBuf>,
+    json: bool,
+) -> Result<(), i32> {
+    if !path.exists() {
+        eprintln!("Error: Path does not exist: {}", path.display());
+        return Err(1);
+    }
+
+    let audio_directory = audio_dir.unwrap_or_else(|| {
+        path.parent()
+            .unwrap_or_else(|| Path::new("."))
+            .to_path_buf()
+    });
+
+    let reports = match validate_cue_durations(&path, &audio_directory) {
+        Ok(reports) => reports,
+        Err(e) => {
+            eprintln!("Error parsing cue file: {}", e);
+            return Err(1);
+        }
+    };
+
+    if json {
+        let wrapper = with_schema_version(&reports);
+        match to_string_pretty(&wrapper) {
+            Ok(s) => println!("{}", s),
+            Err(e) => {
+                eprintln!("Error serializing result: {}", e);
+                return Err(1);
+            }
+        }
+    } else {
+        for report in &reports {
+            println!("{}", format_cue_duration_report(report));
+        }
+    }
+
+    if reports
+        .iter()
+        .any(|r| r.status != CueDurationStatus::Consistent)
+    {
+        return Err(1);
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -562,14 +1601,53 @@ mod tests {
         let audio_file = test_path.join("test.flac");
         fs::copy("tests/fixtures/flac/simple/track1.flac", &audio_file).unwrap();
 
-        let result = handle_scan(test_path, None, false, vec![], false, false, false);
+        let result = handle_scan(
+            test_path.clone(),
+            ScanOptions::default(),
+            false,
+            false,
+            false,
+            None,
+            false,
+        );
+        assert!(result.is_ok());
+
+        let result = handle_scan(
+            test_path.clone(),
+            ScanOptions::default(),
+            true,
+            false,
+            false,
+            Some(temp_dir.path().to_path_buf()),
+            false,
+        );
         assert!(result.is_ok());
+
+        let outside = TempDir::new().unwrap();
+        let result = handle_scan(
+            test_path,
+            ScanOptions::default(),
+            false,
+            false,
+            false,
+            Some(outside.path().to_path_buf()),
+            false,
+        );
+        assert_eq!(result, Err(1));
     }
 
     #[test]
     fn test_handle_scan_with_nonexistent_path() {
         let nonexistent_path = PathBuf::from("/nonexistent/path/test");
-        let result = handle_scan(nonexistent_path, None, false, vec![], false, false, false);
+        let result = handle_scan(
+            nonexistent_path,
+            ScanOptions::default(),
+            false,
+            false,
+            false,
+            None,
+            false,
+        );
         assert_eq!(result, Err(1));
     }
 
@@ -579,42 +1657,62 @@ mod tests {
         let test_path = temp_dir.path().join("test_dir");
         fs::create_dir(&test_path).unwrap();
 
-        let result = handle_tree(test_path, false);
+        let result = handle_tree(
+            test_path,
+            false,
+            HierarchyOptions::default(),
+            TreeDepth::Track,
+            TreeGroupBy::Artist,
+        );
         assert!(result.is_ok());
     }
 
     #[test]
     fn test_handle_tree_with_nonexistent_path() {
         let nonexistent_path = PathBuf::from("/nonexistent/path/test");
-        let result = handle_tree(nonexistent_path, false);
+        let result = handle_tree(
+            nonexistent_path,
+            false,
+            HierarchyOptions::default(),
+            TreeDepth::Track,
+            TreeGroupBy::Artist,
+        );
         assert_eq!(result, Err(1));
     }
 
     #[test]
     fn test_handle_read_with_nonexistent_file() {
         let nonexistent_file = PathBuf::from("/nonexistent/path/test.flac");
-        let result = handle_read(nonexistent_file);
+        let result = handle_read(nonexistent_file, false);
         assert_eq!(result, Err(1));
     }
 
     #[test]
     fn test_handle_emit_with_nonexistent_path() {
         let nonexistent_path = PathBuf::from("/nonexistent/path/test");
-        let result = handle_emit(nonexistent_path, false);
+        let result = handle_emit(
+            nonexistent_path,
+            false,
+            false,
+            false,
+            false,
+            HierarchyOptions::default(),
+            None,
+        );
         assert_eq!(result, Err(1));
     }
 
     #[test]
     fn test_handle_duplicates_with_nonexistent_path() {
         let nonexistent_path = PathBuf::from("/nonexistent/path/test");
-        let result = handle_duplicates(nonexistent_path, false, false, None);
+        let result = handle_duplicates(nonexistent_path, false, false, None, false, None);
         assert_eq!(result, Err(1));
     }
 
     #[test]
     fn test_handle_validate_with_nonexistent_path() {
         let nonexistent_path = PathBuf::from("/nonexistent/path/test");
-        let result = handle_validate(nonexistent_path, false);
+        let result = handle_validate(nonexistent_path, false, ValidateOptions::default());
         assert_eq!(result, Err(1));
     }
 
@@ -645,6 +1743,8 @@ mod tests {
         let params_no_op = CueParams {
             path: PathBuf::from("test.cue"),
             output: None,
+            recursive: false,
+            output_template: None,
             dry_run: false,
             force: false,
             audio_dir: None,
@@ -652,6 +1752,8 @@ mod tests {
             generate: false,
             parse: false,
             validate: false,
+            check_duration: false,
+            charset: CueCharset::default(),
         };
 
         let result_no_op = handle_cue(params_no_op);
@@ -660,6 +1762,8 @@ mod tests {
         let params_multi_op = CueParams {
             path: PathBuf::from("test.cue"),
             output: None,
+            recursive: false,
+            output_template: None,
             dry_run: false,
             force: false,
             audio_dir: None,
@@ -667,6 +1771,8 @@ mod tests {
             generate: true,
             parse: true,
             validate: false,
+            check_duration: false,
+            charset: CueCharset::default(),
         };
 
         let result_multi_op = handle_cue(params_multi_op);
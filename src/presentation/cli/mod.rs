@@ -4,6 +4,6 @@ pub mod commands;
 pub mod commands_processor;
 
 // Re-export commonly used CLI types
-pub use commands::{Cli, Commands};
+pub use commands::{Cli, Commands, OUTPUT_FORMAT_ENV, OutputFormat, resolve_output_format};
 
 pub use commands_processor::handle_command;
@@ -36,6 +36,7 @@ fn test_write_metadata_by_path_dry_run() {
     assert!(output.contains("DRY RUN: Would set title = New Title"));
     assert!(output.contains("DRY RUN: Would set artist = New Artist"));
     assert!(output.contains("DRY RUN: No changes made to file:"));
+    assert!(output.ends_with("1 files would be changed, 0 unchanged, 0 errors\n"));
 
     // Verify the file was not actually modified by checking that original metadata is still there
     let original_track = music_chore::adapters::audio_formats::read_metadata(&test_file).unwrap();
@@ -61,6 +62,7 @@ fn test_write_metadata_by_path_apply_changes() {
     assert!(result.is_ok());
     let output = result.unwrap();
     assert!(output.contains("Successfully updated metadata:"));
+    assert!(output.ends_with("1 files changed, 0 unchanged, 0 errors\n"));
 
     // Verify the file was actually modified
     let updated_track = music_chore::adapters::audio_formats::read_metadata(&test_file).unwrap();
@@ -366,3 +368,19 @@ fn test_write_metadata_by_path_update_album_artist() {
     );
     // Note: MetadataSource is not persisted through file I/O
 }
+
+#[test]
+fn test_write_metadata_by_path_same_value_counts_as_unchanged() {
+    let temp_dir = TempDir::new().unwrap();
+    let test_file = create_test_flac_file(&temp_dir);
+
+    let output = write_metadata_by_path(
+        &test_file,
+        vec!["title=Test Apply Behavior".to_string()],
+        false,
+        true,
+    )
+    .unwrap();
+
+    assert!(output.ends_with("0 files would be changed, 1 unchanged, 0 errors\n"));
+}
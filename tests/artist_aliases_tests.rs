@@ -0,0 +1,94 @@
+use music_chore::core::services::artist_aliases::detect_artist_aliases_by_path;
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn musicctl(args: &[&str], path: &Path) -> (bool, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .args(&args[..1])
+        .arg(path)
+        .args(&args[1..])
+        .output()
+        .expect("Failed to run musicctl");
+    (
+        output.status.success(),
+        String::from_utf8(output.stdout).unwrap(),
+    )
+}
+
+/// Three copies of the tagged fixture, one respelled as "the test artist".
+fn library() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    for name in ["a.flac", "b.flac", "c.flac"] {
+        std::fs::copy(
+            "tests/fixtures/flac/simple/track1.flac",
+            temp_dir.path().join(name),
+        )
+        .unwrap();
+    }
+    let (success, _) = musicctl(
+        &["write", "--set", "artist=the test artist", "--apply"],
+        &temp_dir.path().join("c.flac"),
+    );
+    assert!(success);
+    temp_dir
+}
+
+#[test]
+fn test_artist_aliases_lists_clusters() {
+    let temp_dir = library();
+
+    let (success, stdout) = musicctl(&["artist-aliases"], temp_dir.path());
+
+    assert!(success);
+    assert!(stdout.contains("'Test Artist' (2 files), 'the test artist' (1 file)"));
+    assert!(stdout.contains("--canonical-artist"));
+}
+
+#[test]
+fn test_artist_aliases_rewrites_to_chosen_spelling() {
+    let temp_dir = library();
+    let args = ["artist-aliases", "--canonical-artist", "Test Artist"];
+
+    let (success, stdout) = musicctl(&args, temp_dir.path());
+    assert!(success);
+    assert!(stdout.contains("WOULD UPDATE"));
+    assert!(stdout.contains("c.flac"));
+    assert!(stdout.contains("  artist=Test Artist"));
+    assert!(stdout.ends_with("1 files would be changed, 0 unchanged, 0 errors\n"));
+    assert_eq!(
+        detect_artist_aliases_by_path(temp_dir.path())
+            .unwrap()
+            .len(),
+        1
+    );
+
+    let (success, stdout) = musicctl(
+        &[
+            "artist-aliases",
+            "--canonical-artist",
+            "Test Artist",
+            "--apply",
+        ],
+        temp_dir.path(),
+    );
+    assert!(success);
+    assert!(stdout.ends_with("1 files changed, 0 unchanged, 0 errors\n"));
+    assert!(
+        detect_artist_aliases_by_path(temp_dir.path())
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_artist_aliases_rejects_name_outside_any_cluster() {
+    let temp_dir = library();
+
+    let (success, _) = musicctl(
+        &["artist-aliases", "--canonical-artist", "Someone Else"],
+        temp_dir.path(),
+    );
+
+    assert!(!success);
+}
@@ -272,3 +272,35 @@ fn test_track_metadata_builder_special_characters() {
         "Album: Subtitle [Remix]"
     );
 }
+
+#[test]
+fn test_track_builder_sets_embedded_values() {
+    use music_chore::Track;
+
+    let track = Track::builder("/music/Artist/Album/01.flac")
+        .title("Song")
+        .artist("Artist")
+        .album("Album")
+        .track_number(1)
+        .year(1999)
+        .duration(180.5)
+        .format("flac")
+        .checksum("abc")
+        .build();
+
+    assert_eq!(
+        track.file_path,
+        PathBuf::from("/music/Artist/Album/01.flac")
+    );
+    assert_eq!(track.metadata.path, track.file_path);
+    assert_eq!(
+        track.metadata.title,
+        Some(MetadataValue::embedded("Song".to_string()))
+    );
+    assert_eq!(track.metadata.track_number.as_ref().unwrap().value, 1);
+    assert_eq!(track.metadata.year.as_ref().unwrap().value, 1999);
+    assert_eq!(track.metadata.format, "flac");
+    assert_eq!(track.checksum.as_deref(), Some("abc"));
+    assert!(track.metadata.genre.is_none());
+    assert!(track.metadata.album_artist.is_none());
+}
@@ -79,17 +79,9 @@ fn test_track_checksum_nonexistent_file() {
     let track = Track::new(
         PathBuf::from("/nonexistent/file.flac"),
         music_chore::core::domain::models::TrackMetadata {
-            title: None,
-            artist: None,
-            album: None,
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
-            year: None,
-            genre: None,
-            duration: None,
             format: "flac".to_string(),
             path: PathBuf::from("/nonexistent/file.flac"),
+            ..Default::default()
         },
     );
 
@@ -168,17 +160,9 @@ fn test_duplicate_detection_no_duplicates() {
 fn test_track_with_checksum() {
     let path = PathBuf::from("/test/file.flac");
     let metadata = music_chore::core::domain::models::TrackMetadata {
-        title: None,
-        artist: None,
-        album: None,
-        album_artist: None,
-        track_number: None,
-        disc_number: None,
-        year: None,
-        genre: None,
-        duration: None,
         format: "flac".to_string(),
         path: path.clone(),
+        ..Default::default()
     };
 
     let checksum = "abc123".to_string();
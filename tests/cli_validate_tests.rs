@@ -229,3 +229,46 @@ fn test_validate_command_help() {
     assert!(stdout.contains("Validate metadata completeness"));
     assert!(stdout.contains("--json"));
 }
+
+#[test]
+fn test_validate_command_fail_on_severity_sets_exit_code() {
+    let temp_dir = TempDir::new().unwrap();
+    let flac_path = temp_dir.path().join("test.flac");
+    fs::copy("tests/fixtures/flac/simple/track1.flac", &flac_path).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .arg("write")
+        .arg(&flac_path)
+        .arg("--set")
+        .arg("title=Test Song")
+        .arg("--set")
+        .arg("artist=Test Artist")
+        .arg("--set")
+        .arg("album=Test Album")
+        .arg("--set")
+        .arg("year=1800") // Unusual year, a warning only
+        .arg("--apply")
+        .output()
+        .expect("Failed to set problematic metadata");
+    assert!(output.status.success());
+
+    let run = |severity: &str| {
+        Command::new(env!("CARGO_BIN_EXE_musicctl"))
+            .arg("validate")
+            .arg(temp_dir.path())
+            .arg("--fail-on")
+            .arg(severity)
+            .output()
+            .expect("Failed to execute musicctl validate --fail-on")
+    };
+
+    assert!(run("error").status.success());
+
+    let output = run("warning");
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("at or above severity 'warning'"));
+
+    let output = run("fatal");
+    assert!(!output.status.success());
+}
@@ -50,7 +50,6 @@ mod tests {
                 MetadataSource::Embedded,
                 1.0,
             )),
-            album_artist: None,
             track_number: Some(create_test_metadata_value(5, MetadataSource::Embedded, 1.0)),
             disc_number: Some(create_test_metadata_value(1, MetadataSource::Embedded, 1.0)),
             year: Some(create_test_metadata_value(
@@ -70,6 +69,7 @@ mod tests {
             )),
             format: "flac".to_string(),
             path: PathBuf::from("/test/track.flac"),
+            ..Default::default()
         };
 
         assert_eq!(metadata.title.unwrap().value, "Test Track");
@@ -101,14 +101,9 @@ mod tests {
                 MetadataSource::Embedded,
                 1.0,
             )),
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
-            year: None,
-            genre: None,
-            duration: None,
             format: "flac".to_string(),
             path: PathBuf::from("/test/track.flac"),
+            ..Default::default()
         };
 
         let track = Track {
@@ -140,14 +135,9 @@ mod tests {
                 MetadataSource::Embedded,
                 1.0,
             )),
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
-            year: None,
-            genre: None,
-            duration: None,
             format: "flac".to_string(),
             path: PathBuf::from("/test/node_track.flac"),
+            ..Default::default()
         };
 
         let track_node = TrackNode {
@@ -169,16 +159,9 @@ mod tests {
                     MetadataSource::Embedded,
                     1.0,
                 )),
-                artist: None,
-                album: None,
-                album_artist: None,
-                track_number: None,
-                disc_number: None,
-                year: None,
-                genre: None,
-                duration: None,
                 format: "flac".to_string(),
                 path: PathBuf::from("/test/album/track.flac"),
+                ..Default::default()
             },
         };
 
@@ -239,33 +222,17 @@ mod tests {
                         TrackNode {
                             file_path: PathBuf::from("/album1/track1.flac"),
                             metadata: TrackMetadata {
-                                title: None,
-                                artist: None,
-                                album: None,
-                                album_artist: None,
-                                track_number: None,
-                                disc_number: None,
-                                year: None,
-                                genre: None,
-                                duration: None,
                                 format: "flac".to_string(),
                                 path: PathBuf::from("/album1/track1.flac"),
+                                ..Default::default()
                             },
                         },
                         TrackNode {
                             file_path: PathBuf::from("/album1/track2.flac"),
                             metadata: TrackMetadata {
-                                title: None,
-                                artist: None,
-                                album: None,
-                                album_artist: None,
-                                track_number: None,
-                                disc_number: None,
-                                year: None,
-                                genre: None,
-                                duration: None,
                                 format: "flac".to_string(),
                                 path: PathBuf::from("/album1/track2.flac"),
+                                ..Default::default()
                             },
                         },
                     ],
@@ -283,17 +250,9 @@ mod tests {
                     tracks: vec![TrackNode {
                         file_path: PathBuf::from("/album2/track1.flac"),
                         metadata: TrackMetadata {
-                            title: None,
-                            artist: None,
-                            album: None,
-                            album_artist: None,
-                            track_number: None,
-                            disc_number: None,
-                            year: None,
-                            genre: None,
-                            duration: None,
                             format: "flac".to_string(),
                             path: PathBuf::from("/album2/track1.flac"),
+                            ..Default::default()
                         },
                     }],
                     files: {
@@ -332,7 +291,6 @@ mod tests {
                 MetadataSource::Embedded,
                 1.0,
             )),
-            album_artist: None,
             track_number: Some(create_test_metadata_value(7, MetadataSource::Embedded, 1.0)),
             disc_number: Some(create_test_metadata_value(1, MetadataSource::Embedded, 1.0)),
             year: Some(create_test_metadata_value(
@@ -352,6 +310,7 @@ mod tests {
             )),
             format: "flac".to_string(),
             path: PathBuf::from("/serial/track.flac"),
+            ..Default::default()
         };
 
         let track = Track {
@@ -427,47 +386,92 @@ mod tests {
 
         let metadata1 = TrackMetadata {
             title: Some(mv1.clone()),
-            artist: None,
-            album: None,
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
-            year: None,
-            genre: None,
-            duration: None,
             format: "flac".to_string(),
             path: PathBuf::from("/test.flac"),
+            ..Default::default()
         };
 
         let metadata2 = TrackMetadata {
             title: Some(mv2),
-            artist: None,
-            album: None,
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
-            year: None,
-            genre: None,
-            duration: None,
             format: "flac".to_string(),
             path: PathBuf::from("/test.flac"),
+            ..Default::default()
         };
 
         let metadata3 = TrackMetadata {
             title: Some(mv3),
-            artist: None,
-            album: None,
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
-            year: None,
-            genre: None,
-            duration: None,
             format: "flac".to_string(),
             path: PathBuf::from("/test.flac"),
+            ..Default::default()
         };
 
         assert_eq!(metadata1, metadata2);
         assert_ne!(metadata1, metadata3);
     }
+
+    #[test]
+    fn test_track_is_lossless_by_format() {
+        for format in ["flac", "wav", "wv", "dsf", "ape", "aiff", "FLAC"] {
+            let track = Track::builder("/missing/a").format(format).build();
+            assert!(track.is_lossless(), "{format} should be lossless");
+        }
+        for format in ["mp3", "aac", "m4a", "ogg", "opus", "unknown"] {
+            let track = Track::builder("/missing/a").format(format).build();
+            assert!(!track.is_lossless(), "{format} should be lossy");
+        }
+    }
+
+    #[test]
+    fn test_track_is_lossless_detects_wavpack_hybrid() {
+        let hybrid = Track::builder("/missing/hybrid.wv")
+            .format("wv")
+            .lossy(true)
+            .build();
+        assert!(!hybrid.is_lossless());
+
+        let corrected = Track::builder("/missing/corrected.wv")
+            .format("wv")
+            .lossy(false)
+            .build();
+        assert!(corrected.is_lossless());
+
+        let unknown = Track::builder("/missing/plain.wv").format("wv").build();
+        assert!(unknown.is_lossless());
+    }
+
+    #[test]
+    fn test_split_artist_credit_on_common_separators() {
+        use music_chore::core::domain::models::split_artist_credit;
+
+        assert_eq!(
+            split_artist_credit("Main feat. Guest; Other / Third"),
+            vec!["Main", "Guest", "Other", "Third"]
+        );
+        assert_eq!(split_artist_credit("A FT. B & C"), vec!["A", "B", "C"]);
+        assert_eq!(
+            split_artist_credit("Beethoven, Ludwig van"),
+            vec!["Beethoven, Ludwig van"]
+        );
+        assert!(split_artist_credit("  ").is_empty());
+    }
+
+    #[test]
+    fn test_artist_names_prefers_artists_tag_over_splitting() {
+        let split = Track::builder("/missing/a.flac")
+            .artist("Simon & Garfunkel")
+            .build();
+        assert_eq!(split.metadata.artist_names(), vec!["Simon", "Garfunkel"]);
+
+        let tagged = Track::builder("/missing/b.flac")
+            .artist("Earth, Wind & Fire feat. Guest")
+            .artists(["Earth, Wind & Fire", "Guest"])
+            .build();
+        assert_eq!(
+            tagged.metadata.artist_names(),
+            vec!["Earth, Wind & Fire", "Guest"]
+        );
+
+        let untagged = Track::builder("/missing/c.flac").build();
+        assert!(untagged.metadata.artist_names().is_empty());
+    }
 }
@@ -1,4 +1,11 @@
-use music_chore::core::services::duplicates::find_duplicates;
+use music_chore::core::builders::TrackMetadataBuilder;
+use music_chore::core::services::duplicates::{
+    QualityTier, classify_quality, duration_bucket, find_duplicates, find_filename_collisions,
+    find_filename_collisions_by_path, find_near_duplicates, metadata_completeness,
+    rank_duplicate_group,
+};
+use music_chore::{MetadataSource, Track};
+use std::path::PathBuf;
 
 #[test]
 fn test_find_duplicates_no_duplicates_returns_ok_text() {
@@ -15,3 +22,162 @@ fn test_find_duplicates_no_duplicates_returns_ok_empty_json() {
     assert!(result.is_ok());
     assert_eq!(result.unwrap(), "[]");
 }
+
+fn dup_track(path: &str, format: &str, tagged: bool) -> Track {
+    let mut builder = TrackMetadataBuilder::new(path).format(format);
+    if tagged {
+        builder = builder
+            .title("Song", MetadataSource::Embedded, 1.0)
+            .artist("Artist", MetadataSource::Embedded, 1.0)
+            .year(2001, MetadataSource::Embedded, 1.0);
+    }
+    Track::new(PathBuf::from(path), builder.build())
+}
+
+#[test]
+fn test_classify_quality_by_format() {
+    assert_eq!(
+        classify_quality(&dup_track("a.flac", "flac", false)),
+        QualityTier::Lossless
+    );
+    assert_eq!(
+        classify_quality(&dup_track("a.wv", "wv", false)),
+        QualityTier::Lossless
+    );
+    assert_eq!(
+        classify_quality(&dup_track("a.ape", "ape", false)),
+        QualityTier::Lossless
+    );
+    assert_eq!(
+        classify_quality(&dup_track("a.mp3", "mp3", false)),
+        QualityTier::Lossy
+    );
+    assert_eq!(
+        classify_quality(&dup_track("a.xyz", "unknown", false)),
+        QualityTier::Unknown
+    );
+    assert!(QualityTier::Lossless > QualityTier::Lossy);
+}
+
+#[test]
+fn test_rank_duplicate_group_orders_by_quality_completeness_then_path() {
+    let group = rank_duplicate_group(vec![
+        dup_track("/lib/short.mp3", "mp3", true),
+        dup_track("/lib/a/very/long/path/song.flac", "flac", false),
+        dup_track("/lib/longer/song.flac", "flac", true),
+        dup_track("/lib/b/song.flac", "flac", true),
+    ]);
+
+    let order: Vec<_> = group
+        .tracks
+        .iter()
+        .map(|t| t.file_path.to_string_lossy().into_owned())
+        .collect();
+    assert_eq!(
+        order,
+        vec![
+            "/lib/b/song.flac",
+            "/lib/longer/song.flac",
+            "/lib/a/very/long/path/song.flac",
+            "/lib/short.mp3",
+        ]
+    );
+    assert_eq!(group.recommended_keep, 0);
+    assert_eq!(metadata_completeness(&group.tracks[0].metadata), 3);
+}
+
+#[test]
+fn test_find_duplicates_json_includes_recommended_keep() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    std::fs::create_dir_all(temp_dir.path().join("copies/deeper")).unwrap();
+    let fixture = "tests/fixtures/flac/simple/track1.flac";
+    std::fs::copy(fixture, temp_dir.path().join("copies/deeper/track1.flac")).unwrap();
+    std::fs::copy(fixture, temp_dir.path().join("track1.flac")).unwrap();
+
+    let json = find_duplicates(temp_dir.path(), true, false, None).unwrap();
+    let groups: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(groups.as_array().unwrap().len(), 1);
+    assert_eq!(groups[0]["recommended_keep"], 0);
+    let keep = groups[0]["tracks"][0]["file_path"].as_str().unwrap();
+    assert!(
+        !keep.contains("deeper"),
+        "shortest path is kept first: {keep}"
+    );
+}
+
+#[test]
+fn test_duration_bucket_respects_tolerance() {
+    assert_eq!(duration_bucket(180.2, 2.0), duration_bucket(181.0, 2.0));
+    assert_ne!(duration_bucket(180.2, 0.5), duration_bucket(181.0, 0.5));
+    assert_eq!(duration_bucket(180.4, 0.0), 180);
+}
+
+fn rip(path: &str, format: &str, title: &str, duration: f64) -> Track {
+    let metadata = TrackMetadataBuilder::new(path)
+        .format(format)
+        .title(title, MetadataSource::Embedded, 1.0)
+        .artist("Artist", MetadataSource::Embedded, 1.0)
+        .duration(duration, MetadataSource::Embedded, 1.0)
+        .build();
+    Track::new(PathBuf::from(path), metadata)
+}
+
+#[test]
+fn test_find_near_duplicates_groups_rips_within_tolerance() {
+    let tracks = vec![
+        rip("/lib/mp3/song.mp3", "mp3", "Song", 180.2),
+        rip("/lib/flac/song.flac", "flac", "song ", 181.0),
+        rip("/lib/flac/other.flac", "flac", "Other", 181.0),
+        rip("/lib/flac/long.flac", "flac", "Song", 240.0),
+    ];
+
+    let groups = find_near_duplicates(&tracks, 2.0);
+    assert_eq!(groups.len(), 1);
+    let paths: Vec<_> = groups[0]
+        .tracks
+        .iter()
+        .map(|t| t.file_path.to_string_lossy().into_owned())
+        .collect();
+    assert_eq!(paths, vec!["/lib/flac/song.flac", "/lib/mp3/song.mp3"]);
+
+    assert!(find_near_duplicates(&tracks, 0.5).is_empty());
+}
+
+#[test]
+fn test_find_filename_collisions_groups_names_across_folders() {
+    let tracks = vec![
+        dup_track("A/Album/unknown.flac", "flac", false),
+        dup_track("B/Album/01 Intro.flac", "flac", false),
+        dup_track("C/Other/Unknown.FLAC", "flac", false),
+        dup_track("D/Disc/unknown.flac", "flac", false),
+        dup_track("E/Live/01 Intro.flac", "flac", false),
+        dup_track("F/Solo/02 Song.flac", "flac", false),
+        // CUE tracks of one image share a path and count once
+        dup_track("G/Image/image.flac", "flac", false),
+        dup_track("G/Image/image.flac", "flac", false),
+    ];
+
+    let collisions = find_filename_collisions(&tracks);
+
+    assert_eq!(collisions.len(), 2);
+    assert_eq!(collisions[0].file_name, "unknown.flac");
+    assert_eq!(
+        collisions[0].paths,
+        vec![
+            PathBuf::from("A/Album/unknown.flac"),
+            PathBuf::from("C/Other/Unknown.FLAC"),
+            PathBuf::from("D/Disc/unknown.flac"),
+        ]
+    );
+    assert_eq!(collisions[1].file_name, "01 Intro.flac");
+    assert_eq!(collisions[1].paths.len(), 2);
+}
+
+#[test]
+fn test_find_filename_collisions_by_path_reports_none_for_distinct_names() {
+    let result =
+        find_filename_collisions_by_path(std::path::Path::new("tests/fixtures/flac/simple"), false);
+
+    assert_eq!(result.unwrap(), "No file names shared across folders.");
+}
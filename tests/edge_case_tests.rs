@@ -128,16 +128,9 @@ mod tests {
                     source: MetadataSource::Embedded,
                     confidence: 1.0,
                 }),
-                artist: None,
-                album: None,
-                album_artist: None,
-                track_number: None,
-                disc_number: None,
-                year: None,
-                genre: None,
-                duration: None,
                 format: "flac".to_string(),
                 path: PathBuf::from("partial1.flac"),
+                ..Default::default()
             },
         });
 
@@ -146,21 +139,14 @@ mod tests {
             file_path: PathBuf::from("partial2.flac"),
             checksum: None,
             metadata: TrackMetadata {
-                title: None,
                 artist: Some(MetadataValue {
                     value: "Only Artist".to_string(),
                     source: MetadataSource::Embedded,
                     confidence: 1.0,
                 }),
-                album: None,
-                album_artist: None,
-                track_number: None,
-                disc_number: None,
-                year: None,
-                genre: None,
-                duration: None,
                 format: "flac".to_string(),
                 path: PathBuf::from("partial2.flac"),
+                ..Default::default()
             },
         });
 
@@ -285,14 +271,9 @@ mod tests {
                     source: MetadataSource::Embedded,
                     confidence: 1.0,
                 }),
-                album_artist: None,
-                track_number: None,
-                disc_number: None,
-                year: None,
-                genre: None,
-                duration: None,
                 format: "flac".to_string(),
                 path: PathBuf::from("embedded.flac"),
+                ..Default::default()
             },
         });
 
@@ -301,7 +282,6 @@ mod tests {
             file_path: PathBuf::from("FolderArtist/FolderAlbum/track.flac"),
             checksum: None,
             metadata: TrackMetadata {
-                title: None,
                 artist: Some(MetadataValue {
                     value: "FolderArtist".to_string(),
                     source: MetadataSource::FolderInferred,
@@ -312,14 +292,9 @@ mod tests {
                     source: MetadataSource::FolderInferred,
                     confidence: FOLDER_INFERRED_CONFIDENCE,
                 }),
-                album_artist: None,
-                track_number: None,
-                disc_number: None,
-                year: None,
-                genre: None,
-                duration: None,
                 format: "flac".to_string(),
                 path: PathBuf::from("FolderArtist/FolderAlbum/track.flac"),
+                ..Default::default()
             },
         });
 
@@ -1,6 +1,8 @@
 //! Integration tests for FLAC metadata read/write roundtrip
 //! Verifies that metadata written to a file can be read back correctly
 
+#![cfg(feature = "flac")]
+
 use music_chore::adapters::audio_formats::{read_metadata, write_metadata};
 use music_chore::core::domain::models::{MetadataValue, TrackMetadata};
 use std::fs;
@@ -19,8 +21,11 @@ fn create_full_metadata() -> TrackMetadata {
         year: Some(MetadataValue::user_set(2024)),
         genre: Some(MetadataValue::user_set("Test Genre".to_string())),
         duration: None, // Duration is read-only
+        media: Some(MetadataValue::user_set("Vinyl".to_string())),
+        barcode: Some(MetadataValue::user_set("0724384260927".to_string())),
         format: "flac".to_string(),
         path: PathBuf::from("test.flac"),
+        ..Default::default()
     }
 }
 
@@ -63,6 +68,11 @@ fn test_flac_metadata_roundtrip_all_fields() {
     assert_eq!(read_metadata.disc_number.as_ref().unwrap().value, 2);
     assert_eq!(read_metadata.year.as_ref().unwrap().value, 2024);
     assert_eq!(read_metadata.genre.as_ref().unwrap().value, "Test Genre");
+    assert_eq!(read_metadata.media.as_ref().unwrap().value, "Vinyl");
+    assert_eq!(
+        read_metadata.barcode.as_ref().unwrap().value,
+        "0724384260927"
+    );
 }
 
 #[test]
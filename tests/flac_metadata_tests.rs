@@ -1,3 +1,5 @@
+#![cfg(feature = "flac")]
+
 #[cfg(test)]
 mod flac_metadata_tests {
     use music_chore::adapters::audio_formats::read_metadata;
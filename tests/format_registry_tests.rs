@@ -1,27 +1,56 @@
 //! Tests for the audio format registry
 
 use music_chore::adapters::audio_formats::{
-    create_audio_registry, get_supported_extensions, is_format_supported, read_metadata,
-    write_metadata,
+    can_write_metadata, create_audio_registry, get_format_capabilities, get_supported_extensions,
+    get_supported_formats, get_supported_mime_types, is_format_supported, mime_for_path,
+    read_basic_info, read_duration, read_metadata, write_metadata,
 };
+use music_chore::core::domain::AudioFileError;
 use std::path::PathBuf;
 
+#[test]
+fn test_registry_only_reports_compiled_in_formats() {
+    let extensions = get_supported_extensions();
+    let formats = [
+        (cfg!(feature = "flac"), "flac"),
+        (cfg!(feature = "mp3"), "mp3"),
+        (cfg!(feature = "wav"), "wav"),
+        (cfg!(feature = "ogg"), "ogg"),
+        (cfg!(feature = "m4a"), "m4a"),
+        (cfg!(feature = "dsf"), "dsf"),
+        (cfg!(feature = "wavpack"), "wv"),
+        (cfg!(feature = "ape"), "ape"),
+    ];
+    for (compiled_in, ext) in formats {
+        assert_eq!(
+            extensions.contains(&ext.to_string()),
+            compiled_in,
+            "{}",
+            ext
+        );
+    }
+}
+
+#[cfg(feature = "default")]
 #[test]
 fn test_registry_supported_extensions() {
     let registry = create_audio_registry();
     let extensions = registry.supported_extensions();
 
-    // Should support FLAC, MP3, WAV, OGG, M4A, DSF, and WavPack
+    // Should support FLAC, MP3, WAV, OGG, Opus, M4A, DSF, WavPack and APE
     assert!(extensions.contains(&"flac".to_string()));
     assert!(extensions.contains(&"mp3".to_string()));
     assert!(extensions.contains(&"wav".to_string()));
     assert!(extensions.contains(&"ogg".to_string()));
+    assert!(extensions.contains(&"opus".to_string()));
     assert!(extensions.contains(&"m4a".to_string()));
     assert!(extensions.contains(&"dsf".to_string()));
     assert!(extensions.contains(&"wv".to_string()));
-    assert_eq!(extensions.len(), 7);
+    assert!(extensions.contains(&"ape".to_string()));
+    assert_eq!(extensions.len(), 9);
 }
 
+#[cfg(feature = "default")]
 #[test]
 fn test_is_format_supported() {
     // Supported formats
@@ -33,12 +62,14 @@ fn test_is_format_supported() {
     assert!(is_format_supported(&PathBuf::from("test.WAV")));
     assert!(is_format_supported(&PathBuf::from("test.ogg")));
     assert!(is_format_supported(&PathBuf::from("test.OGG")));
+    assert!(is_format_supported(&PathBuf::from("test.opus")));
     assert!(is_format_supported(&PathBuf::from("test.m4a")));
     assert!(is_format_supported(&PathBuf::from("test.M4A")));
     assert!(is_format_supported(&PathBuf::from("test.dsf")));
     assert!(is_format_supported(&PathBuf::from("test.DSF")));
     assert!(is_format_supported(&PathBuf::from("test.wv")));
     assert!(is_format_supported(&PathBuf::from("test.WV")));
+    assert!(is_format_supported(&PathBuf::from("test.ape")));
 
     // Unsupported formats
     assert!(!is_format_supported(&PathBuf::from("test.aiff")));
@@ -46,6 +77,7 @@ fn test_is_format_supported() {
     assert!(!is_format_supported(&PathBuf::from("test")));
 }
 
+#[cfg(feature = "default")]
 #[test]
 fn test_get_supported_extensions() {
     let extensions = get_supported_extensions();
@@ -54,10 +86,12 @@ fn test_get_supported_extensions() {
     assert!(extensions.contains(&"mp3".to_string()));
     assert!(extensions.contains(&"wav".to_string()));
     assert!(extensions.contains(&"ogg".to_string()));
+    assert!(extensions.contains(&"opus".to_string()));
     assert!(extensions.contains(&"m4a".to_string()));
     assert!(extensions.contains(&"dsf".to_string()));
     assert!(extensions.contains(&"wv".to_string()));
-    assert_eq!(extensions.len(), 7);
+    assert!(extensions.contains(&"ape".to_string()));
+    assert_eq!(extensions.len(), 9);
 }
 
 #[test]
@@ -74,23 +108,65 @@ fn test_read_metadata_nonexistent_file() {
     assert!(result.is_err());
 }
 
+#[test]
+fn test_read_duration_matches_full_read() {
+    let path = PathBuf::from("tests/fixtures/flac/simple/track1.flac");
+    let full = read_metadata(&path)
+        .unwrap()
+        .metadata
+        .duration
+        .unwrap()
+        .value;
+    let duration = read_duration(&path).unwrap().unwrap();
+
+    assert!((duration - full).abs() < 0.001);
+    assert!((duration - 1.0).abs() < 0.001);
+}
+
+#[test]
+fn test_read_basic_info_uses_duration_fast_path() {
+    let path = PathBuf::from("tests/fixtures/flac/simple/track1.flac");
+    let info = read_basic_info(&path).unwrap();
+
+    assert_eq!(info.format, "flac");
+    assert_eq!(
+        info.duration.map(|d| d.value),
+        read_duration(&path).unwrap()
+    );
+}
+
+#[test]
+fn test_read_duration_errors_name_the_file() {
+    let path = PathBuf::from("test.aiff");
+    let err = read_duration(&path).unwrap_err();
+
+    assert_eq!(err.path(), Some(path.as_path()));
+    assert!(read_duration(&PathBuf::from("nonexistent.flac")).is_err());
+}
+
+#[test]
+fn test_read_metadata_errors_name_the_file() {
+    let path = PathBuf::from("test.aiff");
+    let err = read_metadata(&path).unwrap_err();
+
+    assert_eq!(err.path(), Some(path.as_path()));
+    assert!(matches!(err.kind(), AudioFileError::UnsupportedFormat));
+    assert_eq!(err.to_string(), "test.aiff: Unsupported file format");
+    assert_eq!(
+        err.clone().with_path(&PathBuf::from("other")).path(),
+        err.path()
+    );
+}
+
 #[test]
 fn test_write_metadata_unsupported_format() {
     use music_chore::core::domain::models::TrackMetadata;
 
     let path = PathBuf::from("test.aiff");
     let metadata = TrackMetadata {
-        title: None,
-        artist: None,
-        album: None,
-        album_artist: None,
-        track_number: None,
-        disc_number: None,
-        year: None,
-        genre: None,
-        duration: None,
         format: "aiff".to_string(),
         path: path.clone(),
+        ..Default::default()
     };
     let result = write_metadata(&path, &metadata);
     assert!(result.is_err());
@@ -123,16 +199,9 @@ fn test_write_metadata_success_for_flac() {
 
     let metadata = TrackMetadata {
         title: Some(MetadataValue::embedded("Test Title".to_string())),
-        artist: None,
-        album: None,
-        album_artist: None,
-        track_number: None,
-        disc_number: None,
-        year: None,
-        genre: None,
-        duration: None,
         format: "flac".to_string(),
         path: flac_file.clone(),
+        ..Default::default()
     };
 
     let result = write_metadata(&flac_file, &metadata);
@@ -141,3 +210,101 @@ fn test_write_metadata_success_for_flac() {
     assert!(result.is_err());
     assert!(format!("{:?}", result).contains("InvalidFile"));
 }
+
+#[cfg(feature = "default")]
+#[test]
+fn test_mime_for_path() {
+    let cases = [
+        ("test.flac", "audio/flac"),
+        ("test.MP3", "audio/mpeg"),
+        ("test.wav", "audio/wav"),
+        ("test.ogg", "audio/ogg"),
+        ("test.m4a", "audio/mp4"),
+        ("test.dsf", "audio/x-dsf"),
+        ("test.wv", "audio/x-wavpack"),
+        ("test.ape", "audio/x-ape"),
+    ];
+    for (file, mime) in cases {
+        assert_eq!(mime_for_path(&PathBuf::from(file)), Some(mime), "{}", file);
+    }
+
+    assert_eq!(mime_for_path(&PathBuf::from("test.txt")), None);
+    assert_eq!(mime_for_path(&PathBuf::from("no_extension")), None);
+}
+
+#[cfg(feature = "default")]
+#[test]
+fn test_registry_supported_mime_types() {
+    let registry = create_audio_registry();
+    let mime_types = registry.supported_mime_types();
+
+    assert_eq!(mime_types.len(), 8);
+    assert!(mime_types.windows(2).all(|w| w[0] < w[1]));
+    assert_eq!(get_supported_mime_types(), mime_types);
+    assert!(mime_types.contains(&"audio/flac"));
+    assert!(mime_types.contains(&"audio/mpeg"));
+}
+
+#[test]
+fn test_supported_formats_match_registered_handlers() {
+    let formats = get_supported_formats();
+
+    let extensions: Vec<String> = formats.iter().map(|(ext, _)| ext.clone()).collect();
+    assert_eq!(extensions, get_supported_extensions());
+    assert!(formats.contains(&("flac".to_string(), "audio/flac")));
+    assert!(formats.contains(&("wv".to_string(), "audio/x-wavpack")));
+    for (ext, mime) in &formats {
+        let path = PathBuf::from(format!("test.{}", ext));
+        assert_eq!(mime_for_path(&path), Some(*mime));
+    }
+}
+
+#[test]
+fn test_format_capabilities_report_read_only_dsf() {
+    let capabilities = get_format_capabilities();
+
+    let extensions: Vec<String> = capabilities.iter().map(|c| c.extension.clone()).collect();
+    assert_eq!(extensions, get_supported_extensions());
+    assert!(capabilities.iter().all(|c| c.can_read));
+
+    let flac = capabilities.iter().find(|c| c.extension == "flac").unwrap();
+    assert!(flac.can_write);
+    assert!(can_write_metadata(&PathBuf::from("test.flac")));
+
+    #[cfg(feature = "dsf")]
+    {
+        let dsf = capabilities.iter().find(|c| c.extension == "dsf").unwrap();
+        assert!(!dsf.can_write);
+        assert!(!can_write_metadata(&PathBuf::from("test.dsf")));
+    }
+    assert!(!can_write_metadata(&PathBuf::from("test.xyz")));
+}
+
+#[cfg(feature = "dsf")]
+#[test]
+fn test_write_metadata_to_dsf_is_unsupported() {
+    use music_chore::core::domain::models::TrackMetadata;
+
+    let path = PathBuf::from("tests/fixtures/dsf/missing.dsf");
+    let metadata = TrackMetadata {
+        format: "dsf".to_string(),
+        path: path.clone(),
+        ..Default::default()
+    };
+
+    let err = write_metadata(&path, &metadata).unwrap_err();
+    assert!(matches!(err.kind(), AudioFileError::WriteUnsupported(_)));
+    assert_eq!(err.path(), Some(path.as_path()));
+    assert_eq!(
+        err.to_string(),
+        format!("{}: Writing DSF metadata is not supported", path.display())
+    );
+}
+
+#[test]
+fn test_read_metadata_reports_stream_format_for_flac() {
+    let track = read_metadata(&PathBuf::from("tests/fixtures/flac/simple/track1.flac")).unwrap();
+
+    assert!(track.metadata.sample_rate.is_some_and(|r| r.value > 0));
+    assert!(track.metadata.bit_depth.is_some_and(|b| b.value > 0));
+}
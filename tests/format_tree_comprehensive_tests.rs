@@ -80,14 +80,12 @@ fn test_format_library_output_basic() {
             title: Some(MetadataValue::embedded("Test Track".to_string())),
             artist: Some(MetadataValue::embedded("Test Artist".to_string())),
             album: Some(MetadataValue::embedded("Test Album".to_string())),
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
             year: Some(MetadataValue::embedded(2023)),
             genre: Some(MetadataValue::embedded("Test Genre".to_string())),
             duration: Some(MetadataValue::embedded(180.5)),
             format: "flac".to_string(),
             path: PathBuf::from("test/artist/album/track.flac"),
+            ..Default::default()
         },
     };
 
@@ -131,14 +129,12 @@ fn test_format_library_output_multiple_artists() {
             title: Some(MetadataValue::embedded("Track 1".to_string())),
             artist: Some(MetadataValue::embedded("Artist 1".to_string())),
             album: Some(MetadataValue::embedded("Album 1".to_string())),
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
             year: Some(MetadataValue::embedded(2022)),
             genre: Some(MetadataValue::embedded("Genre 1".to_string())),
             duration: Some(MetadataValue::embedded(200.0)),
             format: "flac".to_string(),
             path: PathBuf::from("artist1/album1/track1.flac"),
+            ..Default::default()
         },
     };
 
@@ -164,14 +160,12 @@ fn test_format_library_output_multiple_artists() {
             title: Some(MetadataValue::embedded("Track 2".to_string())),
             artist: Some(MetadataValue::embedded("Artist 2".to_string())),
             album: Some(MetadataValue::embedded("Album 2".to_string())),
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
             year: Some(MetadataValue::embedded(2023)),
             genre: Some(MetadataValue::embedded("Genre 2".to_string())),
             duration: Some(MetadataValue::embedded(220.0)),
             format: "flac".to_string(),
             path: PathBuf::from("artist2/album2/track2.flac"),
+            ..Default::default()
         },
     };
 
@@ -225,14 +219,12 @@ fn test_emit_structured_output_with_data() {
             title: Some(MetadataValue::embedded("Test Song".to_string())),
             artist: Some(MetadataValue::embedded("Test Artist".to_string())),
             album: Some(MetadataValue::embedded("Test Album".to_string())),
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
             year: Some(MetadataValue::embedded(2023)),
             genre: Some(MetadataValue::embedded("Test Genre".to_string())),
             duration: Some(MetadataValue::embedded(180.5)),
             format: "flac".to_string(),
             path: PathBuf::from("test/artist/album/track.flac"),
+            ..Default::default()
         },
     };
 
@@ -501,14 +493,12 @@ fn test_format_library_output_with_years() {
             title: Some(MetadataValue::embedded("Test Song".to_string())),
             artist: Some(MetadataValue::embedded("Test Artist".to_string())),
             album: Some(MetadataValue::embedded("Test Album".to_string())),
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
             year: Some(MetadataValue::embedded(2023)),
             genre: Some(MetadataValue::embedded("Test Genre".to_string())),
             duration: Some(MetadataValue::embedded(180.5)),
             format: "flac".to_string(),
             path: PathBuf::from("test/artist/album/track.flac"),
+            ..Default::default()
         },
     };
 
@@ -546,14 +536,12 @@ fn test_format_library_output_with_multiple_albums_same_artist() {
             title: Some(MetadataValue::embedded("Track 1".to_string())),
             artist: Some(MetadataValue::embedded("Test Artist".to_string())),
             album: Some(MetadataValue::embedded("Album 1".to_string())),
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
             year: Some(MetadataValue::embedded(2022)),
             genre: Some(MetadataValue::embedded("Test Genre".to_string())),
             duration: Some(MetadataValue::embedded(180.5)),
             format: "flac".to_string(),
             path: PathBuf::from("artist/album1/track1.flac"),
+            ..Default::default()
         },
     };
 
@@ -574,14 +562,12 @@ fn test_format_library_output_with_multiple_albums_same_artist() {
             title: Some(MetadataValue::embedded("Track 2".to_string())),
             artist: Some(MetadataValue::embedded("Test Artist".to_string())),
             album: Some(MetadataValue::embedded("Album 2".to_string())),
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
             year: Some(MetadataValue::embedded(2023)),
             genre: Some(MetadataValue::embedded("Test Genre".to_string())),
             duration: Some(MetadataValue::embedded(220.0)),
             format: "flac".to_string(),
             path: PathBuf::from("artist/album2/track2.flac"),
+            ..Default::default()
         },
     };
 
@@ -1,11 +1,15 @@
 //! Comprehensive tests for the format tree module functionality.
 
+use music_chore::core::builders::TrackBuilder;
 use music_chore::core::domain::models::{
     AlbumNode, ArtistNode, Library, MetadataValue, TrackMetadata, TrackNode,
 };
 use music_chore::core::services::format_tree::{
-    emit_by_path, emit_structured_output, format_library_output, format_tree_output,
+    TreeDepth, TreeGroupBy, emit_by_path, emit_structured_output, format_library_output,
+    format_library_output_with_depth, format_tree_output, format_year_hierarchy, parse_tree_depth,
+    parse_tree_group_by,
 };
+use music_chore::core::services::library::{build_library_hierarchy, build_year_hierarchy};
 use std::fs;
 use std::path::PathBuf;
 use tempfile::TempDir;
@@ -79,14 +83,12 @@ fn test_format_library_output_basic() {
             title: Some(MetadataValue::embedded("Test Track".to_string())),
             artist: Some(MetadataValue::embedded("Test Artist".to_string())),
             album: Some(MetadataValue::embedded("Test Album".to_string())),
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
             year: Some(MetadataValue::embedded(2023)),
             genre: Some(MetadataValue::embedded("Test Genre".to_string())),
             duration: Some(MetadataValue::embedded(180.5)),
             format: "flac".to_string(),
             path: PathBuf::from("test/artist/album/track.flac"),
+            ..Default::default()
         },
     };
 
@@ -128,14 +130,12 @@ fn test_format_library_output_multiple_artists() {
             title: Some(MetadataValue::embedded("Track 1".to_string())),
             artist: Some(MetadataValue::embedded("Artist 1".to_string())),
             album: Some(MetadataValue::embedded("Album 1".to_string())),
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
             year: Some(MetadataValue::embedded(2022)),
             genre: Some(MetadataValue::embedded("Genre 1".to_string())),
             duration: Some(MetadataValue::embedded(200.0)),
             format: "flac".to_string(),
             path: PathBuf::from("artist1/album1/track1.flac"),
+            ..Default::default()
         },
     };
 
@@ -161,14 +161,12 @@ fn test_format_library_output_multiple_artists() {
             title: Some(MetadataValue::embedded("Track 2".to_string())),
             artist: Some(MetadataValue::embedded("Artist 2".to_string())),
             album: Some(MetadataValue::embedded("Album 2".to_string())),
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
             year: Some(MetadataValue::embedded(2023)),
             genre: Some(MetadataValue::embedded("Genre 2".to_string())),
             duration: Some(MetadataValue::embedded(220.0)),
             format: "flac".to_string(),
             path: PathBuf::from("artist2/album2/track2.flac"),
+            ..Default::default()
         },
     };
 
@@ -222,14 +220,12 @@ fn test_emit_structured_output_with_data() {
             title: Some(MetadataValue::embedded("Test Apply Behavior".to_string())),
             artist: Some(MetadataValue::embedded("Test Artist".to_string())),
             album: Some(MetadataValue::embedded("Test Album".to_string())),
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
             year: Some(MetadataValue::embedded(2023)),
             genre: Some(MetadataValue::embedded("Test Genre".to_string())),
             duration: Some(MetadataValue::embedded(180.5)),
             format: "flac".to_string(),
             path: PathBuf::from("test/artist/album/track.flac"),
+            ..Default::default()
         },
     };
 
@@ -416,3 +412,73 @@ fn test_format_tree_output_with_multiple_files() {
     assert!(output.contains("🎵")); // File indicators
     assert!(output.contains("📊 Library Summary:"));
 }
+
+#[test]
+fn test_format_library_output_with_depth_stops_early() {
+    let track = |path: &str, album: &str| {
+        TrackBuilder::new(path)
+            .artist("Band")
+            .album(album)
+            .title("Song")
+            .build()
+    };
+    let library = build_library_hierarchy(vec![
+        track("Band/One/01.flac", "One"),
+        track("Band/One/02.flac", "One"),
+        track("Band/Two/01.flac", "Two"),
+    ]);
+
+    let artists = format_library_output_with_depth(&library, TreeDepth::Artist);
+    assert!(artists.contains("📁 Band (2 albums, 3 tracks)"));
+    assert!(!artists.contains("📂"));
+    assert!(artists.contains("Tracks: 3"));
+
+    let albums = format_library_output_with_depth(&library, TreeDepth::Album);
+    assert!(albums.contains("📂 One (2 tracks)"));
+    assert!(albums.contains("📂 Two (1 track)"));
+    assert!(!albums.contains("🎵"));
+
+    assert_eq!(
+        format_library_output_with_depth(&library, TreeDepth::Track),
+        format_library_output(&library)
+    );
+}
+
+#[test]
+fn test_format_year_hierarchy() {
+    let track = |path: &str, year: Option<u32>| {
+        let builder = TrackBuilder::new(path)
+            .artist("Band")
+            .album("One")
+            .title("Song");
+        match year {
+            Some(year) => builder.year(year).build(),
+            None => builder.build(),
+        }
+    };
+    let years = build_year_hierarchy(vec![
+        track("Band/One/01.flac", Some(1994)),
+        track("Band/One/02.flac", None),
+    ]);
+
+    let output = format_year_hierarchy(&years, TreeDepth::Album);
+    assert!(output.contains("📅 1994 (1 track)\n    📁 Band\n    ├── 📂 One (1994) (1 track)"));
+    assert!(output.contains("📅 Unknown Year (1 track)"));
+    assert!(output.find("1994").unwrap() < output.find("Unknown Year").unwrap());
+    assert!(output.contains("Years: 1\n   Tracks: 2"));
+}
+
+#[test]
+fn test_parse_tree_group_by() {
+    assert_eq!(parse_tree_group_by("Year"), Ok(TreeGroupBy::Year));
+    assert_eq!(parse_tree_group_by("artist"), Ok(TreeGroupBy::Artist));
+    assert!(parse_tree_group_by("decade").is_err());
+}
+
+#[test]
+fn test_parse_tree_depth() {
+    assert_eq!(parse_tree_depth("Artist"), Ok(TreeDepth::Artist));
+    assert_eq!(parse_tree_depth("album"), Ok(TreeDepth::Album));
+    assert_eq!(parse_tree_depth("track"), Ok(TreeDepth::Track));
+    assert!(parse_tree_depth("disc").is_err());
+}
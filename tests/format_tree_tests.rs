@@ -4,8 +4,10 @@ use music_chore::core::domain::models::{
     AlbumNode, ArtistNode, Library, MetadataValue, TrackMetadata, TrackNode,
 };
 use music_chore::core::services::format_tree::{
-    emit_by_path, format_library_output, format_tree_output,
+    emit_artist_summaries_by_path, emit_by_path, emit_by_path_with_options, format_library_output,
+    format_tree_output,
 };
+use music_chore::core::services::library::HierarchyOptions;
 use std::fs;
 use std::path::PathBuf;
 use tempfile::TempDir;
@@ -78,14 +80,12 @@ fn test_format_library_output_basic() {
             title: Some(MetadataValue::embedded("Test Track".to_string())),
             artist: Some(MetadataValue::embedded("Test Artist".to_string())),
             album: Some(MetadataValue::embedded("Test Album".to_string())),
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
             year: Some(MetadataValue::embedded(2023)),
             genre: Some(MetadataValue::embedded("Test Genre".to_string())),
             duration: Some(MetadataValue::embedded(180.5)),
             format: "flac".to_string(),
             path: PathBuf::from("test/artist/album/track.flac"),
+            ..Default::default()
         },
     };
 
@@ -129,14 +129,12 @@ fn test_format_library_output_multiple_artists() {
             title: Some(MetadataValue::embedded("Track 1".to_string())),
             artist: Some(MetadataValue::embedded("Artist 1".to_string())),
             album: Some(MetadataValue::embedded("Album 1".to_string())),
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
             year: Some(MetadataValue::embedded(2022)),
             genre: Some(MetadataValue::embedded("Genre 1".to_string())),
             duration: Some(MetadataValue::embedded(200.0)),
             format: "flac".to_string(),
             path: PathBuf::from("artist1/album1/track1.flac"),
+            ..Default::default()
         },
     };
 
@@ -162,14 +160,12 @@ fn test_format_library_output_multiple_artists() {
             title: Some(MetadataValue::embedded("Track 2".to_string())),
             artist: Some(MetadataValue::embedded("Artist 2".to_string())),
             album: Some(MetadataValue::embedded("Album 2".to_string())),
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
             year: Some(MetadataValue::embedded(2023)),
             genre: Some(MetadataValue::embedded("Genre 2".to_string())),
             duration: Some(MetadataValue::embedded(220.0)),
             format: "flac".to_string(),
             path: PathBuf::from("artist2/album2/track2.flac"),
+            ..Default::default()
         },
     };
 
@@ -229,6 +225,56 @@ fn test_emit_by_path_json_output() {
     assert!(json_value.get("__schema_version").is_some());
 }
 
+#[test]
+fn test_emit_by_path_compact_json_output() {
+    let temp_dir = TempDir::new().unwrap();
+    let album_dir = temp_dir.path().join("Test Artist").join("Test Album");
+    fs::create_dir_all(&album_dir).unwrap();
+    fs::copy(
+        "tests/fixtures/flac/simple/track1.flac",
+        album_dir.join("track1.flac"),
+    )
+    .unwrap();
+
+    let options = HierarchyOptions::default();
+    let pretty = emit_by_path_with_options(temp_dir.path(), true, false, &options).unwrap();
+    let compact = emit_by_path_with_options(temp_dir.path(), true, true, &options).unwrap();
+
+    assert!(!compact.contains('\n'));
+    assert!(compact.len() < pretty.len());
+    let pretty_value: serde_json::Value = serde_json::from_str(&pretty).unwrap();
+    let compact_value: serde_json::Value = serde_json::from_str(&compact).unwrap();
+    assert_eq!(pretty_value, compact_value);
+}
+
+#[test]
+fn test_emit_artist_summaries_by_path() {
+    let temp_dir = TempDir::new().unwrap();
+    let album_dir = temp_dir.path().join("Test Artist").join("Test Album");
+    fs::create_dir_all(&album_dir).unwrap();
+    for name in ["track1.flac", "track2.flac"] {
+        fs::copy(
+            "tests/fixtures/flac/simple/track1.flac",
+            album_dir.join(name),
+        )
+        .unwrap();
+    }
+
+    let options = HierarchyOptions::default();
+    let json = emit_artist_summaries_by_path(temp_dir.path(), true, false, &options).unwrap();
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(value["__schema_version"], "1.0.0");
+    let artists = value["artists"].as_array().unwrap();
+    assert_eq!(artists.len(), 1);
+    assert_eq!(artists[0]["name"], "Test Artist");
+    assert_eq!(artists[0]["album_count"], 1);
+    assert_eq!(artists[0]["track_count"], 2);
+    assert!(artists[0].get("genres").is_some());
+
+    let text = emit_artist_summaries_by_path(temp_dir.path(), false, false, &options).unwrap();
+    assert!(text.contains("ARTIST: Test Artist | Albums: 1 | Tracks: 2"));
+}
+
 #[test]
 fn test_emit_by_path_text_output() {
     let temp_dir = TempDir::new().unwrap();
@@ -30,14 +30,9 @@ mod tests {
                     source: MetadataSource::Embedded,
                     confidence: 1.0,
                 }),
-                album_artist: None,
-                track_number: None,
-                disc_number: None,
-                year: None,
-                genre: None,
-                duration: None,
                 format: "flac".to_string(),
                 path: PathBuf::from(path),
+                ..Default::default()
             },
         }
     }
@@ -128,17 +123,9 @@ mod tests {
             file_path: PathBuf::from("UnknownArtist/UnknownAlbum/track1.flac"),
             checksum: None,
             metadata: TrackMetadata {
-                title: None,
-                artist: None,
-                album: None,
-                album_artist: None,
-                track_number: None,
-                disc_number: None,
-                year: None,
-                genre: None,
-                duration: None,
                 format: "flac".to_string(),
                 path: PathBuf::from("UnknownArtist/UnknownAlbum/track1.flac"),
+                ..Default::default()
             },
         }];
 
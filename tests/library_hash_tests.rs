@@ -0,0 +1,52 @@
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn library_hash(path: &Path) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .arg("library-hash")
+        .arg(path)
+        .output()
+        .expect("Failed to run library-hash");
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap().trim().to_string()
+}
+
+fn library() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    let album = temp_dir.path().join("Artist/Album");
+    std::fs::create_dir_all(&album).unwrap();
+    for name in ["01.flac", "02.flac"] {
+        std::fs::copy("tests/fixtures/flac/simple/track1.flac", album.join(name)).unwrap();
+    }
+    temp_dir
+}
+
+#[test]
+fn test_library_hash_is_stable_across_scans_and_locations() {
+    let first = library();
+    let hash = library_hash(first.path());
+
+    assert_eq!(hash.len(), 64);
+    assert!(hash.chars().all(|c| c.is_ascii_hexdigit()));
+    assert_eq!(library_hash(first.path()), hash);
+
+    let second = library();
+    assert_eq!(library_hash(second.path()), hash);
+}
+
+#[test]
+fn test_library_hash_changes_with_metadata() {
+    let temp_dir = library();
+    let before = library_hash(temp_dir.path());
+
+    let status = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .arg("write")
+        .arg(temp_dir.path().join("Artist/Album/02.flac"))
+        .args(["--set", "title=Another Title", "--apply"])
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    assert_ne!(library_hash(temp_dir.path()), before);
+}
@@ -18,14 +18,9 @@ fn create_test_track(
             title: title.map(|t| MetadataValue::embedded(t.to_string())),
             artist: artist.map(|a| MetadataValue::embedded(a.to_string())),
             album: album.map(|a| MetadataValue::embedded(a.to_string())),
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
-            year: None,
-            genre: None,
-            duration: None,
             format: "flac".to_string(),
             path: PathBuf::from(path),
+            ..Default::default()
         },
     )
 }
@@ -522,6 +517,7 @@ fn test_library_metadata_source_serialization() {
         duration: Some(MetadataValue::embedded(180.5)),
         format: "flac".to_string(),
         path: PathBuf::from("test/path/track.flac"),
+        ..Default::default()
     };
 
     let track = Track::new(PathBuf::from("test/path/track.flac"), track_metadata);
@@ -595,7 +591,6 @@ fn test_library_metadata_confidence_serialization() {
             "Medium Confidence Album".to_string(),
             0.7,
         )),
-        album_artist: None,
         track_number: Some(MetadataValue::embedded(1)),
         disc_number: Some(MetadataValue::embedded(1)),
         year: Some(MetadataValue::embedded(2023)),
@@ -603,6 +598,7 @@ fn test_library_metadata_confidence_serialization() {
         duration: Some(MetadataValue::embedded(180.5)),
         format: "flac".to_string(),
         path: PathBuf::from("test/confidence/track.flac"),
+        ..Default::default()
     };
 
     let track = Track::new(PathBuf::from("test/confidence/track.flac"), track_metadata);
@@ -661,7 +657,6 @@ fn test_library_with_checksum_serialization() {
             title: Some(MetadataValue::embedded("Checksum Test Track".to_string())),
             artist: Some(MetadataValue::embedded("Checksum Test Artist".to_string())),
             album: Some(MetadataValue::embedded("Checksum Test Album".to_string())),
-            album_artist: None,
             track_number: Some(MetadataValue::embedded(1)),
             disc_number: Some(MetadataValue::embedded(1)),
             year: Some(MetadataValue::embedded(2023)),
@@ -669,6 +664,7 @@ fn test_library_with_checksum_serialization() {
             duration: Some(MetadataValue::embedded(180.5)),
             format: "flac".to_string(),
             path: PathBuf::from("test/checksum/track.flac"),
+            ..Default::default()
         },
         "abcd1234efgh5678".to_string(),
     );
@@ -713,3 +709,31 @@ fn test_library_with_checksum_serialization() {
         PathBuf::from("test/checksum/track.flac")
     );
 }
+
+#[test]
+fn test_library_from_json_round_trip_with_schema_version() {
+    use music_chore::core::domain::{build_library_hierarchy, with_schema_version};
+
+    let library = build_library_hierarchy(vec![
+        create_test_track(Some("One"), Some("Artist"), Some("Album"), "/m/a/01.flac"),
+        create_test_track(Some("Two"), Some("Artist"), Some("Album"), "/m/a/02.flac"),
+    ]);
+
+    let enveloped = serde_json::to_string(&with_schema_version(&library)).unwrap();
+    assert_eq!(Library::from_json(&enveloped).unwrap(), library);
+
+    let bare = serde_json::to_string(&library).unwrap();
+    assert_eq!(Library::from_json(&bare).unwrap(), library);
+
+    assert_eq!(Library::from_json(&bare).unwrap().tracks().len(), 2);
+}
+
+#[test]
+fn test_library_from_json_rejects_schema_version_mismatch() {
+    let json = r#"{"__schema_version": "2.0.0", "artists": [], "total_tracks": 0,
+        "total_artists": 0, "total_albums": 0, "total_files": 0}"#;
+
+    let err = Library::from_json(json).unwrap_err();
+    assert!(err.contains("Unsupported schema version \"2.0.0\""));
+    assert!(Library::from_json("not json").is_err());
+}
@@ -3,9 +3,14 @@
 use music_chore::core::domain::models::{
     AlbumNode, ArtistNode, Library, MetadataSource, MetadataValue, Track, TrackMetadata,
 };
-use music_chore::core::services::library::build_library_hierarchy;
+use music_chore::core::services::library::{
+    HierarchyOptions, SOUNDTRACKS, VARIOUS_ARTISTS, YearSource, artist_summaries,
+    build_library_hierarchy, build_library_hierarchy_with_options, build_year_hierarchy,
+    common_ancestor, compute_library_stats, count_tracks_without_year, decade_histogram,
+    genre_histogram, is_soundtrack, library_content_hash, split_genres, tracks_by_genre,
+};
 use std::collections::HashSet;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 fn create_test_track(
     artist: Option<&str>,
@@ -19,14 +24,9 @@ fn create_test_track(
             title: title.map(|t| MetadataValue::embedded(t.to_string())),
             artist: artist.map(|a| MetadataValue::embedded(a.to_string())),
             album: album.map(|a| MetadataValue::embedded(a.to_string())),
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
-            year: None,
-            genre: None,
-            duration: None,
             format: "flac".to_string(),
             path: PathBuf::from(path),
+            ..Default::default()
         },
     )
 }
@@ -537,3 +537,588 @@ fn test_build_hierarchy_mixed_metadata() {
         .unwrap();
     assert_eq!(artist_b.albums[0].title, "Unknown Album");
 }
+
+fn compilation_tracks(artist_count: usize) -> Vec<Track> {
+    (1..=artist_count)
+        .map(|i| {
+            create_test_track(
+                Some(&format!("Artist {}", i)),
+                Some("Mixtape"),
+                Some(&format!("Song {}", i)),
+                &format!("/music/mixtape/track{}.flac", i),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_va_threshold_default_groups_at_three_artists() {
+    let library = build_library_hierarchy(compilation_tracks(3));
+
+    assert_eq!(library.total_artists, 1);
+    assert_eq!(library.artists[0].name, VARIOUS_ARTISTS);
+    assert_eq!(library.artists[0].albums.len(), 1);
+    assert_eq!(library.artists[0].albums[0].tracks.len(), 3);
+}
+
+#[test]
+fn test_va_threshold_default_below_threshold_keeps_artists() {
+    let library = build_library_hierarchy(compilation_tracks(2));
+
+    assert_eq!(library.total_artists, 2);
+    assert!(library.artists.iter().all(|a| a.name != VARIOUS_ARTISTS));
+}
+
+#[test]
+fn test_va_threshold_custom_boundary() {
+    let options = HierarchyOptions {
+        va_threshold: 4,
+        ..HierarchyOptions::default()
+    };
+
+    let below = build_library_hierarchy_with_options(compilation_tracks(3), &options);
+    assert_eq!(below.total_artists, 3);
+
+    let at = build_library_hierarchy_with_options(compilation_tracks(4), &options);
+    assert_eq!(at.total_artists, 1);
+    assert_eq!(at.artists[0].name, VARIOUS_ARTISTS);
+}
+
+#[test]
+fn test_va_threshold_zero_disables_artist_count_rule() {
+    let options = HierarchyOptions {
+        va_threshold: 0,
+        ..HierarchyOptions::default()
+    };
+
+    let library = build_library_hierarchy_with_options(compilation_tracks(5), &options);
+    assert_eq!(library.total_artists, 5);
+}
+
+#[test]
+fn test_va_album_artist_forces_compilation() {
+    let mut tracks = compilation_tracks(1);
+    tracks[0].metadata.album_artist = Some(MetadataValue::embedded("va".to_string()));
+
+    let library = build_library_hierarchy(tracks);
+    assert_eq!(library.artists[0].name, VARIOUS_ARTISTS);
+
+    let mut tracks = compilation_tracks(2);
+    for track in &mut tracks {
+        track.metadata.album_artist = Some(MetadataValue::embedded("Now Hits".to_string()));
+    }
+    let options = HierarchyOptions {
+        va_album_artists: vec!["Now Hits".to_string()],
+        ..HierarchyOptions::default()
+    };
+    let library = build_library_hierarchy_with_options(tracks, &options);
+    assert_eq!(library.total_artists, 1);
+    assert_eq!(library.artists[0].name, VARIOUS_ARTISTS);
+}
+
+#[test]
+fn test_compilation_flag_overrides_artist_count() {
+    // A single flagged track marks the album even below the threshold
+    let mut tracks = compilation_tracks(2);
+    tracks[1].metadata.compilation = Some(MetadataValue::embedded(true));
+    let library = build_library_hierarchy(tracks);
+    assert_eq!(library.total_artists, 1);
+    assert_eq!(library.artists[0].name, VARIOUS_ARTISTS);
+
+    // An explicitly cleared flag keeps the artists despite the heuristic
+    let mut tracks = compilation_tracks(4);
+    for track in &mut tracks {
+        track.metadata.compilation = Some(MetadataValue::embedded(false));
+    }
+    let library = build_library_hierarchy(tracks);
+    assert_eq!(library.total_artists, 4);
+}
+
+#[test]
+fn test_va_multi_disc_album_counts_artists_across_disc_folders() {
+    // Two artists per disc stay below the threshold; four across the album do not
+    let tracks = (1..=4)
+        .map(|i| {
+            create_test_track(
+                Some(&format!("Artist {}", i)),
+                Some("Mixtape"),
+                None,
+                &format!("/music/mixtape/CD{}/track{}.flac", (i + 1) / 2, i),
+            )
+        })
+        .collect();
+
+    let library = build_library_hierarchy(tracks);
+    assert_eq!(library.total_artists, 1);
+    assert_eq!(library.artists[0].name, VARIOUS_ARTISTS);
+    assert_eq!(
+        library.artists[0].albums[0].path,
+        PathBuf::from("/music/mixtape")
+    );
+}
+
+#[test]
+fn test_va_same_album_title_in_different_directories_not_grouped() {
+    let tracks = vec![
+        create_test_track(
+            Some("Artist 1"),
+            Some("Greatest Hits"),
+            None,
+            "/a1/gh/t.flac",
+        ),
+        create_test_track(
+            Some("Artist 2"),
+            Some("Greatest Hits"),
+            None,
+            "/a2/gh/t.flac",
+        ),
+        create_test_track(
+            Some("Artist 3"),
+            Some("Greatest Hits"),
+            None,
+            "/a3/gh/t.flac",
+        ),
+    ];
+
+    let library = build_library_hierarchy(tracks);
+    assert_eq!(library.total_artists, 3);
+}
+
+#[test]
+fn test_va_same_titled_compilations_stay_separate_albums() {
+    let mut tracks = Vec::new();
+    for (dir, year) in [
+        ("/music/Now 42 (1999)", 1999),
+        ("/music/Now 42 (2019)", 2019),
+    ] {
+        for i in 1..=3 {
+            let mut track = create_test_track(
+                Some(&format!("Artist {}", i)),
+                Some("Now 42"),
+                None,
+                &format!("{}/track{}.flac", dir, i),
+            );
+            track.metadata.year = Some(MetadataValue::embedded(year));
+            tracks.push(track);
+        }
+    }
+
+    let library = build_library_hierarchy(tracks);
+    assert_eq!(library.total_artists, 1);
+    assert_eq!(library.artists[0].name, VARIOUS_ARTISTS);
+
+    let mut albums: Vec<_> = library.artists[0]
+        .albums
+        .iter()
+        .map(|album| (album.path.clone(), album.year, album.tracks.len()))
+        .collect();
+    albums.sort();
+    assert_eq!(
+        albums,
+        vec![
+            (PathBuf::from("/music/Now 42 (1999)"), Some(1999), 3),
+            (PathBuf::from("/music/Now 42 (2019)"), Some(2019), 3),
+        ]
+    );
+}
+
+#[test]
+fn test_va_tracks_without_album_not_grouped() {
+    let tracks = vec![
+        create_test_track(Some("Artist 1"), None, None, "/loose/t1.flac"),
+        create_test_track(Some("Artist 2"), None, None, "/loose/t2.flac"),
+        create_test_track(Some("Artist 3"), None, None, "/loose/t3.flac"),
+    ];
+
+    let library = build_library_hierarchy(tracks);
+    assert_eq!(library.total_artists, 3);
+}
+
+fn summary_track(
+    artist: &str,
+    album: &str,
+    path: &str,
+    year: u32,
+    genre: &str,
+    duration: f64,
+) -> Track {
+    let mut track = create_test_track(Some(artist), Some(album), Some("Song"), path);
+    track.metadata.year = Some(MetadataValue::embedded(year));
+    track.metadata.genre = Some(MetadataValue::embedded(genre.to_string()));
+    track.metadata.duration = Some(MetadataValue::embedded(duration));
+    track
+}
+
+#[test]
+fn test_artist_summaries_aggregates_per_artist() {
+    let library = build_library_hierarchy(vec![
+        summary_track("Band", "First", "Band/First/01.flac", 1994, "Rock", 200.0),
+        summary_track("Band", "First", "Band/First/02.flac", 1994, "Rock", 100.5),
+        summary_track("Band", "Last", "Band/Last/01.flac", 2005, "Indie", 60.0),
+        summary_track("Solo", "Only", "Solo/Only/01.flac", 2010, "Jazz", 30.0),
+    ]);
+
+    let summaries = artist_summaries(&library);
+    assert_eq!(summaries.len(), 2);
+
+    let band = &summaries[0];
+    assert_eq!(band.name, "Band");
+    assert_eq!(band.album_count, 2);
+    assert_eq!(band.track_count, 3);
+    assert_eq!(band.total_duration, 360.5);
+    assert_eq!(band.first_year, Some(1994));
+    assert_eq!(band.last_year, Some(2005));
+    assert_eq!(band.genres, vec!["Indie".to_string(), "Rock".to_string()]);
+
+    let solo = &summaries[1];
+    assert_eq!(solo.name, "Solo");
+    assert_eq!(solo.track_count, 1);
+    assert_eq!(solo.first_year, Some(2010));
+    assert_eq!(solo.last_year, Some(2010));
+}
+
+#[test]
+fn test_decade_histogram_buckets_by_year() {
+    let library = build_library_hierarchy(vec![
+        summary_track("Band", "First", "Band/First/01.flac", 1970, "Rock", 1.0),
+        summary_track("Band", "First", "Band/First/02.flac", 1979, "Rock", 1.0),
+        summary_track("Band", "Last", "Band/Last/01.flac", 1980, "Rock", 1.0),
+        summary_track("Solo", "Only", "Solo/Only/01.flac", 2009, "Jazz", 1.0),
+        create_test_track(Some("Solo"), Some("Only"), Some("2"), "Solo/Only/02.flac"),
+    ]);
+
+    let histogram = decade_histogram(&library, YearSource::Release);
+    assert_eq!(
+        histogram.into_iter().collect::<Vec<_>>(),
+        vec![(1970, 2), (1980, 1), (2000, 1)]
+    );
+    assert_eq!(count_tracks_without_year(&library, YearSource::Release), 1);
+}
+
+#[test]
+fn test_split_genres() {
+    assert_eq!(
+        split_genres("Rock; Alternative"),
+        vec!["Rock", "Alternative"]
+    );
+    assert_eq!(split_genres("Hip-Hop/Rap"), vec!["Hip-Hop/Rap"]);
+    assert_eq!(split_genres("R&B/Soul; Funk"), vec!["R&B/Soul", "Funk"]);
+    assert_eq!(split_genres("Jazz\0Funk;; Jazz "), vec!["Jazz", "Funk"]);
+    assert!(split_genres(" ; ").is_empty());
+}
+
+#[test]
+fn test_tracks_by_genre_lists_multi_genre_tracks_under_each() {
+    let library = build_library_hierarchy(vec![
+        summary_track(
+            "Band",
+            "First",
+            "Band/First/01.flac",
+            1990,
+            "Rock; Alternative",
+            1.0,
+        ),
+        summary_track("Band", "First", "Band/First/02.flac", 1990, "Rock", 1.0),
+        create_test_track(Some("Band"), Some("First"), Some("3"), "Band/First/03.flac"),
+    ]);
+
+    let by_genre = tracks_by_genre(&library);
+    assert_eq!(
+        by_genre.keys().collect::<Vec<_>>(),
+        vec!["Alternative", "Rock"]
+    );
+    assert_eq!(
+        by_genre["Alternative"],
+        vec![PathBuf::from("Band/First/01.flac")]
+    );
+    assert_eq!(by_genre["Rock"].len(), 2);
+
+    let histogram = genre_histogram(&library);
+    assert_eq!(
+        histogram.into_iter().collect::<Vec<_>>(),
+        vec![("Alternative".to_string(), 1), ("Rock".to_string(), 2)]
+    );
+}
+
+#[test]
+fn test_decade_histogram_prefers_original_year() {
+    let mut reissue = summary_track("Band", "First", "Band/First/01.flac", 2015, "Rock", 1.0);
+    reissue.metadata.original_year = Some(MetadataValue::embedded(1972));
+    let library = build_library_hierarchy(vec![
+        reissue,
+        summary_track("Band", "Last", "Band/Last/01.flac", 1980, "Rock", 1.0),
+    ]);
+
+    assert_eq!(
+        decade_histogram(&library, YearSource::Release)
+            .into_iter()
+            .collect::<Vec<_>>(),
+        vec![(1980, 1), (2010, 1)]
+    );
+    assert_eq!(
+        decade_histogram(&library, YearSource::Original)
+            .into_iter()
+            .collect::<Vec<_>>(),
+        vec![(1970, 1), (1980, 1)]
+    );
+}
+
+#[test]
+fn test_compute_library_stats_ranks_and_truncates() {
+    let library = build_library_hierarchy(vec![
+        summary_track("Band", "First", "Band/First/01.flac", 1970, "Rock", 100.0),
+        summary_track("Band", "First", "Band/First/02.flac", 1971, "Rock", 100.0),
+        summary_track("Band", "Last", "Band/Last/01.flac", 1980, "Indie", 50.0),
+        summary_track("Solo", "Only", "Solo/Only/01.flac", 2009, "Jazz", 10.0),
+        create_test_track(Some("Alpha"), Some("One"), Some("1"), "Alpha/One/1.flac"),
+    ]);
+
+    let stats = compute_library_stats(&library, 2);
+    assert_eq!(stats.total_tracks, 5);
+    assert_eq!(stats.total_artists, 3);
+    assert_eq!(stats.total_albums, 4);
+    assert_eq!(stats.total_duration, 260.0);
+
+    let ranked = |entries: &[music_chore::core::services::library::RankedEntry]| {
+        entries
+            .iter()
+            .map(|e| (e.name.clone(), e.track_count))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        ranked(&stats.top_artists),
+        vec![("Band".to_string(), 3), ("Alpha".to_string(), 1)]
+    );
+    assert_eq!(
+        ranked(&stats.top_albums),
+        vec![
+            ("Band - First".to_string(), 2),
+            ("Alpha - One".to_string(), 1)
+        ]
+    );
+    assert_eq!(
+        ranked(&stats.top_genres),
+        vec![("Rock".to_string(), 2), ("Indie".to_string(), 1)]
+    );
+
+    assert!(compute_library_stats(&library, 0).top_artists.is_empty());
+}
+
+#[test]
+fn test_artist_summaries_sorted_by_track_count_then_name() {
+    let library = build_library_hierarchy(vec![
+        create_test_track(Some("Beta"), Some("A"), Some("1"), "Beta/A/1.flac"),
+        create_test_track(Some("Alpha"), Some("A"), Some("1"), "Alpha/A/1.flac"),
+        create_test_track(Some("Gamma"), Some("A"), Some("1"), "Gamma/A/1.flac"),
+        create_test_track(Some("Gamma"), Some("A"), Some("2"), "Gamma/A/2.flac"),
+    ]);
+
+    let names: Vec<_> = artist_summaries(&library)
+        .into_iter()
+        .map(|s| (s.name, s.total_duration, s.first_year, s.genres.len()))
+        .collect();
+    assert_eq!(
+        names,
+        vec![
+            ("Gamma".to_string(), 0.0, None, 0),
+            ("Alpha".to_string(), 0.0, None, 0),
+            ("Beta".to_string(), 0.0, None, 0),
+        ]
+    );
+}
+
+fn soundtrack_track(artist: &str, album: &str, genre: Option<&str>, path: &str) -> Track {
+    let mut track = create_test_track(Some(artist), Some(album), Some("Song"), path);
+    track.metadata.genre = genre.map(|g| MetadataValue::embedded(g.to_string()));
+    track
+}
+
+#[test]
+fn test_is_soundtrack_keywords() {
+    let album_of = |track: Track| build_library_hierarchy(vec![track]).artists[0].albums[0].clone();
+
+    assert!(is_soundtrack(&album_of(soundtrack_track(
+        "Hans Zimmer",
+        "Inception",
+        Some("Soundtrack"),
+        "/m/a/1.flac"
+    ))));
+    assert!(is_soundtrack(&album_of(soundtrack_track(
+        "Vangelis",
+        "Blade Runner (Original Motion Picture Soundtrack)",
+        None,
+        "/m/b/1.flac"
+    ))));
+    assert!(is_soundtrack(&album_of(soundtrack_track(
+        "Joe Hisaishi",
+        "Spirited Away OST",
+        None,
+        "/m/c/1.flac"
+    ))));
+    // "OST" must be a word of its own
+    assert!(!is_soundtrack(&album_of(soundtrack_track(
+        "Band",
+        "Ghost Stories",
+        Some("Rock"),
+        "/m/d/1.flac"
+    ))));
+}
+
+#[test]
+fn test_group_soundtracks_option() {
+    let tracks = vec![
+        soundtrack_track(
+            "Hans Zimmer",
+            "Inception",
+            Some("Soundtrack"),
+            "/m/hz/1.flac",
+        ),
+        soundtrack_track(
+            "Hans Zimmer",
+            "Solo Album",
+            Some("Ambient"),
+            "/m/hz2/1.flac",
+        ),
+        soundtrack_track("Vangelis", "Blade Runner OST", None, "/m/v/1.flac"),
+    ];
+
+    // Default off: soundtracks stay with their artists
+    let library = build_library_hierarchy(tracks.clone());
+    assert!(library.artists.iter().all(|a| a.name != SOUNDTRACKS));
+
+    let options = HierarchyOptions {
+        group_soundtracks: true,
+        ..HierarchyOptions::default()
+    };
+    let library = build_library_hierarchy_with_options(tracks, &options);
+    assert_eq!(library.total_artists, 2);
+    assert_eq!(library.total_albums, 3);
+    assert_eq!(library.total_tracks, 3);
+
+    let soundtracks = library
+        .artists
+        .iter()
+        .find(|a| a.name == SOUNDTRACKS)
+        .expect("Soundtracks node");
+    let mut titles: Vec<&str> = soundtracks
+        .albums
+        .iter()
+        .map(|a| a.title.as_str())
+        .collect();
+    titles.sort();
+    assert_eq!(titles, vec!["Blade Runner OST", "Inception"]);
+
+    let zimmer = library
+        .artists
+        .iter()
+        .find(|a| a.name == "Hans Zimmer")
+        .unwrap();
+    assert_eq!(zimmer.albums.len(), 1);
+    assert_eq!(zimmer.albums[0].title, "Solo Album");
+}
+
+#[test]
+fn test_common_ancestor() {
+    let paths = [
+        Path::new("/music/Artist/Album/CD1"),
+        Path::new("/music/Artist/Album/CD2"),
+        Path::new("/music/Artist/Album/CD2/Bonus"),
+    ];
+    assert_eq!(common_ancestor(paths), PathBuf::from("/music/Artist/Album"));
+    assert_eq!(
+        common_ancestor([Path::new("/music/Album")]),
+        PathBuf::from("/music/Album")
+    );
+    assert_eq!(
+        common_ancestor([Path::new("a/x"), Path::new("b/y")]),
+        PathBuf::new()
+    );
+    assert_eq!(common_ancestor(Vec::<&Path>::new()), PathBuf::new());
+}
+
+#[test]
+fn test_album_path_is_common_ancestor_of_sibling_subfolders() {
+    let tracks = vec![
+        create_test_track(
+            Some("Artist"),
+            Some("Double Album"),
+            Some("One"),
+            "/music/Artist/Double Album/CD1/01.flac",
+        ),
+        create_test_track(
+            Some("Artist"),
+            Some("Double Album"),
+            Some("Two"),
+            "/music/Artist/Double Album/CD2/01.flac",
+        ),
+    ];
+
+    let library = build_library_hierarchy(tracks);
+    assert_eq!(
+        library.artists[0].albums[0].path,
+        PathBuf::from("/music/Artist/Double Album")
+    );
+}
+
+#[test]
+fn test_album_path_falls_back_to_first_track_folder() {
+    let tracks = vec![
+        create_test_track(Some("Artist"), Some("Album"), Some("One"), "a/01.flac"),
+        create_test_track(Some("Artist"), Some("Album"), Some("Two"), "b/02.flac"),
+    ];
+
+    let library = build_library_hierarchy(tracks);
+    assert_eq!(library.artists[0].albums[0].path, PathBuf::from("a"));
+}
+
+#[test]
+fn test_library_content_hash_ignores_track_order() {
+    let tracks = vec![
+        create_test_track(Some("Artist A"), Some("Album"), Some("One"), "/m/a/1.flac"),
+        create_test_track(Some("Artist B"), Some("Album"), Some("Two"), "/m/b/2.flac"),
+        create_test_track(
+            Some("Artist A"),
+            Some("Album"),
+            Some("Three"),
+            "/m/a/3.flac",
+        ),
+    ];
+    let mut reversed = tracks.clone();
+    reversed.reverse();
+
+    let hash = library_content_hash(&build_library_hierarchy(tracks.clone()));
+    assert_eq!(hash.len(), 64);
+    assert_eq!(
+        hash,
+        library_content_hash(&build_library_hierarchy(reversed))
+    );
+
+    let mut retitled = tracks;
+    retitled[0].metadata.title = Some(MetadataValue::embedded("Uno".to_string()));
+    assert_ne!(
+        hash,
+        library_content_hash(&build_library_hierarchy(retitled))
+    );
+}
+
+#[test]
+fn test_build_year_hierarchy_orders_years_with_unknown_last() {
+    let with_year = |artist: &str, album: &str, path: &str, year: Option<u32>| {
+        let mut track = create_test_track(Some(artist), Some(album), Some("Song"), path);
+        track.metadata.year = year.map(MetadataValue::embedded);
+        track
+    };
+    let years = build_year_hierarchy(vec![
+        with_year("B", "Later", "/m/b/later/1.flac", Some(2001)),
+        with_year("A", "Undated", "/m/a/undated/1.flac", None),
+        with_year("B", "Early", "/m/b/early/1.flac", Some(1999)),
+        with_year("A", "Early Too", "/m/a/early/1.flac", Some(1999)),
+    ]);
+
+    let labels: Vec<String> = years.iter().map(|y| y.label()).collect();
+    assert_eq!(labels, ["1999", "2001", "Unknown Year"]);
+    let artists: Vec<&str> = years[0].artists.iter().map(|a| a.name.as_str()).collect();
+    assert_eq!(artists, ["A", "B"]);
+    assert_eq!(years[0].track_count(), 2);
+    assert_eq!(years[2].artists[0].albums[0].title, "Undated");
+}
@@ -1,5 +1,7 @@
 //! Integration tests for M4A format support.
 
+#![cfg(feature = "m4a")]
+
 use music_chore::adapters::audio_formats::{
     get_supported_extensions, is_format_supported, read_basic_info, read_metadata, write_metadata,
 };
@@ -115,14 +117,9 @@ fn test_m4a_write_metadata_invalid_content_routes_to_m4a_handler() {
         title: Some(MetadataValue::user_set("Title".to_string())),
         artist: Some(MetadataValue::user_set("Artist".to_string())),
         album: Some(MetadataValue::user_set("Album".to_string())),
-        album_artist: None,
-        track_number: None,
-        disc_number: None,
-        year: None,
-        genre: None,
-        duration: None,
         format: "m4a".to_string(),
         path: m4a_path.clone(),
+        ..Default::default()
     };
 
     let result = write_metadata(&m4a_path, &metadata);
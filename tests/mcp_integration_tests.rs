@@ -27,8 +27,13 @@ fn init_tracing() {
 }
 
 async fn spawn_client() -> Result<RunningService<RoleClient, ()>> {
+    spawn_client_with_env(&[]).await
+}
+
+async fn spawn_client_with_env(envs: &[(&str, &str)]) -> Result<RunningService<RoleClient, ()>> {
     init_tracing();
-    let cmd = Command::new(env!("CARGO_BIN_EXE_musicctl-mcp"));
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_musicctl-mcp"));
+    cmd.envs(envs.iter().copied());
     let child =
         TokioChildProcess::new(cmd).map_err(RmcpError::transport_creation::<TokioChildProcess>)?;
     let client = ().serve(child).await?;
@@ -94,7 +99,7 @@ async fn test_tools_list() -> Result<()> {
     let client = spawn_client().await?;
 
     let tools = client.list_all_tools().await?;
-    assert_eq!(tools.len(), 8); // Updated count
+    assert_eq!(tools.len(), 10); // Updated count
 
     let names: Vec<_> = tools.iter().map(|t| t.name.to_string()).collect();
     for expected in [
@@ -103,9 +108,11 @@ async fn test_tools_list() -> Result<()> {
         "read_file_metadata",
         "normalize", // Changed from normalize_titles
         "emit_library_metadata",
+        "get_library_facts",
         "validate_library",
         "find_duplicates",
         "cue_file",
+        "reorganize",
     ] {
         assert!(names.contains(&expected.to_string()));
     }
@@ -1093,3 +1100,124 @@ async fn test_mcp_cue_file_generate_exists_no_force() -> Result<()> {
 
     shutdown(client).await
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_get_library_facts() -> Result<()> {
+    let client = spawn_client().await?;
+
+    let result = call_tool(
+        &client,
+        "get_library_facts",
+        object!({
+            "path": "tests/fixtures/flac/simple"
+        }),
+    )
+    .await?;
+
+    assert_ok(&result);
+
+    let json: serde_json::Value = serde_json::from_str(text_content(&result))?;
+    assert_eq!(json["stats"]["total_tracks"], 2);
+    assert_eq!(json["quality_tiers"]["lossless"], 2);
+    assert!(json["artists"].is_array());
+
+    shutdown(client).await
+}
+
+fn reorganize_library() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::copy(
+        "tests/fixtures/flac/simple/track1.flac",
+        temp_dir.path().join("loose.flac"),
+    )
+    .unwrap();
+    temp_dir
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_reorganize_plans_without_moving() -> Result<()> {
+    let client = spawn_client().await?;
+    let temp_dir = reorganize_library();
+
+    let result = call_tool(
+        &client,
+        "reorganize",
+        object!({
+            "path": temp_dir.path().to_str().unwrap(),
+            "template": "{artist}/{album}/{track} - {title}.{ext}"
+        }),
+    )
+    .await?;
+
+    assert_ok(&result);
+    let json: serde_json::Value = serde_json::from_str(text_content(&result))?;
+    assert_eq!(json["applied"], false);
+    assert_eq!(json["moves"][0]["status"], "planned");
+    assert!(
+        json["moves"][0]["destination"]
+            .as_str()
+            .unwrap()
+            .ends_with("Test Album/01 - Test Apply Behavior.flac")
+    );
+    assert!(temp_dir.path().join("loose.flac").exists());
+
+    shutdown(client).await
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_reorganize_apply_requires_allow_writes() -> Result<()> {
+    let client = spawn_client().await?;
+    let temp_dir = reorganize_library();
+
+    let result = call_tool(
+        &client,
+        "reorganize",
+        object!({
+            "path": temp_dir.path().to_str().unwrap(),
+            "template": "{artist}/{album}/{track} - {title}.{ext}",
+            "apply": true
+        }),
+    )
+    .await?;
+
+    assert_err(&result);
+    assert!(text_content(&result).contains("MUSIC_ALLOW_WRITES"));
+    assert!(temp_dir.path().join("loose.flac").exists());
+
+    shutdown(client).await
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_reorganize_rejects_root_outside_allowed_paths() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    let allowed = temp_dir.path().join("allowed");
+    let outside = temp_dir.path().join("outside");
+    std::fs::create_dir_all(&allowed)?;
+    std::fs::create_dir_all(&outside)?;
+    std::fs::copy(
+        "tests/fixtures/flac/simple/track1.flac",
+        outside.join("loose.flac"),
+    )?;
+    let client = spawn_client_with_env(&[
+        ("MUSIC_ALLOWED_PATHS", allowed.to_str().unwrap()),
+        ("MUSIC_ALLOW_WRITES", "true"),
+    ])
+    .await?;
+
+    let result = call_tool(
+        &client,
+        "reorganize",
+        object!({
+            "path": allowed.join("../outside").to_str().unwrap(),
+            "template": "{artist}/{album}/{track} - {title}.{ext}",
+            "apply": true
+        }),
+    )
+    .await?;
+
+    assert_err(&result);
+    assert!(text_content(&result).contains("Access denied"));
+    assert!(outside.join("loose.flac").exists());
+
+    shutdown(client).await
+}
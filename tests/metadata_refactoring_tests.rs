@@ -59,7 +59,6 @@ fn test_track_checksum_calculation() {
             title: Some(MetadataValue::embedded("Test Song".to_string())),
             artist: Some(MetadataValue::embedded("Test Artist".to_string())),
             album: Some(MetadataValue::embedded("Test Album".to_string())),
-            album_artist: None,
             track_number: Some(MetadataValue::embedded(1)),
             disc_number: Some(MetadataValue::embedded(1)),
             year: Some(MetadataValue::embedded(2023)),
@@ -67,6 +66,7 @@ fn test_track_checksum_calculation() {
             duration: Some(MetadataValue::embedded(1.0)),
             format: "flac".to_string(),
             path: PathBuf::from("tests/fixtures/flac/simple/track1.flac"),
+            ..Default::default()
         },
     );
 
@@ -93,7 +93,6 @@ fn test_track_checksum_deterministic() {
             title: Some(MetadataValue::embedded("Test Song".to_string())),
             artist: Some(MetadataValue::embedded("Test Artist".to_string())),
             album: Some(MetadataValue::embedded("Test Album".to_string())),
-            album_artist: None,
             track_number: Some(MetadataValue::embedded(1)),
             disc_number: Some(MetadataValue::embedded(1)),
             year: Some(MetadataValue::embedded(2023)),
@@ -101,6 +100,7 @@ fn test_track_checksum_deterministic() {
             duration: Some(MetadataValue::embedded(1.0)),
             format: "flac".to_string(),
             path: PathBuf::from("tests/fixtures/flac/simple/track1.flac"),
+            ..Default::default()
         },
     );
 
@@ -125,7 +125,6 @@ fn test_track_with_precomputed_checksum() {
             title: Some(MetadataValue::embedded("Test Song".to_string())),
             artist: Some(MetadataValue::embedded("Test Artist".to_string())),
             album: Some(MetadataValue::embedded("Test Album".to_string())),
-            album_artist: None,
             track_number: Some(MetadataValue::embedded(1)),
             disc_number: Some(MetadataValue::embedded(1)),
             year: Some(MetadataValue::embedded(2023)),
@@ -133,6 +132,7 @@ fn test_track_with_precomputed_checksum() {
             duration: Some(MetadataValue::embedded(1.0)),
             format: "flac".to_string(),
             path: original_path,
+            ..Default::default()
         },
         "precomputed_checksum".to_string(),
     );
@@ -149,7 +149,6 @@ fn test_track_equality_implementation() {
             title: Some(MetadataValue::embedded("Test Song".to_string())),
             artist: Some(MetadataValue::embedded("Test Artist".to_string())),
             album: Some(MetadataValue::embedded("Test Album".to_string())),
-            album_artist: None,
             track_number: Some(MetadataValue::embedded(1)),
             disc_number: Some(MetadataValue::embedded(1)),
             year: Some(MetadataValue::embedded(2023)),
@@ -157,6 +156,7 @@ fn test_track_equality_implementation() {
             duration: Some(MetadataValue::embedded(1.0)),
             format: "flac".to_string(),
             path: PathBuf::from("tests/fixtures/flac/simple/track1.flac"),
+            ..Default::default()
         },
     );
 
@@ -166,7 +166,6 @@ fn test_track_equality_implementation() {
             title: Some(MetadataValue::embedded("Test Song".to_string())),
             artist: Some(MetadataValue::embedded("Test Artist".to_string())),
             album: Some(MetadataValue::embedded("Test Album".to_string())),
-            album_artist: None,
             track_number: Some(MetadataValue::embedded(1)),
             disc_number: Some(MetadataValue::embedded(1)),
             year: Some(MetadataValue::embedded(2023)),
@@ -174,6 +173,7 @@ fn test_track_equality_implementation() {
             duration: Some(MetadataValue::embedded(1.0)),
             format: "flac".to_string(),
             path: PathBuf::from("tests/fixtures/flac/simple/track1.flac"),
+            ..Default::default()
         },
     );
 
@@ -189,7 +189,6 @@ fn test_track_debug_implementation() {
             title: Some(MetadataValue::embedded("Test Song".to_string())),
             artist: Some(MetadataValue::embedded("Test Artist".to_string())),
             album: Some(MetadataValue::embedded("Test Album".to_string())),
-            album_artist: None,
             track_number: Some(MetadataValue::embedded(1)),
             disc_number: Some(MetadataValue::embedded(1)),
             year: Some(MetadataValue::embedded(2023)),
@@ -197,6 +196,7 @@ fn test_track_debug_implementation() {
             duration: Some(MetadataValue::embedded(1.0)),
             format: "flac".to_string(),
             path: PathBuf::from("tests/fixtures/flac/simple/track1.flac"),
+            ..Default::default()
         },
     );
 
@@ -212,7 +212,6 @@ fn test_track_metadata_equality_implementation() {
         title: Some(MetadataValue::embedded("Test Song".to_string())),
         artist: Some(MetadataValue::embedded("Test Artist".to_string())),
         album: Some(MetadataValue::embedded("Test Album".to_string())),
-        album_artist: None,
         track_number: Some(MetadataValue::embedded(1)),
         disc_number: Some(MetadataValue::embedded(1)),
         year: Some(MetadataValue::embedded(2023)),
@@ -220,13 +219,13 @@ fn test_track_metadata_equality_implementation() {
         duration: Some(MetadataValue::embedded(1.0)),
         format: "flac".to_string(),
         path: PathBuf::from("tests/fixtures/flac/simple/track1.flac"),
+        ..Default::default()
     };
 
     let metadata2 = TrackMetadata {
         title: Some(MetadataValue::embedded("Test Song".to_string())),
         artist: Some(MetadataValue::embedded("Test Artist".to_string())),
         album: Some(MetadataValue::embedded("Test Album".to_string())),
-        album_artist: None,
         track_number: Some(MetadataValue::embedded(1)),
         disc_number: Some(MetadataValue::embedded(1)),
         year: Some(MetadataValue::embedded(2023)),
@@ -234,6 +233,7 @@ fn test_track_metadata_equality_implementation() {
         duration: Some(MetadataValue::embedded(1.0)),
         format: "flac".to_string(),
         path: PathBuf::from("tests/fixtures/flac/simple/track1.flac"),
+        ..Default::default()
     };
 
     assert_eq!(metadata1, metadata2);
@@ -1,5 +1,7 @@
 //! Integration tests for MP3 format support.
 
+#![cfg(feature = "mp3")]
+
 use music_chore::adapters::audio_formats::is_format_supported;
 use music_chore::adapters::audio_formats::mp3::Mp3Handler;
 use music_chore::core::domain::traits::AudioFile;
@@ -1,7 +1,11 @@
 //! Tests for the normalization module functionality.
 
+use music_chore::core::services::genre_map::GenreMap;
 use music_chore::core::services::normalization::{
-    normalize_and_format, normalize_genre, to_title_case,
+    DEFAULT_COMMENT_PATTERNS, DEFAULT_NOISE_PHRASES, ascii_transliterate, cluster_genre,
+    cluster_genre_with_map, extract_featured_artists, matching_comment_pattern,
+    normalize_and_format, normalize_genre, normalize_genre_with_map, normalize_unicode_punctuation,
+    strip_noise_phrases, to_title_case,
 };
 use std::path::PathBuf;
 use tempfile::TempDir;
@@ -76,6 +80,187 @@ fn test_normalize_genre_standard_genres() {
     assert_eq!(normalize_genre("r&b"), Some("R&B".to_string()));
 }
 
+#[test]
+fn test_normalize_genre_with_user_map_overrides_builtin() {
+    let temp_dir = TempDir::new().unwrap();
+    let map_path = temp_dir.path().join("genres.tsv");
+    std::fs::write(
+        &map_path,
+        "# variant\tcanonical\nrock\tClassic Rock\nsynthwave\tElectronic\npop/rock\tPop Rock\n",
+    )
+    .unwrap();
+
+    let map = GenreMap::from_file(&map_path).unwrap();
+    assert_eq!(map.len(), 3);
+
+    assert_eq!(
+        normalize_genre_with_map("ROCK", Some(&map)),
+        Some("Classic Rock".to_string())
+    );
+    assert_eq!(
+        normalize_genre_with_map("Synthwave/jazz", Some(&map)),
+        Some("Electronic/Jazz".to_string())
+    );
+    assert_eq!(
+        normalize_genre_with_map("pop/rock", Some(&map)),
+        Some("Pop Rock".to_string())
+    );
+    // Unmapped values still go through the built-in aliases
+    assert_eq!(
+        normalize_genre_with_map("hiphop", Some(&map)),
+        Some("Hip-Hop".to_string())
+    );
+    assert_eq!(normalize_genre("rock"), Some("Rock".to_string()));
+}
+
+#[test]
+fn test_genre_map_json_file_reports_issues() {
+    let temp_dir = TempDir::new().unwrap();
+    let map_path = temp_dir.path().join("genres.json");
+    std::fs::write(
+        &map_path,
+        r#"{"vaporwave": "Vaporwave", "vaporwave": "Vaporwave"}"#,
+    )
+    .unwrap();
+
+    let map = GenreMap::from_file(&map_path).unwrap();
+    assert_eq!(map.len(), 1);
+    assert_eq!(
+        map.issues(),
+        &[
+            "Canonical genre 'Vaporwave' is not a standard genre".to_string(),
+            "Duplicate mapping for 'vaporwave'".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn test_cluster_genre_builtin_hierarchy() {
+    assert_eq!(cluster_genre("Indie Rock"), Some("Rock"));
+    assert_eq!(cluster_genre("shoegaze"), Some("Rock"));
+    assert_eq!(cluster_genre("Metal"), Some("Rock"));
+    assert_eq!(cluster_genre("Techno"), Some("Electronic"));
+    assert_eq!(cluster_genre("Drum and Bass"), Some("Electronic"));
+    assert_eq!(cluster_genre("Soul"), Some("R&B"));
+    assert_eq!(cluster_genre("Opera"), Some("Classical"));
+    assert_eq!(cluster_genre("Podcast"), Some("Spoken Word"));
+    assert_eq!(cluster_genre("Jazz"), Some("Jazz"));
+    // Multi-valued genres cluster by their first value
+    assert_eq!(cluster_genre("Punk/Pop"), Some("Rock"));
+    assert_eq!(cluster_genre("Vaporwave"), None);
+    assert_eq!(cluster_genre(""), None);
+}
+
+#[test]
+fn test_cluster_genre_with_map_overrides_roots() {
+    let temp_dir = TempDir::new().unwrap();
+    let map_path = temp_dir.path().join("genres.tsv");
+    std::fs::write(
+        &map_path,
+        "# variant\tcanonical\troot\nsynthwave\tSynthwave\tPop\nmetal\tMetal\tMetal\n",
+    )
+    .unwrap();
+
+    let map = GenreMap::from_file(&map_path).unwrap();
+    assert_eq!(cluster_genre_with_map("Synthwave", Some(&map)), Some("Pop"));
+    assert_eq!(cluster_genre_with_map("METAL", Some(&map)), Some("Metal"));
+    // Genres without a user root fall back to the built-in hierarchy
+    assert_eq!(
+        cluster_genre_with_map("Techno", Some(&map)),
+        Some("Electronic")
+    );
+    assert_eq!(cluster_genre_with_map("Techno", None), Some("Electronic"));
+}
+
+#[test]
+fn test_strip_noise_phrases_keeps_meaningful_parentheticals() {
+    let strip = |title| strip_noise_phrases(title, DEFAULT_NOISE_PHRASES);
+
+    assert_eq!(strip("Yellow (Official Audio)"), "Yellow");
+    assert_eq!(strip("Yellow (official video) [HQ]"), "Yellow");
+    assert_eq!(strip("Yellow - (Lyrics)"), "Yellow");
+    assert_eq!(
+        strip("Yellow (Live at Wembley)"),
+        "Yellow (Live at Wembley)"
+    );
+    assert_eq!(strip("Yellow (feat. X) [HD]"), "Yellow (feat. X)");
+    assert_eq!(strip("Yellow (Official Audio"), "Yellow (Official Audio");
+}
+
+#[test]
+fn test_extract_featured_artists() {
+    assert_eq!(
+        extract_featured_artists("Song (feat. A & B)"),
+        vec!["A", "B"]
+    );
+    assert_eq!(
+        extract_featured_artists("Song Ft. A, B and C - Radio Edit"),
+        vec!["A", "B", "C"]
+    );
+    assert_eq!(
+        extract_featured_artists("Song [Featuring A] (Remix)"),
+        vec!["A"]
+    );
+    assert!(extract_featured_artists("Featherweight").is_empty());
+    assert!(extract_featured_artists("Left Feat").is_empty());
+    assert!(extract_featured_artists("Song").is_empty());
+}
+
+#[test]
+fn test_matching_comment_pattern_finds_ripper_signatures() {
+    let matches = |comment| matching_comment_pattern(comment, DEFAULT_COMMENT_PATTERNS);
+
+    assert_eq!(matches("RIPPED BY someone using EAC"), Some("Ripped by"));
+    assert_eq!(matches("Visit www.example.com"), Some("www."));
+    assert_eq!(matches("Recorded live in one take"), None);
+    assert_eq!(matches(""), None);
+}
+
+#[test]
+fn test_matching_comment_pattern_ignores_blank_patterns() {
+    let patterns = vec![" ".to_string(), "promo".to_string()];
+
+    assert_eq!(matching_comment_pattern("anything", &patterns), None);
+    assert_eq!(
+        matching_comment_pattern("Promo copy", &patterns),
+        Some("promo")
+    );
+}
+
+#[test]
+fn test_strip_noise_phrases_with_custom_list() {
+    let phrases = vec!["Remastered".to_string()];
+
+    assert_eq!(strip_noise_phrases("Song [Remastered]", &phrases), "Song");
+    assert_eq!(
+        strip_noise_phrases("Song (Official Audio)", &phrases),
+        "Song (Official Audio)"
+    );
+}
+
+#[test]
+fn test_normalize_unicode_punctuation() {
+    assert_eq!(
+        normalize_unicode_punctuation("Don\u{2019}t Stop"),
+        "Don't Stop"
+    );
+    assert_eq!(
+        normalize_unicode_punctuation("\u{201C}Heroes\u{201D} \u{2014} Live\u{2026}"),
+        "\"Heroes\" - Live..."
+    );
+    // Whitespace is not this normalizer's concern
+    assert_eq!(
+        normalize_unicode_punctuation("Caf\u{e9}\u{a0} Song"),
+        "Caf\u{e9}\u{a0} Song"
+    );
+}
+
+#[test]
+fn test_genre_map_missing_file_is_error() {
+    let err = GenreMap::from_file(&PathBuf::from("/nonexistent/genres.tsv")).unwrap_err();
+    assert!(err.contains("Failed to read genre map"));
+}
+
 #[test]
 fn test_normalize_genre_case_insensitive() {
     assert_eq!(normalize_genre("ROCK"), Some("Rock".to_string()));
@@ -250,3 +435,40 @@ fn test_normalize_and_format_with_real_files() {
     assert!(output.contains("--- Genre Normalization ---"));
     assert!(output.contains("--- Artist Normalization ---"));
 }
+
+#[test]
+fn test_ascii_transliterate_accented_latin() {
+    assert_eq!(ascii_transliterate("Café Society"), "Cafe Society");
+    assert_eq!(ascii_transliterate("Straße"), "Strasse");
+    assert_eq!(
+        ascii_transliterate("Sigur Rós - Ágætis byrjun"),
+        "Sigur Ros - Agaetis byrjun"
+    );
+    assert_eq!(
+        ascii_transliterate("Motörhead – Ace of Spades"),
+        "Motorhead - Ace of Spades"
+    );
+    assert_eq!(ascii_transliterate("Łódź"), "Lodz");
+}
+
+#[test]
+fn test_ascii_transliterate_cyrillic() {
+    assert_eq!(
+        ascii_transliterate("Кино - Группа крови"),
+        "Kino - Gruppa krovi"
+    );
+    assert_eq!(ascii_transliterate("Чайковский"), "Chaikovskii");
+    assert_eq!(ascii_transliterate("ДДТ"), "DDT");
+    assert_eq!(ascii_transliterate("ЖУК"), "ZhUK");
+    assert_eq!(ascii_transliterate("Жук"), "Zhuk");
+    assert_eq!(ascii_transliterate("Океан Ельзи"), "Okean El'zi");
+}
+
+#[test]
+fn test_ascii_transliterate_keeps_ascii_and_replaces_unknown() {
+    assert_eq!(ascii_transliterate("01 - Intro.flac"), "01 - Intro.flac");
+    assert_eq!(ascii_transliterate("e\u{301}te\u{301}"), "ete");
+    assert_eq!(ascii_transliterate("音楽"), "Yin Le");
+    assert_eq!(ascii_transliterate("a\u{e000}b"), "a_b");
+    assert!(ascii_transliterate("Björk – Jóga ✓").is_ascii());
+}
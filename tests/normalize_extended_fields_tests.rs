@@ -134,4 +134,45 @@ mod tests {
         assert_eq!(year_reports[0].normalized_year, Some(2020));
         assert!(!year_reports[0].changed);
     }
+
+    #[test]
+    fn test_normalize_limit_genres_reports_remappings() {
+        let temp_dir = TempDir::new().unwrap();
+        create_dummy_flac(
+            &temp_dir,
+            "a.flac",
+            None,
+            None,
+            None,
+            Some("shoegaze"),
+            None,
+        );
+        create_dummy_flac(&temp_dir, "b.flac", None, None, None, Some("polka"), None);
+        create_dummy_flac(&temp_dir, "c.flac", None, None, None, Some("jazz"), None);
+
+        let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+            .arg("normalize")
+            .arg(temp_dir.path())
+            .args(["--limit-genres", "Rock,Jazz", "--json"])
+            .output()
+            .expect("Failed to execute normalize command with --limit-genres");
+
+        assert!(output.status.success());
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let report: CombinedNormalizationReport = serde_json::from_str(&stdout).unwrap();
+
+        let mut remapped: Vec<(String, String)> = report
+            .genre_constraint_reports
+            .into_iter()
+            .map(|r| (r.original_genre, r.constrained_genre))
+            .collect();
+        remapped.sort();
+        assert_eq!(
+            remapped,
+            vec![
+                ("polka".to_string(), "Other".to_string()),
+                ("shoegaze".to_string(), "Rock".to_string()),
+            ]
+        );
+    }
 }
@@ -3,6 +3,9 @@ mod tests {
     use music_chore::adapters::audio_formats::{read_metadata, write_metadata};
     use music_chore::core::domain::models::MetadataValue;
     use music_chore::core::services::normalization::CombinedNormalizationReport; // Import CombinedNormalizationReport
+    use music_chore::core::services::normalize_manifest::{
+        ManifestApplyResult, ManifestApplyStatus, ManifestChange, NormalizeManifest,
+    };
     use music_chore::to_title_case;
     use std::fs;
     use std::path::Path;
@@ -202,4 +205,307 @@ mod tests {
         let stdout = String::from_utf8_lossy(&output.stdout);
         assert!(stdout.contains("Normalize"));
     }
+
+    #[test]
+    fn test_normalize_command_unicode_punctuation() {
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("track1.flac");
+        fs::copy("tests/fixtures/flac/simple/track1.flac", &test_file).unwrap();
+
+        let mut track_metadata = read_metadata(&test_file).unwrap().metadata;
+        track_metadata.title = Some(MetadataValue::user_set("Don\u{2019}t Stop".to_string()));
+        write_metadata(&test_file, &track_metadata).unwrap();
+
+        let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+            .arg("normalize")
+            .arg(&test_file)
+            .arg("--normalize-unicode-punctuation")
+            .arg("--json")
+            .output()
+            .expect("Failed to execute normalize command");
+
+        assert!(output.status.success());
+        let combined_report: CombinedNormalizationReport =
+            serde_json::from_slice(&output.stdout).unwrap();
+        let title = combined_report
+            .punctuation_reports
+            .iter()
+            .find(|r| r.field == "title")
+            .expect("title should be reported");
+        assert!(title.changed);
+        assert_eq!(title.original_value, "Don\u{2019}t Stop");
+        assert_eq!(title.normalized_value, "Don't Stop");
+
+        // Without the flag no punctuation reports are produced
+        let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+            .arg("normalize")
+            .arg(&test_file)
+            .arg("--json")
+            .output()
+            .expect("Failed to execute normalize command");
+        let combined_report: CombinedNormalizationReport =
+            serde_json::from_slice(&output.stdout).unwrap();
+        assert!(combined_report.punctuation_reports.is_empty());
+    }
+
+    #[test]
+    fn test_normalize_command_strips_noise_phrases() {
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("track1.flac");
+        fs::copy("tests/fixtures/flac/simple/track1.flac", &test_file).unwrap();
+
+        let mut track_metadata = read_metadata(&test_file).unwrap().metadata;
+        track_metadata.title = Some(MetadataValue::user_set("Song (Official Audio)".to_string()));
+        write_metadata(&test_file, &track_metadata).unwrap();
+
+        let normalize = |extra: &[&str]| {
+            let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+                .arg("normalize")
+                .arg(&test_file)
+                .arg("--json")
+                .args(extra)
+                .output()
+                .expect("Failed to execute normalize command");
+            assert!(output.status.success());
+            serde_json::from_slice::<CombinedNormalizationReport>(&output.stdout).unwrap()
+        };
+
+        let report = normalize(&[]);
+        assert_eq!(report.noise_reports.len(), 1);
+        assert!(report.noise_reports[0].changed);
+        assert_eq!(report.noise_reports[0].stripped_title, "Song");
+
+        // --strip-phrases replaces the default list
+        let report = normalize(&["--strip-phrases", "Remastered,HQ"]);
+        assert!(!report.noise_reports[0].changed);
+    }
+
+    #[test]
+    fn test_normalize_command_strip_comments_only_writes_with_apply() {
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("track1.flac");
+        fs::copy("tests/fixtures/flac/simple/track1.flac", &test_file).unwrap();
+
+        let mut track_metadata = read_metadata(&test_file).unwrap().metadata;
+        track_metadata.comment = Some(MetadataValue::user_set("Ripped by EAC".to_string()));
+        write_metadata(&test_file, &track_metadata).unwrap();
+
+        let normalize = |extra: &[&str]| {
+            let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+                .arg("normalize")
+                .arg(&test_file)
+                .arg("--json")
+                .arg("--strip-comments")
+                .args(extra)
+                .output()
+                .expect("Failed to execute normalize command");
+            assert!(output.status.success());
+            serde_json::from_slice::<CombinedNormalizationReport>(&output.stdout).unwrap()
+        };
+
+        let report = normalize(&[]);
+        assert_eq!(report.comment_reports.len(), 1);
+        assert_eq!(report.comment_reports[0].pattern, "Ripped by");
+        assert!(!report.comment_reports[0].applied);
+        assert!(
+            read_metadata(&test_file)
+                .unwrap()
+                .metadata
+                .comment
+                .is_some()
+        );
+
+        // Custom patterns replace the default list
+        let report = normalize(&["--comment-patterns", "promo"]);
+        assert!(report.comment_reports.is_empty());
+
+        let report = normalize(&["--apply"]);
+        assert!(report.comment_reports[0].applied);
+        assert!(report.comment_reports[0].error.is_none());
+        let metadata = read_metadata(&test_file).unwrap().metadata;
+        assert!(metadata.comment.is_none());
+        assert_eq!(metadata.title, track_metadata.title);
+    }
+
+    #[test]
+    fn test_normalize_command_apply_requires_strip_comments() {
+        let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+            .args(["normalize", "tests/fixtures/flac/simple", "--apply"])
+            .output()
+            .expect("Failed to execute normalize command");
+
+        assert!(!output.status.success());
+    }
+
+    #[test]
+    fn test_normalize_report_only_manifest_then_apply() {
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("track1.flac");
+        let manifest_file = temp_dir.path().join("manifest.json");
+        fs::copy("tests/fixtures/flac/simple/track1.flac", &test_file).unwrap();
+
+        let mut track_metadata = read_metadata(&test_file).unwrap().metadata;
+        track_metadata.title = Some(MetadataValue::user_set("hello world".to_string()));
+        track_metadata.comment = Some(MetadataValue::user_set("Ripped by EAC".to_string()));
+        write_metadata(&test_file, &track_metadata).unwrap();
+
+        let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+            .arg("normalize")
+            .arg(temp_dir.path())
+            .arg("--strip-comments")
+            .arg("--report-only")
+            .arg(&manifest_file)
+            .output()
+            .expect("Failed to execute normalize command");
+        assert!(output.status.success());
+        assert!(String::from_utf8_lossy(&output.stdout).contains("proposed changes"));
+
+        // Nothing is written in report-only mode
+        let unchanged = read_metadata(&test_file).unwrap().metadata;
+        assert_eq!(unchanged.title.unwrap().value, "hello world");
+
+        let manifest = NormalizeManifest::from_file(&manifest_file).unwrap();
+        let title = manifest
+            .changes
+            .iter()
+            .find(|c| c.field == "title")
+            .unwrap();
+        assert_eq!(title.old.as_deref(), Some("hello world"));
+        assert_eq!(title.new.as_deref(), Some("Hello World"));
+        assert_eq!(title.rule, "title_case");
+        let comment = manifest
+            .changes
+            .iter()
+            .find(|c| c.field == "comment")
+            .unwrap();
+        assert_eq!(comment.new, None);
+
+        let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+            .arg("normalize")
+            .arg(temp_dir.path())
+            .arg("--apply-manifest")
+            .arg(&manifest_file)
+            .output()
+            .expect("Failed to execute normalize command");
+        assert!(output.status.success());
+        assert!(String::from_utf8_lossy(&output.stdout).contains("APPLIED: title"));
+
+        let metadata = read_metadata(&test_file).unwrap().metadata;
+        assert_eq!(metadata.title.unwrap().value, "Hello World");
+        assert!(metadata.comment.is_none());
+    }
+
+    #[test]
+    fn test_normalize_apply_manifest_skips_changed_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("track1.flac");
+        let manifest_file = temp_dir.path().join("manifest.json");
+        fs::copy("tests/fixtures/flac/simple/track1.flac", &test_file).unwrap();
+
+        let manifest = NormalizeManifest {
+            changes: vec![ManifestChange {
+                path: test_file.clone(),
+                field: "title".to_string(),
+                old: Some("hello world".to_string()),
+                new: Some("Hello World".to_string()),
+                rule: "title_case".to_string(),
+            }],
+        };
+        fs::write(&manifest_file, manifest.to_json().unwrap()).unwrap();
+
+        let mut track_metadata = read_metadata(&test_file).unwrap().metadata;
+        track_metadata.title = Some(MetadataValue::user_set("edited since".to_string()));
+        write_metadata(&test_file, &track_metadata).unwrap();
+
+        let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+            .arg("normalize")
+            .arg(temp_dir.path())
+            .arg("--apply-manifest")
+            .arg(&manifest_file)
+            .arg("--json")
+            .output()
+            .expect("Failed to execute normalize command");
+        assert!(!output.status.success());
+
+        let results: Vec<ManifestApplyResult> = serde_json::from_slice(&output.stdout).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].status, ManifestApplyStatus::Conflicted);
+        assert!(results[0].error.as_ref().unwrap().contains("edited since"));
+        let metadata = read_metadata(&test_file).unwrap().metadata;
+        assert_eq!(metadata.title.unwrap().value, "edited since");
+    }
+
+    #[test]
+    fn test_apply_manifest_command_reports_counts() {
+        let temp_dir = TempDir::new().unwrap();
+        let manifest_file = temp_dir.path().join("manifest.json");
+        let mut changes = Vec::new();
+        for (name, title) in [("a.flac", "hello world"), ("b.flac", "edited since")] {
+            let file = temp_dir.path().join(name);
+            fs::copy("tests/fixtures/flac/simple/track1.flac", &file).unwrap();
+            let mut metadata = read_metadata(&file).unwrap().metadata;
+            metadata.title = Some(MetadataValue::user_set(title.to_string()));
+            write_metadata(&file, &metadata).unwrap();
+            changes.push(ManifestChange {
+                path: file,
+                field: "title".to_string(),
+                old: Some("hello world".to_string()),
+                new: Some("Hello World".to_string()),
+                rule: "title_case".to_string(),
+            });
+        }
+        fs::write(
+            &manifest_file,
+            NormalizeManifest { changes }.to_json().unwrap(),
+        )
+        .unwrap();
+
+        let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+            .arg("apply-manifest")
+            .arg(&manifest_file)
+            .output()
+            .expect("Failed to execute apply-manifest command");
+
+        assert!(!output.status.success());
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(stdout.contains("CONFLICT: title"));
+        assert!(stdout.contains("Manifest Summary: 1 applied, 0 skipped, 1 conflicted\n"));
+        assert!(stdout.ends_with("1 files changed, 0 unchanged, 1 errors\n"));
+        let title = |name: &str| {
+            read_metadata(&temp_dir.path().join(name))
+                .unwrap()
+                .metadata
+                .title
+                .unwrap()
+                .value
+        };
+        assert_eq!(title("a.flac"), "Hello World");
+        assert_eq!(title("b.flac"), "edited since");
+    }
+
+    #[test]
+    fn test_normalize_ends_with_mutation_summary() {
+        let temp_dir = TempDir::new().unwrap();
+        for (name, title) in [("a.flac", "test song"), ("b.flac", "Test Song")] {
+            let file = temp_dir.path().join(name);
+            fs::copy("tests/fixtures/flac/simple/track1.flac", &file).unwrap();
+            let mut metadata = read_metadata(&file).unwrap().metadata;
+            metadata.title = Some(MetadataValue::user_set(title.to_string()));
+            metadata.artist = Some(MetadataValue::user_set("Artist".to_string()));
+            metadata.album = Some(MetadataValue::user_set("Album".to_string()));
+            metadata.genre = Some(MetadataValue::user_set("Rock".to_string()));
+            metadata.year = Some(MetadataValue::user_set(2001));
+            write_metadata(&file, &metadata).unwrap();
+        }
+
+        let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+            .arg("normalize")
+            .arg(temp_dir.path())
+            .output()
+            .expect("Failed to execute normalize command");
+
+        assert!(output.status.success());
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(stdout.ends_with("1 files would be changed, 1 unchanged, 0 errors\n\n"));
+    }
 }
@@ -1,5 +1,7 @@
 //! Integration tests for OGG format support.
 
+#![cfg(feature = "ogg")]
+
 use music_chore::adapters::audio_formats::{
     get_supported_extensions, is_format_supported, read_basic_info, read_metadata, write_metadata,
 };
@@ -19,6 +21,7 @@ fn test_ogg_format_detection() {
 fn test_ogg_supported_extensions_registry() {
     let extensions = get_supported_extensions();
     assert!(extensions.contains(&"ogg".to_string()));
+    assert!(extensions.contains(&"opus".to_string()));
 }
 
 #[test]
@@ -115,14 +118,9 @@ fn test_ogg_write_metadata_invalid_content_routes_to_ogg_handler() {
         title: Some(MetadataValue::user_set("Title".to_string())),
         artist: Some(MetadataValue::user_set("Artist".to_string())),
         album: Some(MetadataValue::user_set("Album".to_string())),
-        album_artist: None,
-        track_number: None,
-        disc_number: None,
-        year: None,
-        genre: None,
-        duration: None,
         format: "ogg".to_string(),
         path: ogg_path.clone(),
+        ..Default::default()
     };
 
     let result = write_metadata(&ogg_path, &metadata);
@@ -0,0 +1,69 @@
+//! Tests for discarding weak inferences (`scan --drop-below`).
+
+use music_chore::core::services::scanner::{ScanOptions, parse_confidence, scan_with_options};
+use music_chore::{MetadataSource, MetadataValue};
+use std::fs;
+use tempfile::TempDir;
+
+fn library_with_untagged_file() -> TempDir {
+    let library = TempDir::new().unwrap();
+    fs::copy(
+        "tests/fixtures/flac/simple/track1.flac",
+        library.path().join("Some Album - 01 - Song.flac"),
+    )
+    .unwrap();
+    library
+}
+
+#[test]
+fn test_parse_confidence() {
+    assert_eq!(parse_confidence("0.5"), Ok(0.5));
+    assert_eq!(parse_confidence("0"), Ok(0.0));
+    assert_eq!(parse_confidence("1"), Ok(1.0));
+    assert!(parse_confidence("1.5").is_err());
+    assert!(parse_confidence("-0.1").is_err());
+    assert!(parse_confidence("high").is_err());
+}
+
+#[test]
+fn test_filename_album_dropped_below_threshold() {
+    let library = library_with_untagged_file();
+
+    let kept = scan_with_options(
+        library.path(),
+        &ScanOptions {
+            skip_metadata: true,
+            ..ScanOptions::default()
+        },
+    );
+    let album = kept[0].metadata.album.as_ref().unwrap();
+    assert_eq!(album.source, MetadataSource::FolderInferred);
+    assert_eq!(album.confidence, 0.3);
+
+    let dropped = scan_with_options(
+        library.path(),
+        &ScanOptions {
+            skip_metadata: true,
+            drop_below: 0.5,
+            ..ScanOptions::default()
+        },
+    );
+    assert!(dropped[0].metadata.album.is_none());
+    assert!(dropped[0].metadata.title.is_none());
+    assert!(dropped[0].metadata.artist.is_none());
+}
+
+#[test]
+fn test_drop_below_keeps_embedded_values() {
+    let mut metadata = music_chore::core::builders::TrackMetadataBuilder::new("a.flac")
+        .title("Tagged", MetadataSource::Embedded, 1.0)
+        .build();
+    metadata.album = Some(MetadataValue::inferred("Folder".to_string(), 0.3));
+    metadata.genre = Some(MetadataValue::inferred("Rock".to_string(), 0.8));
+
+    metadata.drop_inferred_below(0.5);
+
+    assert_eq!(metadata.title.unwrap().value, "Tagged");
+    assert!(metadata.album.is_none());
+    assert_eq!(metadata.genre.unwrap().value, "Rock");
+}
@@ -0,0 +1,114 @@
+//! Tests for the JSON-lines scan error log (`scan --error-log`).
+
+use music_chore::core::services::scanner::{
+    ScanErrorKind, ScanErrorRecord, ScanOptions, scan_with_options,
+};
+use std::fs;
+use tempfile::TempDir;
+
+const FIXTURE: &str = "tests/fixtures/flac/simple/track1.flac";
+
+fn read_log(path: &std::path::Path) -> Vec<ScanErrorRecord> {
+    fs::read_to_string(path)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("each line is a JSON record"))
+        .collect()
+}
+
+#[test]
+fn test_error_log_records_skipped_and_unreadable_files() {
+    let library = TempDir::new().unwrap();
+    let logs = TempDir::new().unwrap();
+    let log_path = logs.path().join("errors.jsonl");
+
+    fs::copy(FIXTURE, library.path().join("good.flac")).unwrap();
+    fs::write(library.path().join("empty.flac"), b"").unwrap();
+    fs::write(library.path().join("corrupt.mp3"), b"not really an mp3").unwrap();
+
+    let options = ScanOptions {
+        error_log: Some(log_path.clone()),
+        ..ScanOptions::default()
+    };
+    let tracks = scan_with_options(library.path(), &options);
+
+    // The corrupt file is still listed, relying on path inference
+    assert_eq!(tracks.len(), 2);
+
+    let records = read_log(&log_path);
+    assert_eq!(records.len(), 2);
+    let kind_of = |name: &str| {
+        records
+            .iter()
+            .find(|r| r.path.ends_with(name))
+            .map(|r| r.error_kind)
+    };
+    assert_eq!(kind_of("empty.flac"), Some(ScanErrorKind::InvalidFile));
+    assert_eq!(kind_of("corrupt.mp3"), Some(ScanErrorKind::MetadataRead));
+    assert!(records.iter().all(|r| !r.message.is_empty()));
+}
+
+#[test]
+fn test_error_log_is_empty_for_clean_scan() {
+    let library = TempDir::new().unwrap();
+    let logs = TempDir::new().unwrap();
+    let log_path = logs.path().join("nested").join("errors.jsonl");
+    fs::copy(FIXTURE, library.path().join("good.flac")).unwrap();
+
+    let options = ScanOptions {
+        error_log: Some(log_path.clone()),
+        ..ScanOptions::default()
+    };
+    scan_with_options(library.path(), &options);
+
+    assert!(read_log(&log_path).is_empty());
+}
+
+#[test]
+fn test_error_record_serializes_snake_case_kind() {
+    let record = ScanErrorRecord {
+        path: "/music/a.flac".into(),
+        error_kind: ScanErrorKind::UnsupportedFormat,
+        message: "Unsupported audio format".to_string(),
+    };
+    let json: serde_json::Value = serde_json::to_value(&record).unwrap();
+    assert_eq!(json["error_kind"], "unsupported_format");
+    assert_eq!(json["path"], "/music/a.flac");
+}
+
+#[test]
+fn test_multiple_cue_files_are_reported_and_preference_is_honored() {
+    let library = TempDir::new().unwrap();
+    let logs = TempDir::new().unwrap();
+    let log_path = logs.path().join("errors.jsonl");
+    let album = library.path().join("Artist").join("Album");
+    fs::create_dir_all(&album).unwrap();
+    fs::copy(FIXTURE, album.join("album.flac")).unwrap();
+    let cue = |title: &str| {
+        format!(
+            "FILE \"album.flac\" WAVE\n  TRACK 01 AUDIO\n    TITLE \"{title}\"\n    INDEX 01 00:00:00\n"
+        )
+    };
+    fs::write(album.join("a.cue"), cue("From A")).unwrap();
+    fs::write(album.join("b.cue"), cue("From B")).unwrap();
+
+    let title_with = |preferred_cue: Option<&str>| {
+        let options = ScanOptions {
+            error_log: Some(log_path.clone()),
+            preferred_cue: preferred_cue.map(str::to_string),
+            ..ScanOptions::default()
+        };
+        let tracks = scan_with_options(library.path(), &options);
+        assert_eq!(tracks.len(), 1);
+        tracks[0].metadata.title.as_ref().unwrap().value.clone()
+    };
+
+    // Without a preference the first sheet by name wins, deterministically
+    assert_eq!(title_with(None), "From A");
+    let records = read_log(&log_path);
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].error_kind, ScanErrorKind::MultipleCueFiles);
+    assert!(records[0].message.contains("a.cue, b.cue"));
+
+    assert_eq!(title_with(Some("B.cue")), "From B");
+}
@@ -1,5 +1,10 @@
+use music_chore::Track;
+use music_chore::core::builders::TrackMetadataBuilder;
 use music_chore::core::services::scanner::{
-    scan_dir_with_metadata, scan_tracks, scan_with_duplicates,
+    AlbumFrom, ChecksumMode, CuePrecedence, ScanOptions, canonical_dedupe, is_dangerous_scan_root,
+    merge_cue_metadata, parse_album_from, parse_concurrency, parse_cue_precedence,
+    scan_dir_with_metadata, scan_tracks, scan_with_duplicates, scan_with_duplicates_with_mode,
+    scan_with_options,
 };
 use std::fs;
 use std::path::PathBuf;
@@ -160,6 +165,53 @@ fn test_scan_with_duplicates_mixed_formats() {
     assert!(tracks.iter().all(|t| t.checksum.is_some()));
 }
 
+/// Builds a 16-bit mono PCM WAV, optionally with a trailing LIST chunk so the
+/// file bytes differ while the audio stays the same.
+fn pcm_wav(samples: &[u8], list_chunk: Option<&[u8]>) -> Vec<u8> {
+    let list = list_chunk.map(|c| c.len() + 8).unwrap_or(0);
+    let mut out = Vec::new();
+    out.extend_from_slice(b"RIFF");
+    out.extend_from_slice(&((36 + samples.len() + list) as u32).to_le_bytes());
+    out.extend_from_slice(b"WAVEfmt ");
+    out.extend_from_slice(&16u32.to_le_bytes());
+    out.extend_from_slice(&[1, 0, 1, 0]);
+    out.extend_from_slice(&44100u32.to_le_bytes());
+    out.extend_from_slice(&88200u32.to_le_bytes());
+    out.extend_from_slice(&[2, 0, 16, 0]);
+    if let Some(chunk) = list_chunk {
+        out.extend_from_slice(b"LIST");
+        out.extend_from_slice(&(chunk.len() as u32).to_le_bytes());
+        out.extend_from_slice(chunk);
+    }
+    out.extend_from_slice(b"data");
+    out.extend_from_slice(&(samples.len() as u32).to_le_bytes());
+    out.extend_from_slice(samples);
+    out
+}
+
+#[test]
+fn test_scan_with_duplicates_pcm_content_matches_same_audio() {
+    let temp_dir = TempDir::new().unwrap();
+    let album = temp_dir.path().join("artist/album");
+    fs::create_dir_all(&album).unwrap();
+
+    let samples = [0x10, 0x00, 0x20, 0x00, 0xF0, 0xFF, 0x00, 0x80];
+    fs::write(album.join("a.wav"), pcm_wav(&samples, None)).unwrap();
+    fs::write(album.join("b.wav"), pcm_wav(&samples, Some(b"INFOtags"))).unwrap();
+    fs::copy("tests/fixtures/mp3/simple/track1.mp3", album.join("c.mp3")).unwrap();
+
+    let (_, by_bytes) = scan_with_duplicates(temp_dir.path(), false, None);
+    assert!(by_bytes.is_empty());
+
+    let (tracks, by_pcm) =
+        scan_with_duplicates_with_mode(temp_dir.path(), false, None, ChecksumMode::PcmContent);
+    assert_eq!(by_pcm.len(), 1);
+    assert_eq!(by_pcm[0].len(), 2);
+    // Formats without a PCM checksum are left out of the comparison
+    let mp3 = tracks.iter().find(|t| t.metadata.format == "mp3").unwrap();
+    assert!(mp3.checksum.is_none());
+}
+
 #[test]
 fn test_scan_dir_with_metadata_preserves_order() {
     let temp_dir = TempDir::new().unwrap();
@@ -204,3 +256,369 @@ fn test_scan_dir_with_metadata_preserves_order() {
         .collect();
     assert_eq!(paths, vec!["a_track.flac", "b_track.flac", "z_track.flac"]);
 }
+
+#[cfg(unix)]
+#[test]
+fn test_canonical_dedupe_paths_differing_only_in_case() {
+    let temp_dir = TempDir::new().unwrap();
+    let real = temp_dir.path().join("Track.flac");
+    let alias = temp_dir.path().join("track.flac");
+    fs::copy("tests/fixtures/flac/simple/track1.flac", &real).unwrap();
+    // Emulate a case-insensitive filesystem: the lower-case name resolves to the same file
+    std::os::unix::fs::symlink(&real, &alias).unwrap();
+
+    let tracks = vec![
+        Track::new(real.clone(), TrackMetadataBuilder::new(&real).build()),
+        Track::new(alias.clone(), TrackMetadataBuilder::new(&alias).build()),
+    ];
+
+    let deduped = canonical_dedupe(tracks);
+    assert_eq!(deduped.len(), 1);
+    assert_eq!(deduped[0].file_path, real);
+}
+
+#[test]
+fn test_canonical_dedupe_keeps_distinct_files() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::copy(
+        "tests/fixtures/flac/simple/track1.flac",
+        temp_dir.path().join("a.flac"),
+    )
+    .unwrap();
+    fs::copy(
+        "tests/fixtures/flac/simple/track1.flac",
+        temp_dir.path().join("b.flac"),
+    )
+    .unwrap();
+
+    let tracks = scan_dir_with_metadata(temp_dir.path()).unwrap();
+    assert_eq!(canonical_dedupe(tracks).len(), 2);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_scan_dedupe_paths_with_followed_symlink_dir() {
+    let temp_dir = TempDir::new().unwrap();
+    let album = temp_dir.path().join("Artist/Album");
+    fs::create_dir_all(&album).unwrap();
+    fs::copy(
+        "tests/fixtures/flac/simple/track1.flac",
+        album.join("track1.flac"),
+    )
+    .unwrap();
+    std::os::unix::fs::symlink(&album, temp_dir.path().join("Artist/Album Link")).unwrap();
+
+    let mut options = ScanOptions {
+        follow_symlinks: true,
+        ..ScanOptions::default()
+    };
+    assert_eq!(scan_with_options(temp_dir.path(), &options).len(), 2);
+
+    options.dedupe_paths = true;
+    assert_eq!(scan_with_options(temp_dir.path(), &options).len(), 1);
+}
+
+#[test]
+fn test_scan_with_single_thread_matches_default_concurrency() {
+    let base = PathBuf::from("tests/fixtures");
+    let serial = scan_with_options(
+        &base,
+        &ScanOptions {
+            concurrency: Some(1),
+            ..ScanOptions::default()
+        },
+    );
+    let parallel = scan_with_options(&base, &ScanOptions::default());
+
+    assert!(!serial.is_empty());
+    let paths = |tracks: &[Track]| {
+        tracks
+            .iter()
+            .map(|t| t.file_path.clone())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(paths(&serial), paths(&parallel));
+}
+
+#[test]
+fn test_parse_concurrency_requires_at_least_one_thread() {
+    assert_eq!(parse_concurrency("1"), Ok(1));
+    assert_eq!(parse_concurrency(" 8 "), Ok(8));
+    assert!(parse_concurrency("0").is_err());
+    assert!(parse_concurrency("-2").is_err());
+    assert!(parse_concurrency("many").is_err());
+}
+
+#[test]
+fn test_scan_skip_cue_expansion_reports_single_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let album = temp_dir.path().join("Artist/Album");
+    fs::create_dir_all(&album).unwrap();
+    fs::copy(
+        "tests/fixtures/flac/simple/track1.flac",
+        album.join("album.flac"),
+    )
+    .unwrap();
+    fs::write(
+        album.join("album.cue"),
+        "FILE \"album.flac\" WAVE\n  TRACK 01 AUDIO\n    TITLE \"One\"\n    INDEX 01 00:00:00\n  TRACK 02 AUDIO\n    TITLE \"Two\"\n    INDEX 01 01:00:00\n",
+    )
+    .unwrap();
+
+    let expanded = scan_with_options(temp_dir.path(), &ScanOptions::default());
+    assert_eq!(expanded.len(), 2);
+
+    let options = ScanOptions {
+        skip_cue_expansion: true,
+        ..ScanOptions::default()
+    };
+    let tracks = scan_with_options(temp_dir.path(), &options);
+    assert_eq!(tracks.len(), 1);
+    assert!(tracks[0].file_path.ends_with("album.flac"));
+}
+
+/// Scans an album of tagged fixture copies named `files` described by `cue`
+/// and returns the track titles.
+fn cue_titles(files: &[&str], cue: &str, cue_precedence: CuePrecedence) -> Vec<String> {
+    let temp_dir = TempDir::new().unwrap();
+    let album = temp_dir.path().join("Artist/Album");
+    fs::create_dir_all(&album).unwrap();
+    for file in files {
+        fs::copy("tests/fixtures/flac/simple/track1.flac", album.join(file)).unwrap();
+    }
+    fs::write(album.join("album.cue"), cue).unwrap();
+
+    let options = ScanOptions {
+        cue_precedence,
+        ..ScanOptions::default()
+    };
+    let tracks = scan_with_options(temp_dir.path(), &options);
+    for track in &tracks {
+        // Only the embedded tags have a genre, so both modes fill it in
+        assert_eq!(track.metadata.genre.as_ref().unwrap().value, "Test Genre");
+    }
+    tracks
+        .into_iter()
+        .map(|t| t.metadata.title.unwrap().value)
+        .collect()
+}
+
+#[test]
+fn test_scan_cue_precedence_split_album() {
+    let cue = "FILE \"1.flac\" WAVE\n  TRACK 01 AUDIO\n    TITLE \"One\"\n    INDEX 01 00:00:00\nFILE \"2.flac\" WAVE\n  TRACK 02 AUDIO\n    TITLE \"Two\"\n    INDEX 01 00:00:00\n";
+    let files = ["1.flac", "2.flac"];
+
+    let embedded = vec!["Test Apply Behavior"; 2];
+    assert_eq!(cue_titles(&files, cue, CuePrecedence::Auto), embedded);
+    assert_eq!(cue_titles(&files, cue, CuePrecedence::Embedded), embedded);
+    assert_eq!(cue_titles(&files, cue, CuePrecedence::Cue), ["One", "Two"]);
+}
+
+#[test]
+fn test_scan_cue_precedence_single_file_album() {
+    let cue = "FILE \"album.flac\" WAVE\n  TRACK 01 AUDIO\n    TITLE \"One\"\n    INDEX 01 00:00:00\n  TRACK 02 AUDIO\n    TITLE \"Two\"\n    INDEX 01 01:00:00\n";
+    let files = ["album.flac"];
+
+    assert_eq!(cue_titles(&files, cue, CuePrecedence::Auto), ["One", "Two"]);
+    assert_eq!(cue_titles(&files, cue, CuePrecedence::Cue), ["One", "Two"]);
+    assert_eq!(
+        cue_titles(&files, cue, CuePrecedence::Embedded),
+        vec!["Test Apply Behavior"; 2]
+    );
+}
+
+#[test]
+fn test_merge_cue_metadata_keeps_only_album_level_embedded_fields() {
+    let cue = Track::builder("album.flac")
+        .title("One")
+        .artist("Cue Performer")
+        .track_number(1)
+        .format("flac")
+        .build()
+        .metadata;
+    let embedded = Track::builder("album.flac")
+        .artists(["Image Artist A", "Image Artist B"])
+        .track_gain(-7.5)
+        .album_gain(-6.0)
+        .sample_rate(44100)
+        .bpm(120)
+        .disc_number(2)
+        .duration(3600.0)
+        .build()
+        .metadata;
+
+    let merged = merge_cue_metadata(cue, embedded, false);
+
+    assert_eq!(merged.artist_names(), ["Cue Performer"]);
+    assert!(merged.track_gain.is_none());
+    assert!(merged.bpm.is_none());
+    assert!(merged.disc_number.is_none());
+    assert!(merged.duration.is_none());
+    assert_eq!(merged.album_gain.unwrap().value, -6.0);
+    assert_eq!(merged.sample_rate.unwrap().value, 44100);
+}
+
+#[test]
+fn test_parse_cue_precedence() {
+    assert_eq!(parse_cue_precedence("auto"), Ok(CuePrecedence::Auto));
+    assert_eq!(parse_cue_precedence("CUE"), Ok(CuePrecedence::Cue));
+    assert_eq!(
+        parse_cue_precedence("embedded"),
+        Ok(CuePrecedence::Embedded)
+    );
+    assert!(parse_cue_precedence("tags").is_err());
+}
+
+#[test]
+fn test_scan_skip_filename_album_fallback_leaves_album_empty() {
+    // "[FLAC]" cleans to an empty folder name, so only the file name is left
+    let temp_dir = TempDir::new().unwrap();
+    let folder = temp_dir.path().join("[FLAC]");
+    fs::create_dir_all(&folder).unwrap();
+    fs::copy(
+        "tests/fixtures/flac/simple/track1.flac",
+        folder.join("Song.flac"),
+    )
+    .unwrap();
+
+    let options = ScanOptions {
+        skip_metadata: true,
+        ..ScanOptions::default()
+    };
+    let tracks = scan_with_options(temp_dir.path(), &options);
+    assert_eq!(tracks[0].metadata.album.as_ref().unwrap().value, "Song");
+
+    let options = ScanOptions {
+        skip_metadata: true,
+        skip_filename_album_fallback: true,
+        ..ScanOptions::default()
+    };
+    let tracks = scan_with_options(temp_dir.path(), &options);
+    assert!(tracks[0].metadata.album.is_none());
+}
+
+#[test]
+fn test_scan_exclude_under_prunes_subtree() {
+    let temp_dir = TempDir::new().unwrap();
+    for dir in ["Album", "Incoming/Deep", "Downloads"] {
+        let dir = temp_dir.path().join(dir);
+        fs::create_dir_all(&dir).unwrap();
+        fs::copy(
+            "tests/fixtures/flac/simple/track1.flac",
+            dir.join("track.flac"),
+        )
+        .unwrap();
+    }
+
+    let options = ScanOptions {
+        exclude_under: vec![PathBuf::from("Incoming"), temp_dir.path().join("Downloads")],
+        ..ScanOptions::default()
+    };
+    let tracks = scan_with_options(temp_dir.path(), &options);
+
+    assert_eq!(tracks.len(), 1);
+    assert!(tracks[0].file_path.ends_with("Album/track.flac"));
+    assert!(
+        tracks
+            .iter()
+            .all(|t| !t.file_path.starts_with(temp_dir.path().join("Incoming")))
+    );
+}
+
+#[test]
+fn test_is_dangerous_scan_root() {
+    let root = if cfg!(windows) { r"C:\" } else { "/" };
+    assert!(is_dangerous_scan_root(std::path::Path::new(root)));
+    if let Some(home) = std::env::var_os(if cfg!(windows) { "USERPROFILE" } else { "HOME" }) {
+        assert!(is_dangerous_scan_root(&PathBuf::from(home)));
+    }
+    #[cfg(not(windows))]
+    assert!(is_dangerous_scan_root(std::path::Path::new("/usr")));
+
+    let temp_dir = TempDir::new().unwrap();
+    assert!(!is_dangerous_scan_root(temp_dir.path()));
+    assert!(!is_dangerous_scan_root(&temp_dir.path().join("Music")));
+}
+
+#[test]
+fn test_scan_refuses_filesystem_root_without_force() {
+    let root = if cfg!(windows) { r"C:\" } else { "/" };
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .args(["scan", root])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--force"));
+}
+
+#[test]
+fn test_scan_album_from_folder_overrides_tag() {
+    let temp_dir = TempDir::new().unwrap();
+    let album = temp_dir.path().join("Band/Folder Album");
+    fs::create_dir_all(&album).unwrap();
+    fs::copy(
+        "tests/fixtures/flac/simple/track1.flac",
+        album.join("01.flac"),
+    )
+    .unwrap();
+    let album_of = |album_from| {
+        let options = ScanOptions {
+            album_from,
+            ..ScanOptions::default()
+        };
+        let tracks = scan_with_options(temp_dir.path(), &options);
+        tracks[0].metadata.album.clone().unwrap()
+    };
+
+    assert_eq!(album_of(AlbumFrom::Tag).value, "Test Album");
+    let folder = album_of(AlbumFrom::Folder);
+    assert_eq!(folder.value, "Folder Album");
+    assert_eq!(folder.source, music_chore::MetadataSource::FolderInferred);
+
+    assert_eq!(parse_album_from("Folder"), Ok(AlbumFrom::Folder));
+    assert!(parse_album_from("path").is_err());
+}
+
+#[test]
+fn test_scan_album_from_folder_skips_disc_folders() {
+    let temp_dir = TempDir::new().unwrap();
+    let disc = temp_dir.path().join("Band/Folder Album/CD1");
+    fs::create_dir_all(&disc).unwrap();
+    fs::copy(
+        "tests/fixtures/flac/simple/track1.flac",
+        disc.join("01.flac"),
+    )
+    .unwrap();
+    let options = ScanOptions {
+        album_from: AlbumFrom::Folder,
+        ..ScanOptions::default()
+    };
+
+    let tracks = scan_with_options(temp_dir.path(), &options);
+
+    assert_eq!(
+        tracks[0].metadata.album.as_ref().unwrap().value,
+        "Folder Album"
+    );
+}
+
+#[test]
+fn test_scan_album_from_folder_never_uses_filename() {
+    let temp_dir = TempDir::new().unwrap();
+    let flat = temp_dir.path().join("Flat");
+    fs::create_dir_all(&flat).unwrap();
+    fs::copy(
+        "tests/fixtures/flac/simple/track1.flac",
+        flat.join("Artist - Song.flac"),
+    )
+    .unwrap();
+    let options = ScanOptions {
+        album_from: AlbumFrom::Folder,
+        ..ScanOptions::default()
+    };
+
+    let tracks = scan_with_options(temp_dir.path(), &options);
+
+    assert_eq!(tracks[0].metadata.album.as_ref().unwrap().value, "Flat");
+}
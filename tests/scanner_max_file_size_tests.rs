@@ -0,0 +1,68 @@
+//! Tests for skipping oversized files (`scan --max-file-size`).
+
+use music_chore::core::services::scanner::{
+    ScanErrorKind, ScanErrorRecord, ScanOptions, parse_file_size, scan_with_options,
+};
+use std::fs::{self, File};
+use tempfile::TempDir;
+
+#[test]
+fn test_parse_file_size() {
+    assert_eq!(parse_file_size("1000"), Ok(1000));
+    assert_eq!(parse_file_size("300K"), Ok(300 * 1024));
+    assert_eq!(parse_file_size("200mb"), Ok(200 * 1024 * 1024));
+    assert_eq!(parse_file_size("1.5GiB"), Ok(3 * 512 * 1024 * 1024));
+    assert!(parse_file_size("big").is_err());
+    assert!(parse_file_size("0").is_err());
+    assert!(parse_file_size("-5M").is_err());
+}
+
+#[test]
+fn test_sparse_large_file_is_skipped() {
+    let library = TempDir::new().unwrap();
+    let logs = TempDir::new().unwrap();
+    let log_path = logs.path().join("errors.jsonl");
+
+    fs::copy(
+        "tests/fixtures/flac/simple/track1.flac",
+        library.path().join("small.flac"),
+    )
+    .unwrap();
+    // Sparse: reports 3 GiB without using the disk space
+    File::create(library.path().join("huge.flac"))
+        .unwrap()
+        .set_len(3 << 30)
+        .unwrap();
+
+    let options = ScanOptions {
+        max_file_size: Some(1 << 30),
+        error_log: Some(log_path.clone()),
+        ..ScanOptions::default()
+    };
+    let tracks = scan_with_options(library.path(), &options);
+
+    assert_eq!(tracks.len(), 1);
+    assert!(tracks[0].file_path.ends_with("small.flac"));
+
+    let records: Vec<ScanErrorRecord> = fs::read_to_string(&log_path)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].error_kind, ScanErrorKind::TooLarge);
+    assert!(records[0].path.ends_with("huge.flac"));
+}
+
+#[test]
+fn test_no_limit_by_default() {
+    let library = TempDir::new().unwrap();
+    fs::copy(
+        "tests/fixtures/flac/simple/track1.flac",
+        library.path().join("small.flac"),
+    )
+    .unwrap();
+
+    let tracks = scan_with_options(library.path(), &ScanOptions::default());
+    assert_eq!(tracks.len(), 1);
+}
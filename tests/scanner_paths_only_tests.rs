@@ -0,0 +1,87 @@
+//! Tests for listing discovered audio paths without metadata (`scan --paths-only`).
+
+use std::process::Command;
+
+#[test]
+fn test_scan_paths_only_prints_one_path_per_line() {
+    let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .args(["scan", "tests/fixtures/flac/simple", "--paths-only"])
+        .output()
+        .expect("Failed to run scan");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(
+        lines,
+        vec![
+            "tests/fixtures/flac/simple/track1.flac",
+            "tests/fixtures/flac/simple/track2.flac",
+        ]
+    );
+}
+
+#[test]
+fn test_scan_paths_only_json_is_string_array() {
+    let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .args([
+            "scan",
+            "tests/fixtures/flac/simple",
+            "--paths-only",
+            "--json",
+            "--root",
+            "tests/fixtures/flac",
+        ])
+        .output()
+        .expect("Failed to run scan");
+
+    assert!(output.status.success());
+    let paths: Vec<String> = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(paths, vec!["simple/track1.flac", "simple/track2.flac"]);
+}
+
+#[test]
+fn test_scan_paths_only_applies_discovery_filters() {
+    let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .args([
+            "scan",
+            "tests/fixtures/flac/simple",
+            "--paths-only",
+            "--exclude",
+            "*track2*",
+        ])
+        .output()
+        .expect("Failed to run scan");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(
+        stdout.lines().collect::<Vec<_>>(),
+        vec!["tests/fixtures/flac/simple/track1.flac"]
+    );
+
+    let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .args([
+            "scan",
+            "tests/fixtures/flac",
+            "--paths-only",
+            "--max-depth",
+            "0",
+        ])
+        .output()
+        .expect("Failed to run scan");
+
+    assert!(output.status.success());
+    assert!(!String::from_utf8_lossy(&output.stdout).contains("simple/"));
+}
+
+#[test]
+fn test_scan_paths_only_missing_path_fails() {
+    let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .args(["scan", "/nonexistent/music", "--paths-only"])
+        .output()
+        .expect("Failed to run scan");
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Path does not exist"));
+}
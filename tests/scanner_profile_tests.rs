@@ -0,0 +1,51 @@
+//! Tests for scan phase timing (`scan --profile`).
+
+use music_chore::core::services::scanner::{
+    ScanOptions, ScanProfile, format_scan_profile, scan_with_options, scan_with_profile,
+};
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+
+#[test]
+fn test_scan_with_profile_returns_same_tracks() {
+    let path = Path::new("tests/fixtures/flac/simple");
+    let (tracks, profile) = scan_with_profile(path, &ScanOptions::default());
+
+    assert_eq!(tracks, scan_with_options(path, &ScanOptions::default()));
+    assert_eq!(profile.files, 2);
+    assert!(profile.total() >= profile.metadata);
+}
+
+#[test]
+fn test_format_scan_profile_lists_phases_and_rate() {
+    let profile = ScanProfile {
+        discovery: Duration::from_millis(250),
+        metadata: Duration::from_millis(750),
+        files: 10,
+        ..ScanProfile::default()
+    };
+
+    let text = format_scan_profile(&profile);
+
+    assert!(text.contains("discovery      0.250s"));
+    assert!(text.contains("metadata       0.750s"));
+    assert!(text.contains("total          1.000s"));
+    assert!(text.ends_with("10 files, 10.0 files/s"));
+    assert_eq!(ScanProfile::default().files_per_second(), 0.0);
+}
+
+#[test]
+fn test_scan_profile_goes_to_stderr() {
+    let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .args(["scan", "tests/fixtures/flac/simple", "--json", "--profile"])
+        .output()
+        .expect("Failed to run scan");
+
+    assert!(output.status.success());
+    let tracks: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(tracks.as_array().unwrap().len(), 2);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Scan profile:"));
+    assert!(stderr.contains("files/s"));
+}
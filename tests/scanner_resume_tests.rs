@@ -0,0 +1,153 @@
+//! Tests for resumable (`--resume`) scans backed by a checkpoint file.
+
+use music_chore::core::domain::models::MetadataValue;
+use music_chore::core::services::scanner::{
+    ScanOptions, checkpoint_line, default_checkpoint_path, scan_with_options,
+};
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+use tempfile::TempDir;
+
+const FIXTURE: &str = "tests/fixtures/flac/simple/track1.flac";
+
+#[test]
+fn test_resume_reuses_checkpointed_tracks_and_scans_the_rest() {
+    let library = TempDir::new().unwrap();
+    let state = TempDir::new().unwrap();
+    let checkpoint = state.path().join("scan.jsonl");
+
+    fs::copy(FIXTURE, library.path().join("a.flac")).unwrap();
+    let mut recorded = scan_with_options(library.path(), &ScanOptions::default()).remove(0);
+    recorded.metadata.title.as_mut().unwrap().value = "From Checkpoint".to_string();
+
+    // A previous run recorded a.flac, then died mid-write
+    let mut file = fs::File::create(&checkpoint).unwrap();
+    let line = checkpoint_line(&recorded, &ScanOptions::default()).unwrap();
+    writeln!(file, "{}", line).unwrap();
+    write!(file, "{{\"file_path\": \"").unwrap();
+    drop(file);
+
+    fs::copy(FIXTURE, library.path().join("b.flac")).unwrap();
+    let options = ScanOptions {
+        checkpoint: Some(checkpoint.clone()),
+        ..ScanOptions::default()
+    };
+    let tracks = scan_with_options(library.path(), &options);
+
+    assert_eq!(tracks.len(), 2);
+    let title = |name: &str| {
+        tracks
+            .iter()
+            .find(|t| t.file_path.ends_with(name))
+            .and_then(|t| t.metadata.title.as_ref())
+            .map(|t| t.value.clone())
+    };
+    assert_eq!(title("a.flac").as_deref(), Some("From Checkpoint"));
+    assert_eq!(title("b.flac").as_deref(), Some("Test Apply Behavior"));
+    assert!(
+        !checkpoint.exists(),
+        "checkpoint is removed after completion"
+    );
+}
+
+#[test]
+fn test_checkpoint_ignores_files_no_longer_present() {
+    let library = TempDir::new().unwrap();
+    let state = TempDir::new().unwrap();
+    let checkpoint = state.path().join("nested/dir/scan.jsonl");
+
+    fs::copy(FIXTURE, library.path().join("gone.flac")).unwrap();
+    let recorded = scan_with_options(library.path(), &ScanOptions::default());
+    let line = checkpoint_line(&recorded[0], &ScanOptions::default()).unwrap();
+    fs::remove_file(library.path().join("gone.flac")).unwrap();
+    fs::create_dir_all(checkpoint.parent().unwrap()).unwrap();
+    fs::write(&checkpoint, format!("{}\n", line)).unwrap();
+
+    fs::copy(FIXTURE, library.path().join("kept.flac")).unwrap();
+    let options = ScanOptions {
+        checkpoint: Some(checkpoint.clone()),
+        ..ScanOptions::default()
+    };
+    let tracks = scan_with_options(library.path(), &options);
+
+    assert_eq!(tracks.len(), 1);
+    assert!(tracks[0].file_path.ends_with("kept.flac"));
+    assert!(!checkpoint.exists());
+}
+
+/// Records `a.flac` in a checkpoint under `recorded_with` with a changed
+/// title, lets `change` touch the library, then resumes with `resume_with`
+/// and returns the title the scan reports.
+fn resumed_title(
+    recorded_with: &ScanOptions,
+    change: impl FnOnce(&Path),
+    resume_with: ScanOptions,
+) -> String {
+    let library = TempDir::new().unwrap();
+    let state = TempDir::new().unwrap();
+    let checkpoint = state.path().join("scan.jsonl");
+
+    fs::copy(FIXTURE, library.path().join("a.flac")).unwrap();
+    let mut recorded = scan_with_options(library.path(), recorded_with).remove(0);
+    recorded.metadata.title = Some(MetadataValue::embedded("From Checkpoint".to_string()));
+    let line = checkpoint_line(&recorded, recorded_with).unwrap();
+    fs::write(&checkpoint, format!("{}\n", line)).unwrap();
+
+    change(&library.path().join("a.flac"));
+    let options = ScanOptions {
+        checkpoint: Some(checkpoint),
+        ..resume_with
+    };
+    let tracks = scan_with_options(library.path(), &options);
+    tracks[0].metadata.title.as_ref().unwrap().value.clone()
+}
+
+#[test]
+fn test_resume_rereads_files_modified_since_the_checkpoint() {
+    let title = resumed_title(
+        &ScanOptions::default(),
+        |path| {
+            let file = fs::File::options().write(true).open(path).unwrap();
+            file.set_modified(SystemTime::now() + Duration::from_secs(60))
+                .unwrap();
+        },
+        ScanOptions::default(),
+    );
+    assert_eq!(title, "Test Apply Behavior");
+}
+
+#[test]
+fn test_resume_rereads_tracks_recorded_under_other_options() {
+    let skip_metadata = ScanOptions {
+        skip_metadata: true,
+        ..ScanOptions::default()
+    };
+    let title = resumed_title(&skip_metadata, |_| {}, ScanOptions::default());
+    assert_eq!(title, "Test Apply Behavior");
+
+    let title = resumed_title(&ScanOptions::default(), |_| {}, ScanOptions::default());
+    assert_eq!(title, "From Checkpoint");
+}
+
+#[test]
+fn test_default_checkpoint_path_is_stable_per_library() {
+    let a = TempDir::new().unwrap();
+    let b = TempDir::new().unwrap();
+
+    assert_eq!(
+        default_checkpoint_path(a.path()),
+        default_checkpoint_path(a.path())
+    );
+    assert_ne!(
+        default_checkpoint_path(a.path()),
+        default_checkpoint_path(b.path())
+    );
+    assert!(
+        default_checkpoint_path(a.path())
+            .parent()
+            .unwrap()
+            .ends_with("musicctl/checkpoints")
+    );
+}
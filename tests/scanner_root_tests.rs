@@ -0,0 +1,72 @@
+//! Tests for rebasing track paths onto a library root (`scan --root`).
+
+use music_chore::core::services::scanner::{rebase_path, rebase_tracks, scan_dir};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tempfile::TempDir;
+
+#[test]
+fn test_rebase_path_strips_root() {
+    let rebased = rebase_path(
+        Path::new("/music/Artist/Album/01.flac"),
+        Path::new("/music"),
+    );
+    assert_eq!(rebased, Ok(PathBuf::from("Artist/Album/01.flac")));
+}
+
+#[test]
+fn test_rebase_path_rejects_track_outside_root() {
+    let err = rebase_path(Path::new("/other/01.flac"), Path::new("/music")).unwrap_err();
+    assert!(err.contains("not under root"));
+}
+
+#[test]
+fn test_rebase_tracks_rewrites_file_and_metadata_paths() {
+    let dir = TempDir::new().unwrap();
+    let album = dir.path().join("Artist").join("Album");
+    fs::create_dir_all(&album).unwrap();
+    fs::copy(
+        "tests/fixtures/flac/simple/track1.flac",
+        album.join("01.flac"),
+    )
+    .unwrap();
+
+    let tracks = rebase_tracks(scan_dir(&album, false), dir.path()).unwrap();
+    assert_eq!(tracks.len(), 1);
+    assert_eq!(tracks[0].file_path, PathBuf::from("Artist/Album/01.flac"));
+    assert_eq!(tracks[0].metadata.path, tracks[0].file_path);
+}
+
+#[test]
+fn test_scan_command_root_outside_fails() {
+    let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .args([
+            "scan",
+            "tests/fixtures/flac/simple",
+            "--root",
+            "tests/fixtures/wav",
+        ])
+        .output()
+        .expect("Failed to run scan");
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("not under root"));
+}
+
+#[test]
+fn test_scan_command_root_prints_relative_paths() {
+    let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .args([
+            "scan",
+            "tests/fixtures/flac/simple",
+            "--root",
+            "tests/fixtures",
+        ])
+        .output()
+        .expect("Failed to run scan");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.lines().all(|l| l.starts_with("flac/simple/")));
+}
@@ -0,0 +1,126 @@
+//! Tests for the modification-time (`--since`) scan filter.
+
+use music_chore::core::services::scanner::{ScanOptions, parse_rfc3339, scan_with_options};
+use std::fs::{self, File};
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tempfile::TempDir;
+
+fn copy_with_mtime(dest: &Path, mtime: SystemTime) {
+    fs::create_dir_all(dest.parent().unwrap()).unwrap();
+    fs::copy("tests/fixtures/flac/simple/track1.flac", dest).unwrap();
+    File::options()
+        .write(true)
+        .open(dest)
+        .unwrap()
+        .set_modified(mtime)
+        .unwrap();
+}
+
+#[test]
+fn test_parse_rfc3339_utc() {
+    let t = parse_rfc3339("2024-05-01T12:00:00Z").unwrap();
+    assert_eq!(t, UNIX_EPOCH + Duration::from_secs(1_714_564_800));
+}
+
+#[test]
+fn test_parse_rfc3339_offset_and_fraction() {
+    let utc = parse_rfc3339("2024-05-01T10:00:00Z").unwrap();
+    let offset = parse_rfc3339("2024-05-01T12:00:00+02:00").unwrap();
+    assert_eq!(utc, offset);
+
+    let frac = parse_rfc3339("2024-05-01T10:00:00.25Z").unwrap();
+    assert_eq!(
+        frac.duration_since(utc).unwrap(),
+        Duration::from_millis(250)
+    );
+
+    let lower = parse_rfc3339("2024-05-01t04:30:00-05:30").unwrap();
+    assert_eq!(lower, utc);
+}
+
+#[test]
+fn test_parse_rfc3339_leap_day_and_epoch_edges() {
+    assert!(parse_rfc3339("2024-02-29T00:00:00Z").is_ok());
+    assert!(parse_rfc3339("2023-02-29T00:00:00Z").is_err());
+    assert_eq!(parse_rfc3339("1970-01-01T00:00:00Z").unwrap(), UNIX_EPOCH);
+    assert_eq!(
+        parse_rfc3339("1969-12-31T23:59:59Z").unwrap(),
+        UNIX_EPOCH - Duration::from_secs(1)
+    );
+}
+
+#[test]
+fn test_parse_rfc3339_rejects_invalid_input() {
+    for bad in [
+        "",
+        "yesterday",
+        "2024-05-01",
+        "2024-05-01T12:00:00",
+        "2024-13-01T00:00:00Z",
+        "2024-05-01T24:00:00Z",
+        "2024-05-01T12:00:00.Z",
+        "2024-05-01T12:00:00+2:00",
+        "2024/05/01T12:00:00Z",
+    ] {
+        let err = parse_rfc3339(bad).unwrap_err();
+        assert!(
+            err.contains("RFC 3339"),
+            "unexpected error for {bad:?}: {err}"
+        );
+    }
+}
+
+#[test]
+fn test_scan_since_includes_only_newer_files() {
+    let temp_dir = TempDir::new().unwrap();
+    let cutoff = parse_rfc3339("2024-01-01T00:00:00Z").unwrap();
+
+    copy_with_mtime(
+        &temp_dir.path().join("Artist/Album/old.flac"),
+        cutoff - Duration::from_secs(86_400),
+    );
+    copy_with_mtime(
+        &temp_dir.path().join("Artist/Album/new.flac"),
+        cutoff + Duration::from_secs(86_400),
+    );
+
+    let options = ScanOptions {
+        modified_since: Some(cutoff),
+        ..ScanOptions::default()
+    };
+    let tracks = scan_with_options(temp_dir.path(), &options);
+
+    assert_eq!(tracks.len(), 1);
+    assert!(tracks[0].file_path.ends_with("new.flac"));
+}
+
+#[test]
+fn test_scan_without_since_includes_everything() {
+    let temp_dir = TempDir::new().unwrap();
+    copy_with_mtime(&temp_dir.path().join("a.flac"), UNIX_EPOCH);
+    copy_with_mtime(&temp_dir.path().join("b.flac"), SystemTime::now());
+
+    let tracks = scan_with_options(temp_dir.path(), &ScanOptions::default());
+    assert_eq!(tracks.len(), 2);
+}
+
+#[test]
+fn test_scan_since_combines_with_exclude() {
+    let temp_dir = TempDir::new().unwrap();
+    let cutoff = parse_rfc3339("2024-01-01T00:00:00Z").unwrap();
+    let newer = cutoff + Duration::from_secs(60);
+
+    copy_with_mtime(&temp_dir.path().join("keep.flac"), newer);
+    copy_with_mtime(&temp_dir.path().join("skip.flac"), newer);
+
+    let options = ScanOptions {
+        exclude_patterns: vec!["*skip.flac".to_string()],
+        modified_since: Some(cutoff),
+        ..ScanOptions::default()
+    };
+    let tracks = scan_with_options(temp_dir.path(), &options);
+
+    assert_eq!(tracks.len(), 1);
+    assert!(tracks[0].file_path.ends_with("keep.flac"));
+}
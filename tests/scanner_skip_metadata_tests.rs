@@ -27,11 +27,7 @@ mod tests {
             year: year.map(|y| MetadataValue::embedded(y)),
             format: "flac".to_string(),
             path: file_path.clone(),
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
-            genre: None,
-            duration: None,
+            ..Default::default()
         };
         write_metadata(&file_path, &metadata).unwrap();
         file_path
@@ -44,17 +40,9 @@ mod tests {
 
         // Overwrite metadata with empty/none fields
         let metadata = TrackMetadata {
-            title: None,
-            artist: None,
-            album: None,
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
-            year: None,
-            genre: None,
-            duration: None,
             format: "flac".to_string(),
             path: file_path.clone(),
+            ..Default::default()
         };
         write_metadata(&file_path, &metadata).unwrap();
         file_path
@@ -216,4 +204,35 @@ mod tests {
             Some(MetadataSource::Embedded)
         );
     }
+
+    #[test]
+    fn test_scan_skip_metadata_numbered_files_get_track_numbers_not_titles() {
+        use music_chore::core::services::scanner::{ScanOptions, scan_with_options};
+
+        let temp_dir = TempDir::new().unwrap();
+        let album = temp_dir.path().join("Artist").join("Album");
+        fs::create_dir_all(&album).unwrap();
+        for n in 1..=12 {
+            fs::copy(
+                "tests/fixtures/flac/simple/track1.flac",
+                album.join(format!("{:02}.flac", n)),
+            )
+            .unwrap();
+        }
+
+        let options = ScanOptions {
+            skip_metadata: true,
+            ..ScanOptions::default()
+        };
+        let tracks = scan_with_options(temp_dir.path(), &options);
+        assert_eq!(tracks.len(), 12);
+
+        let mut numbers: Vec<u32> = tracks
+            .iter()
+            .map(|t| t.metadata.track_number.as_ref().unwrap().value)
+            .collect();
+        numbers.sort();
+        assert_eq!(numbers, (1..=12).collect::<Vec<_>>());
+        assert!(tracks.iter().all(|t| t.metadata.title.is_none()));
+    }
 }
@@ -0,0 +1,90 @@
+//! Tests for ordering scan output by metadata (`scan --sort metadata`).
+
+use music_chore::core::builders::TrackBuilder;
+use music_chore::core::services::scanner::{
+    ScanOptions, ScanSort, compare_by_metadata, parse_scan_sort, scan_with_options,
+};
+use std::path::Path;
+
+#[test]
+fn test_compare_by_metadata_orders_artist_album_disc_track() {
+    let mut tracks = vec![
+        TrackBuilder::new("/m/z.flac")
+            .artist("beta")
+            .album("One")
+            .track_number(1)
+            .build(),
+        TrackBuilder::new("/m/y.flac")
+            .artist("Alpha")
+            .album("Two")
+            .track_number(1)
+            .build(),
+        TrackBuilder::new("/m/x.flac")
+            .artist("Alpha")
+            .album("One")
+            .disc_number(2)
+            .track_number(1)
+            .build(),
+        TrackBuilder::new("/m/w.flac")
+            .artist("Alpha")
+            .album("One")
+            .disc_number(1)
+            .track_number(2)
+            .build(),
+        TrackBuilder::new("/m/v.flac")
+            .artist("Alpha")
+            .album("One")
+            .disc_number(1)
+            .track_number(1)
+            .build(),
+    ];
+
+    tracks.sort_by(compare_by_metadata);
+
+    let order: Vec<_> = tracks
+        .iter()
+        .map(|t| t.file_path.to_str().unwrap())
+        .collect();
+    assert_eq!(
+        order,
+        vec![
+            "/m/v.flac",
+            "/m/w.flac",
+            "/m/x.flac",
+            "/m/y.flac",
+            "/m/z.flac"
+        ]
+    );
+}
+
+#[test]
+fn test_compare_by_metadata_ties_fall_back_to_path() {
+    let a = TrackBuilder::new("/m/a.flac").title("Same").build();
+    let b = TrackBuilder::new("/m/b.flac").title("Same").build();
+
+    assert_eq!(compare_by_metadata(&a, &b), std::cmp::Ordering::Less);
+    assert_eq!(compare_by_metadata(&b, &a), std::cmp::Ordering::Greater);
+}
+
+#[test]
+fn test_parse_scan_sort() {
+    assert_eq!(parse_scan_sort("filename"), Ok(ScanSort::Filename));
+    assert_eq!(parse_scan_sort("Metadata"), Ok(ScanSort::Metadata));
+    assert!(parse_scan_sort("size").is_err());
+}
+
+#[test]
+fn test_scan_with_options_sorts_by_metadata() {
+    let options = ScanOptions {
+        sort: ScanSort::Metadata,
+        ..ScanOptions::default()
+    };
+    let tracks = scan_with_options(Path::new("tests/fixtures/flac/simple"), &options);
+
+    assert_eq!(tracks.len(), 2);
+    assert!(
+        tracks
+            .windows(2)
+            .all(|w| compare_by_metadata(&w[0], &w[1]).is_le())
+    );
+}
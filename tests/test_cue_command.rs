@@ -167,3 +167,136 @@ fn test_cue_content_format() {
     assert!(stdout.contains("TRACK"));
     assert!(stdout.contains("INDEX 01"));
 }
+
+fn single_file_rip(index: &str) -> TempDir {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    // track1.flac is exactly one second long
+    fs::copy(
+        "tests/fixtures/flac/simple/track1.flac",
+        temp_dir.path().join("album.flac"),
+    )
+    .unwrap();
+    fs::write(
+        temp_dir.path().join("album.cue"),
+        format!(
+            "FILE \"album.flac\" WAVE\n  TRACK 01 AUDIO\n    INDEX 01 00:00:00\n  TRACK 02 AUDIO\n    INDEX 01 {}\n",
+            index
+        ),
+    )
+    .unwrap();
+    temp_dir
+}
+
+#[test]
+fn test_cue_check_duration_consistent() {
+    let temp_dir = single_file_rip("00:00:30");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .args(&["cue", "--check-duration"])
+        .arg(temp_dir.path().join("album.cue"))
+        .output()
+        .expect("Failed to run cue command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).expect("Invalid UTF-8");
+    assert!(stdout.contains("album.flac: OK"));
+    assert!(stdout.contains("Last INDEX: 00:00:30 (2 track(s))"));
+}
+
+#[test]
+fn test_cue_check_duration_flags_truncated_audio() {
+    let temp_dir = single_file_rip("00:01:10");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .args(&["cue", "--check-duration", "--json"])
+        .arg(temp_dir.path().join("album.cue"))
+        .output()
+        .expect("Failed to run cue command");
+
+    assert!(!output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let report = &json["data"][0];
+    assert_eq!(report["status"], "truncated");
+    assert_eq!(report["last_index"], "00:01:10");
+    assert!(report["last_track_duration"].as_f64().unwrap() < 0.0);
+}
+
+#[test]
+fn test_cue_check_duration_missing_audio() {
+    let temp_dir = single_file_rip("00:00:30");
+    fs::remove_file(temp_dir.path().join("album.flac")).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .args(&["cue", "--check-duration"])
+        .arg(temp_dir.path().join("album.cue"))
+        .output()
+        .expect("Failed to run cue command");
+
+    assert!(!output.status.success());
+    let stdout = String::from_utf8(output.stdout).expect("Invalid UTF-8");
+    assert!(stdout.contains("UNKNOWN"));
+    assert!(stdout.contains("Error:"));
+}
+
+/// Two albums by the fixture's album artist under a temporary library.
+fn two_album_library() -> TempDir {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    for album in ["One", "Two"] {
+        let dir = temp_dir.path().join("Band").join(album);
+        fs::create_dir_all(&dir).unwrap();
+        fs::copy(
+            "tests/fixtures/flac/simple/track1.flac",
+            dir.join("01.flac"),
+        )
+        .unwrap();
+    }
+    temp_dir
+}
+
+fn cue_recursive(base: &std::path::Path, template: Option<&str>) -> std::process::Output {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_musicctl"));
+    command.args(["cue", "--generate", "--recursive"]).arg(base);
+    if let Some(template) = template {
+        command.args(["--output-template", template]);
+    }
+    command.output().expect("Failed to run cue command")
+}
+
+#[test]
+fn test_cue_recursive_writes_next_to_audio_by_default() {
+    let library = two_album_library();
+
+    let output = cue_recursive(library.path(), None);
+
+    assert!(output.status.success());
+    assert!(library.path().join("Band/One/One.cue").exists());
+    assert!(library.path().join("Band/Two/Two.cue").exists());
+}
+
+#[test]
+fn test_cue_recursive_output_template_mirrors_library() {
+    let library = two_album_library();
+
+    let output = cue_recursive(library.path(), Some("cues/{album_dir}/{album}.cue"));
+
+    assert!(output.status.success());
+    assert!(library.path().join("cues/Band/One/One.cue").exists());
+    assert!(library.path().join("cues/Band/Two/Two.cue").exists());
+    assert!(!library.path().join("Band/One/One.cue").exists());
+}
+
+#[test]
+fn test_cue_recursive_rejects_colliding_or_escaping_templates() {
+    let library = two_album_library();
+
+    // Both albums share an artist, so they would land on the same file
+    let output = cue_recursive(library.path(), Some("{artist}.cue"));
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("both at"));
+
+    let output = cue_recursive(library.path(), Some("../{album}.cue"));
+    assert!(!output.status.success());
+
+    assert!(!library.path().join("Test Album Artist.cue").exists());
+    assert!(!library.path().join("Band/One/One.cue").exists());
+}
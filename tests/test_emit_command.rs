@@ -142,3 +142,51 @@ fn test_emit_command_help() {
     assert!(stdout.contains("--json"));
     assert!(stdout.contains("PATH"));
 }
+
+#[test]
+fn test_emit_command_genre_roots_from_genre_map() {
+    let temp_dir = TempDir::new().unwrap();
+    let map_path = temp_dir.path().join("genres.tsv");
+    std::fs::write(&map_path, "test genre\tIndie\tRock\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .args(&["emit", "tests/fixtures/flac/simple", "--genre-roots"])
+        .arg("--genre-map")
+        .arg(&map_path)
+        .output()
+        .expect("Failed to run emit command with genre roots");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).expect("Invalid UTF-8");
+    let parsed: serde_json::Value = serde_json::from_str(&stdout).expect("Invalid JSON");
+    let roots: Vec<&serde_json::Value> = parsed["artists"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .flat_map(|artist| artist["albums"].as_array().unwrap())
+        .flat_map(|album| album["tracks"].as_array().unwrap())
+        .filter_map(|track| track.get("genre_root"))
+        .collect();
+    assert!(!roots.is_empty());
+    assert!(roots.iter().all(|root| *root == "Rock"));
+}
+
+#[test]
+fn test_emit_command_decades_json() {
+    let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .args(["emit", "tests/fixtures/flac/simple", "--decades", "--json"])
+        .output()
+        .expect("Failed to run emit --decades");
+
+    assert!(output.status.success());
+    let parsed: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Output should be valid JSON");
+
+    let dated: u64 = parsed["decades"]
+        .as_object()
+        .expect("decades should be an object")
+        .values()
+        .map(|count| count.as_u64().unwrap())
+        .sum();
+    assert_eq!(dated + parsed["unknown"].as_u64().unwrap(), 2);
+}
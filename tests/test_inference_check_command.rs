@@ -0,0 +1,32 @@
+use std::process::Command;
+
+#[test]
+fn test_inference_check_command_json() {
+    let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .args([
+            "inference-check",
+            "tests/fixtures/flac/simple",
+            "--json",
+            "--worst",
+            "1",
+        ])
+        .output()
+        .expect("Failed to run inference-check");
+
+    assert!(output.status.success());
+    let parsed: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Output should be valid JSON");
+
+    assert!(parsed["compared"].as_u64().is_some());
+    assert!(parsed["worst_folders"].as_array().unwrap().len() <= 1);
+}
+
+#[test]
+fn test_inference_check_command_missing_path() {
+    let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .args(["inference-check", "/nonexistent/path"])
+        .output()
+        .expect("Failed to run inference-check");
+
+    assert!(!output.status.success());
+}
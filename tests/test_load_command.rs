@@ -0,0 +1,67 @@
+use std::process::Command;
+use tempfile::TempDir;
+
+fn emit_library_json(dir: &TempDir) -> std::path::PathBuf {
+    let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .args(["emit", "tests/fixtures/flac/simple", "--json"])
+        .output()
+        .expect("Failed to run emit command");
+    assert!(output.status.success());
+
+    let path = dir.path().join("library.json");
+    std::fs::write(&path, output.stdout).unwrap();
+    path
+}
+
+#[test]
+fn test_load_command_tree_stats_and_validate() {
+    let dir = TempDir::new().unwrap();
+    let library_json = emit_library_json(&dir);
+
+    let tree = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .arg("load")
+        .arg(&library_json)
+        .output()
+        .expect("Failed to run load command");
+    assert!(tree.status.success());
+    assert!(String::from_utf8_lossy(&tree.stdout).contains("track1.flac"));
+
+    let stats = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .arg("load")
+        .arg(&library_json)
+        .args(["--stats", "--json"])
+        .output()
+        .expect("Failed to run load --stats");
+    assert!(stats.status.success());
+    let parsed: serde_json::Value = serde_json::from_slice(&stats.stdout).unwrap();
+    assert!(parsed["artists"].is_array());
+
+    let validate = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .arg("load")
+        .arg(&library_json)
+        .args(["--validate", "--json"])
+        .output()
+        .expect("Failed to run load --validate");
+    assert!(validate.status.success());
+    let parsed: serde_json::Value = serde_json::from_slice(&validate.stdout).unwrap();
+    assert_eq!(parsed["summary"]["total_files"], 2);
+}
+
+#[test]
+fn test_load_command_rejects_schema_version_mismatch() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("library.json");
+    std::fs::write(
+        &path,
+        r#"{"__schema_version": "9.9.9", "artists": [], "total_tracks": 0, "total_artists": 0, "total_albums": 0, "total_files": 0}"#,
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .arg("load")
+        .arg(&path)
+        .output()
+        .expect("Failed to run load command");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Unsupported schema version"));
+}
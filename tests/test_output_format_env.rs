@@ -0,0 +1,67 @@
+use music_chore::presentation::cli::{OUTPUT_FORMAT_ENV, OutputFormat, resolve_output_format};
+use std::process::Command;
+
+#[test]
+fn test_resolve_output_format_precedence() {
+    // CLI flags win over the environment
+    assert_eq!(
+        resolve_output_format(true, false, Some(OutputFormat::Text)),
+        OutputFormat::Json
+    );
+    assert_eq!(
+        resolve_output_format(false, true, Some(OutputFormat::Json)),
+        OutputFormat::Text
+    );
+    // The environment wins over the built-in default
+    assert_eq!(
+        resolve_output_format(false, false, Some(OutputFormat::Json)),
+        OutputFormat::Json
+    );
+    assert_eq!(
+        resolve_output_format(false, false, None),
+        OutputFormat::Text
+    );
+}
+
+#[test]
+fn test_output_format_parse() {
+    assert_eq!(OutputFormat::parse(" JSON "), Some(OutputFormat::Json));
+    assert_eq!(OutputFormat::parse("text"), Some(OutputFormat::Text));
+    assert_eq!(OutputFormat::parse("yaml"), None);
+}
+
+#[test]
+fn test_env_var_selects_json_and_text_flag_overrides_it() {
+    let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .args(["emit", "tests/fixtures/flac/simple"])
+        .env(OUTPUT_FORMAT_ENV, "json")
+        .output()
+        .expect("Failed to run emit command");
+    assert!(output.status.success());
+    let parsed: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Output should be valid JSON");
+    assert_eq!(parsed["total_tracks"], 2);
+
+    let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .args(["emit", "tests/fixtures/flac/simple", "--text"])
+        .env(OUTPUT_FORMAT_ENV, "json")
+        .output()
+        .expect("Failed to run emit command");
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("=== MUSIC LIBRARY METADATA ==="));
+}
+
+#[test]
+fn test_env_json_does_not_override_tree_depth() {
+    let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .args(["tree", "tests/fixtures/flac/simple", "--depth", "artist"])
+        .env(OUTPUT_FORMAT_ENV, "json")
+        .output()
+        .expect("Failed to run tree command");
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(serde_json::from_str::<serde_json::Value>(&stdout).is_err());
+    assert!(stdout.contains("📁"));
+    assert!(!stdout.contains("Test Album"));
+}
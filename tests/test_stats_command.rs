@@ -0,0 +1,48 @@
+use std::process::Command;
+
+#[test]
+fn test_stats_command_json_respects_top() {
+    let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .args([
+            "stats",
+            "tests/fixtures/flac/simple",
+            "--json",
+            "--top",
+            "1",
+        ])
+        .output()
+        .expect("Failed to run stats");
+
+    assert!(output.status.success());
+    let parsed: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Output should be valid JSON");
+
+    assert_eq!(parsed["total_tracks"], 2);
+    for section in ["top_artists", "top_albums", "top_genres"] {
+        assert!(parsed[section].as_array().unwrap().len() <= 1);
+    }
+}
+
+#[test]
+fn test_stats_command_text_output() {
+    let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .args(["stats", "tests/fixtures/flac/simple"])
+        .output()
+        .expect("Failed to run stats");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("=== LIBRARY STATS ==="));
+    assert!(stdout.contains("TOP ARTISTS:"));
+    assert!(stdout.contains("TOP GENRES:"));
+}
+
+#[test]
+fn test_stats_command_nonexistent_path() {
+    let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .args(["stats", "/nonexistent/path"])
+        .output()
+        .expect("Failed to run stats");
+
+    assert!(!output.status.success());
+}
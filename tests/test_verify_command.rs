@@ -0,0 +1,45 @@
+use std::process::Command;
+use tempfile::TempDir;
+
+#[test]
+fn test_verify_command_json_per_file_results() {
+    let dir = TempDir::new().unwrap();
+    std::fs::copy(
+        "tests/fixtures/flac/simple/track1.flac",
+        dir.path().join("good.flac"),
+    )
+    .unwrap();
+    std::fs::write(dir.path().join("broken.flac"), b"not a flac").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .args(["verify", dir.path().to_str().unwrap(), "--json"])
+        .output()
+        .expect("Failed to run verify");
+
+    assert_eq!(output.status.code(), Some(1));
+    let parsed: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Output should be valid JSON");
+    let results = parsed.as_array().unwrap();
+    assert_eq!(results.len(), 2);
+    assert!(
+        results[0]["path"]
+            .as_str()
+            .unwrap()
+            .ends_with("broken.flac")
+    );
+    assert_eq!(results[0]["ok"], false);
+    assert!(results[0]["error"].is_string());
+    assert_eq!(results[1]["ok"], true);
+}
+
+#[test]
+fn test_verify_command_text_summary() {
+    let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .args(["verify", "tests/fixtures/flac/simple"])
+        .output()
+        .expect("Failed to run verify");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("2 passed, 0 failed (2 files)"));
+}
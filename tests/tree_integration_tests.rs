@@ -32,19 +32,16 @@ mod tests {
                     source: MetadataSource::Embedded,
                     confidence: 1.0,
                 }),
-                album_artist: None,
                 track_number: Some(MetadataValue {
                     value: 1,
                     source: MetadataSource::Embedded,
                     confidence: 1.0,
                 }),
-                disc_number: None,
                 year: Some(MetadataValue {
                     value: 2023,
                     source: MetadataSource::Embedded,
                     confidence: 1.0,
                 }),
-                genre: None,
                 duration: Some(MetadataValue {
                     value: 180.5,
                     source: MetadataSource::Embedded,
@@ -52,6 +49,7 @@ mod tests {
                 }),
                 format: "flac".to_string(),
                 path: PathBuf::from("Test Artist/First Album/01 Track.flac"),
+                ..Default::default()
             },
         };
 
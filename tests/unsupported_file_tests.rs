@@ -1,5 +1,8 @@
-use music_chore::core::services::scanner::{scan_dir, scan_dir_with_depth};
+use music_chore::core::services::scanner::{
+    scan_dir, scan_dir_with_depth, scan_dir_with_metadata, scan_single_file,
+};
 use std::fs;
+use std::process::Command;
 use tempfile::TempDir;
 
 #[test]
@@ -210,3 +213,71 @@ fn test_scan_dir_with_depth_limits_warnings() {
     let tracks_depth_2 = scan_dir_with_depth(source_path, Some(2));
     assert_eq!(tracks_depth_2.len(), 3);
 }
+
+#[test]
+fn test_scan_single_file_reads_one_track() {
+    let track = scan_single_file(std::path::Path::new(
+        "tests/fixtures/flac/simple/track1.flac",
+    ))
+    .unwrap();
+    assert!(track.file_path.ends_with("track1.flac"));
+
+    let tracks = scan_dir_with_metadata(std::path::Path::new(
+        "tests/fixtures/flac/simple/track1.flac",
+    ))
+    .unwrap();
+    assert_eq!(tracks.len(), 1);
+}
+
+#[test]
+fn test_scan_single_file_explains_unsupported_and_empty_files() {
+    let temp_dir = TempDir::new().unwrap();
+    let aiff = temp_dir.path().join("song.aiff");
+    fs::write(&aiff, "fake aiff content").unwrap();
+    let empty = temp_dir.path().join("empty.flac");
+    fs::write(&empty, "").unwrap();
+
+    let err = scan_single_file(&aiff).unwrap_err();
+    assert!(err.starts_with("Unsupported audio format"));
+    assert!(err.contains("flac"));
+    assert!(
+        scan_single_file(&empty)
+            .unwrap_err()
+            .contains("File is empty")
+    );
+}
+
+#[test]
+fn test_commands_accept_a_single_file() {
+    for (command, expected) in [
+        ("scan", "track1.flac"),
+        ("emit", "track1.flac"),
+        ("stats", "\"total_tracks\": 1"),
+        ("validate", "\"total_files\": 1"),
+    ] {
+        let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+            .args([command, "tests/fixtures/flac/simple/track1.flac", "--json"])
+            .output()
+            .expect("Failed to run musicctl");
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(stdout.contains(expected), "{}: {}", command, stdout);
+    }
+}
+
+#[test]
+fn test_scan_unsupported_single_file_fails_with_reason() {
+    let temp_dir = TempDir::new().unwrap();
+    let aiff = temp_dir.path().join("song.aiff");
+    fs::write(&aiff, "fake aiff content").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .arg("scan")
+        .arg(&aiff)
+        .output()
+        .expect("Failed to run scan");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Unsupported audio format"));
+    assert!(!stderr.contains("No music files found"));
+}
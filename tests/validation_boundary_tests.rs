@@ -11,14 +11,11 @@ fn create_test_track_with_year(year: Option<u32>) -> Track {
             title: Some(MetadataValue::embedded("Test Title".to_string())),
             artist: Some(MetadataValue::embedded("Test Artist".to_string())),
             album: Some(MetadataValue::embedded("Test Album".to_string())),
-            album_artist: None,
             track_number: Some(MetadataValue::embedded(1)),
-            disc_number: None,
             year: year.map(|y| MetadataValue::embedded(y)),
-            genre: None,
-            duration: None,
             format: "flac".to_string(),
             path: PathBuf::from("test.flac"),
+            ..Default::default()
         },
     )
 }
@@ -30,14 +27,11 @@ fn create_test_track_with_track_number(track_number: Option<u32>) -> Track {
             title: Some(MetadataValue::embedded("Test Title".to_string())),
             artist: Some(MetadataValue::embedded("Test Artist".to_string())),
             album: Some(MetadataValue::embedded("Test Album".to_string())),
-            album_artist: None,
             track_number: track_number.map(|t| MetadataValue::embedded(t)),
-            disc_number: None,
             year: Some(MetadataValue::embedded(2020)),
-            genre: None,
-            duration: None,
             format: "flac".to_string(),
             path: PathBuf::from("test.flac"),
+            ..Default::default()
         },
     )
 }
@@ -49,14 +43,12 @@ fn create_test_track_with_disc_number(disc_number: Option<u32>) -> Track {
             title: Some(MetadataValue::embedded("Test Title".to_string())),
             artist: Some(MetadataValue::embedded("Test Artist".to_string())),
             album: Some(MetadataValue::embedded("Test Album".to_string())),
-            album_artist: None,
             track_number: Some(MetadataValue::embedded(1)),
             disc_number: disc_number.map(|d| MetadataValue::embedded(d)),
             year: Some(MetadataValue::embedded(2020)),
-            genre: None,
-            duration: None,
             format: "flac".to_string(),
             path: PathBuf::from("test.flac"),
+            ..Default::default()
         },
     )
 }
@@ -83,7 +75,7 @@ fn test_validate_year_boundary_below_minimum() {
     let year_warnings: Vec<_> = result
         .warnings
         .iter()
-        .filter(|w| w.field == "year")
+        .filter(|w| w.category == "year")
         .collect();
     assert!(!year_warnings.is_empty());
 }
@@ -110,7 +102,7 @@ fn test_validate_year_boundary_above_maximum() {
     let year_warnings: Vec<_> = result
         .warnings
         .iter()
-        .filter(|w| w.field == "year")
+        .filter(|w| w.category == "year")
         .collect();
     assert!(!year_warnings.is_empty());
 }
@@ -147,7 +139,7 @@ fn test_validate_track_number_zero() {
     let track_warnings: Vec<_> = result
         .warnings
         .iter()
-        .filter(|w| w.field == "track_number")
+        .filter(|w| w.category == "track_number")
         .collect();
     assert!(!track_warnings.is_empty());
 }
@@ -184,7 +176,7 @@ fn test_validate_track_number_one_hundred() {
     let track_warnings: Vec<_> = result
         .warnings
         .iter()
-        .filter(|w| w.field == "track_number")
+        .filter(|w| w.category == "track_number")
         .collect();
     assert!(!track_warnings.is_empty());
 }
@@ -206,14 +198,9 @@ fn test_validate_empty_title() {
             title: Some(MetadataValue::embedded("".to_string())),
             artist: Some(MetadataValue::embedded("Test Artist".to_string())),
             album: Some(MetadataValue::embedded("Test Album".to_string())),
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
-            year: None,
-            genre: None,
-            duration: None,
             format: "flac".to_string(),
             path: PathBuf::from("test.flac"),
+            ..Default::default()
         },
     );
 
@@ -223,7 +210,7 @@ fn test_validate_empty_title() {
     let title_errors: Vec<_> = result
         .errors
         .iter()
-        .filter(|e| e.field == "title")
+        .filter(|e| e.category == "title")
         .collect();
     assert!(!title_errors.is_empty());
 }
@@ -236,14 +223,9 @@ fn test_validate_whitespace_only_title() {
             title: Some(MetadataValue::embedded("   ".to_string())),
             artist: Some(MetadataValue::embedded("Test Artist".to_string())),
             album: Some(MetadataValue::embedded("Test Album".to_string())),
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
-            year: None,
-            genre: None,
-            duration: None,
             format: "flac".to_string(),
             path: PathBuf::from("test.flac"),
+            ..Default::default()
         },
     );
 
@@ -256,17 +238,9 @@ fn test_validate_missing_required_fields() {
     let track = Track::new(
         PathBuf::from("test.flac"),
         TrackMetadata {
-            title: None,
-            artist: None,
-            album: None,
-            album_artist: None,
-            track_number: None,
-            disc_number: None,
-            year: None,
-            genre: None,
-            duration: None,
             format: "flac".to_string(),
             path: PathBuf::from("test.flac"),
+            ..Default::default()
         },
     );
 
@@ -291,6 +265,7 @@ fn test_validate_perfect_metadata() {
             duration: Some(MetadataValue::embedded(180.5)),
             format: "flac".to_string(),
             path: PathBuf::from("test.flac"),
+            ..Default::default()
         },
     );
 
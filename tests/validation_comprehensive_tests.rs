@@ -23,14 +23,11 @@ fn create_test_track_with_metadata(
             title: title.map(|t| MetadataValue::embedded(t.to_string())),
             artist: artist.map(|a| MetadataValue::embedded(a.to_string())),
             album: album.map(|a| MetadataValue::embedded(a.to_string())),
-            album_artist: None,
             track_number: track_number.map(|n| MetadataValue::embedded(n)),
-            disc_number: None,
             year: year.map(|y| MetadataValue::embedded(y)),
-            genre: None,
-            duration: None,
             format: "flac".to_string(),
             path: PathBuf::from(path),
+            ..Default::default()
         },
     )
 }
@@ -345,7 +342,6 @@ fn test_validate_tracks_invalid_track_number() {
             title: Some(MetadataValue::embedded("Test Title".to_string())),
             artist: Some(MetadataValue::embedded("Test Artist".to_string())),
             album: Some(MetadataValue::embedded("Test Album".to_string())),
-            album_artist: None,
             track_number: Some(MetadataValue::embedded(0)), // Invalid: 0 is not allowed
             disc_number: Some(MetadataValue::embedded(1)),
             year: Some(MetadataValue::embedded(2023)),
@@ -353,6 +349,7 @@ fn test_validate_tracks_invalid_track_number() {
             duration: Some(MetadataValue::embedded(180.5)),
             format: "flac".to_string(),
             path: file_path.clone(),
+            ..Default::default()
         },
     );
 
@@ -383,7 +380,6 @@ fn test_validate_tracks_invalid_disc_number() {
             title: Some(MetadataValue::embedded("Test Title".to_string())),
             artist: Some(MetadataValue::embedded("Test Artist".to_string())),
             album: Some(MetadataValue::embedded("Test Album".to_string())),
-            album_artist: None,
             track_number: Some(MetadataValue::embedded(1)),
             disc_number: Some(MetadataValue::embedded(0)), // Invalid: 0 is not allowed
             year: Some(MetadataValue::embedded(2023)),
@@ -391,6 +387,7 @@ fn test_validate_tracks_invalid_disc_number() {
             duration: Some(MetadataValue::embedded(180.5)),
             format: "flac".to_string(),
             path: file_path.clone(),
+            ..Default::default()
         },
     );
 
@@ -421,7 +418,6 @@ fn test_validate_tracks_invalid_year() {
             title: Some(MetadataValue::embedded("Test Title".to_string())),
             artist: Some(MetadataValue::embedded("Test Artist".to_string())),
             album: Some(MetadataValue::embedded("Test Album".to_string())),
-            album_artist: None,
             track_number: Some(MetadataValue::embedded(1)),
             disc_number: Some(MetadataValue::embedded(1)),
             year: Some(MetadataValue::embedded(500)), // Invalid: below MIN_YEAR (1000)
@@ -429,6 +425,7 @@ fn test_validate_tracks_invalid_year() {
             duration: Some(MetadataValue::embedded(180.5)),
             format: "flac".to_string(),
             path: file_path.clone(),
+            ..Default::default()
         },
     );
 
@@ -456,7 +453,6 @@ fn test_validate_tracks_future_year() {
             title: Some(MetadataValue::embedded("Test Title".to_string())),
             artist: Some(MetadataValue::embedded("Test Artist".to_string())),
             album: Some(MetadataValue::embedded("Test Album".to_string())),
-            album_artist: None,
             track_number: Some(MetadataValue::embedded(1)),
             disc_number: Some(MetadataValue::embedded(1)),
             year: Some(MetadataValue::embedded(3001)), // Invalid: above MAX_YEAR (3000)
@@ -464,6 +460,7 @@ fn test_validate_tracks_future_year() {
             duration: Some(MetadataValue::embedded(180.5)),
             format: "flac".to_string(),
             path: file_path.clone(),
+            ..Default::default()
         },
     );
 
@@ -491,7 +488,6 @@ fn test_validate_tracks_valid_year_range() {
             title: Some(MetadataValue::embedded("Test Title".to_string())),
             artist: Some(MetadataValue::embedded("Test Artist".to_string())),
             album: Some(MetadataValue::embedded("Test Album".to_string())),
-            album_artist: None,
             track_number: Some(MetadataValue::embedded(1)),
             disc_number: Some(MetadataValue::embedded(1)),
             year: Some(MetadataValue::embedded(1000)), // Valid: at MIN_YEAR bound
@@ -499,6 +495,7 @@ fn test_validate_tracks_valid_year_range() {
             duration: Some(MetadataValue::embedded(180.5)),
             format: "flac".to_string(),
             path: file_path.clone(),
+            ..Default::default()
         },
     );
 
@@ -518,7 +515,6 @@ fn test_validate_tracks_empty_string_fields() {
             title: Some(MetadataValue::embedded("".to_string())), // Empty string
             artist: Some(MetadataValue::embedded("Test Artist".to_string())),
             album: Some(MetadataValue::embedded("Test Album".to_string())),
-            album_artist: None,
             track_number: Some(MetadataValue::embedded(1)),
             disc_number: Some(MetadataValue::embedded(1)),
             year: Some(MetadataValue::embedded(2023)),
@@ -526,6 +522,7 @@ fn test_validate_tracks_empty_string_fields() {
             duration: Some(MetadataValue::embedded(180.5)),
             format: "flac".to_string(),
             path: file_path.clone(),
+            ..Default::default()
         },
     );
 
@@ -556,7 +553,6 @@ fn test_validate_tracks_whitespace_only_fields() {
             title: Some(MetadataValue::embedded("   ".to_string())), // Whitespace only
             artist: Some(MetadataValue::embedded("Test Artist".to_string())),
             album: Some(MetadataValue::embedded("Test Album".to_string())),
-            album_artist: None,
             track_number: Some(MetadataValue::embedded(1)),
             disc_number: Some(MetadataValue::embedded(1)),
             year: Some(MetadataValue::embedded(2023)),
@@ -564,6 +560,7 @@ fn test_validate_tracks_whitespace_only_fields() {
             duration: Some(MetadataValue::embedded(180.5)),
             format: "flac".to_string(),
             path: file_path.clone(),
+            ..Default::default()
         },
     );
 
@@ -595,7 +592,6 @@ fn test_validate_tracks_different_metadata_sources() {
             title: Some(MetadataValue::cue_inferred("CUE Title".to_string(), 1.0)),
             artist: Some(MetadataValue::inferred("Folder Artist".to_string(), 0.3)),
             album: Some(MetadataValue::user_set("User Album".to_string())),
-            album_artist: None,
             track_number: Some(MetadataValue::embedded(1)),
             disc_number: Some(MetadataValue::embedded(1)),
             year: Some(MetadataValue::embedded(2023)),
@@ -603,6 +599,7 @@ fn test_validate_tracks_different_metadata_sources() {
             duration: Some(MetadataValue::embedded(180.5)),
             format: "flac".to_string(),
             path: file_path.clone(),
+            ..Default::default()
         },
     );
 
@@ -622,7 +619,6 @@ fn test_validate_tracks_max_values() {
             title: Some(MetadataValue::embedded("Test Title".to_string())),
             artist: Some(MetadataValue::embedded("Test Artist".to_string())),
             album: Some(MetadataValue::embedded("Test Album".to_string())),
-            album_artist: None,
             track_number: Some(MetadataValue::embedded(1000)), // Exceeds MAX_TRACK_NUMBER (999)
             disc_number: Some(MetadataValue::embedded(100)),   // Exceeds MAX_DISC_NUMBER (99)
             year: Some(MetadataValue::embedded(2023)),
@@ -630,6 +626,7 @@ fn test_validate_tracks_max_values() {
             duration: Some(MetadataValue::embedded(180.5)),
             format: "flac".to_string(),
             path: file_path.clone(),
+            ..Default::default()
         },
     );
 
@@ -1,4 +1,7 @@
-use music_chore::core::services::validation::validate_path;
+use music_chore::core::services::validation::{
+    Severity, ValidateOptions, parse_severity, validate_path, validate_path_with_options,
+    validate_path_with_template,
+};
 use std::fs;
 use std::path::PathBuf;
 use tempfile::TempDir;
@@ -281,3 +284,195 @@ fn test_validate_path_json_structure() {
     assert!(summary.get("files_with_errors").is_some());
     assert!(summary.get("files_with_warnings").is_some());
 }
+
+const TEMPLATE: &str = "{artist}/{album}/{track} - {title}.{ext}";
+
+#[test]
+fn test_validate_path_with_template_conforming_layout() {
+    let temp_dir = TempDir::new().unwrap();
+    let album_dir = temp_dir.path().join("Test Artist/Test Album");
+    fs::create_dir_all(&album_dir).unwrap();
+    fs::copy(
+        "tests/fixtures/flac/simple/track1.flac",
+        album_dir.join("01 - Test Apply Behavior.flac"),
+    )
+    .unwrap();
+
+    let output =
+        validate_path_with_template(&temp_dir.path().to_path_buf(), true, Some(TEMPLATE)).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(json["structure_violations"].as_array().unwrap().len(), 0);
+}
+
+#[test]
+fn test_validate_path_with_template_reports_misplaced_file() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::create_dir_all(temp_dir.path().join("artist/album")).unwrap();
+    fs::copy(
+        "tests/fixtures/flac/simple/track1.flac",
+        temp_dir.path().join("artist/album/track1.flac"),
+    )
+    .unwrap();
+
+    let output =
+        validate_path_with_template(&temp_dir.path().to_path_buf(), false, Some(TEMPLATE)).unwrap();
+    assert!(output.contains("STRUCTURE (1 files not matching template)"));
+    assert!(output.contains("Test Artist/Test Album/01 - Test Apply Behavior.flac"));
+}
+
+#[test]
+fn test_validate_path_with_invalid_template() {
+    let temp_dir = TempDir::new().unwrap();
+    let result = validate_path_with_template(
+        &temp_dir.path().to_path_buf(),
+        false,
+        Some("{artist}/{bogus}"),
+    );
+    assert!(result.unwrap_err().contains("Unknown placeholder {bogus}"));
+}
+
+#[test]
+fn test_validate_path_fail_on_unknown_passes_tagged_library() {
+    let temp_dir = TempDir::new().unwrap();
+    let album_dir = temp_dir.path().join("Artist/Album");
+    fs::create_dir_all(&album_dir).unwrap();
+    fs::copy(
+        "tests/fixtures/flac/simple/track1.flac",
+        album_dir.join("track1.flac"),
+    )
+    .unwrap();
+
+    let options = ValidateOptions {
+        fail_on_unknown: Some(0.0),
+        ..ValidateOptions::default()
+    };
+    let output = validate_path_with_options(&temp_dir.path().to_path_buf(), true, &options)
+        .expect("no tracks fall under placeholders");
+
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(json["placeholders"]["affected_tracks"], 0);
+    assert_eq!(json["placeholders"]["percentage"], 0.0);
+}
+
+#[test]
+fn test_validate_path_single_file_cue_album_uses_cue_tracks() {
+    let temp_dir = TempDir::new().unwrap();
+    let album_dir = temp_dir.path().join("Artist/Album");
+    fs::create_dir_all(&album_dir).unwrap();
+    fs::copy(
+        "tests/fixtures/flac/simple/track1.flac",
+        album_dir.join("Artist - Album.flac"),
+    )
+    .unwrap();
+    fs::write(
+        album_dir.join("Album.cue"),
+        r#"PERFORMER "Artist"
+TITLE "Album"
+FILE "Artist - Album.flac" WAVE
+  TRACK 01 AUDIO
+    TITLE "First"
+    INDEX 01 00:00:00
+  TRACK 02 AUDIO
+    TITLE "Second"
+    INDEX 01 03:00:00
+  TRACK 03 AUDIO
+    TITLE "Third"
+    INDEX 01 06:00:00
+"#,
+    )
+    .unwrap();
+
+    let options = ValidateOptions {
+        template: Some("{artist}/{album}/{track} - {title}.{ext}".to_string()),
+        ..ValidateOptions::default()
+    };
+    let output = validate_path_with_options(&temp_dir.path().to_path_buf(), true, &options)
+        .expect("validation should succeed");
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+    assert_eq!(json["summary"]["total_files"], 3);
+    let warnings = json["warnings"].as_array().unwrap();
+    assert!(
+        warnings.iter().all(|w| w["category"] != "track_number"),
+        "CUE track numbers should not be reported: {:?}",
+        warnings
+    );
+    assert!(json["structure_violations"].as_array().unwrap().is_empty());
+}
+
+fn library_with_blank_titles(count: usize) -> TempDir {
+    use music_chore::adapters::audio_formats::{read_metadata, write_metadata};
+
+    let temp_dir = TempDir::new().unwrap();
+    let album_dir = temp_dir.path().join("Artist/Album");
+    fs::create_dir_all(&album_dir).unwrap();
+    for i in 1..=count {
+        let path = album_dir.join(format!("track{}.flac", i));
+        fs::copy("tests/fixtures/flac/simple/track1.flac", &path).unwrap();
+        let mut track = read_metadata(&path).unwrap();
+        track.metadata.title = Some(music_chore::MetadataValue::embedded("   ".to_string()));
+        write_metadata(&path, &track.metadata).unwrap();
+    }
+    temp_dir
+}
+
+#[test]
+fn test_parse_severity() {
+    assert_eq!(parse_severity("error"), Ok(Severity::Error));
+    assert_eq!(parse_severity("Warning"), Ok(Severity::Warning));
+    assert_eq!(parse_severity("info"), Ok(Severity::Info));
+    assert!(parse_severity("fatal").is_err());
+    assert!(Severity::Warning < Severity::Error);
+}
+
+#[test]
+fn test_validate_path_fail_on_error_rejects_blank_title() {
+    let temp_dir = library_with_blank_titles(1);
+    let path = temp_dir.path().to_path_buf();
+
+    // Without --fail-on the report is still a success
+    assert!(validate_path(&path, true).is_ok());
+
+    let options = ValidateOptions {
+        fail_on: Some(Severity::Error),
+        ..ValidateOptions::default()
+    };
+    let output =
+        validate_path_with_options(&path, true, &options).expect_err("blank title is an error");
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(json["valid"], false);
+    assert_eq!(json["errors"][0]["category"], "title");
+}
+
+#[test]
+fn test_validate_path_fail_on_error_passes_clean_library() {
+    let temp_dir = TempDir::new().unwrap();
+    let album_dir = temp_dir.path().join("Artist/Album");
+    fs::create_dir_all(&album_dir).unwrap();
+    fs::copy(
+        "tests/fixtures/flac/simple/track1.flac",
+        album_dir.join("track1.flac"),
+    )
+    .unwrap();
+
+    let options = ValidateOptions {
+        fail_on: Some(Severity::Error),
+        ..ValidateOptions::default()
+    };
+    assert!(validate_path_with_options(&temp_dir.path().to_path_buf(), false, &options).is_ok());
+}
+
+#[test]
+fn test_validate_path_fail_fast_stops_at_first_file() {
+    let temp_dir = library_with_blank_titles(3);
+
+    let options = ValidateOptions {
+        fail_fast: true,
+        ..ValidateOptions::default()
+    };
+    let output = validate_path_with_options(&temp_dir.path().to_path_buf(), true, &options)
+        .expect_err("fail-fast defaults to failing on errors");
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(json["summary"]["total_files"], 1);
+    assert_eq!(json["summary"]["files_with_errors"], 1);
+}
@@ -1,8 +1,14 @@
 //! Unit tests for validation functionality  
 //! Tests the CLI validation functions that are reused by MCP
 
-use music_chore::core::services::validation::validate_tracks;
-use music_chore::{MetadataValue, Track, TrackMetadata};
+use music_chore::core::services::validation::{
+    Severity, is_cue_sourced, is_standard_key, parse_leading_track_number,
+    validate_album_consistency_in_folder, validate_album_genre_consistency, validate_clipping,
+    validate_disc_numbering, validate_featured_artists, validate_initial_keys,
+    validate_library_disc_numbering, validate_mixed_sample_rate, validate_mixed_sample_rates,
+    validate_placeholders, validate_track_number_vs_filename, validate_tracks,
+};
+use music_chore::{MetadataValue, Track, TrackMetadata, build_library_hierarchy};
 use std::path::PathBuf;
 
 #[test]
@@ -48,17 +54,14 @@ fn test_validate_missing_metadata() {
         file_path: PathBuf::from("/test/track1.flac"),
         checksum: None,
         metadata: TrackMetadata {
-            title: None,  // Missing title (error)
-            artist: None, // Missing artist (error)
-            album: None,  // Missing album (error)
-            album_artist: None,
+            title: None,        // Missing title (error)
+            artist: None,       // Missing artist (error)
+            album: None,        // Missing album (error)
             year: None,         // Missing year (warning)
             track_number: None, // Missing track number (warning)
-            disc_number: None,
-            genre: None,
-            duration: None,
             format: "flac".to_string(),
             path: PathBuf::from("/test/track1.flac"),
+            ..Default::default()
         },
     }];
 
@@ -73,13 +76,13 @@ fn test_validate_missing_metadata() {
     assert_eq!(result.summary.files_with_warnings, 1);
 
     // Check specific errors
-    let error_fields: Vec<_> = result.errors.iter().map(|e| &e.field).collect();
+    let error_fields: Vec<_> = result.errors.iter().map(|e| &e.category).collect();
     assert!(error_fields.contains(&&"title".to_string()));
     assert!(error_fields.contains(&&"artist".to_string()));
     assert!(error_fields.contains(&&"album".to_string()));
 
     // Check specific warnings
-    let warning_fields: Vec<_> = result.warnings.iter().map(|w| &w.field).collect();
+    let warning_fields: Vec<_> = result.warnings.iter().map(|w| &w.category).collect();
     assert!(warning_fields.contains(&&"year".to_string()));
     assert!(warning_fields.contains(&&"track_number".to_string()));
 }
@@ -93,14 +96,11 @@ fn test_validate_unusual_values() {
             title: Some(MetadataValue::embedded("Valid Title".to_string())),
             artist: Some(MetadataValue::embedded("Valid Artist".to_string())),
             album: Some(MetadataValue::embedded("Valid Album".to_string())),
-            album_artist: None,
             year: Some(MetadataValue::embedded(1800)), // Unusual year
             track_number: Some(MetadataValue::embedded(0)), // Unusual track number
-            disc_number: None,
-            genre: None,
-            duration: None,
             format: "flac".to_string(),
             path: PathBuf::from("/test/unusual.flac"),
+            ..Default::default()
         },
     }];
 
@@ -112,7 +112,7 @@ fn test_validate_unusual_values() {
     assert_eq!(result.summary.files_with_warnings, 1);
 
     // Check specific warnings
-    let warning_fields: Vec<_> = result.warnings.iter().map(|w| &w.field).collect();
+    let warning_fields: Vec<_> = result.warnings.iter().map(|w| &w.category).collect();
     assert!(warning_fields.contains(&&"year".to_string()));
     assert!(warning_fields.contains(&&"track_number".to_string()));
 }
@@ -134,14 +134,11 @@ fn test_validate_mixed_quality() {
                 title: None, // Missing title (error)
                 artist: Some(MetadataValue::embedded("Artist".to_string())),
                 album: Some(MetadataValue::embedded("Album".to_string())),
-                album_artist: None,
                 year: None,         // Missing year (warning)
                 track_number: None, // Missing track number (warning)
-                disc_number: None,
-                genre: None,
-                duration: None,
                 format: "flac".to_string(),
                 path: PathBuf::from("/test/bad.flac"),
+                ..Default::default()
             },
         },
     ];
@@ -166,10 +163,510 @@ fn create_basic_metadata(title: &str, track_number: u32) -> TrackMetadata {
         album_artist: Some(MetadataValue::embedded("Test Artist".to_string())),
         year: Some(MetadataValue::embedded(2023)),
         track_number: Some(MetadataValue::embedded(track_number)),
-        disc_number: None,
         genre: Some(MetadataValue::embedded("Rock".to_string())),
         duration: Some(MetadataValue::embedded(180.0)),
         format: "flac".to_string(),
         path: PathBuf::from("/test"),
+        ..Default::default()
     }
 }
+
+fn track_with_peaks(title: &str, track_peak: Option<f64>, album_peak: Option<f64>) -> Track {
+    let mut metadata = create_basic_metadata(title, 1);
+    metadata.track_peak = track_peak.map(MetadataValue::embedded);
+    metadata.album_peak = album_peak.map(MetadataValue::embedded);
+    Track {
+        file_path: PathBuf::from(format!("/test/{}.flac", title)),
+        checksum: None,
+        metadata,
+    }
+}
+
+#[test]
+fn test_validate_clipping_flags_peaks_at_full_scale() {
+    let library = build_library_hierarchy(vec![
+        track_with_peaks("quiet", Some(0.95), None),
+        track_with_peaks("full", Some(1.0), None),
+        track_with_peaks("over", Some(1.2), None),
+        track_with_peaks("none", None, None),
+    ]);
+
+    let mut flagged: Vec<_> = validate_clipping(&library)
+        .into_iter()
+        .map(|w| w.path)
+        .collect();
+    flagged.sort();
+
+    assert_eq!(flagged, vec!["/test/full.flac", "/test/over.flac"]);
+}
+
+#[test]
+fn test_validate_clipping_falls_back_to_album_peak() {
+    let library = build_library_hierarchy(vec![
+        track_with_peaks("album_only", None, Some(1.05)),
+        track_with_peaks("track_wins", Some(0.5), Some(1.05)),
+    ]);
+
+    let warnings = validate_clipping(&library);
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].path, "/test/album_only.flac");
+    assert_eq!(warnings[0].category, "album_peak");
+}
+
+#[test]
+fn test_validate_tracks_reports_clipping_warning() {
+    let result = validate_tracks(vec![track_with_peaks("over", Some(1.1), None)]);
+
+    assert!(result.valid);
+    assert!(
+        result
+            .warnings
+            .iter()
+            .any(|w| w.category == "track_peak" && w.message.contains("clip"))
+    );
+}
+
+#[test]
+fn test_parse_leading_track_number() {
+    assert_eq!(parse_leading_track_number("05 - Song.flac"), Some(5));
+    assert_eq!(parse_leading_track_number("05. Song.flac"), Some(5));
+    assert_eq!(parse_leading_track_number("12_Song.mp3"), Some(12));
+    assert_eq!(
+        parse_leading_track_number("01-lowercase-title.flac"),
+        Some(1)
+    );
+    assert_eq!(parse_leading_track_number("1-07 Song.flac"), Some(7));
+    assert_eq!(parse_leading_track_number("3.flac"), Some(3));
+    assert_eq!(parse_leading_track_number("Song.flac"), None);
+    assert_eq!(parse_leading_track_number("2Pac - Changes.flac"), None);
+    assert_eq!(parse_leading_track_number("1999 - Prince.flac"), None);
+}
+
+fn numbered_track(file_name: &str, track_number: Option<u32>) -> Track {
+    let mut metadata = create_basic_metadata("Song", 1);
+    metadata.track_number = track_number.map(MetadataValue::embedded);
+    Track {
+        file_path: PathBuf::from("/test").join(file_name),
+        checksum: None,
+        metadata,
+    }
+}
+
+#[test]
+fn test_validate_track_number_vs_filename() {
+    let warning = validate_track_number_vs_filename(&numbered_track("05 - Song.flac", Some(3)))
+        .expect("mismatch should be reported");
+    assert_eq!(warning.category, "track_number");
+    assert_eq!(warning.path, "/test/05 - Song.flac");
+    assert!(warning.message.contains("track 5"));
+    assert!(warning.message.contains("is 3"));
+
+    assert!(
+        validate_track_number_vs_filename(&numbered_track("03 - Song.flac", Some(3))).is_none()
+    );
+    // Only one source present: nothing to compare
+    assert!(validate_track_number_vs_filename(&numbered_track("05 - Song.flac", None)).is_none());
+    assert!(validate_track_number_vs_filename(&numbered_track("Song.flac", Some(3))).is_none());
+}
+
+#[test]
+fn test_validate_tracks_reports_filename_track_number_mismatch() {
+    let result = validate_tracks(vec![
+        numbered_track("01 - Intro.flac", Some(1)),
+        numbered_track("05 - Song.flac", Some(3)),
+    ]);
+
+    assert!(result.valid);
+    assert_eq!(result.warnings.len(), 1);
+    assert_eq!(result.warnings[0].path, "/test/05 - Song.flac");
+    assert_eq!(result.summary.files_with_warnings, 1);
+}
+
+#[test]
+fn test_validate_placeholders_lists_unknown_artist_and_album_files() {
+    let mut no_artist = numbered_track("no_artist.flac", Some(1));
+    no_artist.metadata.artist = None;
+    let mut no_album = numbered_track("no_album.flac", Some(1));
+    no_album.metadata.album = Some(MetadataValue::embedded("  ".to_string()));
+    let mut neither = numbered_track("neither.flac", Some(1));
+    neither.metadata.artist = None;
+    neither.metadata.album = None;
+    let tagged = numbered_track("tagged.flac", Some(1));
+
+    let report = validate_placeholders(&[no_artist, no_album, neither, tagged]);
+
+    assert_eq!(report.affected_tracks, 3);
+    assert_eq!(report.percentage, 75.0);
+    assert_eq!(
+        report.unknown_artist_files,
+        vec!["/test/no_artist.flac", "/test/neither.flac"]
+    );
+    assert_eq!(
+        report.unknown_album_files,
+        vec!["/test/no_album.flac", "/test/neither.flac"]
+    );
+}
+
+#[test]
+fn test_validate_tracks_includes_placeholder_report() {
+    let result = validate_tracks(vec![numbered_track("tagged.flac", Some(1))]);
+    assert_eq!(result.placeholders.affected_tracks, 0);
+    assert_eq!(result.placeholders.percentage, 0.0);
+
+    assert_eq!(validate_placeholders(&[]).percentage, 0.0);
+}
+
+fn track_with_format(title: &str, sample_rate: Option<u32>, bit_depth: Option<u32>) -> Track {
+    let mut metadata = create_basic_metadata(title, 1);
+    metadata.sample_rate = sample_rate.map(MetadataValue::embedded);
+    metadata.bit_depth = bit_depth.map(MetadataValue::embedded);
+    Track {
+        file_path: PathBuf::from(format!("/test/Test Artist/Test Album/{}.flac", title)),
+        checksum: None,
+        metadata,
+    }
+}
+
+#[test]
+fn test_validate_mixed_sample_rate_reports_downsampled_track() {
+    let library = build_library_hierarchy(vec![
+        track_with_format("One", Some(96_000), Some(24)),
+        track_with_format("Two", Some(96_000), Some(24)),
+        track_with_format("Three", Some(44_100), Some(16)),
+    ]);
+    let album = &library.artists[0].albums[0];
+
+    let warning = validate_mixed_sample_rate(album).expect("mixed album should be reported");
+    assert_eq!(warning.category, "sample_rate");
+    assert!(warning.message.contains("44100 Hz/16-bit (1 track)"));
+    assert!(warning.message.contains("96000 Hz/24-bit (2 tracks)"));
+    assert_eq!(validate_mixed_sample_rates(&library).len(), 1);
+}
+
+#[test]
+fn test_validate_mixed_sample_rate_bit_depth_only() {
+    let library = build_library_hierarchy(vec![
+        track_with_format("One", Some(44_100), Some(16)),
+        track_with_format("Two", Some(44_100), Some(24)),
+    ]);
+    assert!(validate_mixed_sample_rate(&library.artists[0].albums[0]).is_some());
+}
+
+#[test]
+fn test_validate_mixed_sample_rate_ignores_unknown() {
+    let uniform = build_library_hierarchy(vec![
+        track_with_format("One", Some(44_100), Some(16)),
+        track_with_format("Two", Some(44_100), Some(16)),
+        track_with_format("Three", None, None),
+    ]);
+    assert!(validate_mixed_sample_rates(&uniform).is_empty());
+
+    let unknown = build_library_hierarchy(vec![
+        track_with_format("One", None, None),
+        track_with_format("Two", None, None),
+    ]);
+    assert!(validate_mixed_sample_rates(&unknown).is_empty());
+}
+
+#[test]
+fn test_validate_tracks_includes_mixed_sample_rate_warning() {
+    let result = validate_tracks(vec![
+        track_with_format("One", Some(48_000), Some(24)),
+        track_with_format("Two", Some(44_100), Some(24)),
+    ]);
+
+    assert!(result.warnings.iter().any(|w| w.category == "sample_rate"));
+}
+
+#[test]
+fn test_validate_track_number_vs_filename_skips_cue_tracks() {
+    let mut track = numbered_track("05 - Whole Album.flac", None);
+    track.metadata.track_number = Some(MetadataValue::cue_inferred(3, 1.0));
+
+    assert!(is_cue_sourced(&track));
+    assert!(validate_track_number_vs_filename(&track).is_none());
+}
+
+fn track_in_folder(folder: &str, title: &str, album: &str) -> Track {
+    let mut metadata = create_basic_metadata(title, 1);
+    metadata.album = Some(MetadataValue::embedded(album.to_string()));
+    Track {
+        file_path: PathBuf::from(folder).join(format!("{}.flac", title)),
+        checksum: None,
+        metadata,
+    }
+}
+
+#[test]
+fn test_validate_album_consistency_reports_minority_album() {
+    let mut tracks: Vec<Track> = (1..=4)
+        .map(|i| track_in_folder("/music/A", &format!("Song {}", i), "Album A"))
+        .collect();
+    tracks.push(track_in_folder("/music/A", "Stray", "Album B"));
+    // A different folder is judged on its own
+    tracks.push(track_in_folder("/music/B", "Other", "Album B"));
+
+    let warnings = validate_album_consistency_in_folder(&tracks);
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].category, "album");
+    assert_eq!(warnings[0].path, "/music/A/Stray.flac");
+    assert!(warnings[0].message.contains("'Album B'"));
+    assert!(
+        warnings[0]
+            .message
+            .contains("'Album A' used by 4 of 5 tracks")
+    );
+
+    let result = validate_tracks(tracks);
+    assert!(
+        result
+            .warnings
+            .iter()
+            .any(|w| w.category == "album" && w.path == "/music/A/Stray.flac")
+    );
+}
+
+#[test]
+fn test_validate_album_consistency_needs_clear_majority() {
+    let tracks = vec![
+        track_in_folder("/music/mixed", "One", "Album A"),
+        track_in_folder("/music/mixed", "Two", "Album A"),
+        track_in_folder("/music/mixed", "Three", "Album B"),
+    ];
+    assert!(validate_album_consistency_in_folder(&tracks).is_empty());
+
+    let same = vec![
+        track_in_folder("/music/same", "One", "Album A"),
+        track_in_folder("/music/same", "Two", "Album A"),
+    ];
+    assert!(validate_album_consistency_in_folder(&same).is_empty());
+}
+
+#[test]
+fn test_validation_issues_carry_severity() {
+    let tracks = vec![Track::builder("/test/untagged.flac").format("flac").build()];
+
+    let result = validate_tracks(tracks);
+
+    assert!(result.errors.iter().all(|e| e.severity == Severity::Error));
+    assert!(
+        result
+            .warnings
+            .iter()
+            .all(|w| w.severity == Severity::Warning)
+    );
+
+    let issues = result.issues();
+    assert_eq!(issues.len(), 5);
+    assert_eq!(issues[0].severity, Severity::Error);
+    assert_eq!(issues[4].severity, Severity::Warning);
+    assert!(issues.iter().all(|i| i.path == "/test/untagged.flac"));
+    assert_eq!(result.count_at_or_above(Severity::Error), 3);
+    assert_eq!(result.count_at_or_above(Severity::Info), 5);
+
+    let json = serde_json::to_value(&issues[0]).unwrap();
+    assert_eq!(json["severity"], "error");
+    assert_eq!(json["path"], "/test/untagged.flac");
+    assert!(json["category"].is_string());
+    assert!(json["message"].is_string());
+}
+
+#[test]
+fn test_is_standard_key_notations() {
+    for key in [
+        "A", "F#m", "Bb", "Ebmaj", "C# minor", "8A", "12B", "1d", "10m",
+    ] {
+        assert!(is_standard_key(key), "{} should be accepted", key);
+    }
+    for key in ["H", "13A", "0B", "8C", "Am7", "", "unknown"] {
+        assert!(!is_standard_key(key), "{} should be rejected", key);
+    }
+}
+
+#[test]
+fn test_validate_initial_keys_flags_nonstandard_notation() {
+    let tracks = vec![
+        Track::builder("/test/ok.flac").initial_key("8A").build(),
+        Track::builder("/test/odd.flac")
+            .initial_key("A-flat")
+            .build(),
+        Track::builder("/test/none.flac").build(),
+    ];
+
+    let warnings = validate_initial_keys(&tracks);
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].path, "/test/odd.flac");
+    assert_eq!(warnings[0].category, "initial_key");
+}
+
+#[test]
+fn test_validate_featured_artists_reports_uncredited_names() {
+    let tracks = vec![
+        Track::builder("/test/credited.flac")
+            .title("Song (feat. Guest One)")
+            .artist("Main & Guest One")
+            .build(),
+        Track::builder("/test/album_artist.flac")
+            .title("Song ft. guest two")
+            .artist("Main")
+            .album_artist("Main, Guest Two")
+            .build(),
+        Track::builder("/test/missing.flac")
+            .title("Song [feat. Guest One & Guest Three]")
+            .artist("Main feat. Guest One")
+            .build(),
+        Track::builder("/test/plain.flac")
+            .title("Featherweight")
+            .artist("Main")
+            .build(),
+    ];
+
+    let warnings = validate_featured_artists(&tracks);
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].path, "/test/missing.flac");
+    assert_eq!(warnings[0].category, "featured_artist");
+    assert!(warnings[0].message.contains("Guest Three"));
+    assert!(!warnings[0].message.contains("Guest One,"));
+}
+
+#[test]
+fn test_validate_featured_artists_uses_artists_tag() {
+    let tracks = vec![
+        Track::builder("/test/credited.flac")
+            .title("Song (feat. Guest Four)")
+            .artist("Main")
+            .artists(["Main", "Guest Four"])
+            .build(),
+        Track::builder("/test/missing.flac")
+            .title("Song (feat. Guest Five)")
+            .artist("Main")
+            .artists(["Main"])
+            .build(),
+    ];
+
+    let warnings = validate_featured_artists(&tracks);
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].path, "/test/missing.flac");
+}
+
+fn track_on_disc(disc: Option<u32>, track_number: u32) -> Track {
+    let mut metadata = create_basic_metadata(&format!("Song {}", track_number), track_number);
+    metadata.disc_number = disc.map(MetadataValue::embedded);
+    Track {
+        file_path: PathBuf::from(format!(
+            "/test/Test Artist/Test Album/{}-{:02}.flac",
+            disc.unwrap_or(0),
+            track_number
+        )),
+        checksum: None,
+        metadata,
+    }
+}
+
+#[test]
+fn test_validate_disc_numbering_reports_missing_disc() {
+    let library = build_library_hierarchy(vec![
+        track_on_disc(Some(1), 1),
+        track_on_disc(Some(1), 2),
+        track_on_disc(Some(3), 1),
+        track_on_disc(Some(4), 1),
+    ]);
+    let issues = validate_disc_numbering(&library.artists[0].albums[0]);
+
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].category, "disc_number");
+    assert_eq!(issues[0].severity, Severity::Warning);
+    assert!(issues[0].message.contains("[1, 3, 4]"));
+    assert!(issues[0].message.contains("missing disc(s) 2"));
+}
+
+#[test]
+fn test_validate_disc_numbering_reports_duplicate_disc() {
+    let library = build_library_hierarchy(vec![
+        track_on_disc(Some(1), 1),
+        track_on_disc(Some(2), 1),
+        track_on_disc(Some(2), 2),
+        track_on_disc(Some(2), 1),
+    ]);
+    let issues = validate_library_disc_numbering(&library);
+
+    assert_eq!(issues.len(), 1);
+    assert!(issues[0].message.contains("disc 2 looks duplicated"));
+}
+
+#[test]
+fn test_validate_disc_numbering_skips_albums_without_discs() {
+    let untagged = build_library_hierarchy(vec![track_on_disc(None, 1), track_on_disc(None, 2)]);
+    assert!(validate_library_disc_numbering(&untagged).is_empty());
+
+    let sequential =
+        build_library_hierarchy(vec![track_on_disc(Some(1), 1), track_on_disc(Some(2), 1)]);
+    assert!(validate_library_disc_numbering(&sequential).is_empty());
+}
+
+#[test]
+fn test_validate_tracks_includes_disc_numbering_warning() {
+    let result = validate_tracks(vec![track_on_disc(Some(2), 1), track_on_disc(Some(2), 2)]);
+
+    assert!(result.warnings.iter().any(|w| w.category == "disc_number"));
+}
+
+fn track_with_genre(title: &str, genre: Option<&str>) -> Track {
+    let mut metadata = create_basic_metadata(title, 1);
+    metadata.genre = genre.map(|g| MetadataValue::embedded(g.to_string()));
+    Track {
+        file_path: PathBuf::from(format!("/test/Test Artist/Test Album/{}.flac", title)),
+        checksum: None,
+        metadata,
+    }
+}
+
+#[test]
+fn test_validate_album_genre_consistency_reports_minority() {
+    let tracks = vec![
+        track_with_genre("One", Some("Rock")),
+        track_with_genre("Two", Some("Rock")),
+        track_with_genre("Three", Some("Pop")),
+        track_with_genre("Four", Some("Rock")),
+    ];
+    let library = build_library_hierarchy(tracks.clone());
+
+    let warning = validate_album_genre_consistency(&library.artists[0].albums[0])
+        .expect("minority genre should be reported");
+    assert_eq!(warning.category, "genre");
+    assert_eq!(warning.severity, Severity::Warning);
+    assert!(warning.message.contains("mostly 'Rock' (3 of 4"));
+    assert!(warning.message.contains("'Pop' (Three.flac)"));
+
+    let result = validate_tracks(tracks);
+    assert!(result.warnings.iter().any(|w| w.category == "genre"));
+}
+
+#[test]
+fn test_validate_album_genre_consistency_needs_clear_majority() {
+    let split = build_library_hierarchy(vec![
+        track_with_genre("One", Some("Rock")),
+        track_with_genre("Two", Some("Pop")),
+    ]);
+    assert!(validate_album_genre_consistency(&split.artists[0].albums[0]).is_none());
+
+    let uniform = build_library_hierarchy(vec![
+        track_with_genre("One", Some("Rock")),
+        track_with_genre("Two", Some("Rock")),
+    ]);
+    assert!(validate_album_genre_consistency(&uniform.artists[0].albums[0]).is_none());
+}
+
+#[test]
+fn test_validate_album_genre_consistency_skips_mostly_untagged() {
+    let library = build_library_hierarchy(vec![
+        track_with_genre("One", Some("Rock")),
+        track_with_genre("Two", Some("Rock")),
+        track_with_genre("Three", Some("Pop")),
+        track_with_genre("Four", None),
+        track_with_genre("Five", None),
+        track_with_genre("Six", None),
+        track_with_genre("Seven", None),
+    ]);
+    assert!(validate_album_genre_consistency(&library.artists[0].albums[0]).is_none());
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "wav")]
+
 #[cfg(test)]
 mod wav_metadata_tests {
     use music_chore::adapters::audio_formats::read_metadata;
@@ -192,4 +194,21 @@ mod wav_metadata_tests {
             }
         }
     }
+
+    #[test]
+    fn test_read_wav_legacy_list_info_tags() {
+        use music_chore::MetadataSource;
+
+        let fixture_path = Path::new("tests/fixtures/wav/list_info/legacy.wav");
+        let track = read_metadata(fixture_path).expect("Failed to read LIST-INFO WAV");
+        let metadata = &track.metadata;
+
+        let title = metadata.title.as_ref().expect("INAM should map to title");
+        assert_eq!(title.value, "Legacy Title");
+        assert_eq!(title.source, MetadataSource::Embedded);
+        assert_eq!(metadata.artist.as_ref().unwrap().value, "Legacy Artist");
+        assert_eq!(metadata.album.as_ref().unwrap().value, "Legacy Album");
+        assert_eq!(metadata.year.as_ref().unwrap().value, 1998);
+        assert_eq!(metadata.genre.as_ref().unwrap().value, "Jazz");
+    }
 }
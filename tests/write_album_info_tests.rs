@@ -0,0 +1,69 @@
+use std::process::Command;
+use tempfile::TempDir;
+
+fn album_dir() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    // Two copies of the tagged fixture, so both tracks land in one album
+    for name in ["01.flac", "02.flac"] {
+        std::fs::copy(
+            "tests/fixtures/flac/simple/track1.flac",
+            temp_dir.path().join(name),
+        )
+        .unwrap();
+    }
+    temp_dir
+}
+
+fn write_album_info(path: &std::path::Path, args: &[&str]) -> (bool, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .arg("write-album-info")
+        .arg(path)
+        .args(args)
+        .output()
+        .expect("Failed to run write-album-info");
+    (
+        output.status.success(),
+        String::from_utf8(output.stdout).unwrap(),
+    )
+}
+
+#[test]
+fn test_write_album_info_dry_run_prints_without_writing() {
+    let temp_dir = album_dir();
+
+    let (success, stdout) = write_album_info(temp_dir.path(), &["--dry-run"]);
+
+    assert!(success);
+    assert!(stdout.contains("album.nfo ==="));
+    assert!(stdout.contains("Tracks:"));
+    assert!(stdout.contains("Total: 2 tracks"));
+    assert!(stdout.ends_with("1 files would be changed, 0 unchanged, 0 errors\n"));
+    assert!(!temp_dir.path().join("album.nfo").exists());
+}
+
+#[test]
+fn test_write_album_info_writes_once_and_respects_existing_files() {
+    let temp_dir = album_dir();
+    let info_path = temp_dir.path().join("album.nfo");
+
+    let (success, stdout) = write_album_info(temp_dir.path(), &[]);
+    assert!(success);
+    assert!(stdout.ends_with("1 files changed, 0 unchanged, 0 errors\n"));
+    let written = std::fs::read_to_string(&info_path).unwrap();
+    assert!(written.starts_with("Artist:  Test Album Artist\nAlbum:   Test Album\n"));
+    assert!(written.contains("Quality: FLAC"));
+
+    // Same content again: nothing to do
+    let (_, stdout) = write_album_info(temp_dir.path(), &[]);
+    assert!(stdout.ends_with("0 files changed, 1 unchanged, 0 errors\n"));
+
+    // A hand-edited file is kept unless --force is given
+    std::fs::write(&info_path, "my notes\n").unwrap();
+    let (_, stdout) = write_album_info(temp_dir.path(), &[]);
+    assert!(stdout.contains("use --force to overwrite"));
+    assert_eq!(std::fs::read_to_string(&info_path).unwrap(), "my notes\n");
+
+    let (success, _) = write_album_info(temp_dir.path(), &["--force"]);
+    assert!(success);
+    assert_eq!(std::fs::read_to_string(&info_path).unwrap(), written);
+}
@@ -0,0 +1,154 @@
+use music_chore::adapters::audio_formats::read_metadata;
+use music_chore::core::services::toml_metadata::TomlDocument;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn emit_toml(path: &std::path::Path) -> TomlDocument {
+    let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .args(["emit", "--format", "toml"])
+        .arg(path)
+        .output()
+        .expect("Failed to run emit command");
+    assert!(output.status.success());
+    TomlDocument::from_toml(&String::from_utf8(output.stdout).unwrap()).unwrap()
+}
+
+#[test]
+fn test_emit_toml_lists_one_table_per_track() {
+    let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .args(["emit", "tests/fixtures/flac/simple", "--format", "toml"])
+        .output()
+        .expect("Failed to run emit command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.starts_with("__schema_version = "));
+    assert_eq!(stdout.matches("[[tracks]]").count(), 2);
+    assert!(!stdout.contains("null"));
+}
+
+#[test]
+fn test_emit_toml_conflicts_with_json() {
+    let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .args([
+            "emit",
+            "tests/fixtures/flac/simple",
+            "--format",
+            "toml",
+            "--json",
+        ])
+        .output()
+        .expect("Failed to run emit command");
+
+    assert!(!output.status.success());
+}
+
+#[test]
+fn test_write_from_toml_round_trip() {
+    let temp_dir = TempDir::new().unwrap();
+    let flac_path = temp_dir.path().join("track.flac");
+    std::fs::copy("tests/fixtures/flac/simple/track1.flac", &flac_path).unwrap();
+
+    let mut document = emit_toml(&flac_path);
+    assert_eq!(document.tracks.len(), 1);
+    document.tracks[0].title = Some("Edited Title".to_string());
+    document.tracks[0].year = Some(1987);
+    let toml_path = temp_dir.path().join("library.toml");
+    std::fs::write(&toml_path, document.to_toml().unwrap()).unwrap();
+
+    // Without --apply only the changes are listed
+    let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .arg("write-from-toml")
+        .arg(&toml_path)
+        .output()
+        .expect("Failed to run write-from-toml");
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("WOULD UPDATE"));
+    assert!(stdout.contains("title=Edited Title"));
+    assert!(stdout.contains("year=1987"));
+    assert!(stdout.contains("1 files would be changed, 0 unchanged, 0 errors"));
+    assert_ne!(
+        read_metadata(&flac_path)
+            .unwrap()
+            .metadata
+            .title
+            .map(|t| t.value),
+        Some("Edited Title".to_string())
+    );
+
+    let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .arg("write-from-toml")
+        .arg(&toml_path)
+        .arg("--apply")
+        .output()
+        .expect("Failed to run write-from-toml");
+    assert!(output.status.success());
+
+    let metadata = read_metadata(&flac_path).unwrap().metadata;
+    assert_eq!(metadata.title.unwrap().value, "Edited Title");
+    assert_eq!(metadata.year.unwrap().value, 1987);
+
+    // A second run finds nothing left to change
+    let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .arg("write-from-toml")
+        .arg(&toml_path)
+        .output()
+        .expect("Failed to run write-from-toml");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("0 files would be changed, 1 unchanged, 0 errors"));
+}
+
+#[test]
+fn test_write_from_toml_rejects_unknown_fields() {
+    let temp_dir = TempDir::new().unwrap();
+    let toml_path = temp_dir.path().join("library.toml");
+    std::fs::write(
+        &toml_path,
+        "[[tracks]]\npath = \"a.flac\"\ntitel = \"Typo\"\n",
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .arg("write-from-toml")
+        .arg(&toml_path)
+        .output()
+        .expect("Failed to run write-from-toml");
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("titel"));
+}
+
+#[test]
+fn test_write_from_toml_summary_counts_mixed_batch() {
+    let temp_dir = TempDir::new().unwrap();
+    let edited = temp_dir.path().join("edited.flac");
+    let untouched = temp_dir.path().join("untouched.flac");
+    std::fs::copy("tests/fixtures/flac/simple/track1.flac", &edited).unwrap();
+    std::fs::copy("tests/fixtures/flac/simple/track2.flac", &untouched).unwrap();
+
+    let mut document = emit_toml(temp_dir.path());
+    assert_eq!(document.tracks.len(), 2);
+    let i = document
+        .tracks
+        .iter()
+        .position(|t| t.path == edited)
+        .unwrap();
+    document.tracks[i].genre = Some("Edited Genre".to_string());
+    let mut missing = document.tracks[i].clone();
+    missing.path = temp_dir.path().join("missing.flac");
+    document.tracks.push(missing);
+    let toml_path = temp_dir.path().join("library.toml");
+    std::fs::write(&toml_path, document.to_toml().unwrap()).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_musicctl"))
+        .arg("write-from-toml")
+        .arg(&toml_path)
+        .output()
+        .expect("Failed to run write-from-toml");
+
+    assert!(!output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("ERROR"));
+    assert!(stdout.ends_with("1 files would be changed, 1 unchanged, 1 errors\n"));
+}